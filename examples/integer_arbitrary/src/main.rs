@@ -33,6 +33,13 @@ struct LessThan2(u128);
 #[nutype(validate(less = 2), derive(Arbitrary, Debug))]
 struct LessOrEqual2(u128);
 
+// Zero is excluded, so the NonZeroSmall can only be -1 or 1.
+#[nutype(
+    validate(greater_or_equal = -1, less_or_equal = 1, non_zero),
+    derive(Arbitrary, Debug)
+)]
+struct NonZeroSmall(i8);
+
 fn main() {
     arbtest::builder().run(|u| {
         let tax_class = GermanTaxClass::arbitrary(u)?.into_inner();
@@ -70,4 +77,10 @@ fn main() {
         assert!(value == 0 || value == 1 || value == 2);
         Ok(())
     });
+
+    arbtest::builder().run(|u| {
+        let value = NonZeroSmall::arbitrary(u)?.into_inner();
+        assert!(value == -1 || value == 1);
+        Ok(())
+    });
 }
@@ -0,0 +1,51 @@
+// This example exists to ensure that the integer and float types, whose errors carry
+// a message or a list of errors, as well as the string types, can compile in no_std
+// environment with `alloc`.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::{String, ToString};
+use nutype::nutype;
+
+fn validate_tax_class(value: &u8) -> Result<(), String> {
+    if *value == 0 {
+        return Err("tax class 0 does not exist".to_string());
+    }
+    Ok(())
+}
+
+#[nutype(
+    validate(with_result = validate_tax_class, less_or_equal = 6),
+    derive(Debug, Clone, Copy, PartialEq, Eq, FromStr, Display)
+)]
+pub struct GermanTaxClass(u8);
+
+#[nutype(
+    sanitize(try_with = |value: f32| if value.is_nan() { Err("NaN") } else { Ok(value) }),
+    validate(greater_or_equal = 0.0, less_or_equal = 1024.0),
+    derive(Debug, Clone, Copy, PartialEq, FromStr, Display)
+)]
+pub struct Width(f32);
+
+#[nutype(
+    validate(greater_or_equal = -40, less_or_equal = 85, predicate = |t| t % 5 == 0),
+    collect_errors,
+    derive(Debug, Clone, Copy, PartialEq, Eq)
+)]
+pub struct Temperature(i16);
+
+/// Describe why the temperature is invalid, e.g. to show it on a display.
+pub fn describe_temperature(value: i16) -> String {
+    match Temperature::new(value) {
+        Ok(_) => String::new(),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[nutype(
+    sanitize(trim),
+    validate(not_empty, len_char_max = 20),
+    derive(Debug, Clone, PartialEq, Eq, AsRef, Display)
+)]
+pub struct Username(String);
@@ -60,6 +60,20 @@ struct GermanTaxClass(i64);
 )]
 struct Width(f64);
 
+#[nutype(
+    validate(predicate = |x| x % 2 == 0, one_of = [2, 4, 8]),
+    non_exhaustive_error,
+    derive(Debug, Clone, Copy, PartialEq, FromStr, Display, TryFrom, CheckedArith)
+)]
+struct PowerOfTwo(u32);
+
+#[nutype(
+    validate(greater = 0, less = 100),
+    enumerate,
+    derive(Debug, Clone, Copy, PartialEq)
+)]
+struct Percent(u8);
+
 // NOTE: strings are not working yet with no_std
 
 // Any other type
@@ -0,0 +1,45 @@
+// This example exists to ensure that serde implementations generated by nutype macro
+// can compile in no_std environment without an allocator.
+// postcard is used as a serde format, since it's a common choice for embedded.
+#![no_std]
+
+use nutype::nutype;
+
+#[nutype(
+    validate(greater_or_equal = 1, less_or_equal = 6),
+    derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)
+)]
+pub struct GermanTaxClass(u8);
+
+#[nutype(
+    validate(greater_or_equal = 0.0, less_or_equal = 1024.0, finite),
+    derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)
+)]
+pub struct Width(f32);
+
+/// Serialize the tax class into the given buffer, returning the used part of it.
+pub fn encode_tax_class<'a>(
+    tax_class: &GermanTaxClass,
+    buf: &'a mut [u8],
+) -> Result<&'a mut [u8], postcard::Error> {
+    postcard::to_slice(tax_class, buf)
+}
+
+/// Deserialize the tax class. Values out of the range are rejected by the validation.
+pub fn decode_tax_class(bytes: &[u8]) -> Result<GermanTaxClass, postcard::Error> {
+    postcard::from_bytes(bytes)
+}
+
+/// Serialize and deserialize a valid tax class, then make sure that an invalid one is rejected.
+pub fn roundtrip() -> bool {
+    let mut buf = [0u8; 8];
+    let tax_class = GermanTaxClass::new(3).unwrap();
+
+    let Ok(bytes) = encode_tax_class(&tax_class, &mut buf) else {
+        return false;
+    };
+    let decoded = decode_tax_class(bytes);
+    let is_invalid_rejected = decode_tax_class(&[7]).is_err();
+
+    matches!(decoded, Ok(value) if value == tax_class) && is_invalid_rejected
+}
@@ -0,0 +1,10 @@
+//! A crate that re-exports `nutype`, so its users don't need to depend on it directly.
+
+pub use nutype::nutype;
+
+/// The crates the code generated by `nutype` refers to.
+/// Pass it to the macro with `#[nutype(crate = facade::reexports)]`.
+pub mod reexports {
+    pub use nutype;
+    pub use serde;
+}
@@ -0,0 +1,36 @@
+use facade::nutype;
+
+#[nutype(
+    crate = facade::reexports,
+    sanitize(trim, lowercase),
+    validate(not_empty, len_char_max = 20),
+    derive(Debug, Clone, PartialEq, AsRef, Serialize, Deserialize),
+)]
+pub struct Username(String);
+
+#[nutype(
+    crate = facade::reexports,
+    validate(greater_or_equal = 18, less_or_equal = 150),
+    derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize),
+)]
+pub struct Age(u8);
+
+fn main() {
+    let username = Username::new("  Alice ").unwrap();
+    assert_eq!(username.as_ref(), "alice");
+    assert_eq!(
+        Username::new("   ").unwrap_err(),
+        UsernameError::NotEmptyViolated
+    );
+
+    let json = serde_json::to_string(&username).unwrap();
+    assert_eq!(json, r#""alice""#);
+    let username: Username = serde_json::from_str(r#""BOB""#).unwrap();
+    assert_eq!(username.into_inner(), "bob");
+
+    let age: Age = serde_json::from_str("42").unwrap();
+    assert_eq!(age.into_inner(), 42);
+    assert!(serde_json::from_str::<Age>("17").is_err());
+
+    println!("Done");
+}
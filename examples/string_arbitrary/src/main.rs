@@ -0,0 +1,71 @@
+use arbitrary::Arbitrary;
+use nutype::nutype;
+
+// Without validation any string is generated.
+#[nutype(sanitize(trim), derive(Arbitrary, Debug))]
+struct Comment(String);
+
+// The value is picked from the allowed ones.
+#[nutype(
+    validate(one_of = ["draft", "published", "archived"]),
+    derive(Arbitrary, Debug)
+)]
+struct Status(String);
+
+// The length and the characters are respected by construction.
+#[nutype(
+    validate(len_char_min = 3, len_char_max = 12, alphanumeric),
+    derive(Arbitrary, Debug)
+)]
+struct Username(String);
+
+// The prefix is glued to the generated string.
+#[nutype(
+    validate(
+        starts_with = "#",
+        len_char_max = 8,
+        contains_only = "#0123456789ABCDEF"
+    ),
+    derive(Arbitrary, Debug)
+)]
+struct HexColor(String);
+
+// A random string would almost never match a regex, so such input is rejected
+// and the fuzzer moves on to the next one.
+#[nutype(validate(regex = "^[0-9]{3}$"), derive(Arbitrary, Debug))]
+struct AreaCode(String);
+
+fn main() {
+    arbtest::builder().run(|u| {
+        let comment = Comment::arbitrary(u)?.into_inner();
+        assert_eq!(comment, comment.trim());
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let status = Status::arbitrary(u)?.into_inner();
+        assert!(["draft", "published", "archived"].contains(&status.as_str()));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let username = Username::arbitrary(u)?.into_inner();
+        let len = username.chars().count();
+        assert!((3..=12).contains(&len));
+        assert!(username.chars().all(|c| c.is_ascii_alphanumeric()));
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let color = HexColor::arbitrary(u)?.into_inner();
+        assert!(color.starts_with('#'));
+        assert!(color.chars().count() <= 8);
+        Ok(())
+    });
+
+    arbtest::builder().run(|u| {
+        let area_code = AreaCode::arbitrary(u)?.into_inner();
+        assert_eq!(area_code.len(), 3);
+        Ok(())
+    });
+}
@@ -0,0 +1,35 @@
+//! Base64 decoding used by `from_base64`. Requires `base64` feature.
+
+use ::base64::Engine;
+
+/// The error returned when a string is not a valid base64 encoding.
+pub use ::base64::DecodeError;
+
+/// Decodes a base64 string into bytes.
+///
+/// The standard alphabet ([RFC 4648](https://datatracker.ietf.org/doc/html/rfc4648#section-4))
+/// with padding is expected:
+///
+/// ```
+/// use nutype::base64::decode;
+///
+/// assert_eq!(decode("wP/u"), Ok(vec![0xC0, 0xFF, 0xEE]));
+/// assert_eq!(decode("AQ=="), Ok(vec![0x01]));
+/// assert!(decode("AQ").is_err());
+/// ```
+pub fn decode(value: &str) -> Result<Vec<u8>, DecodeError> {
+    ::base64::engine::general_purpose::STANDARD.decode(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode(""), Ok(vec![]));
+        assert_eq!(decode("aGVsbG8="), Ok(b"hello".to_vec()));
+        assert_eq!(decode("aGVsbG8"), Err(DecodeError::InvalidPadding));
+        assert_eq!(decode("a$=="), Err(DecodeError::InvalidByte(1, b'$')));
+    }
+}
@@ -0,0 +1,156 @@
+//! Email syntax check used by `validate(email)`. Requires `email` feature.
+
+/// Max length of an email address (RFC 5321 limits the forward path to 256 octets,
+/// including the enclosing angle brackets).
+const MAX_EMAIL_LEN: usize = 254;
+
+/// Max length of the local part (before `@`) according to RFC 5321.
+const MAX_LOCAL_PART_LEN: usize = 64;
+
+/// Max length of a domain label according to RFC 1035.
+const MAX_LABEL_LEN: usize = 63;
+
+/// Checks that the value is a syntactically valid email address.
+///
+/// The accepted grammar is the "dot-atom" form of RFC 5322 with the domain restricted
+/// to host names (RFC 1035), which covers the addresses used in practice:
+///
+/// ```text
+/// email      = local-part "@" domain           ; at most 254 characters
+/// local-part = atom *("." atom)                ; at most 64 characters
+/// atom       = 1*atext
+/// atext      = ALPHA / DIGIT / "!" / "#" / "$" / "%" / "&" / "'" / "*" / "+" / "-"
+///              / "/" / "=" / "?" / "^" / "_" / "`" / "{" / "|" / "}" / "~"
+/// domain     = label 1*("." label)             ; at least two labels
+/// label      = ALPHA / DIGIT                   ; 1 to 63 characters,
+///              / (ALPHA / DIGIT) *(ALPHA / DIGIT / "-") (ALPHA / DIGIT)
+/// ```
+///
+/// Quoted local parts (e.g. `"john doe"@example.com`), comments, IP address literals
+/// (e.g. `john@[127.0.0.1]`) and non-ASCII characters are rejected.
+///
+/// ```
+/// use nutype::email::is_valid_email;
+///
+/// assert!(is_valid_email("john.doe+news@mail.example.com"));
+/// assert!(!is_valid_email("john..doe@example.com"));
+/// assert!(!is_valid_email("john@localhost"));
+/// ```
+pub fn is_valid_email(value: &str) -> bool {
+    if value.len() > MAX_EMAIL_LEN {
+        return false;
+    }
+    let Some((local_part, domain)) = value.split_once('@') else {
+        return false;
+    };
+    is_valid_local_part(local_part) && is_valid_domain(domain)
+}
+
+fn is_valid_local_part(local_part: &str) -> bool {
+    local_part.len() <= MAX_LOCAL_PART_LEN
+        && local_part
+            .split('.')
+            .all(|atom| !atom.is_empty() && atom.bytes().all(is_atext))
+}
+
+fn is_atext(byte: u8) -> bool {
+    byte.is_ascii_alphanumeric() || b"!#$%&'*+-/=?^_`{|}~".contains(&byte)
+}
+
+fn is_valid_domain(domain: &str) -> bool {
+    let mut labels_count = 0;
+    for label in domain.split('.') {
+        if !is_valid_label(label) {
+            return false;
+        }
+        labels_count += 1;
+    }
+    labels_count >= 2
+}
+
+fn is_valid_label(label: &str) -> bool {
+    let bytes = label.as_bytes();
+    match (bytes.first(), bytes.last()) {
+        (Some(first), Some(last)) => {
+            bytes.len() <= MAX_LABEL_LEN
+                && first.is_ascii_alphanumeric()
+                && last.is_ascii_alphanumeric()
+                && bytes
+                    .iter()
+                    .all(|b| b.is_ascii_alphanumeric() || *b == b'-')
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_valid_emails() {
+        let emails = [
+            "john@example.com",
+            "john.doe@example.com",
+            "john+news@example.com",
+            "o'reilly@example.co.uk",
+            "x@a.io",
+            "!#$%&'*+-/=?^_`{|}~@example.com",
+            "john@sub-domain.example.com",
+            "JOHN@EXAMPLE.COM",
+            "123@456.789",
+        ];
+        for email in emails {
+            assert!(is_valid_email(email), "{email} must be valid");
+        }
+    }
+
+    #[test]
+    fn test_invalid_emails() {
+        let emails = [
+            "",
+            "john",
+            "@example.com",
+            "john@",
+            "john@@example.com",
+            "john@doe@example.com",
+            ".john@example.com",
+            "john.@example.com",
+            "john..doe@example.com",
+            "john doe@example.com",
+            "\"john doe\"@example.com",
+            "john@localhost",
+            "john@example..com",
+            "john@.example.com",
+            "john@example.com.",
+            "john@-example.com",
+            "john@example-.com",
+            "john@exa_mple.com",
+            "john@[127.0.0.1]",
+            "jöhn@example.com",
+            "john@exämple.com",
+        ];
+        for email in emails {
+            assert!(!is_valid_email(email), "{email} must be invalid");
+        }
+    }
+
+    #[test]
+    fn test_length_limits() {
+        let local_part = "a".repeat(MAX_LOCAL_PART_LEN);
+        assert!(is_valid_email(&format!("{local_part}@example.com")));
+        assert!(!is_valid_email(&format!("{local_part}a@example.com")));
+
+        let label = "a".repeat(MAX_LABEL_LEN);
+        assert!(is_valid_email(&format!("john@{label}.com")));
+        assert!(!is_valid_email(&format!("john@{label}a.com")));
+
+        // 63 * 3 + 2 dots + ".example.com" = 203 characters
+        let domain = [label.as_str(); 3].join(".") + ".example.com";
+        let email = format!("{}@{domain}", "a".repeat(50));
+        assert_eq!(email.len(), MAX_EMAIL_LEN);
+        assert!(is_valid_email(&email));
+        let email = format!("{}@{domain}", "a".repeat(51));
+        assert!(!is_valid_email(&email));
+    }
+}
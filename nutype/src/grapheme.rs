@@ -0,0 +1,38 @@
+//! Grapheme cluster counting used by `validate(len_grapheme_max = N)`. Requires `unicode-segmentation` feature.
+
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Counts the extended grapheme clusters of the value, i.e. the characters as a user perceives them.
+///
+/// Unlike `str::len()` (bytes) and `str::chars().count()` (Unicode scalar values), a flag or an emoji
+/// sequence joined with zero width joiners (ZWJ) is counted as a single grapheme:
+///
+/// ```
+/// use nutype::grapheme::grapheme_count;
+///
+/// let family = "👨‍👩‍👧";
+/// assert_eq!(family.len(), 18);
+/// assert_eq!(family.chars().count(), 5);
+/// assert_eq!(grapheme_count(family), 1);
+/// ```
+pub fn grapheme_count(value: &str) -> usize {
+    value.graphemes(true).count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_grapheme_count() {
+        assert_eq!(grapheme_count(""), 0);
+        assert_eq!(grapheme_count("abc"), 3);
+        // "e" followed by a combining acute accent
+        assert_eq!(grapheme_count("e\u{301}"), 1);
+        // Flag of Ukraine: two regional indicator symbols
+        assert_eq!(grapheme_count("🇺🇦"), 1);
+        // Woman technologist: woman, ZWJ, laptop
+        assert_eq!(grapheme_count("👩‍💻"), 1);
+        assert_eq!(grapheme_count("hi 👋🏽!"), 5);
+    }
+}
@@ -0,0 +1,33 @@
+//! Hex decoding used by `from_hex`. Requires `hex` feature.
+
+/// The error returned when a string is not a valid hex encoding.
+pub use ::hex::FromHexError as DecodeError;
+
+/// Decodes a hex string into bytes. Both lowercase and uppercase digits are accepted:
+///
+/// ```
+/// use nutype::hex::decode;
+///
+/// assert_eq!(decode("c0ffee"), Ok(vec![0xC0, 0xFF, 0xEE]));
+/// assert_eq!(decode("C0FFEE"), Ok(vec![0xC0, 0xFF, 0xEE]));
+/// assert!(decode("c0f").is_err());
+/// ```
+pub fn decode(value: &str) -> Result<Vec<u8>, DecodeError> {
+    ::hex::decode(value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode() {
+        assert_eq!(decode(""), Ok(vec![]));
+        assert_eq!(decode("00ff"), Ok(vec![0x00, 0xFF]));
+        assert_eq!(decode("abc"), Err(DecodeError::OddLength));
+        assert_eq!(
+            decode("zz"),
+            Err(DecodeError::InvalidHexCharacter { c: 'z', index: 0 })
+        );
+    }
+}
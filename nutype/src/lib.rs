@@ -85,7 +85,7 @@
 //! * String
 //! * Integer (`u8`, `u16`,`u32`, `u64`, `u128`, `i8`, `i16`, `i32`, `i64`, `i128`, `usize`, `isize`)
 //! * Float (`f32`, `f64`)
-//! * Any other arbitrary type
+//! * Any other arbitrary type (e.g. `bool`, tuples, enums or collections)
 //!
 //! ## String
 //!
@@ -93,22 +93,58 @@
 //!
 //! ### String sanitizers
 //!
-//! | Sanitizer   | Description                                                                         | Example                                         |
-//! |-------------|-------------------------------------------------------------------------------------|-------------------------------------------------|
-//! | `trim`      | Removes leading and trailing whitespaces                                            | `trim`                                          |
-//! | `lowercase` | Converts the string to lowercase                                                    | `lowercase`                                     |
-//! | `uppercase` | Converts the string to uppercase                                                    | `uppercase`                                     |
-//! | `with`      | Custom sanitizer. A function or closure that receives `String` and returns `String` | `with = \|mut s: String\| { s.truncate(5); s }` |
+//! | Sanitizer          | Description                                                                         | Example                                         |
+//! |--------------------|-------------------------------------------------------------------------------------|-------------------------------------------------|
+//! | `trim`             | Removes leading and trailing whitespaces                                            | `trim`                                          |
+//! | `lowercase`        | Converts the string to lowercase                                                    | `lowercase`                                     |
+//! | `uppercase`        | Converts the string to uppercase                                                    | `uppercase`                                     |
+//! | `dedup_whitespace` | Replaces every run of whitespaces with a single space (does not trim)               | `dedup_whitespace`                              |
+//! | `truncate`         | Truncates the string to at most the given number of chars                           | `truncate(10)`                                  |
+//! | `pad`              | Appends the fill char until the string has at least the given number of chars       | `pad(10, ' ')`                                  |
+//! | `with`             | Custom sanitizer. A function or closure that receives `String` and returns `String` | `with = \|mut s: String\| { s.truncate(5); s }` |
+//! | `try_with`         | Custom sanitizer that can fail. Returns `Result<String, E>`, where `E: Display`     | `try_with = normalize_phone`                    |
+//!
+//! `truncate` and `pad` count chars, not bytes, so a multi-byte char is never split.
+//! Together they produce fixed-width values, e.g. `sanitize(truncate(10), pad(10, ' '))`.
 //!
 //! ### String validators
 //!
-//! | Validator      | Description                                                                     | Error variant        | Example                                      |
-//! |----------------|---------------------------------------------------------------------------------|----------------------|----------------------------------------------|
-//! | `len_char_min` | Min length of the string (in chars, not bytes)                                  | `LenCharMinViolated` | `len_char_min = 5`                           |
-//! | `len_char_max` | Max length of the string (in chars, not bytes)                                  | `LenCharMaxViolated` | `len_char_max = 255`                         |
-//! | `not_empty`    | Rejects an empty string                                                         | `NotEmptyViolated`   | `not_empty`                                  |
-//! | `regex`        | Validates format with a regex. Requires `regex` feature.                        | `RegexViolated`      | `regex = "^[0-9]{7}$"` or `regex = ID_REGEX` |
-//! | `predicate`    | Custom validator. A function or closure that receives `&str` and returns `bool` | `PredicateViolated`  | `predicate = \|s: &str\| s.contains('@')`    |
+//! | Validator      | Description                                                                                   | Error variant                | Example                                      |
+//! |----------------|-----------------------------------------------------------------------------------------------|------------------------------|----------------------------------------------|
+//! | `len_char_min` | Min length of the string (in chars, not bytes)                                                | `LenCharMinViolated`         | `len_char_min = 5`                           |
+//! | `len_char_max` | Max length of the string (in chars, not bytes)                                                | `LenCharMaxViolated`         | `len_char_max = 255`                         |
+//! | `len_grapheme_max` | Max length of the string in grapheme clusters. Requires `unicode-segmentation` feature.  | `LenGraphemeMaxViolated`     | `len_grapheme_max = 160`                     |
+//! | `len_utf16_max` | Max length of the string in UTF-16 code units, the way JavaScript measures `String.length` | `LenUtf16MaxViolated`     | `len_utf16_max = 280`                        |
+//! | `not_empty`    | Rejects an empty string                                                                       | `NotEmptyViolated`           | `not_empty`                                  |
+//! | `ascii`        | Rejects a string with non-ASCII characters                                                    | `AsciiViolated`              | `ascii`                                      |
+//! | `alphanumeric` | Rejects a string with non-alphanumeric characters (see `char::is_alphanumeric`)               | `AlphanumericViolated`       | `alphanumeric`                               |
+//! | `starts_with`  | Checks that the string starts with the given prefix                                           | `StartsWithViolated`         | `starts_with = "sk_"`                        |
+//! | `ends_with`    | Checks that the string ends with the given suffix                                             | `EndsWithViolated`           | `ends_with = ".rs"`                          |
+//! | `starts_with_any` | Checks that the string starts with one of the given prefixes                               | `StartsWithAnyViolated`      | `starts_with_any = ["sk_", "pk_"]`           |
+//! | `ends_with_any` | Checks that the string ends with one of the given suffixes                                  | `EndsWithAnyViolated`        | `ends_with_any = [".png", ".jpg"]`           |
+//! | `contains`     | Checks that the string contains the given substring or char                                   | `ContainsViolated`           | `contains = '@'`                             |
+//! | `contains_only` | Checks that every char of the string is one of the given chars                              | `ContainsOnlyViolated(char)` | `contains_only = "0123456789abcdef"`         |
+//! | `one_of`       | Checks that the string is one of the allowed values                                           | `OneOfViolated`              | `one_of = ["USD", "EUR", "GBP"]`             |
+//! | `min`          | Rejects a string that goes before the given one in byte-lexicographic order                   | `MinViolated`                | `min = "v1.0"`                               |
+//! | `max`          | Rejects a string that goes after the given one in byte-lexicographic order                    | `MaxViolated`                | `max = "v2.9"`                               |
+//! | `any_of`       | Passes if at least one of the nested validators passes. The rest is skipped after the first pass. | `AnyOfViolated`    | `any_of(starts_with = "A", starts_with = "B")` |
+//! | `all_of`       | Passes if all of the nested validators pass                                                   | `AllOfViolated`              | `all_of(starts_with = "#", len_char_max = 7)` |
+//! | `email`        | Checks the email syntax. Requires `email` feature.                                            | `EmailViolated`              | `email`                                      |
+//! | `url`          | Checks that the string is an absolute URL, optionally with one of the given schemes. Requires `url` feature. | `UrlViolated`, `UrlSchemeViolated` | `url` or `url(schemes = ["https"])` |
+//! | `regex`        | Validates format with a regex. Requires `regex` feature.                                      | `RegexViolated`              | `regex = "^[0-9]{7}$"` or `regex = ID_REGEX` |
+//! | `predicate`    | Custom validator. A function or closure that receives `&str` and returns `bool`               | `PredicateViolated`          | `predicate = \|s: &str\| s.contains('@')`    |
+//! | `with_result`  | Custom validator. A function or closure that receives `&str` and returns `Result<(), String>` | `WithResultViolated(String)` | `with_result = validate_email`               |
+//!
+//! Both length boundaries can be set at once with a range: `len_char = 3..=20` is the same as
+//! `len_char_min = 3, len_char_max = 20`, and `len_char = 3..20` is the same as `len_char_min = 3, len_char_max = 19`.
+//! Either end of the range can be omitted, e.g. `len_char = 1..`.
+//!
+//! `any_of` and `all_of` group nested validators (including other `any_of` and `all_of`) and report a single error variant
+//! for the whole group. `contains_only`, `with_result` and `regex` with a string literal cannot be nested, use `predicate` instead.
+//!
+//! `min` and `max` compare strings the same way as `str` does, byte by byte, so they know nothing about numbers or case:
+//! `"v10.0"` goes before `"v2.0"`, and every uppercase ASCII letter goes before any lowercase one (`"Z" < "a"`).
+//! Both boundaries are inclusive.
 //!
 //! #### Regex validation
 //!
@@ -157,11 +193,101 @@
 //! ```
 //!
 //!
+//! #### Email validation
+//!
+//! Requirements:
+//! * `email` feature of `nutype` is enabled.
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(email))]
+//! pub struct Email(String);
+//! ```
+//!
+//! The check is performed by [`nutype::email::is_valid_email`](https://docs.rs/nutype/latest/nutype/email/fn.is_valid_email.html).
+//! It accepts the "dot-atom" form of RFC 5322 with the domain restricted to host names, which covers the addresses used in practice:
+//! * the local part (before `@`) consists of one or more dot-separated atoms of ASCII letters, digits and ``!#$%&'*+-/=?^_`{|}~``, and is at most 64 characters long;
+//! * the domain consists of at least two dot-separated labels, each label is 1 to 63 ASCII letters, digits or hyphens and does not start or end with a hyphen;
+//! * the whole address is at most 254 characters long.
+//!
+//! Quoted local parts, comments, IP address literals and non-ASCII characters are rejected.
+//! If you need a different grammar, use `predicate` or `with_result` validators instead.
+//!
+//! #### URL validation
+//!
+//! Requirements:
+//! * `url` feature of `nutype` is enabled.
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(sanitize(trim), validate(url(schemes = ["https"])))]
+//! pub struct Homepage(String);
+//! ```
+//!
+//! The value is parsed with the [`url`](https://crates.io/crates/url) crate and must be an absolute URL, otherwise `UrlViolated` is returned.
+//! With `schemes` the scheme of the URL must be one of the listed ones (compared case-insensitively), otherwise `UrlSchemeViolated` is returned.
+//! Without `schemes` (just `validate(url)`) any scheme is accepted.
+//! As any other validator, `url` runs after the sanitizers, so the surrounding whitespace can be removed with `trim`.
+//!
+//! #### UTF-16 length validation
+//!
+//! A frontend written in JavaScript (or running in WASM and talking to the DOM) measures the length of a string
+//! in UTF-16 code units. A char outside of the Basic Multilingual Plane (e.g. most emojis) takes two of them,
+//! so `len_char_max` may accept a string which the frontend rejects. Use `len_utf16_max` to apply the same limit on both sides:
+//!
+//! ```rs
+//! #[nutype(validate(len_utf16_max = 280))]
+//! pub struct Post(String);
+//! ```
+//!
+//! | Value   | `len()` (bytes) | `chars().count()` | `encode_utf16().count()` |
+//! |---------|-----------------|-------------------|--------------------------|
+//! | `abc`   | 3               | 3                 | 3                        |
+//! | `Юля`   | 6               | 3                 | 3                        |
+//! | `ab🦀`  | 6               | 3                 | 4                        |
+//!
+//! #### Grapheme length validation
+//!
+//! Requirements:
+//! * `unicode-segmentation` feature of `nutype` is enabled.
+//!
+//! `len_char_max` counts chars (Unicode scalar values), which is not what a user perceives as a character:
+//! a flag like `🇺🇦` consists of 2 chars and a family emoji like `👨‍👩‍👧` consists of 5 chars joined with zero width joiners.
+//! `len_grapheme_max` counts extended grapheme clusters instead, so each of those is counted as one:
+//!
+//! ```ignore
+//! #[nutype(validate(len_grapheme_max = 160))]
+//! pub struct Bio(String);
+//! ```
+//!
+//! | Value   | `len()` (bytes) | `chars().count()` | Graphemes |
+//! |---------|-----------------|-------------------|-----------|
+//! | `abc`   | 3               | 3                 | 3         |
+//! | `🇺🇦`   | 8               | 2                 | 1         |
+//! | `👨‍👩‍👧` | 18              | 5                 | 1         |
+//!
+//! The graphemes are counted with [`nutype::grapheme::grapheme_count`](https://docs.rs/nutype/latest/nutype/grapheme/fn.grapheme_count.html).
+//!
+//!
 //! ### String derivable traits
 //!
 //! The following traits can be derived for a string-based type:
 //! `Debug`, `Clone`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`,
-//! `From`, `TryFrom`, `Into`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`.
+//! `From`, `FromIterator`, `TryFrom`, `Into`, `IntoInner`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`, `Nutype`, `EqInner`, `OrdInner`.
+//!
+//! `FromIterator` (of `char` and `&str`) cannot fail, so like `From` it can be derived only when there is no validation.
+//!
+//! `TryFrom` implements both `TryFrom<String>` and `TryFrom<&str>`. Together with `FromStr` they return the same error type as `new()`,
+//! so a value fails with the same error and message no matter how it is constructed.
+//!
+//! `AsRef` implements `AsRef<str>`, so a newtype can be passed to functions that take `impl AsRef<str>`.
+//! `AsRef<String>` is not implemented, since it would make `.as_ref()` calls ambiguous.
+//!
+//! With `arbitrary` feature `Arbitrary` can be derived as well. `one_of`, the length boundaries, `ascii`, `alphanumeric`, `contains_only`,
+//! `starts_with`, `contains` and `ends_with` are respected by construction. The input that produces a value violating
+//! the other validators (e.g. `regex` or `predicate`) is rejected with `arbitrary::Error::IncorrectFormat`.
 //!
 //!
 //! ## Integer
@@ -170,25 +296,100 @@
 //!
 //! ### Integer sanitizers
 //!
-//! | Sanitizer | Description       | Example                            |
-//! |-----------|-------------------|------------------------------------|
-//! | `with`    | Custom sanitizer. | `with = \|raw\| raw.clamp(0, 100)` |
+//! | Sanitizer  | Description                | Example                            |
+//! |------------|----------------------------|------------------------------------|
+//! | `with`     | Custom sanitizer.          | `with = \|raw\| raw.clamp(0, 100)` |
+//! | `try_with` | Custom sanitizer that can fail. | `try_with = \|raw\| raw.checked_mul(100).ok_or("overflow")` |
+//! | `wrapping` | Wraps the value into `[0, modulus)` range, e.g. `-1` becomes `modulus - 1`. | `wrapping(360)`                    |
+//! | `saturating_clamp` | Saturates the value at the bounds of `[min, max]` range.              | `saturating_clamp(0, 100)`         |
+//! | `snap`     | Rounds the value to the nearest multiple of the step (half rounds up).      | `snap = 5`                         |
+//!
+//! `snap` counts from the lower bound (`greater_or_equal` or `greater`) the same way as `step` validator does,
+//! so e.g. with `greater_or_equal = 2` and `snap = 5` the values are rounded to `2, 7, 12, ...`.
+//!
+//! `wrapping(modulus)` computes `value.rem_euclid(modulus)`, so negative values wrap around as well.
+//! The `wrapping = modulus` form is accepted too.
+//! `saturating_clamp(min, max)` is `value.clamp(min, max)`: values out of the range are replaced with the nearest bound,
+//! they never wrap around and never cause an error.
+//!
+//! Sanitizers are applied in the given order before the validation. So if `wrapping(360)` is the last sanitizer,
+//! the value is always within `0..360` by the time validators run, and e.g. `less = 360` can never be violated.
+//! Likewise after `saturating_clamp(0, 100)` `less_or_equal = 100` can never be violated, but `less = 100` still rejects `100`.
 //!
 //! ### Integer validators
 //!
-//! | Validator           | Description           | Error variant             | Example                              |
-//! | ------------------- | --------------------- | ------------------------- | ------------------------------------ |
-//! | `less`              | Exclusive upper bound | `LessViolated`            | `less = 100`                         |
-//! | `less_or_equal`     | Inclusive upper bound | `LessOrEqualViolated`     | `less_or_equal = 99`                 |
-//! | `greater`           | Exclusive lower bound | `GreaterViolated`         | `greater = 17`                       |
-//! | `greater_or_equal`  | Inclusive lower bound | `GreaterOrEqualViolated`  | `greater_or_equal = 18`              |
-//! | `predicate`         | Custom predicate      | `PredicateViolated`       | `predicate = \|num\| num % 2 == 0`   |
+//! | Validator          | Description                                        | Error variant                | Example                            |
+//! | ------------------ | -------------------------------------------------- | ---------------------------- | ---------------------------------- |
+//! | `less`             | Exclusive upper bound                              | `LessViolated`               | `less = 100`                       |
+//! | `less_or_equal`    | Inclusive upper bound                              | `LessOrEqualViolated`        | `less_or_equal = 99`               |
+//! | `greater`          | Exclusive lower bound                              | `GreaterViolated`            | `greater = 17`                     |
+//! | `greater_or_equal` | Inclusive lower bound                              | `GreaterOrEqualViolated`     | `greater_or_equal = 18`            |
+//! | `non_zero`         | Rejects zero                                       | `NonZeroViolated`            | `non_zero`                         |
+//! | `step`             | Multiple of the step, counted from the lower bound | `StepViolated`               | `step = 5`                         |
+//! | `one_of`           | One of the allowed values                          | `OneOfViolated`              | `one_of = [1, 2, 4, 8]`            |
+//! | `predicate`        | Custom predicate                                   | `PredicateViolated`          | `predicate = \|num\| num % 2 == 0` |
+//! | `with_result`      | Custom validator that returns `Result<(), String>` | `WithResultViolated(String)` | `with_result = validate_num`       |
+//!
+//! Both boundaries can be set at once with a range: `range = 0..=100` is the same as `greater_or_equal = 0, less_or_equal = 100`,
+//! and `range = 0..100` is the same as `greater_or_equal = 0, less = 100`. Either end of the range can be omitted, e.g. `range = 1..`.
 //!
 //! ### Integer derivable traits
 //!
 //! The following traits can be derived for an integer-based type:
 //! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`,
-//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`.
+//! `Into`, `From`, `TryFrom`, `TryFromWide`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`, `Nutype`, `EqInner`, `OrdInner`, `Sum`, `Product`, `CheckedArith`, `Neg`.
+//!
+//! `Sum` and `Product` (over values and over references) can be derived only when there is no validation,
+//! because the sum or the product of valid values is not necessarily valid.
+//! The result goes through the sanitizers and overflows the same way as it does for the inner type.
+//!
+//! `CheckedArith` generates `checked_add()` and `checked_sub()`, that return `None` on overflow
+//! or when the result violates the validation rules, so it can be derived for a type with validation too:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(less_or_equal = 100), derive(Debug, Clone, Copy, PartialEq, CheckedArith))]
+//! pub struct Quantity(u8);
+//!
+//! let q = |n| Quantity::new(n).unwrap();
+//! assert_eq!(q(40).checked_add(q(60)), Some(q(100)));
+//! assert_eq!(q(60).checked_add(q(41)), None);
+//! assert_eq!(q(40).checked_sub(q(60)), None);
+//! ```
+//!
+//! `Neg` can be derived only for a signed inner type. With validation it's allowed only when the bounds are
+//! symmetric literals (optionally with `non_zero`), so the negated value is always valid:
+//!
+//! ```rs
+//! #[nutype(
+//!     validate(greater_or_equal = -100, less_or_equal = 100),
+//!     derive(Debug, Clone, Copy, PartialEq, Neg),
+//! )]
+//! pub struct Offset(i32);
+//!
+//! assert_eq!(-Offset::new(40).unwrap(), Offset::new(-40).unwrap());
+//! ```
+//!
+//! Without validation the inner type's `MIN` (e.g. `i32::MIN`) has no positive counterpart, so negating it panics
+//! with `Negation of <Type> overflows` in both debug and release builds, rather than wrapping around silently.
+//!
+//! `TryFromWide` generates `TryFrom<i64>` and `TryFrom<u64>`, which is handy when the values come from JSON or a database.
+//! A value that doesn't fit the inner type is rejected with `OutOfRange` before the validation runs:
+//!
+//! ```rs
+//! #[nutype(validate(greater_or_equal = 1024), derive(Debug, PartialEq, TryFromWide))]
+//! pub struct Port(u16);
+//!
+//! assert_eq!(Port::try_from(8080_i64).unwrap().into_inner(), 8080);
+//! assert_eq!(Port::try_from(70_000_i64), Err(PortTryFromWideError::OutOfRange));
+//! assert_eq!(
+//!     Port::try_from(80_u64),
+//!     Err(PortTryFromWideError::Validate(PortError::GreaterOrEqualViolated)),
+//! );
+//! ```
+//!
+//! With several `TryFrom` implementations an integer literal needs a suffix, e.g. `8080_i64`.
 //!
 //!
 //! ## Float
@@ -200,25 +401,31 @@
 //! | Sanitizer | Description       | Example                                |
 //! |-----------|-------------------|----------------------------------------|
 //! | `with`    | Custom sanitizer. | `with = \|val\| val.clamp(0.0, 100.0)` |
+//! | `try_with` | Custom sanitizer that can fail. | `try_with = to_meters` |
 //!
 //! ### Float validators
 //!
-//! | Validator          | Description                      | Error variant            | Example                             |
-//! | ------------------ | -------------------------------- | ---------------------    | ----------------------------------- |
-//! | `less`             | Exclusive upper bound            | `LessViolated`           | `less = 100.0`                      |
-//! | `less_or_equal`    | Inclusive upper bound            | `LessOrEqualViolated`    | `less_or_equal = 100.0`             |
-//! | `greater`          | Exclusive lower bound            | `GreaterViolated`        | `greater = 0.0`                     |
-//! | `greater_or_equal` | Inclusive lower bound            | `GreaterOrEqualViolated` | `greater_or_equal = 0.0`            |
-//! | `finite`           | Check against NaN and infinity   | `FiniteViolated`         | `finite`                            |
-//! | `predicate`        | Custom predicate                 | `PredicateViolated`      | `predicate = \|val\| val != 50.0`   |
+//! | Validator          | Description                                        | Error variant                | Example                           |
+//! | ------------------ | -------------------------------------------------- | ---------------------------- | --------------------------------- |
+//! | `less`             | Exclusive upper bound                              | `LessViolated`               | `less = 100.0`                    |
+//! | `less_or_equal`    | Inclusive upper bound                              | `LessOrEqualViolated`        | `less_or_equal = 100.0`           |
+//! | `greater`          | Exclusive lower bound                              | `GreaterViolated`            | `greater = 0.0`                   |
+//! | `greater_or_equal` | Inclusive lower bound                              | `GreaterOrEqualViolated`     | `greater_or_equal = 0.0`          |
+//! | `finite`           | Check against NaN and infinity                     | `FiniteViolated`             | `finite`                          |
+//! | `predicate`        | Custom predicate                                   | `PredicateViolated`          | `predicate = \|val\| val != 50.0` |
+//! | `with_result`      | Custom validator that returns `Result<(), String>` | `WithResultViolated(String)` | `with_result = validate_val`      |
 //!
 //! ### Float derivable traits
 //!
 //! The following traits can be derived for a float-based type:
 //! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `FromStr`, `AsRef`, `Deref`,
-//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`.
+//! `Into`, `From`, `TryFrom`, `Hash`, `Borrow`, `Display`, `Default`, `Serialize`, `Deserialize`, `Nutype`, `EqInner`, `OrdInner`, `Sum`, `Product`, `CheckedArith`, `Neg`.
 //!
-//! It's also possible to derive `Eq` and `Ord` if the validation rules guarantee that `NaN` is excluded.
+//! As for integers, `Sum` and `Product` can be derived only when there is no validation.
+//! `CheckedArith` treats a result that is not finite as an overflow, unless one of the operands is not finite already.
+//! `Neg` follows the same rules as for integers, with `finite` in place of `non_zero`.
+//!
+//! It's also possible to derive `Eq`, `Ord` and `Hash` if the validation rules guarantee that `NaN` is excluded.
 //! This can be done by applying `finite` validation. For example:
 //!
 //! ```rust
@@ -233,8 +440,10 @@
 //!
 //! ## Other inner types
 //!
-//! For any other type it is possible to define custom sanitizers with `with` and custom
-//! validations with `predicate`:
+//! For any other type it is possible to define custom sanitizers with `with` or `try_with` and custom
+//! validations with `predicate` or `with_result`.
+//! Collections like `Vec<T>` can be also validated with `len_min`, `len_max` and `not_empty`,
+//! and `Vec<T>` based types can be borrowed as a slice `&[T]` with `as_slice()`:
 //!
 //! ```
 //! use nutype::nutype;
@@ -242,14 +451,14 @@
 //! #[nutype(
 //!     derive(Debug, PartialEq, Deref, AsRef),
 //!     sanitize(with = |mut guests| { guests.sort(); guests }),
-//!     validate(predicate = |guests| !guests.is_empty() ),
+//!     validate(not_empty, len_max = 10),
 //! )]
 //! pub struct GuestList(Vec<String>);
 //!
 //! // Empty list is not allowed
 //! assert_eq!(
 //!     GuestList::new(vec![]),
-//!     Err(GuestListError::PredicateViolated)
+//!     Err(GuestListError::NotEmptyViolated)
 //! );
 //!
 //! // Create the list of our guests
@@ -281,6 +490,120 @@
 //!
 //! ```
 //!
+//! Tuples are supported as inner types as well. Closures can destructure them:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     derive(Debug, PartialEq, AsRef, Into),
+//!     validate(predicate = |(lat, lon)| (-90.0..=90.0).contains(lat) && (-180.0..=180.0).contains(lon)),
+//! )]
+//! pub struct LatLon((f64, f64));
+//!
+//! assert_eq!(
+//!     LatLon::new((91.0, 0.0)),
+//!     Err(LatLonError::PredicateViolated)
+//! );
+//! ```
+//!
+//! Types that implement `PartialOrd` (and `Debug`, which is used in the error messages) can be validated with `greater`,
+//! `greater_or_equal`, `less` and `less_or_equal`. The boundaries are expressions of the inner type:
+//!
+//! ```
+//! use nutype::nutype;
+//! use std::time::Duration;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = Duration::from_millis(100), less_or_equal = Duration::from_secs(30)),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Timeout(Duration);
+//!
+//! # fn main() {
+//! assert_eq!(
+//!     Timeout::new(Duration::from_secs(60)),
+//!     Err(TimeoutError::LessOrEqualViolated)
+//! );
+//! # }
+//! ```
+//!
+//! ### Date and time with `chrono`
+//!
+//! With `chrono` feature enabled, the boundaries of `greater`, `greater_or_equal`, `less` and `less_or_equal`
+//! for `NaiveDate`, `NaiveTime`, `NaiveDateTime` and `DateTime` inner types can be given as string literals.
+//! The literals are parsed with `FromStr` of the inner type and verified at compile time,
+//! so a malformed date is reported by the compiler:
+//!
+//! ```ignore
+//! use chrono::NaiveDate;
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = "2024-01-01", less = "2025-01-01"),
+//!     derive(Debug, PartialEq, FromStr, Display),
+//! )]
+//! pub struct Date2024(NaiveDate);
+//!
+//! assert!(Date2024::new(NaiveDate::from_ymd_opt(2024, 6, 1).unwrap()).is_ok());
+//! assert_eq!(
+//!     "2025-03-15".parse::<Date2024>(),
+//!     Err(Date2024ParseError::Validate(Date2024Error::LessViolated))
+//! );
+//! ```
+//!
+//! Derived `FromStr` and `Display` delegate to the inner type, so the values are parsed and printed in the ISO 8601 format.
+//! Relative boundaries (e.g. "not in the future") depend on the current time and have to be expressed with `predicate`.
+//!
+//! ### Borrowed inner types
+//!
+//! A newtype can have a single lifetime parameter, which allows to wrap borrowed values without copying them (e.g. for zero-copy parsing):
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(with = |s| s.trim()),
+//!     validate(predicate = |s| !s.is_empty()),
+//!     derive(Debug, Clone, Copy, PartialEq, AsRef, Deref, Display),
+//! )]
+//! pub struct Name<'a>(&'a str);
+//!
+//! let input = String::from("  Anna  ");
+//! let name = Name::new(&input).unwrap();
+//! assert_eq!(name.into_inner(), "Anna");
+//! ```
+//!
+//! At the moment only the following traits can be derived for such types:
+//! `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `AsRef`, `Deref`, `Borrow`, `Display`, `From`, `Into`.
+//!
+//! ### Generic inner types
+//!
+//! A newtype can also be generic over its inner type, e.g. to use the same unit of measure with `f32` and `f64`.
+//! The bounds of the type parameter have to be specified inline, and sanitizers and validators are expressed in terms of them:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(predicate = |v| (*v).into() >= 0.0),
+//!     derive(Debug, Clone, Copy, PartialEq, PartialOrd),
+//! )]
+//! pub struct Meters<T: Copy + Into<f64>>(T);
+//!
+//! let short = Meters::<f32>::new(1.5).unwrap();
+//! let long = Meters::<f64>::new(42_000.0).unwrap();
+//! assert_eq!(short.into_inner(), 1.5);
+//! assert_eq!(long.into_inner(), 42_000.0);
+//! ```
+//!
+//! With the `num-traits` crate it can be as simple as `pub struct Meters<T: num_traits::Float>(T);`.
+//!
+//! Generic newtypes have the following limitations:
+//! * The inner type must be an arbitrary type (e.g. `T` or `Vec<T>`), so the numeric validators like `finite` are not available.
+//! * `greater`, `greater_or_equal`, `less` and `less_or_equal` cannot be used, use `predicate` instead.
+//! * Only the following traits can be derived: `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, `AsRef`, `Deref`, `Borrow`, `Display`, `From`.
+//!
 //! ## Custom sanitizers
 //!
 //! You can set custom sanitizers using the `with` option.
@@ -312,6 +635,30 @@
 //! assert_eq!(city.into_inner(), "Old York");
 //! ```
 //!
+//! A function can be referenced by any path, e.g. `with = rules::new_to_old`, `with = crate::rules::new_to_old` or
+//! `with = super::new_to_old`. The same applies to `predicate` and `with_result` validators, so the rules can be kept in one place
+//! and reused by multiple newtypes.
+//!
+//! A sanitizer that can fail is set with `try_with`. It returns `Result<Inner, E>`, where `E` implements `Display`.
+//! An error aborts the construction with `SanitizationFailed` variant, so the remaining sanitizers and the validators do not run.
+//! A type with `try_with` gets an error type even if it has no validators:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim, try_with = |s| s.parse::<u16>().map(|port| port.to_string())),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Port(String);
+//!
+//! assert_eq!(Port::new(" 8080 ").unwrap().into_inner(), "8080");
+//! assert_eq!(
+//!     Port::new("http"),
+//!     Err(PortError::SanitizationFailed("invalid digit found in string".to_string())),
+//! );
+//! ```
+//!
 //! ## Custom validators
 //!
 //! In similar fashion it's possible to define custom validators, but a validation function receives a reference and returns `bool`.
@@ -331,6 +678,89 @@
 //! fn main() { }
 //! ```
 //!
+//! The value is never cloned for the validation: validators of `String` types receive `&str` borrowed from the sanitized value,
+//! while validators of other inner types receive `&Inner`. A closure annotated with `|s: &String|` receives
+//! the inner `String` itself, also without a copy. In that case `validate()` takes `&String` as well.
+//!
+//! If a plain `bool` is not expressive enough, use `with_result` instead: the validation function returns `Result<(), String>`
+//! and the message ends up in `WithResultViolated` error variant.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(with_result = validate_name), derive(Debug))]
+//! pub struct Name(String);
+//!
+//! fn validate_name(name: &str) -> Result<(), String> {
+//!     match name.chars().next() {
+//!         Some(first) if first.is_uppercase() => Ok(()),
+//!         Some(first) => Err(format!("`{first}` must be uppercase")),
+//!         None => Err("name must not be empty".to_string()),
+//!     }
+//! }
+//!
+//! fn main() {
+//! assert_eq!(
+//!     Name::new("alice").unwrap_err(),
+//!     NameError::WithResultViolated("`a` must be uppercase".to_string()),
+//! );
+//! }
+//! ```
+//!
+//! Closures can refer to the statics, constants and functions of the module where the newtype is defined,
+//! either by name or by a path starting with `self::`, `super::` or `crate::`. For example, to check membership in a table:
+//!
+//! ```
+//! use nutype::nutype;
+//! use std::collections::HashSet;
+//! use std::sync::LazyLock;
+//!
+//! static RESERVED_NAMES: LazyLock<HashSet<&str>> =
+//!     LazyLock::new(|| ["admin", "root"].into_iter().collect());
+//!
+//! #[nutype(validate(predicate = |name| !RESERVED_NAMES.contains(name)), derive(Debug))]
+//! pub struct Login(String);
+//!
+//! fn main() {
+//! assert!(Login::new("ferris").is_ok());
+//! assert_eq!(Login::new("root").unwrap_err(), LoginError::PredicateViolated);
+//! }
+//! ```
+//!
+//! The generated code is placed in a nested module, that imports everything from the module of the newtype with `use super::*`.
+//! Because of that, items declared within a function body are not visible to the closures, even if the newtype is declared in the same function.
+//! Declare such items at the module level instead.
+//!
+//! ## Evaluation order
+//!
+//! The constructor processes a value in the following order:
+//!
+//! 1. Validators within `raw(...)` check the original value, in the order they are declared (string based types only).
+//! 2. Sanitizers transform the value, in the order they are declared. A failing `try_with` sanitizer stops the pipeline with `SanitizationFailed` error.
+//! 3. Regular validators check the sanitized value, in the order they are declared.
+//! 4. The newtype is constructed from the sanitized value.
+//!
+//! By default the first violated validator stops the pipeline. With `collect_errors` all the validators of a stage are checked,
+//! but if any raw validator is violated, sanitizers and regular validators do not run.
+//!
+//! Raw validators are useful when a sanitizer could hide a violation, e.g. trimming a huge input:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(raw(len_char_max = 100), not_empty),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Title(String);
+//!
+//! assert_eq!(Title::new(" ".repeat(200)), Err(TitleError::LenCharMaxViolated));
+//! ```
+//!
+//! A validator can be used either within `raw(...)` or outside of it, but not in both places. The `validate()` function checks
+//! the raw validators and the regular ones against the given value without sanitizing it.
+//!
 //! ## Recipes
 //!
 //! ### Derive `Default`
@@ -345,72 +775,1179 @@
 //! pub struct Name(String);
 //! ```
 //!
-//! ### Derive `Eq` and `Ord` on float types
+//! For integer types with a lower boundary, `default = min` takes the smallest valid value:
 //!
-//! With nutype it's possible to derive `Eq` and `Ord` if there is `finite` validation set.
-//! The `finite` validation ensures that the valid value excludes `NaN`.
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 5, less_or_equal = 100),
+//!     derive(Default),
+//!     default = min,
+//! )]
+//! pub struct PageSize(u32);
+//!
+//! assert_eq!(PageSize::default().into_inner(), 5);
+//! ```
+//!
+//! Together with `Deserialize`, the default makes the newtype usable with `#[serde(default)]`,
+//! so a missing field in a containing struct falls back to it, while a present value is still validated:
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(greater = 0),
+//!     default = 8080,
+//!     derive(Deserialize, Default),
+//! )]
+//! pub struct Port(u16);
+//!
+//! #[derive(Deserialize)]
+//! struct Config {
+//!     #[serde(default)]
+//!     port: Port,
+//! }
+//!
+//! let config: Config = serde_json::from_str("{}").unwrap();
+//! assert_eq!(config.port.into_inner(), 8080);
+//! ```
+//!
+//! ### Derive `Display` with a custom format
+//!
+//! By default `Display` is forwarded to the inner type. The format can be customized with `display = `,
+//! the inner value is passed as the only argument:
 //!
 //! ```
 //! use nutype::nutype;
 //!
 //! #[nutype(
-//!     validate(finite),
-//!     derive(PartialEq, Eq, PartialOrd, Ord),
+//!     derive(Display),
+//!     display = "User #{}",
 //! )]
-//! pub struct Weight(f64);
+//! pub struct UserId(u64);
 //! ```
 //!
-//! ## How to break the constraints?
+//! ### Debug without the newtype name
 //!
-//! It's discouraged, but it's possible to bypass the constraints by enabling `new_unchecked` crate feature and marking a type with `new_unchecked`:
+//! Derived `Debug` prints the newtype name, e.g. `Username("bob")`. With `transparent_debug` it is forwarded
+//! to the inner type, so the same value is printed as `"bob"`:
 //!
-//! ```ignore
+//! ```
 //! use nutype::nutype;
 //!
 //! #[nutype(
-//!     new_unchecked
-//!     sanitize(trim)
-//!     validate(len_char_min = 8)
+//!     derive(Debug),
+//!     transparent_debug,
 //! )]
-//! pub struct Name(String);
+//! pub struct Username(String);
+//! ```
 //!
-//! // Yes, you're forced to use `unsafe` here, so everyone will point fingers at YOU.
-//! let name = unsafe { Name::new_unchecked(" boo ".to_string()) };
+//! ### Compare with the inner value
+//!
+//! `EqInner` implements `PartialEq` against the inner type (and against `str` and `&str` for string-based types),
+//! which comes handy in tests. It does not affect `PartialEq` derived between two values of the newtype:
 //!
-//! // `name` violates the sanitization and validation rules!!!
-//! assert_eq!(name.into_inner(), " boo ");
 //! ```
+//! use nutype::nutype;
 //!
-//! ## Feature flags
+//! #[nutype(
+//!     derive(Debug, PartialEq, EqInner),
+//!     sanitize(trim),
+//! )]
+//! pub struct Username(String);
 //!
-//! * `arbitrary` - enables derive of [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
-//! * `diesel_derive_newtype` - enables derive of [`diesel_derive_newtype::DieselNewType`](https://docs.rs/diesel-derive-newtype/latest/diesel_derive_newtype/).
-//! * `new_unchecked` - enables generation of unsafe `::new_unchecked()` function.
-//! * `regex` - allows to use `regex = ` validation on string-based types. Note: your crate also has to explicitly have `regex` and `lazy_static` within dependencies.
-//! * `serde` - integrations with [`serde`](https://crates.io/crates/serde) crate. Allows to derive `Serialize` and `Deserialize` traits.
-//! * `schemars08` - allows to derive [`JsonSchema`](https://docs.rs/schemars/0.8.12/schemars/trait.JsonSchema.html) trait of [schemars](https://crates.io/crates/schemars) crate. Note that at the moment validation rules are not respected.
-//! * `std` - enabled by default. Use `default-features = false` to disable.
+//! assert_eq!(Username::new(" alice "), "alice");
+//! ```
 //!
-//! ## Support Ukrainian military forces 🇺🇦
+//! `OrdInner` does the same for `PartialOrd`, so a value can be checked against a range with a plain literal.
+//! Since `PartialOrd<Inner>` requires `PartialEq<Inner>`, it implies `EqInner`:
 //!
-//! Today I live in Berlin, I have the luxury to live a physically safe life.
-//! But I am Ukrainian. The first 25 years of my life I spent in [Kharkiv](https://en.wikipedia.org/wiki/Kharkiv),
-//! the second-largest city in Ukraine, 60km away from the border with russia. Today about [a third of my home city is destroyed](https://www.youtube.com/watch?v=ihoufBFSZds) by russians.
-//! My parents, my relatives and my friends had to survive the artillery and air attack, living for over a month in basements.
+//! ```rs
+//! #[nutype(derive(Debug, PartialEq, PartialOrd, OrdInner))]
+//! pub struct Port(u16);
 //!
-//! Some of them have managed to evacuate to EU. Some others are trying to live "normal lives" in Kharkiv, doing there daily duties.
-//! And some are at the front line right now, risking their lives every second to protect the rest.
+//! let port = Port::new(8080);
+//! assert!(port >= 1024);
+//! ```
 //!
-//! I encourage you to donate to [Charity foundation of Serhiy Prytula](https://prytulafoundation.org/en).
-//! Just pick the project you like and donate. This is one of the best-known foundations, you can watch a [little documentary](https://www.youtube.com/watch?v=VlmWqoeub1Q) about it.
-//! Your contribution to the Ukrainian military force is a contribution to my calmness, so I can spend more time developing the project.
+//! ### Case-insensitive equality
 //!
-//! Thank you.
-
-// Set `no_std` flag if `std` feature is disabled.
-#![cfg_attr(not(feature = "std"), no_std)]
-
-pub use nutype_macros::nutype;
+//! `sanitize(lowercase)` normalizes the stored value. If the original casing has to be preserved,
+//! use `eq = case_insensitive` instead: `PartialEq`, `Eq`, `Hash` and `EqInner` compare lowercased values,
+//! so `Hash` stays consistent with `Eq`.
+//! Deriving `PartialOrd` or `Ord` together with it requires `ord = case_insensitive`, otherwise the ordering would disagree with the equality.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(eq = case_insensitive, derive(Debug, PartialEq, Eq, Hash, AsRef))]
+//! pub struct Email(String);
+//!
+//! fn main() {
+//! let email = Email::new("Foo@Example.com");
+//! assert_eq!(email, Email::new("foo@example.com"));
+//! assert_eq!(email.as_ref(), "Foo@Example.com");
+//! }
+//! ```
+//!
+//! ### Case-insensitive ordering
+//!
+//! With `ord = case_insensitive` the derived `PartialOrd`, `Ord` and `OrdInner` compare lowercased values, e.g. to sort usernames.
+//! Since `Ord` must agree with `Eq`, a derived `PartialEq` has to be case-insensitive too:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     eq = case_insensitive,
+//!     ord = case_insensitive,
+//!     derive(Debug, PartialEq, Eq, PartialOrd, Ord),
+//! )]
+//! pub struct Username(String);
+//!
+//! let mut usernames = vec![Username::new("bob"), Username::new("Alice"), Username::new("carol")];
+//! usernames.sort();
+//! assert_eq!(usernames[0], Username::new("alice"));
+//! ```
+//!
+//! ### Serialize as an object
+//!
+//! By default the derived `Serialize` and `Deserialize` represent a newtype as its inner value, e.g. `"ferris"`.
+//! With `serde_repr = object` it is represented as an object with a single field `value` instead, e.g. `{ "value": "ferris" }`.
+//! The field name can be changed with `serde_repr = object(field = "name")`:
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(not_empty),
+//!     serde_repr = object(field = "name"),
+//!     derive(Debug, Serialize, Deserialize),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! ### Deserialize numbers from strings
+//!
+//! Some clients (e.g. JavaScript ones) send numbers as strings to avoid losing precision.
+//! With `serde_lenient` the derived `Deserialize` of an integer based type accepts both a number and a numeric string,
+//! and validates the value as usual. It is still serialized as a number.
+//! It relies on `deserialize_any`, so it works only with self-describing formats like JSON:
+//!
+//! ```rs
+//! #[nutype(
+//!     validate(less_or_equal = 100),
+//!     serde_lenient,
+//!     derive(Debug, PartialEq, Deserialize),
+//! )]
+//! pub struct Percent(u8);
+//!
+//! assert_eq!(serde_json::from_str::<Percent>("42").unwrap(), Percent::new(42).unwrap());
+//! assert_eq!(serde_json::from_str::<Percent>("\"42\"").unwrap(), Percent::new(42).unwrap());
+//! assert!(serde_json::from_str::<Percent>("\"101\"").is_err());
+//! ```
+//!
+//! ### Describe the JSON schema
+//!
+//! With `schemars08` feature enabled, the doc comment of a newtype that derives `JsonSchema` becomes the `description` of the schema.
+//! An example value can be added to the schema with `schema_example = `. The example is passed through `new()`,
+//! so it is sanitized and validated like any other value, and an invalid example panics when the schema is generated:
+//!
+//! ```ignore
+//! /// Unique identifier of a customer.
+//! #[nutype(
+//!     sanitize(trim, uppercase),
+//!     validate(not_empty, len_char_max = 20),
+//!     derive(Debug, JsonSchema),
+//!     schema_example = "CUST-0042",
+//! )]
+//! pub struct CustomerId(String);
+//! ```
+//!
+//! ### Store a newtype as JSON with `sqlx`
+//!
+//! With `sqlx` and `serde` features enabled, deriving `SqlxJson` implements `sqlx::Type`, `sqlx::Encode` and `sqlx::Decode`,
+//! so a newtype can live in a JSON column (the inner type must implement `Serialize` and `Deserialize`).
+//! The round-trip works as follows:
+//!
+//! * encoding stores the inner value as JSON, e.g. `Username("alice")` is stored as `"alice"`;
+//! * decoding parses the JSON into the inner type and passes it through `new()`, so the sanitizers and validators are applied again;
+//! * if the stored value violates the validation rules, decoding fails with the validation error.
+//!
+//! ```ignore
+//! #[nutype(
+//!     derive(Debug, SqlxJson),
+//!     validate(not_empty, len_char_max = 20),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! ### Property testing with `proptest`
+//!
+//! With `proptest` feature enabled, deriving `ProptestArbitrary` implements [`proptest::arbitrary::Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html),
+//! so a newtype can be used as an argument in `proptest!` tests. It can coexist with `Arbitrary` of the `arbitrary` crate.
+//!
+//! * numeric boundaries (`greater`, `less_or_equal`, etc.) are turned into ranged strategies;
+//! * string length boundaries are turned into regex strategies like `.{1,20}`, and `regex = ` is used as a strategy directly;
+//! * `starts_with`, `contains` and `ends_with` are glued to the generated strings;
+//! * everything else (e.g. `predicate`) is respected by filtering out generated values that fail validation.
+//!
+//! ```ignore
+//! use nutype::nutype;
+//! #[nutype(
+//!     validate(greater_or_equal = 1, less_or_equal = 6),
+//!     derive(Debug, ProptestArbitrary),
+//! )]
+//! pub struct Dice(u8);
+//! ```
+//!
+//! ### Random values with `rand`
+//!
+//! With `rand` feature enabled, deriving `RandDistribution` implements [`Distribution<T>`](https://docs.rs/rand/0.8/rand/distributions/trait.Distribution.html)
+//! for [`Standard`](https://docs.rs/rand/0.8/rand/distributions/struct.Standard.html) of [rand 0.8](https://crates.io/crates/rand),
+//! so valid values can be generated at runtime with `rng.gen()` (e.g. for load testing):
+//!
+//! * numeric boundaries (`greater`, `less_or_equal`, etc.) define the range of generated numbers;
+//! * string length boundaries define the length of generated strings, and `starts_with`, `contains` and `ends_with` are glued to them;
+//! * `one_of` picks one of the allowed values;
+//! * everything else (e.g. `predicate`) is respected by rejection sampling: a value that fails validation is generated again;
+//! * if no valid value is generated within 1000 attempts, `sample()` panics instead of looping forever;
+//! * `regex` validation is not supported.
+//!
+//! Inner types of `any` based newtypes must be supported by `Standard` too.
+//!
+//! ```ignore
+//! use nutype::nutype;
+//! use rand::Rng;
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 1, less_or_equal = 6),
+//!     derive(Debug, RandDistribution),
+//! )]
+//! pub struct Dice(u8);
+//!
+//! let dice: Dice = rand::thread_rng().gen();
+//! ```
+//!
+//! ### Cache newtypes in Redis
+//!
+//! With `redis` feature enabled, a newtype can derive `ToRedisArgs` and `FromRedisValue`:
+//!
+//! * `ToRedisArgs` writes the inner value transparently, e.g. `UserId(42)` is written as `42`;
+//! * `FromRedisValue` reads the inner value and passes it through `new()`, so the sanitizers and validators are applied again;
+//! * if the stored value violates the validation rules, reading fails with a `redis::RedisError` of kind `TypeError`.
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(greater = 0),
+//!     derive(Debug, ToRedisArgs, FromRedisValue),
+//! )]
+//! pub struct UserId(u64);
+//! ```
+//!
+//! ### Encode with `bincode`
+//!
+//! With `bincode` feature enabled, a newtype can derive `Encode` and `Decode` traits of [bincode 2](https://crates.io/crates/bincode)
+//! without going through serde:
+//!
+//! * `Encode` writes the inner value transparently, so `Username("alice")` is encoded exactly as `"alice"`;
+//! * `Decode` (and `BorrowDecode`) reads the inner value and passes it through `new()`, so the sanitizers and validators are applied again;
+//! * if the decoded value violates the validation rules, decoding fails with `bincode::error::DecodeError::OtherString`.
+//!
+//! ```ignore
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(not_empty),
+//!     derive(Debug, Encode, Decode),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! ### Map protobuf messages
+//!
+//! There is no `prost` feature and none is needed: messages generated by [`prost`](https://crates.io/crates/prost)
+//! use plain scalar types for their fields (e.g. `String` or `i64`), so the standard conversions are enough.
+//! Derive `Into` to put a newtype into a message and `TryFrom` (or `From` when there is no validation)
+//! to get it out of a message, validating the value on the way:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! // Generated by prost for `message User { string username = 1; int64 balance = 2; }`
+//! pub struct UserMessage {
+//!     pub username: String,
+//!     pub balance: i64,
+//! }
+//!
+//! #[nutype(validate(not_empty), derive(Debug, Into, TryFrom))]
+//! pub struct Username(String);
+//!
+//! #[nutype(derive(Debug, Into, From))]
+//! pub struct Balance(i64);
+//!
+//! # fn main() {
+//! let message = UserMessage { username: "ferris".to_string(), balance: 100 };
+//! let username = Username::try_from(message.username).unwrap();
+//! let balance = Balance::from(message.balance);
+//!
+//! let message = UserMessage { username: username.into(), balance: balance.into() };
+//! assert_eq!(message.username, "ferris");
+//! # }
+//! ```
+//!
+//! ### Generic numeric code with `num-traits`
+//!
+//! With `num-traits` feature enabled, integer and float based newtypes can derive `Zero`, `One` and `Bounded` from [`num-traits`](https://crates.io/crates/num-traits):
+//!
+//! * `Bounded` returns the boundaries set by `greater`, `greater_or_equal`, `less` and `less_or_equal` validators, or the boundaries of the inner type;
+//! * `Zero` and `One` can be derived only if `0` and `1` pass the validation, which is verified at compile time;
+//! * types with `predicate` or `with_result` validators cannot derive them, since the validation cannot be checked at compile time;
+//! * `Zero` and `One` also require `Add` and `Mul` implementations, which have to be provided manually.
+//!
+//! ```ignore
+//! #[nutype(
+//!     validate(greater_or_equal = 1, less_or_equal = 12),
+//!     derive(Debug, Bounded),
+//! )]
+//! pub struct Month(u8);
+//! ```
+//!
+//! ### Derive `Eq`, `Ord` and `Hash` on float types
+//!
+//! With nutype it's possible to derive `Eq`, `Ord` and `Hash` if there is `finite` validation set.
+//! The `finite` validation ensures that the valid value excludes `NaN`.
+//! `Hash` is computed from the bit pattern of the value, with `-0.0` hashed as `0.0`, so it agrees with `PartialEq`.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(finite),
+//!     derive(PartialEq, Eq, PartialOrd, Ord, Hash),
+//! )]
+//! pub struct Weight(f64);
+//! ```
+//!
+//! ### Non-exhaustive error enum
+//!
+//! If the generated error enum is part of your public API, it can be marked with `#[non_exhaustive]`,
+//! so adding a new validator later is not a breaking change for the downstream crates:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     non_exhaustive_error,
+//!     validate(not_empty, len_char_max = 20),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! Note, that this forces the downstream crates to include a wildcard arm (`_ => ...`) when they `match` on `UsernameError`.
+//!
+//! ### Report all validation errors
+//!
+//! By default validation stops at the first violated validator. For form-style APIs it's often more helpful
+//! to report everything at once, which can be enabled with `collect_errors`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     collect_errors,
+//!     validate(len_char_min = 3, len_char_max = 20, alphanumeric),
+//! )]
+//! pub struct Username(String);
+//! ```
+//!
+//! All the validators are checked then. If only one of them is violated, the error is returned as it is, otherwise the
+//! errors are wrapped into `UsernameError::Multiple(Vec<UsernameError>)`. Its `Display` lists all the messages, so
+//! `Deserialize` reports all of them as well.
+//!
+//! ### Handle errors of different newtypes
+//!
+//! Every generated error implements `nutype::ValidationError`, which exposes the name of the newtype with `kind()`
+//! and the name of the violated validator with `constraint()`. This allows to log errors of unrelated newtypes the same way:
+//!
+//! ```rs
+//! use nutype::ValidationError;
+//!
+//! fn log_error(err: &dyn ValidationError) {
+//!     // e.g. "Username violates len_char_max: Username is too long. ..."
+//!     eprintln!("{} violates {}: {}", err.kind(), err.constraint(), err);
+//! }
+//! ```
+//!
+//! ### Transparent layout for FFI
+//!
+//! With `repr_transparent` the newtype is marked with `#[repr(transparent)]`, so it's guaranteed to have the same layout and ABI as its inner type.
+//! It's supported only by integer and float based types.
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(repr_transparent, validate(greater = 0), derive(Debug, Clone, Copy))]
+//! pub struct Port(u16);
+//!
+//! let ports = [Port::new(80).unwrap(), Port::new(443).unwrap()];
+//! // SAFETY: `Port` has the same layout as `u16`.
+//! let raw: &[u16] = unsafe { std::slice::from_raw_parts(ports.as_ptr().cast(), ports.len()) };
+//! assert_eq!(raw, &[80, 443]);
+//! ```
+//!
+//! Note that only the direction from a newtype to its inner type is safe: transmuting a raw value into a newtype bypasses the validation.
+//!
+//! ### Iterate over all valid values
+//!
+//! For a small bounded integer type `enumerate` generates `all()`, that iterates over all the valid values in ascending order.
+//! It requires both a lower (`greater_or_equal` or `greater`) and an upper (`less_or_equal` or `less`) boundary.
+//! The values rejected by other validators (e.g. `step` or `predicate`) are skipped:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     enumerate,
+//!     validate(greater_or_equal = 1, less_or_equal = 6),
+//! )]
+//! pub struct Dice(u8);
+//!
+//! let faces: Vec<u8> = Dice::all().map(Dice::into_inner).collect();
+//! assert_eq!(faces, vec![1, 2, 3, 4, 5, 6]);
+//! ```
+//!
+//! It's handy for exhaustive tests and for building a list of options (e.g. a dropdown).
+//!
+//! ### Read the declared boundaries
+//!
+//! Tools that describe the types outside of the macro (e.g. generate OpenAPI docs) can read the boundaries
+//! declared with the validators from associated consts. Integer and float based types get `MIN` and `MAX`
+//! (of the inner type) together with `MIN_EXCLUSIVE` and `MAX_EXCLUSIVE`, string based types get `MIN_LEN` and `MAX_LEN` (in chars):
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(greater = 0, less_or_equal = 100))]
+//! pub struct Percentage(u8);
+//!
+//! assert_eq!((Percentage::MIN, Percentage::MIN_EXCLUSIVE), (Some(0), true));
+//! assert_eq!((Percentage::MAX, Percentage::MAX_EXCLUSIVE), (Some(100), false));
+//!
+//! #[nutype(validate(not_empty, len_char_max = 20))]
+//! pub struct Username(String);
+//!
+//! assert_eq!(Username::MIN_LEN, Some(1));
+//! assert_eq!(Username::MAX_LEN, Some(20));
+//! ```
+//!
+//! A const is `None` when the corresponding validator is not set.
+//!
+//! ### Unused values
+//!
+//! The generated newtype and its constructor are marked with `#[must_use]`, so a call like `Username::new(name);` that
+//! drops the result produces a warning. If there is a good reason to create a value and throw it away, the attribute can be
+//! omitted with `allow_unused`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     allow_unused,
+//!     sanitize(trim, lowercase),
+//! )]
+//! pub struct Tag(String);
+//! ```
+//!
+//! ### Rename the constructor
+//!
+//! By default the constructor is called `new()`. It can be renamed with `new_fn = `, e.g. to follow the
+//! convention of naming fallible constructors `try_new`:
+//!
+//! ```
+//! use nutype::nutype;
+//! #[nutype(
+//!     new_fn = try_new,
+//!     validate(greater_or_equal = 1, less_or_equal = 12),
+//! )]
+//! pub struct Month(u8);
+//!
+//! let month = Month::try_new(3).unwrap();
+//! assert!(Month::try_new(13).is_err());
+//! ```
+//!
+//! The derived traits (e.g. `TryFrom`, `FromStr`, `Deserialize`) call the renamed constructor, so they keep working as before.
+//!
+//! ### Hide the constructor
+//!
+//! With `skip_new` the constructor is private, so a value can be constructed only through the derived traits
+//! (e.g. `TryFrom`, `FromStr` or `Deserialize`). This helps to keep a single entry point for the construction:
+//!
+//! ```rs
+//! #[nutype(
+//!     skip_new,
+//!     validate(greater_or_equal = 1, less_or_equal = 12),
+//!     derive(Debug, TryFrom, Deserialize),
+//! )]
+//! pub struct Month(u8);
+//!
+//! let month = Month::try_from(3).unwrap();
+//! // Month::new(3) does not compile
+//! ```
+//!
+//! At least one trait that constructs the value must be derived. `skip_new` does not affect `new_unchecked()`:
+//! it stays public, since it has to be opted in explicitly anyway.
+//!
+//! ### Access the inner value
+//!
+//! Every newtype gets `into_inner()`, which consumes the newtype and returns the inner value,
+//! and `inner()`, which borrows it:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(finite))]
+//! pub struct Ratio(f64);
+//!
+//! let ratio = Ratio::new(0.5).unwrap();
+//! assert_eq!(ratio.inner(), &0.5);
+//! assert_eq!(ratio.into_inner(), 0.5);
+//! ```
+//!
+//! To convert a newtype into its inner value with the standard traits, derive `IntoInner`.
+//! It implements `From<Newtype> for Inner` (and so `Into<Inner> for Newtype`), which makes the direction of the conversion explicit
+//! and works well for the codebases that avoid `Deref`. It is the same as deriving `Into`,
+//! and it can be derived together with `From`, which converts in the opposite direction:
+//!
+//! ```
+//! use nutype::nutype;
+//! #[nutype(derive(Debug, PartialEq, From, IntoInner))]
+//! pub struct Age(u8);
+//!
+//! let age = Age::from(32);
+//! let raw: u8 = age.into();
+//! assert_eq!(raw, 32);
+//! ```
+//!
+//! ### Borrow the inner value as `Cow`
+//!
+//! Every newtype gets `as_cow()`, which borrows the inner value without cloning it.
+//! For string based types it returns `Cow<'_, str>`, so it can be passed directly to APIs that take `Cow<str>`:
+//!
+//! ```
+//! use nutype::nutype;
+//! use std::borrow::Cow;
+//!
+//! #[nutype(sanitize(trim))]
+//! pub struct Username(String);
+//!
+//! let username = Username::new("  ferris ");
+//! assert_eq!(username.as_cow(), Cow::Borrowed("ferris"));
+//! ```
+//!
+//! For other types it returns `Cow<'_, T>` where `T` is the inner type. It is available as long as the inner type implements `Clone`.
+//!
+//! String based types also get `as_str()` and `as_bytes()`, which are handy e.g. for hashing:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(sanitize(trim))]
+//! pub struct Username(String);
+//!
+//! let username = Username::new("  ferris ");
+//! assert_eq!(username.as_str(), "ferris");
+//! assert_eq!(username.as_bytes(), b"ferris");
+//! ```
+//!
+//! ### Modify the inner value
+//!
+//! Nutype does not implement `AsMut` or `DerefMut`, since it would allow to bypass the validation.
+//! Instead, every newtype gets `modify()`, which applies a closure to a copy of the inner value and passes the result through the constructor.
+//! For types with validation it returns `Result`, and the value is left unchanged if the validation fails:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize(trim),
+//!     validate(not_empty),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Name(String);
+//!
+//! let mut name = Name::new("Anna").unwrap();
+//!
+//! name.modify(|s| s.push_str(" Maria ")).unwrap();
+//! assert_eq!(name.as_cow(), "Anna Maria");
+//!
+//! assert_eq!(name.modify(|s| s.clear()), Err(NameError::NotEmptyViolated));
+//! assert_eq!(name.as_cow(), "Anna Maria");
+//! ```
+//!
+//! It is available as long as the inner type implements `Clone`.
+//!
+//! `try_map()` does the same by value: it consumes the newtype, transforms the inner value and
+//! wraps the result again, so the sanitizers and validators are applied to it. Types without validation get an infallible `map()`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(sanitize(trim), validate(not_empty), derive(Debug, PartialEq))]
+//! pub struct Name(String);
+//!
+//! #[nutype(sanitize(with = |n| n % 360))]
+//! pub struct Degree(i32);
+//!
+//! let name = Name::new("anna").unwrap();
+//! let name = name.try_map(|s| s.to_uppercase()).unwrap();
+//! assert_eq!(name.as_str(), "ANNA");
+//! assert_eq!(name.try_map(|_| String::new()), Err(NameError::NotEmptyViolated));
+//!
+//! let degree = Degree::new(350).map(|d| d + 20);
+//! assert_eq!(degree.into_inner(), 10);
+//! ```
+//!
+//! `Vec<T>` based types also get `try_push()`, which appends an item in place and validates
+//! the whole collection again. The item is popped back if it does not fit, so no clone is needed.
+//! With sanitizers `try_push()` goes through `modify()` instead, so the inner type must be `Clone`.
+//! Types without validation get an infallible `push()`:
+//!
+//! ```rs
+//! #[nutype(validate(len_max = 2), derive(Debug, PartialEq))]
+//! pub struct Tags(Vec<String>);
+//!
+//! let mut tags = Tags::new(vec!["rust".to_string()]).unwrap();
+//! tags.try_push("nutype".to_string()).unwrap();
+//! assert_eq!(tags.try_push("macros".to_string()), Err(TagsError::LenMaxViolated));
+//! assert_eq!(tags.into_inner(), vec!["rust", "nutype"]);
+//! ```
+//!
+//! ### Build a value step by step
+//!
+//! With `builder` a newtype gets `builder()`, which returns a companion builder (e.g. `LocationBuilder` for `Location`).
+//! The builder holds a raw inner value, which is changed with chained `update()` calls,
+//! and `build()` passes the final value through the constructor, so the sanitizers and validators are applied once.
+//! `build()` returns `Result` for types with validation and the newtype itself otherwise.
+//! It is handy for newtypes that wrap a struct:
+//!
+//! ```rs
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! pub struct Point {
+//!     x: i32,
+//!     y: i32,
+//! }
+//!
+//! #[nutype(
+//!     validate(predicate = |p| p.x.abs() <= 100 && p.y.abs() <= 100),
+//!     builder,
+//!     derive(Debug),
+//! )]
+//! pub struct Location(Point);
+//!
+//! let location = Location::builder(Point { x: 0, y: 0 })
+//!     .update(|p| p.x = 42)
+//!     .update(|p| p.y = -7)
+//!     .build()
+//!     .unwrap();
+//! assert_eq!(location.into_inner(), Point { x: 42, y: -7 });
+//! ```
+//!
+//! `builder` cannot be combined with `context = `, `async_with` or `skip_new`.
+//!
+//! ### Validate with a runtime context
+//!
+//! Some rules depend on runtime configuration, e.g. the max length of a bio is read from settings.
+//! With `context = ` the custom validators (`predicate` and `with_result`) receive a reference to the context as the second argument,
+//! and the constructor turns into `new_with_context()`, which takes the context together with the value.
+//! The error type stays the same:
+//!
+//! ```
+//! use nutype::nutype;
+//! pub struct Settings {
+//!     pub max_bio_len: usize,
+//! }
+//!
+//! #[nutype(
+//!     context = Settings,
+//!     sanitize(trim),
+//!     validate(not_empty, predicate = |bio, settings| bio.chars().count() <= settings.max_bio_len),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Bio(String);
+//!
+//! # fn main() {
+//! let settings = Settings { max_bio_len: 6 };
+//! assert!(Bio::new_with_context(" Ferris ", &settings).is_ok());
+//! assert_eq!(Bio::new_with_context("Rustacean", &settings), Err(BioError::PredicateViolated));
+//! # }
+//! ```
+//!
+//! A value cannot be built without a context, so `new()`, `modify()` and `try_map()` are not generated,
+//! and the traits that construct a value (e.g. `TryFrom`, `FromStr`, `Deserialize`, `Default`) cannot be derived.
+//! The public `validate()` takes the context too.
+//!
+//! ### Validate asynchronously
+//!
+//! Some rules cannot be checked without I/O, e.g. whether a username is already taken.
+//! With the feature `async` a validator set with `async_with = ` returns a future of `Result<(), String>`,
+//! and the value is constructed with `new_async()`, which runs the regular sanitizers and validators first
+//! and then awaits the async validators one after another:
+//!
+//! ```ignore
+//! async fn is_not_taken(username: &str) -> Result<(), String> {
+//!     // e.g. query the database
+//!     if username == "admin" {
+//!         Err("the username is taken".to_string())
+//!     } else {
+//!         Ok(())
+//!     }
+//! }
+//!
+//! #[nutype(
+//!     sanitize(trim, lowercase),
+//!     validate(not_empty, async_with = is_not_taken),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Username(String);
+//!
+//! assert!(Username::new_async(" Ferris ").await.is_ok());
+//! assert_eq!(
+//!     Username::new_async(" Admin ").await,
+//!     Err(UsernameAsyncError::AsyncWithViolated("the username is taken".to_string())),
+//! );
+//! assert_eq!(
+//!     Username::new_async("").await,
+//!     Err(UsernameAsyncError::Validate(UsernameError::NotEmptyViolated)),
+//! );
+//! ```
+//!
+//! A closure works too, e.g. `async_with = |name| async move { ... }`. Leave its argument without a type annotation,
+//! so the future may borrow it.
+//! The regular constructor is private, so a value cannot skip the async validators: `modify()` and `try_map()` are not generated,
+//! and the traits that construct a value (e.g. `TryFrom`, `FromStr`, `Deserialize`, `Default`) cannot be derived.
+//!
+//! ### Read from an environment variable
+//!
+//! With `from_env` a newtype that derives `FromStr` gets `from_env()`, which reads an environment variable
+//! and parses it with `FromStr`, so the value is sanitized and validated the same way as with `parse()`.
+//! It returns `<TypeName>FromEnvError`, which tells apart the cases when the variable is not set (`Missing`),
+//! cannot be parsed into the inner type (`Parse`) or violates the validation rules (`Validate`):
+//!
+//! ```
+//! use nutype::nutype;
+//! #[nutype(
+//!     validate(greater_or_equal = 1024),
+//!     derive(Debug, FromStr),
+//!     from_env,
+//! )]
+//! pub struct Port(u16);
+//!
+//! std::env::set_var("PORT", "8080");
+//! let port = Port::from_env("PORT").unwrap();
+//! assert_eq!(port.into_inner(), 8080);
+//!
+//! assert!(matches!(Port::from_env("NO_SUCH_PORT"), Err(PortFromEnvError::Missing { .. })));
+//! ```
+//!
+//! `Parse` is available only for non-string types, since parsing of a string cannot fail.
+//! `from_env` requires `std` feature.
+//!
+//! ### Decode bytes from hex or base64
+//!
+//! Keys, hashes and signatures are usually passed around as hex or base64 strings.
+//! With `from_hex` (requires `hex` feature) or `from_base64` (requires `base64` feature) a `Vec<u8>` based newtype
+//! gets `FromStr`, which decodes the string and then passes the bytes to the constructor, so they are sanitized and validated as usual.
+//! It returns `<TypeName>ParseError`, which tells apart a string that cannot be decoded (`Decode`)
+//! and bytes that violate the validation rules (`Validate`):
+//!
+//! ```ignore
+//! #[nutype(
+//!     from_hex,
+//!     validate(len_min = 4, len_max = 4),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Signature(Vec<u8>);
+//!
+//! let signature: Signature = "c0ffee00".parse().unwrap();
+//! assert_eq!(signature.into_inner(), vec![0xC0, 0xFF, 0xEE, 0x00]);
+//!
+//! assert!(matches!("c0ffee0".parse::<Signature>(), Err(SignatureParseError::Decode(_))));
+//! assert!(matches!("c0ffee".parse::<Signature>(), Err(SignatureParseError::Validate(_))));
+//! ```
+//!
+//! `from_base64` expects the standard alphabet with padding.
+//! Since `FromStr` is generated, it must not be derived.
+//!
+//! ### Report whether sanitizers changed the value
+//!
+//! For data-cleaning pipelines it's useful to know whether the input was already clean. With `sanitize_then_return_changed`
+//! an associated `new_reporting()` function is generated. It constructs the value the same way as the constructor does
+//! and also returns `true` if the sanitizers changed the raw value:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     sanitize_then_return_changed,
+//!     sanitize(trim, lowercase),
+//!     validate(not_empty),
+//! )]
+//! pub struct Email(String);
+//!
+//! let (email, changed) = Email::new_reporting("  Foo@Bar.com ").unwrap();
+//! assert_eq!(email.into_inner(), "foo@bar.com");
+//! assert!(changed);
+//!
+//! let (_email, changed) = Email::new_reporting("foo@bar.com").unwrap();
+//! assert!(!changed);
+//! ```
+//!
+//! For types without validation `new_reporting()` returns `(Self, bool)` instead of a `Result`.
+//! The inner type must implement `Clone` and `PartialEq`, which is always the case for strings and numbers.
+//!
+//! ### Validate without constructing
+//!
+//! Types with validation get an associated `validate()` function, which checks a borrowed value against the validation rules
+//! without constructing the newtype. This is useful to collect the errors of all the fields of a form before constructing
+//! anything. Note, that sanitizers are not applied by `validate()`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     validate(len_char_min = 3, len_char_max = 20),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Username(String);
+//!
+//! #[nutype(
+//!     validate(greater_or_equal = 18),
+//!     derive(Debug, PartialEq),
+//! )]
+//! pub struct Age(u8);
+//!
+//! let username_result = Username::validate("al");
+//! let age_result = Age::validate(&21);
+//!
+//! assert_eq!(username_result, Err(UsernameError::LenCharMinViolated));
+//! assert_eq!(age_result, Ok(()));
+//! ```
+//!
+//! ### Restrict enum variants
+//!
+//! Any type can be wrapped, including enums. With `predicate` a newtype can allow only a subset of the variants.
+//! If the enum implements `FromStr`, the derived `FromStr` of the newtype parses the enum first and then validates it,
+//! its error combines both failures:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[derive(Debug, Clone, Copy, PartialEq)]
+//! pub enum Color {
+//!     Red,
+//!     Green,
+//!     Blue,
+//! }
+//!
+//! impl std::str::FromStr for Color {
+//!     type Err = String;
+//!
+//!     fn from_str(s: &str) -> Result<Self, Self::Err> {
+//!         match s {
+//!             "red" => Ok(Color::Red),
+//!             "green" => Ok(Color::Green),
+//!             "blue" => Ok(Color::Blue),
+//!             _ => Err(format!("Unknown color `{s}`")),
+//!         }
+//!     }
+//! }
+//!
+//! #[nutype(
+//!     validate(predicate = |c| matches!(c, Color::Red | Color::Green)),
+//!     derive(Debug, FromStr),
+//! )]
+//! pub struct TrafficLight(Color);
+//!
+//! # fn main() {
+//! assert!(matches!("red".parse::<TrafficLight>(), Ok(_)));
+//! assert!(matches!("purple".parse::<TrafficLight>(), Err(TrafficLightParseError::Parse(_))));
+//! assert!(matches!(
+//!     "blue".parse::<TrafficLight>(),
+//!     Err(TrafficLightParseError::Validate(TrafficLightError::PredicateViolated))
+//! ));
+//! # }
+//! ```
+//!
+//! ### Generic code over newtypes
+//!
+//! Deriving `Nutype` implements the [`Nutype`] trait,
+//! which exposes the inner type, the error type, `new()` and `into_inner()` in a uniform way.
+//! It allows to write generic code that works with any newtype:
+//!
+//! ```
+//! use nutype::{nutype, Nutype};
+//!
+//! #[nutype(derive(Nutype), validate(greater = 0))]
+//! struct Quantity(u32);
+//!
+//! fn parse_all<T: Nutype>(values: Vec<T::Inner>) -> Result<Vec<T>, T::Error> {
+//!     values.into_iter().map(T::new).collect()
+//! }
+//!
+//! let quantities: Vec<Quantity> = parse_all(vec![1, 2, 3]).unwrap();
+//! assert_eq!(quantities.len(), 3);
+//! ```
+//!
+//! For types without validation, the associated `Error` type is `std::convert::Infallible`.
+//!
+//! ### Apply to a type alias
+//!
+//! `#[nutype]` can be applied to a type alias as well.
+//! The alias is replaced by a tuple struct with the same name, visibility, doc comments and generics that wraps the aliased type,
+//! so `type UserId = u64;` produces `struct UserId(u64);`. It is a distinct type, not an alias anymore:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(validate(greater = 0), derive(Debug, Clone, Copy, PartialEq))]
+//! pub type UserId = u64;
+//!
+//! let id = UserId::new(42).unwrap();
+//! assert_eq!(id.into_inner(), 42);
+//! assert_eq!(UserId::new(0), Err(UserIdError::GreaterViolated));
+//! ```
+//!
+//! ### Set the inner type explicitly
+//!
+//! The kind of a newtype (string, integer, float or any other) is recognized by the name of the inner type as it's written,
+//! e.g. `String` or `u64`. A type behind an alias, a re-exported type or a full path (e.g. `std::string::String`)
+//! is not recognized and is treated as any other type, so string and number specific sanitizers, validators and traits are not available.
+//! In such case set the inner type explicitly with `inner = `:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! type Cents = u64;
+//!
+//! #[nutype(inner = u64, validate(less_or_equal = 100), derive(Debug))]
+//! pub struct Discount(Cents);
+//!
+//! assert_eq!(Discount::new(50).unwrap().into_inner(), 50);
+//! assert_eq!(Discount::MAX, Some(100));
+//! ```
+//!
+//! The macro checks that `inner` is the same type as the declared one, so it can only name it differently.
+//! `inner` cannot be used with generic newtypes.
+//!
+//! ### Re-export nutype from another crate
+//!
+//! The generated code refers to `nutype` and to the crates required by the derived traits (e.g. `serde`) with absolute paths,
+//! like `::serde::Serialize`. When `nutype` is used through a facade crate that re-exports it,
+//! these crates are not dependencies of the crate that uses the macro, so the paths don't resolve.
+//! Set a module that re-exports them with `crate = `, and the generated code refers to them through it:
+//!
+//! ```rs
+//! // In the facade crate:
+//! pub use nutype::nutype;
+//!
+//! pub mod reexports {
+//!     pub use nutype;
+//!     pub use serde;
+//! }
+//!
+//! // In the crate that depends only on the facade:
+//! use facade::nutype;
+//!
+//! #[nutype(crate = facade::reexports, derive(Debug, Serialize, Deserialize))]
+//! pub struct Username(String);
+//! ```
+//!
+//! The derive macros of other crates (e.g. `JsonSchema`) are invoked through the path too,
+//! but their own generated code resolves the crates as those macros do.
+//! See [reexport_example](https://github.com/greyblake/nutype/tree/master/examples/reexport_example).
+//!
+//! ## How to break the constraints?
+//!
+//! It's discouraged, but it's possible to bypass the constraints by enabling `new_unchecked` crate feature and marking a type with `new_unchecked`:
+//!
+//! ```ignore
+//! use nutype::nutype;
+//!
+//! #[nutype(
+//!     new_unchecked
+//!     sanitize(trim)
+//!     validate(len_char_min = 8)
+//! )]
+//! pub struct Name(String);
+//!
+//! // Yes, you're forced to use `unsafe` here, so everyone will point fingers at YOU.
+//! let name = unsafe { Name::new_unchecked(" boo ".to_string()) };
+//!
+//! // `name` violates the sanitization and validation rules!!!
+//! assert_eq!(name.into_inner(), " boo ");
+//! ```
+//!
+//! ## Exposing the inner field with inner_vis
+//!
+//! By default the inner field is private, so the only way to obtain a value is to pass the guards.
+//! For FFI and other interop code that needs direct access to the representation, the field can be made `pub` or `pub(crate)` with `inner_vis`:
+//!
+//! ```
+//! use nutype::nutype;
+//!
+//! #[nutype(inner_vis = pub(crate), validate(finite), derive(Debug))]
+//! pub struct Latitude(f64);
+//!
+//! let latitude = Latitude::new(52.52).unwrap();
+//! assert_eq!(latitude.0, 52.52);
+//!
+//! // Nothing stops you from constructing an invalid value now!
+//! let latitude = Latitude(f64::NAN);
+//! ```
+//!
+//! **WARNING:** this throws away the main guarantee of nutype.
+//! Anyone who can see the field can construct or modify a value without passing the sanitizers and validators,
+//! so other code can no longer assume that a value is valid.
+//! Prefer `pub(crate)` over `pub`, and keep the code that touches the field to a minimum.
+//!
+//! ## Feature flags
+//!
+//! * `arbitrary` - enables derive of [`arbitrary::Arbitrary`](https://docs.rs/arbitrary/latest/arbitrary/trait.Arbitrary.html).
+//! * `bincode` - integrations with [`bincode`](https://crates.io/crates/bincode) 2 crate. Allows to derive `Encode` and `Decode` (which also implements `BorrowDecode`). Decoding goes through `new()`, so the validation rules are respected.
+//! * `diesel_derive_newtype` - enables derive of [`diesel_derive_newtype::DieselNewType`](https://docs.rs/diesel-derive-newtype/latest/diesel_derive_newtype/). For a type with validation `TryFrom` must be derived as well, so the values read from the database go through `new()` (requires `diesel-derive-newtype` 2.1.3 or newer).
+//! * `email` - allows to use `email` validation on string-based types.
+//! * `url` - allows to use `url` validation on string-based types. Parsing is done with [`url`](https://crates.io/crates/url) crate.
+//! * `hex` - allows to use `from_hex` on byte-based types. Decoding is done with [`hex`](https://crates.io/crates/hex) crate.
+//! * `base64` - allows to use `from_base64` on byte-based types. Decoding is done with [`base64`](https://crates.io/crates/base64) crate.
+//! * `async` - allows to use `async_with = ` validators, which are awaited by the generated `new_async()` function.
+//! * `chrono` - allows to use string literals as boundaries of range validators for [`chrono`](https://crates.io/crates/chrono) date and time inner types. The literals are verified at compile time.
+//! * `unicode-segmentation` - allows to use `len_grapheme_max` validation on string-based types.
+//! * `new_unchecked` - enables generation of unsafe `::new_unchecked()` function.
+//! * `num-traits` - allows to derive `Zero`, `One` and `Bounded` traits of [`num-traits`](https://crates.io/crates/num-traits) crate for integer and float types. `Zero` and `One` require `0` and `1` to pass the validation.
+//! * `proptest` - allows to derive `ProptestArbitrary`, which implements [`proptest::arbitrary::Arbitrary`](https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html) respecting the validation rules.
+//! * `rand` - integrations with [`rand`](https://crates.io/crates/rand) 0.8 crate. Allows to derive `RandDistribution`, which implements `Distribution<T>` for `Standard` producing values that pass the validation rules.
+//! * `redis` - integrations with [`redis`](https://crates.io/crates/redis) 0.27 crate. Allows to derive `ToRedisArgs` and `FromRedisValue`. Reading goes through `new()`, so the validation rules are respected.
+//! * `regex` - allows to use `regex = ` validation on string-based types. Note: your crate also has to explicitly have `regex` and `lazy_static` within dependencies.
+//! * `rkyv` - integrations with [`rkyv`](https://crates.io/crates/rkyv) 0.8 crate. Allows to derive `Archive`, which implements `Archive`, `Serialize` and `Deserialize` traits of rkyv for string, integer and float types. Deserialization goes through `new()`, so the validation rules are respected.
+//! * `serde` - integrations with [`serde`](https://crates.io/crates/serde) crate. Allows to derive `Serialize` and `Deserialize` traits. A value that fails the validation on deserialization is reported with the message of the validation error (and the value itself for integer and float types). Use `serde_repr = object` to represent a newtype as an object instead of its inner value, and `serde_lenient` to accept numeric strings for integer types.
+//! * `serde_json` - implies `serde`. A newtype that derives `Serialize` gets `From<T> for serde_json::Value`, so it can be used with `json!` directly. Requires `serde_json` to be a dependency of your crate.
+//! * `sqlx` - allows to derive `SqlxJson`, which stores a newtype in a JSON column with [`sqlx`](https://crates.io/crates/sqlx) 0.8. Requires `serde` feature as well.
+//! * `schemars08` - allows to derive [`JsonSchema`](https://docs.rs/schemars/0.8.12/schemars/trait.JsonSchema.html) trait of [schemars](https://crates.io/crates/schemars) crate. Note that at the moment validation rules are not respected, but the doc comment and `schema_example = ` are.
+//! * `std` - enabled by default. Use `default-features = false` to disable.
+//! * `alloc` - for `no_std` environments with an allocator. Allows to use `with_result`, `try_with` and `collect_errors` on integer and float types without `std`.
+//!
+//! ## Support Ukrainian military forces 🇺🇦
+//!
+//! Today I live in Berlin, I have the luxury to live a physically safe life.
+//! But I am Ukrainian. The first 25 years of my life I spent in [Kharkiv](https://en.wikipedia.org/wiki/Kharkiv),
+//! the second-largest city in Ukraine, 60km away from the border with russia. Today about [a third of my home city is destroyed](https://www.youtube.com/watch?v=ihoufBFSZds) by russians.
+//! My parents, my relatives and my friends had to survive the artillery and air attack, living for over a month in basements.
+//!
+//! Some of them have managed to evacuate to EU. Some others are trying to live "normal lives" in Kharkiv, doing there daily duties.
+//! And some are at the front line right now, risking their lives every second to protect the rest.
+//!
+//! I encourage you to donate to [Charity foundation of Serhiy Prytula](https://prytulafoundation.org/en).
+//! Just pick the project you like and donate. This is one of the best-known foundations, you can watch a [little documentary](https://www.youtube.com/watch?v=VlmWqoeub1Q) about it.
+//! Your contribution to the Ukrainian military force is a contribution to my calmness, so I can spend more time developing the project.
+//!
+//! Thank you.
+
+// Set `no_std` flag if `std` feature is disabled.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+pub use nutype_macros::nutype;
+
+// Allow the generated code to refer to `::nutype` within this crate (e.g. in the tests below).
+extern crate self as nutype;
+
+// Not a public API. Without `std` the generated code refers to `String` and `Vec` through it.
+#[doc(hidden)]
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+pub mod __private {
+    pub extern crate alloc;
+}
+
+#[cfg(feature = "email")]
+pub mod email;
+
+#[cfg(feature = "url")]
+pub mod url;
+
+#[cfg(feature = "hex")]
+pub mod hex;
+
+#[cfg(feature = "base64")]
+pub mod base64;
+
+#[cfg(feature = "unicode-segmentation")]
+pub mod grapheme;
+
+/// A trait implemented by types generated with `#[nutype]` macro that derive `Nutype`.
+///
+/// It provides a uniform interface over the generated newtypes, so it's possible to write
+/// generic code that works with any of them:
+///
+/// ```
+/// use nutype::{nutype, Nutype};
+///
+/// #[nutype(derive(Nutype), validate(greater = 0))]
+/// struct Quantity(u32);
+///
+/// fn parse_all<T: Nutype>(values: Vec<T::Inner>) -> Result<Vec<T>, T::Error> {
+///     values.into_iter().map(T::new).collect()
+/// }
+///
+/// let quantities: Vec<Quantity> = parse_all(vec![1, 2, 3]).unwrap();
+/// assert_eq!(quantities.len(), 3);
+///
+/// assert!(parse_all::<Quantity>(vec![1, 0]).is_err());
+/// ```
+pub trait Nutype: Sized {
+    /// The inner type of the newtype.
+    type Inner;
+
+    /// The error returned by [`Nutype::new`].
+    /// For types without validation it's [`core::convert::Infallible`].
+    type Error;
+
+    /// Sanitizes and validates the given value and creates an instance of the newtype.
+    fn new(raw_value: Self::Inner) -> Result<Self, Self::Error>;
+
+    /// Returns the inner value.
+    fn into_inner(self) -> Self::Inner;
+}
+
+/// A trait implemented by every validation error generated with `#[nutype]` macro.
+///
+/// It allows to handle and log errors of different newtypes in the same way:
+///
+/// ```
+/// use nutype::{nutype, ValidationError};
+///
+/// #[nutype(validate(len_char_max = 20), derive(Debug))]
+/// struct Username(String);
+///
+/// #[nutype(validate(greater_or_equal = 18), derive(Debug))]
+/// struct Age(u8);
+///
+/// let errors: Vec<Box<dyn ValidationError>> = vec![
+///     Box::new(Username::new("ferris_the_crab_from_rust_land").unwrap_err()),
+///     Box::new(Age::new(16).unwrap_err()),
+/// ];
+///
+/// let logged: Vec<String> = errors
+///     .iter()
+///     .map(|err| format!("{}: {}", err.kind(), err.constraint()))
+///     .collect();
+/// assert_eq!(logged, ["Username: len_char_max", "Age: greater_or_equal"]);
+/// ```
+pub trait ValidationError: core::fmt::Debug + core::fmt::Display {
+    /// The name of the newtype that rejected the value, e.g. `"Username"`.
+    fn kind(&self) -> &'static str;
+
+    /// The name of the violated constraint as it's written in `#[nutype]`, e.g. `"len_char_max"`.
+    ///
+    /// For `SanitizationFailed` it's `"try_with"`. For `Multiple` (see `collect_errors`)
+    /// it's the constraint of the first error.
+    fn constraint(&self) -> &'static str;
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,47 @@
+//! URL parsing used by `validate(url)`. Requires `url` feature.
+
+/// Parses the value as an absolute URL and returns its scheme, or `None` if the value is not a valid URL.
+///
+/// The parsing follows the [WHATWG URL Standard](https://url.spec.whatwg.org/) as implemented by
+/// the [`url`](https://crates.io/crates/url) crate. The scheme is always returned in lowercase:
+///
+/// ```
+/// use nutype::url::parse_url_scheme;
+///
+/// assert_eq!(parse_url_scheme("HTTPS://example.com/"), Some("https".to_string()));
+/// assert_eq!(parse_url_scheme("mailto:someone@example.com"), Some("mailto".to_string()));
+/// assert_eq!(parse_url_scheme("example.com"), None);
+/// ```
+pub fn parse_url_scheme(value: &str) -> Option<String> {
+    ::url::Url::parse(value)
+        .ok()
+        .map(|url| url.scheme().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_url_scheme() {
+        assert_eq!(
+            parse_url_scheme("https://example.com"),
+            Some("https".to_string())
+        );
+        assert_eq!(
+            parse_url_scheme("ftp://user@example.com:21/file.txt"),
+            Some("ftp".to_string())
+        );
+        assert_eq!(
+            parse_url_scheme("git+ssh://example.com/repo.git"),
+            Some("git+ssh".to_string())
+        );
+        assert_eq!(parse_url_scheme(""), None);
+        assert_eq!(parse_url_scheme("/relative/path"), None);
+        assert_eq!(parse_url_scheme("http://"), None);
+        assert_eq!(
+            parse_url_scheme(" https://example.com"),
+            Some("https".to_string())
+        );
+    }
+}
@@ -4,40 +4,106 @@ use quote::quote;
 use crate::{
     any::models::AnyValidator,
     common::{
-        gen::error::{gen_error_type_name, gen_impl_error_trait},
+        gen::error::{
+            gen_error_type_name, gen_impl_error_trait, gen_impl_validation_error_trait,
+            gen_multiple_errors_display_arm, gen_multiple_errors_variant, gen_non_exhaustive_attr,
+            gen_sanitization_failed_display_arm, gen_sanitization_failed_variant,
+        },
         models::{ErrorTypeName, TypeName},
+        parse::kind_name,
     },
 };
 
-pub fn gen_validation_error_type(type_name: &TypeName, validators: &[AnyValidator]) -> TokenStream {
+pub fn gen_validation_error_type(
+    type_name: &TypeName,
+    validators: &[&AnyValidator],
+    non_exhaustive_error: bool,
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
+) -> TokenStream {
     let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
+    let definition = gen_definition(
+        &error_type_name,
+        validators,
+        collect_errors,
+        has_fallible_sanitizers,
+    );
+    let impl_display_trait = gen_impl_display_trait(
+        type_name,
+        &error_type_name,
+        validators,
+        collect_errors,
+        has_fallible_sanitizers,
+    );
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
+    let impl_validation_error_trait = gen_impl_validation_error_trait(
+        type_name,
+        &error_type_name,
+        gen_constraint_arms(&error_type_name, validators),
+        collect_errors,
+        has_fallible_sanitizers,
+    );
+    let non_exhaustive_attr = gen_non_exhaustive_attr(non_exhaustive_error);
 
     quote! {
         #[derive(Debug, Clone, PartialEq, Eq)]
+        #non_exhaustive_attr
         #definition
 
         #impl_display_trait
         #impl_error_trait
+        #impl_validation_error_trait
     }
 }
 
-fn gen_definition(error_type_name: &ErrorTypeName, validators: &[AnyValidator]) -> TokenStream {
+fn gen_definition(
+    error_type_name: &ErrorTypeName,
+    validators: &[&AnyValidator],
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
+) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
         .map(|validator| match validator {
+            AnyValidator::LenMin(_) => {
+                quote!(LenMinViolated,)
+            }
+            AnyValidator::LenMax(_) => {
+                quote!(LenMaxViolated,)
+            }
+            AnyValidator::NotEmpty => {
+                quote!(NotEmptyViolated,)
+            }
+            AnyValidator::Greater(_) => {
+                quote!(GreaterViolated,)
+            }
+            AnyValidator::GreaterOrEqual(_) => {
+                quote!(GreaterOrEqualViolated,)
+            }
+            AnyValidator::Less(_) => {
+                quote!(LessViolated,)
+            }
+            AnyValidator::LessOrEqual(_) => {
+                quote!(LessOrEqualViolated,)
+            }
             AnyValidator::Predicate(_) => {
                 quote!(PredicateViolated,)
             }
+            AnyValidator::WithResult(_) => {
+                quote!(WithResultViolated(::std::string::String),)
+            }
         })
         .collect();
 
+    let multiple_variant = gen_multiple_errors_variant(error_type_name, collect_errors);
+    let sanitization_failed_variant = gen_sanitization_failed_variant(has_fallible_sanitizers);
+
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
             #error_variants
+            #sanitization_failed_variant
+            #multiple_variant
         }
     }
 }
@@ -45,21 +111,78 @@ fn gen_definition(error_type_name: &ErrorTypeName, validators: &[AnyValidator])
 fn gen_impl_display_trait(
     type_name: &TypeName,
     error_type_name: &ErrorTypeName,
-    validators: &[AnyValidator],
+    validators: &[&AnyValidator],
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
+        AnyValidator::LenMin(len_min) => quote! {
+             #error_type_name::LenMinViolated => write!(f, "{} is too short. The length must be at least {:#?}.", stringify!(#type_name), #len_min)
+        },
+        AnyValidator::LenMax(len_max) => quote! {
+             #error_type_name::LenMaxViolated => write!(f, "{} is too long. The length must be at most {:#?}.", stringify!(#type_name), #len_max)
+        },
+        AnyValidator::NotEmpty => quote! {
+             #error_type_name::NotEmptyViolated => write!(f, "{} is empty.", stringify!(#type_name))
+        },
+        AnyValidator::Greater(val) => quote! {
+             #error_type_name::GreaterViolated => write!(f, "{} is too small. The value must be greater than {:?}.", stringify!(#type_name), #val)
+        },
+        AnyValidator::GreaterOrEqual(val) => quote! {
+             #error_type_name::GreaterOrEqualViolated => write!(f, "{} is too small. The value must be greater or equal to {:?}.", stringify!(#type_name), #val)
+        },
+        AnyValidator::Less(val) => quote! {
+             #error_type_name::LessViolated => write!(f, "{} is too big. The value must be less than {:?}.", stringify!(#type_name), #val)
+        },
+        AnyValidator::LessOrEqual(val) => quote! {
+             #error_type_name::LessOrEqualViolated => write!(f, "{} is too big. The value must be less or equal to {:?}.", stringify!(#type_name), #val)
+        },
         AnyValidator::Predicate(_) => quote! {
              #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
+        AnyValidator::WithResult(_) => quote! {
+             #error_type_name::WithResultViolated(message) => write!(f, "{} is invalid: {}", stringify!(#type_name), message)
+        },
     });
 
+    let sanitization_failed_arm =
+        gen_sanitization_failed_display_arm(type_name, error_type_name, has_fallible_sanitizers);
+    let multiple_arm = gen_multiple_errors_display_arm(error_type_name, collect_errors);
+
     quote! {
         impl ::core::fmt::Display for #error_type_name {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 match self {
                     #(#match_arms,)*
+                    #sanitization_failed_arm
+                    #multiple_arm
                 }
             }
         }
     }
 }
+
+/// Map every error variant to the name of the validator that produces it.
+fn gen_constraint_arms(
+    error_type_name: &ErrorTypeName,
+    validators: &[&AnyValidator],
+) -> Vec<TokenStream> {
+    validators
+        .iter()
+        .map(|validator| {
+            let constraint = kind_name(validator.kind());
+            let pattern = match validator {
+                AnyValidator::LenMin(_) => quote!(#error_type_name::LenMinViolated),
+                AnyValidator::LenMax(_) => quote!(#error_type_name::LenMaxViolated),
+                AnyValidator::NotEmpty => quote!(#error_type_name::NotEmptyViolated),
+                AnyValidator::Greater(_) => quote!(#error_type_name::GreaterViolated),
+                AnyValidator::GreaterOrEqual(_) => quote!(#error_type_name::GreaterOrEqualViolated),
+                AnyValidator::Less(_) => quote!(#error_type_name::LessViolated),
+                AnyValidator::LessOrEqual(_) => quote!(#error_type_name::LessOrEqualViolated),
+                AnyValidator::Predicate(_) => quote!(#error_type_name::PredicateViolated),
+                AnyValidator::WithResult(_) => quote!(#error_type_name::WithResultViolated(_)),
+            };
+            quote!(#pattern => #constraint)
+        })
+        .collect()
+}
@@ -5,14 +5,18 @@ use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::parse_quote;
+use syn::{parse_quote, Generics};
 
 use crate::common::{
     gen::{
-        error::gen_error_type_name, tests::gen_test_should_have_valid_default_value,
-        traits::GeneratedTraits, GenerateNewtype,
+        error::gen_error_type_name, gen_call_custom_validation_fn, gen_call_fallible_sanitizer,
+        gen_context_param, gen_sanitize_output, gen_validation_checks,
+        tests::gen_test_should_have_valid_default_value, traits::GeneratedTraits, GenerateNewtype,
+    },
+    models::{
+        has_fallible_sanitizers, ConstructorName, EqMode, ErrorTypeName, Guard, OrdMode, SerdeRepr,
+        TypeName, TypedCustomFunction,
     },
-    models::{ErrorTypeName, Guard, TypeName, TypedCustomFunction},
 };
 
 use self::error::gen_validation_error_type;
@@ -49,13 +53,30 @@ impl GenerateNewtype for AnyNewtype {
                         value = (#typed_sanitizer)(value);
                     )
                 }
+                AnySanitizer::TryWith(custom_sanitizer) => {
+                    let inner_type_ref: syn::Type = parse_quote!(
+                        #inner_type
+                    );
+                    let typed_sanitizer: TypedCustomFunction = custom_sanitizer
+                        .clone()
+                        .try_into_typed(&inner_type_ref)
+                        .expect("Failed to convert `try_with` sanitizer into a typed closure");
+                    let call = gen_call_fallible_sanitizer(typed_sanitizer);
+                    quote!(
+                        value = #call;
+                    )
+                }
             })
             .collect();
+        let (output_type, output_value) =
+            gen_sanitize_output(inner_type, has_fallible_sanitizers(sanitizers));
 
+        let lifetimes = inner_type.lifetimes();
+        let type_params = inner_type.type_params();
         quote!(
-            fn sanitize(mut value: #inner_type) -> #inner_type {
+            fn sanitize<#(#lifetimes,)* #(#type_params),*>(mut value: #inner_type) -> #output_type {
                 #transformations
-                value
+                #output_value
             }
         )
     }
@@ -64,31 +85,118 @@ impl GenerateNewtype for AnyNewtype {
         inner_type: &Self::InnerType,
         type_name: &TypeName,
         validators: &[Self::Validator],
+        collect_errors: bool,
+        maybe_context: Option<&syn::Type>,
     ) -> TokenStream {
         let error_name = gen_error_type_name(type_name);
+        let context_param = gen_context_param(maybe_context);
 
-        let validations: TokenStream = validators
+        let checks: Vec<TokenStream> = validators
             .iter()
             .map(|validator| match validator {
+                AnyValidator::LenMin(len_min) => {
+                    quote!(
+                        if val.len() < #len_min {
+                            return Err(#error_name::LenMinViolated);
+                        }
+                    )
+                }
+                AnyValidator::LenMax(len_max) => {
+                    quote!(
+                        if val.len() > #len_max {
+                            return Err(#error_name::LenMaxViolated);
+                        }
+                    )
+                }
+                AnyValidator::NotEmpty => {
+                    quote!(
+                        if val.is_empty() {
+                            return Err(#error_name::NotEmptyViolated);
+                        }
+                    )
+                }
+                AnyValidator::Greater(exclusive_lower_bound) => {
+                    quote!(
+                        if val <= &(#exclusive_lower_bound) {
+                            return Err(#error_name::GreaterViolated);
+                        }
+                    )
+                }
+                AnyValidator::GreaterOrEqual(min) => {
+                    quote!(
+                        if val < &(#min) {
+                            return Err(#error_name::GreaterOrEqualViolated);
+                        }
+                    )
+                }
+                AnyValidator::Less(exclusive_upper_bound) => {
+                    quote!(
+                        if val >= &(#exclusive_upper_bound) {
+                            return Err(#error_name::LessViolated);
+                        }
+                    )
+                }
+                AnyValidator::LessOrEqual(max) => {
+                    quote!(
+                        if val > &(#max) {
+                            return Err(#error_name::LessOrEqualViolated);
+                        }
+                    )
+                }
                 AnyValidator::Predicate(predicate) => {
                     let inner_type_ref: syn::Type = parse_quote!(
-                        &'a #inner_type
+                        &'__nutype #inner_type
                     );
                     let typed_predicate: TypedCustomFunction = predicate
                         .clone()
                         .try_into_typed(&inner_type_ref)
                         .expect("Failed to convert predicate into a typed closure");
+                    let is_valid = gen_call_custom_validation_fn(
+                        typed_predicate,
+                        quote!(val),
+                        quote!(#inner_type_ref),
+                        quote!(bool),
+                        maybe_context,
+                    );
                     quote!(
-                        if !(#typed_predicate)(val) {
+                        if !#is_valid {
                             return Err(#error_name::PredicateViolated);
                         }
                     )
                 }
+                AnyValidator::WithResult(custom_validate_fn) => {
+                    let inner_type_ref: syn::Type = parse_quote!(
+                        &'__nutype #inner_type
+                    );
+                    let typed_custom_validate_fn: TypedCustomFunction = custom_validate_fn
+                        .clone()
+                        .try_into_typed(&inner_type_ref)
+                        .expect("Failed to convert validation function into a typed closure");
+                    let result = gen_call_custom_validation_fn(
+                        typed_custom_validate_fn,
+                        quote!(val),
+                        quote!(#inner_type_ref),
+                        quote!(::core::result::Result<(), ::std::string::String>),
+                        maybe_context,
+                    );
+                    quote!(
+                        let result: ::core::result::Result<(), ::std::string::String> = #result;
+                        if let Err(message) = result {
+                            return Err(#error_name::WithResultViolated(message));
+                        }
+                    )
+                }
             })
             .collect();
+        let validations = gen_validation_checks(&error_name, checks, collect_errors);
 
+        // `'__nutype` does not clash with the lifetimes used by the inner type (e.g. `&'a str`).
+        let lifetimes = inner_type.lifetimes();
+        let type_params = inner_type.type_params();
         quote!(
-            fn validate<'a>(val: &'a #inner_type) -> ::core::result::Result<(), #error_name> {
+            // With `len_min`, `len_max` and `not_empty` only, clippy would suggest `&[T]` for `&Vec<T>`.
+            #[allow(clippy::ptr_arg)]
+            fn validate<'__nutype, #(#lifetimes,)* #(#type_params),*>(val: &'__nutype #inner_type #context_param) -> ::core::result::Result<(), #error_name> {
                 #validations
                 Ok(())
             }
@@ -97,38 +205,153 @@ impl GenerateNewtype for AnyNewtype {
 
     fn gen_validation_error_type(
         type_name: &TypeName,
-        validators: &[Self::Validator],
+        validators: &[&Self::Validator],
+        non_exhaustive_error: bool,
+        collect_errors: bool,
+        has_fallible_sanitizers: bool,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(
+            type_name,
+            validators,
+            non_exhaustive_error,
+            collect_errors,
+            has_fallible_sanitizers,
+        )
     }
 
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
         inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        maybe_display_format: Option<syn::LitStr>,
+        _eq: EqMode,
+        _ord: OrdMode,
+        serde_repr: &SerdeRepr,
+        _serde_lenient: bool,
         guard: &AnyGuard,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
+            generics,
+            constructor_name,
             inner_type,
             maybe_error_type_name,
             traits,
             maybe_default_value,
+            maybe_display_format,
+            serde_repr,
             guard,
         )
     }
 
+    /// `Vec<T>` based types get `as_slice()`. It's not offered as `AsRef<[T]>`, because together
+    /// with `AsRef<Vec<T>>` it would make `.as_ref()` calls ambiguous.
+    fn gen_impl_inner_views(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+    ) -> TokenStream {
+        let Some(element_type) = inner_type.vec_element_type() else {
+            return quote!();
+        };
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics #type_name #ty_generics {
+                #[inline]
+                pub fn as_slice(&self) -> &[#element_type] {
+                    self.0.as_slice()
+                }
+            }
+        }
+    }
+
+    /// Without sanitizers the item is pushed in place and popped back if the validation fails,
+    /// so the newtype is left unchanged. Sanitizers may rewrite the whole collection, so then
+    /// `push()` goes through `modify()`, which requires the inner type to be `Clone`.
+    fn gen_impl_push(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        guard: &AnyGuard,
+    ) -> TokenStream {
+        let Some(element_type) = inner_type.vec_element_type() else {
+            return quote!();
+        };
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
+        let has_sanitizers = !guard.sanitizers().is_empty();
+        match (guard.has_validation(), has_sanitizers) {
+            (true, false) => {
+                let error_type_name = gen_error_type_name(type_name);
+                quote! {
+                    impl #impl_generics #type_name #ty_generics {
+                        pub fn try_push(&mut self, item: #element_type) -> ::core::result::Result<(), #error_type_name> {
+                            self.0.push(item);
+                            if let Err(err) = Self::validate(&self.0) {
+                                self.0.pop();
+                                return Err(err);
+                            }
+                            Ok(())
+                        }
+                    }
+                }
+            }
+            (true, true) => {
+                let error_type_name = gen_error_type_name(type_name);
+                quote! {
+                    impl #impl_generics #type_name #ty_generics {
+                        pub fn try_push(&mut self, item: #element_type) -> ::core::result::Result<(), #error_type_name>
+                        where
+                            for<'__nutype> #inner_type: ::core::clone::Clone,
+                        {
+                            self.modify(|raw_value| raw_value.push(item))
+                        }
+                    }
+                }
+            }
+            (false, false) => {
+                quote! {
+                    impl #impl_generics #type_name #ty_generics {
+                        pub fn push(&mut self, item: #element_type) {
+                            self.0.push(item);
+                        }
+                    }
+                }
+            }
+            (false, true) => {
+                quote! {
+                    impl #impl_generics #type_name #ty_generics {
+                        pub fn push(&mut self, item: #element_type)
+                        where
+                            for<'__nutype> #inner_type: ::core::clone::Clone,
+                        {
+                            self.modify(|raw_value| raw_value.push(item))
+                        }
+                    }
+                }
+            }
+        }
+    }
+
     fn gen_tests(
         type_name: &TypeName,
-        _inner_type: &Self::InnerType,
+        constructor_name: &ConstructorName,
+        inner_type: &Self::InnerType,
         maybe_default_value: &Option<syn::Expr>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
         _traits: &HashSet<Self::TypedTrait>,
     ) -> TokenStream {
+        // The type parameters are not known outside of the type, so there is nothing to test with.
+        if inner_type.has_type_params() {
+            return quote!();
+        }
+
         let test_valid_default_value = gen_test_should_have_valid_default_value(
             type_name,
+            constructor_name,
             maybe_default_value,
             guard.has_validation(),
         );
@@ -3,11 +3,12 @@ use quote::quote;
 
 use crate::{
     any::models::{AnyGuard, AnyInnerType},
-    common::models::TypeName,
+    common::models::{ConstructorName, TypeName},
 };
 
 pub fn gen_impl_trait_arbitrary(
     type_name: &TypeName,
+    constructor_name: &ConstructorName,
     inner_type: &AnyInnerType,
     guard: &AnyGuard,
 ) -> Result<TokenStream, syn::Error> {
@@ -26,7 +27,7 @@ pub fn gen_impl_trait_arbitrary(
         impl ::arbitrary::Arbitrary<'_> for #type_name {
             fn arbitrary(u: &mut ::arbitrary::Unstructured<'_>) -> ::arbitrary::Result<Self> {
                 let inner_value: #inner_type = u.arbitrary()?;
-                Ok(#type_name::new(inner_value))
+                Ok(#type_name::#constructor_name(inner_value))
             }
         }
 
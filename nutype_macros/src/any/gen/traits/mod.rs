@@ -1,21 +1,27 @@
 pub mod arbitrary;
+pub mod proptest;
+mod rand;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 use std::collections::HashSet;
+use syn::Generics;
 
 use crate::{
     any::models::AnyDeriveTrait,
-    any::models::{AnyGuard, AnyInnerType},
+    any::models::{AnyGuard, AnyInnerType, AnyValidator},
     common::{
         gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+            gen_impl_trait_as_ref, gen_impl_trait_bincode_decode, gen_impl_trait_bincode_encode,
+            gen_impl_trait_borrow, gen_impl_trait_default, gen_impl_trait_deref,
+            gen_impl_trait_display, gen_impl_trait_eq_inner, gen_impl_trait_from,
+            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_nutype,
+            gen_impl_trait_ord_inner, gen_impl_trait_redis_from_redis_value,
+            gen_impl_trait_redis_to_redis_args, gen_impl_trait_serde_deserialize,
+            gen_impl_trait_serde_serialize, gen_impl_trait_sqlx_json, gen_impl_trait_try_from,
+            split_into_generatable_traits, GeneratableTrait, GeneratableTraits, GeneratedTraits,
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ConstructorName, ErrorTypeName, SerdeRepr, TypeName},
     },
 };
 
@@ -40,6 +46,9 @@ impl From<AnyDeriveTrait> for AnyGeneratableTrait {
             AnyDeriveTrait::From => AnyGeneratableTrait::Irregular(AnyIrregularTrait::From),
             AnyDeriveTrait::Into => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Into),
             AnyDeriveTrait::Display => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Display),
+            AnyDeriveTrait::Nutype => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Nutype),
+            AnyDeriveTrait::EqInner => AnyGeneratableTrait::Irregular(AnyIrregularTrait::EqInner),
+            AnyDeriveTrait::OrdInner => AnyGeneratableTrait::Irregular(AnyIrregularTrait::OrdInner),
             AnyDeriveTrait::Deref => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Deref),
             AnyDeriveTrait::Borrow => AnyGeneratableTrait::Irregular(AnyIrregularTrait::Borrow),
             AnyDeriveTrait::FromStr => AnyGeneratableTrait::Irregular(AnyIrregularTrait::FromStr),
@@ -48,12 +57,31 @@ impl From<AnyDeriveTrait> for AnyGeneratableTrait {
             AnyDeriveTrait::SerdeSerialize => {
                 AnyGeneratableTrait::Irregular(AnyIrregularTrait::SerdeSerialize)
             }
+            AnyDeriveTrait::RedisFromRedisValue => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::RedisFromRedisValue)
+            }
+            AnyDeriveTrait::BincodeEncode => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::BincodeEncode)
+            }
+            AnyDeriveTrait::BincodeDecode => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::BincodeDecode)
+            }
+            AnyDeriveTrait::RedisToRedisArgs => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::RedisToRedisArgs)
+            }
+            AnyDeriveTrait::SqlxJson => AnyGeneratableTrait::Irregular(AnyIrregularTrait::SqlxJson),
             AnyDeriveTrait::SerdeDeserialize => {
                 AnyGeneratableTrait::Irregular(AnyIrregularTrait::SerdeDeserialize)
             }
             AnyDeriveTrait::ArbitraryArbitrary => {
                 AnyGeneratableTrait::Irregular(AnyIrregularTrait::ArbitraryArbitrary)
             }
+            AnyDeriveTrait::ProptestArbitrary => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::ProptestArbitrary)
+            }
+            AnyDeriveTrait::RandDistribution => {
+                AnyGeneratableTrait::Irregular(AnyIrregularTrait::RandDistribution)
+            }
             AnyDeriveTrait::DieselNewType => {
                 AnyGeneratableTrait::Transparent(AnyTransparentTrait::DieselNewType)
             }
@@ -101,21 +129,36 @@ enum AnyIrregularTrait {
     Into,
     Display,
     Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
     Borrow,
     FromStr,
     TryFrom,
     Default,
     SerdeSerialize,
     SerdeDeserialize,
+    SqlxJson,
+    RedisToRedisArgs,
+    RedisFromRedisValue,
+    BincodeEncode,
+    BincodeDecode,
     ArbitraryArbitrary,
+    ProptestArbitrary,
+    RandDistribution,
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_traits(
     type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
     inner_type: &AnyInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     traits: HashSet<AnyDeriveTrait>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_display_format: Option<syn::LitStr>,
+    serde_repr: &SerdeRepr,
     guard: &AnyGuard,
 ) -> Result<GeneratedTraits, syn::Error> {
     let GeneratableTraits {
@@ -131,10 +174,14 @@ pub fn gen_traits(
 
     let implement_traits = gen_implemented_traits(
         type_name,
+        generics,
+        constructor_name,
         inner_type,
         maybe_error_type_name,
         irregular_traits,
         maybe_default_value,
+        maybe_display_format,
+        serde_repr,
         guard,
     )?;
 
@@ -144,34 +191,49 @@ pub fn gen_traits(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_implemented_traits(
     type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
     inner_type: &AnyInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     impl_traits: Vec<AnyIrregularTrait>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_display_format: Option<syn::LitStr>,
+    serde_repr: &SerdeRepr,
     guard: &AnyGuard,
 ) -> Result<TokenStream, syn::Error> {
+    if inner_type.has_type_params() {
+        validate_traits_with_type_params(&impl_traits)?;
+        validate_validators_with_type_params(guard)?;
+    } else if !generics.params.is_empty() {
+        validate_traits_with_lifetime(&impl_traits)?;
+    }
+
     impl_traits
         .iter()
         .map(|t| match t {
-            AnyIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, inner_type)),
-            AnyIrregularTrait::From => Ok(gen_impl_trait_from(type_name, inner_type)),
-            AnyIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, inner_type.clone())),
-            AnyIrregularTrait::Display => Ok(gen_impl_trait_display(type_name)),
-            AnyIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
-            AnyIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, inner_type)),
+            AnyIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            AnyIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, constructor_name, inner_type)),
+            AnyIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type.clone())),
+            AnyIrregularTrait::Display => Ok(gen_impl_trait_display(type_name, generics, maybe_display_format.as_ref())),
+            AnyIrregularTrait::Nutype => Ok(gen_impl_trait_nutype(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            AnyIrregularTrait::EqInner => Ok(gen_impl_trait_eq_inner(type_name, inner_type)),
+            AnyIrregularTrait::OrdInner => Ok(gen_impl_trait_ord_inner(type_name, inner_type)),
+            AnyIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
+            AnyIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, generics, inner_type)),
             AnyIrregularTrait::FromStr => Ok(
-                gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref())
+                gen_impl_trait_from_str(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())
             ),
             AnyIrregularTrait::TryFrom => Ok(
-                gen_impl_trait_try_from(type_name, inner_type, maybe_error_type_name.as_ref())
+                gen_impl_trait_try_from(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())
             ),
             AnyIrregularTrait::Default => Ok(
                 match maybe_default_value {
                     Some(ref default_value) => {
                         let has_validation = maybe_error_type_name.is_some();
-                        gen_impl_trait_default(type_name, default_value, has_validation)
+                        gen_impl_trait_default(type_name, constructor_name, default_value, has_validation)
                     }
                     None => {
                         panic!(
@@ -181,12 +243,96 @@ fn gen_implemented_traits(
                 }
             ),
             AnyIrregularTrait::SerdeSerialize => Ok(
-                gen_impl_trait_serde_serialize(type_name)
+                gen_impl_trait_serde_serialize(type_name, serde_repr)
             ),
+            AnyIrregularTrait::BincodeEncode => Ok(gen_impl_trait_bincode_encode(type_name)),
+            AnyIrregularTrait::BincodeDecode => Ok(gen_impl_trait_bincode_decode(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            AnyIrregularTrait::RedisFromRedisValue => Ok(gen_impl_trait_redis_from_redis_value(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            AnyIrregularTrait::RedisToRedisArgs => Ok(gen_impl_trait_redis_to_redis_args(type_name)),
+            AnyIrregularTrait::SqlxJson => Ok(gen_impl_trait_sqlx_json(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
             AnyIrregularTrait::SerdeDeserialize => Ok(
-                gen_impl_trait_serde_deserialize(type_name, inner_type, maybe_error_type_name.as_ref())
+                gen_impl_trait_serde_deserialize(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref(), serde_repr)
             ),
-            AnyIrregularTrait::ArbitraryArbitrary => arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard),
+            AnyIrregularTrait::ArbitraryArbitrary => arbitrary::gen_impl_trait_arbitrary(type_name, constructor_name, inner_type, guard),
+            AnyIrregularTrait::ProptestArbitrary => {
+                proptest::gen_impl_trait_proptest_arbitrary(type_name, constructor_name, inner_type, guard)
+            }
+            AnyIrregularTrait::RandDistribution => {
+                rand::gen_impl_trait_rand_distribution(type_name, constructor_name, inner_type, guard)
+            }
         })
         .collect()
 }
+
+/// Only a subset of the traits can be generated for a type with a lifetime parameter
+/// (e.g. `struct Name<'a>(&'a str)`).
+fn validate_traits_with_lifetime(impl_traits: &[AnyIrregularTrait]) -> Result<(), syn::Error> {
+    let is_supported = |t: &AnyIrregularTrait| {
+        matches!(
+            t,
+            AnyIrregularTrait::AsRef
+                | AnyIrregularTrait::Deref
+                | AnyIrregularTrait::Borrow
+                | AnyIrregularTrait::Display
+                | AnyIrregularTrait::From
+                | AnyIrregularTrait::Into
+        )
+    };
+    if impl_traits.iter().all(is_supported) {
+        Ok(())
+    } else {
+        let msg = "For a type with a lifetime parameter only the following traits can be derived: \
+            `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, \
+            `AsRef`, `Deref`, `Borrow`, `Display`, `From`, `Into`.";
+        Err(syn::Error::new(proc_macro2::Span::call_site(), msg))
+    }
+}
+
+/// A generic type (e.g. `struct Meters<T: Float>(T)`) supports the same subset of traits
+/// as a type with a lifetime parameter, except `Into`: `impl<T> From<Meters<T>> for T`
+/// is forbidden by the orphan rule.
+fn validate_traits_with_type_params(impl_traits: &[AnyIrregularTrait]) -> Result<(), syn::Error> {
+    let is_supported = |t: &AnyIrregularTrait| {
+        matches!(
+            t,
+            AnyIrregularTrait::AsRef
+                | AnyIrregularTrait::Deref
+                | AnyIrregularTrait::Borrow
+                | AnyIrregularTrait::Display
+                | AnyIrregularTrait::From
+        )
+    };
+    if impl_traits.iter().all(is_supported) {
+        Ok(())
+    } else {
+        let msg = "For a type with a type parameter only the following traits can be derived: \
+            `Debug`, `Clone`, `Copy`, `PartialEq`, `Eq`, `PartialOrd`, `Ord`, `Hash`, \
+            `AsRef`, `Deref`, `Borrow`, `Display`, `From`.";
+        Err(syn::Error::new(proc_macro2::Span::call_site(), msg))
+    }
+}
+
+/// The boundaries of `greater`, `greater_or_equal`, `less` and `less_or_equal` are printed by
+/// the error type, which is not generic, so they cannot be expressed in terms of a type parameter.
+/// Generic bounds can be checked with `predicate` instead.
+fn validate_validators_with_type_params(guard: &AnyGuard) -> Result<(), syn::Error> {
+    let AnyGuard::WithValidation { validators, .. } = guard else {
+        return Ok(());
+    };
+    let has_comparison = validators.iter().any(|v| {
+        matches!(
+            v,
+            AnyValidator::Greater(_)
+                | AnyValidator::GreaterOrEqual(_)
+                | AnyValidator::Less(_)
+                | AnyValidator::LessOrEqual(_)
+        )
+    });
+    if has_comparison {
+        let msg = "Validators `greater`, `greater_or_equal`, `less` and `less_or_equal` cannot be used with a type parameter.\n\
+            Consider using `predicate` instead (e.g. `predicate = |v| *v >= T::zero()`).";
+        Err(syn::Error::new(proc_macro2::Span::call_site(), msg))
+    } else {
+        Ok(())
+    }
+}
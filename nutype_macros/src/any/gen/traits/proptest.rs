@@ -0,0 +1,27 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    any::models::{AnyGuard, AnyInnerType},
+    common::{
+        gen::traits::gen_impl_trait_proptest_arbitrary_from_strategy,
+        models::{ConstructorName, TypeName},
+    },
+};
+
+pub fn gen_impl_trait_proptest_arbitrary(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &AnyInnerType,
+    guard: &AnyGuard,
+) -> Result<TokenStream, syn::Error> {
+    // Assume that the inner type implements `proptest::arbitrary::Arbitrary` too.
+    // Since the validation rules are not known, the values that fail them are filtered out.
+    let inner_strategy = quote!(::proptest::arbitrary::any::<#inner_type>());
+    Ok(gen_impl_trait_proptest_arbitrary_from_strategy(
+        type_name,
+        constructor_name,
+        inner_strategy,
+        guard.has_validation(),
+    ))
+}
@@ -0,0 +1,28 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    any::models::{AnyGuard, AnyInnerType},
+    common::{
+        gen::traits::gen_impl_trait_rand_distribution_from_sampler,
+        models::{ConstructorName, TypeName},
+    },
+};
+
+pub fn gen_impl_trait_rand_distribution(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &AnyInnerType,
+    guard: &AnyGuard,
+) -> Result<TokenStream, syn::Error> {
+    // Assume that `Standard` can sample the inner type too.
+    // Since the validation rules are not known, the values that fail them are sampled again.
+    let sample_inner = quote!(rng.gen::<#inner_type>());
+    Ok(gen_impl_trait_rand_distribution_from_sampler(
+        type_name,
+        constructor_name,
+        inner_type,
+        sample_inner,
+        guard.has_validation(),
+    ))
+}
@@ -9,7 +9,7 @@ use std::collections::HashSet;
 use self::models::{AnyDeriveTrait, AnyGuard, AnyInnerType, AnySanitizer, AnyValidator};
 use crate::common::gen::GenerateNewtype;
 use crate::{
-    any::validate::validate_any_derive_traits,
+    any::validate::{resolve_date_literals, validate_any_derive_traits},
     common::models::{Attributes, GenerateParams, Newtype, SpannedDeriveTrait},
 };
 
@@ -37,6 +37,7 @@ impl Newtype for AnyNewtype {
     fn generate(
         params: GenerateParams<AnyInnerType, Self::TypedTrait, AnyGuard>,
     ) -> Result<TokenStream, syn::Error> {
-        AnyNewtype::gen_nutype(params)
+        let guard = resolve_date_literals(params.guard, &params.inner_type)?;
+        AnyNewtype::gen_nutype(GenerateParams { guard, ..params })
     }
 }
@@ -1,25 +1,42 @@
 use kinded::Kinded;
-use proc_macro2::TokenStream;
+use proc_macro2::{TokenStream, TokenTree};
 use quote::ToTokens;
 use std::fmt::Debug;
-use syn::Field;
+use syn::{Field, Generics};
 
-use crate::common::models::{CustomFunction, Guard, RawGuard, SpannedItem, TypeTrait};
+use crate::common::models::{
+    CustomFunction, FallibleSanitizer, Guard, RawGuard, SpannedItem, TypeTrait, ValueOrExpr,
+};
 
 /// Sanitizer for "any" type.
 #[derive(Debug, Kinded)]
 #[kinded(display = "snake_case")]
 pub enum AnySanitizer {
     With(CustomFunction),
+    TryWith(CustomFunction),
 }
 
 pub type SpannedAnySanitizer = SpannedItem<AnySanitizer>;
 
+impl FallibleSanitizer for AnySanitizer {
+    fn is_fallible(&self) -> bool {
+        matches!(self, Self::TryWith(_))
+    }
+}
+
 /// Validator for "any" type.
 #[derive(Debug, Kinded)]
 #[kinded(display = "snake_case")]
 pub enum AnyValidator {
+    LenMin(ValueOrExpr<usize>),
+    LenMax(ValueOrExpr<usize>),
+    NotEmpty,
+    Greater(syn::Expr),
+    GreaterOrEqual(syn::Expr),
+    Less(syn::Expr),
+    LessOrEqual(syn::Expr),
     Predicate(CustomFunction),
+    WithResult(CustomFunction),
 }
 
 pub type SpannedAnyValidator = SpannedItem<AnyValidator>;
@@ -39,6 +56,9 @@ pub enum AnyDeriveTrait {
     Into,
     From,
     Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
     Borrow,
     FromStr,
     TryFrom,
@@ -48,7 +68,14 @@ pub enum AnyDeriveTrait {
     // External crates
     SerdeSerialize,
     SerdeDeserialize,
+    SqlxJson,
+    RedisToRedisArgs,
+    RedisFromRedisValue,
+    BincodeEncode,
+    BincodeDecode,
     ArbitraryArbitrary,
+    ProptestArbitrary,
+    RandDistribution,
     DieselNewType,
 }
 
@@ -56,22 +83,128 @@ impl TypeTrait for AnyDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &AnyDeriveTrait::FromStr
     }
+
+    fn is_diesel_new_type(&self) -> bool {
+        self == &AnyDeriveTrait::DieselNewType
+    }
+
+    fn is_debug(&self) -> bool {
+        self == &AnyDeriveTrait::Debug
+    }
 }
 
 pub type AnyRawGuard = RawGuard<SpannedAnySanitizer, SpannedAnyValidator>;
 pub type AnyGuard = Guard<AnySanitizer, AnyValidator>;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct AnyInnerType(Field);
+pub struct AnyInnerType {
+    field: Field,
+    /// Type parameters of the newtype (e.g. `T: Float` in `struct Meters<T: Float>(T)`).
+    type_params: Vec<syn::TypeParam>,
+}
 
 impl AnyInnerType {
-    pub fn new(field: Field) -> Self {
-        Self(field)
+    pub fn new(field: Field, generics: &Generics) -> Self {
+        let type_params = generics.type_params().cloned().collect();
+        Self { field, type_params }
+    }
+
+    /// Element type of `Vec<T>` inner type, e.g. `u8` in `Vec<u8>`.
+    pub fn vec_element_type(&self) -> Option<&syn::Type> {
+        let syn::Type::Path(type_path) = &self.field.ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        if segment.ident != "Vec" {
+            return None;
+        }
+        let syn::PathArguments::AngleBracketed(args) = &segment.arguments else {
+            return None;
+        };
+        match args.args.first() {
+            Some(syn::GenericArgument::Type(element_type)) if args.args.len() == 1 => {
+                Some(element_type)
+            }
+            _ => None,
+        }
+    }
+
+    /// Named lifetimes used by the inner type (e.g. `'a` in `&'a str`), except `'static`.
+    pub fn lifetimes(&self) -> Vec<syn::Lifetime> {
+        let mut lifetimes: Vec<syn::Lifetime> = Vec::new();
+        collect_lifetimes(self.field.ty.to_token_stream(), &mut lifetimes);
+        lifetimes
+    }
+
+    /// Type parameters with their bounds, so they can be declared again by the generated
+    /// functions, which cannot use the generic parameters of the outer impl block.
+    /// Defaults (e.g. `T = f64`) are not allowed on functions, so they are dropped.
+    pub fn type_params(&self) -> Vec<syn::TypeParam> {
+        self.type_params
+            .iter()
+            .cloned()
+            .map(|mut type_param| {
+                type_param.eq_token = None;
+                type_param.default = None;
+                type_param
+            })
+            .collect()
+    }
+
+    pub fn has_type_params(&self) -> bool {
+        !self.type_params.is_empty()
+    }
+
+    /// Date or time type of `chrono` crate, recognized by the last segment of the path,
+    /// e.g. `NaiveDate` in `chrono::NaiveDate`.
+    pub fn chrono_type(&self) -> Option<ChronoType> {
+        let syn::Type::Path(type_path) = &self.field.ty else {
+            return None;
+        };
+        let segment = type_path.path.segments.last()?;
+        match segment.ident.to_string().as_str() {
+            "NaiveDate" => Some(ChronoType::NaiveDate),
+            "NaiveDateTime" => Some(ChronoType::NaiveDateTime),
+            "NaiveTime" => Some(ChronoType::NaiveTime),
+            "DateTime" => Some(ChronoType::DateTime),
+            _ => None,
+        }
+    }
+}
+
+/// Date and time types of `chrono` crate, that accept the boundaries given as string literals,
+/// e.g. `greater = "2024-01-01"`.
+#[cfg_attr(not(feature = "chrono"), allow(dead_code))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChronoType {
+    NaiveDate,
+    NaiveDateTime,
+    NaiveTime,
+    DateTime,
+}
+
+fn collect_lifetimes(token_stream: TokenStream, lifetimes: &mut Vec<syn::Lifetime>) {
+    let mut tokens = token_stream.into_iter().peekable();
+    while let Some(token) = tokens.next() {
+        match token {
+            TokenTree::Group(group) => collect_lifetimes(group.stream(), lifetimes),
+            TokenTree::Punct(punct) if punct.as_char() == '\'' => {
+                if let Some(TokenTree::Ident(ident)) = tokens.peek() {
+                    if ident != "static" && ident != "_" {
+                        let lifetime = syn::Lifetime::new(&format!("'{ident}"), ident.span());
+                        if !lifetimes.contains(&lifetime) {
+                            lifetimes.push(lifetime);
+                        }
+                    }
+                }
+            }
+            _ => {}
+        }
     }
 }
 
 impl ToTokens for AnyInnerType {
     fn to_tokens(&self, token_stream: &mut TokenStream) {
-        self.0.to_tokens(token_stream)
+        self.field.to_tokens(token_stream)
     }
 }
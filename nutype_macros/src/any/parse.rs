@@ -1,10 +1,19 @@
 use crate::common::{
     models::{Attributes, CustomFunction, SpannedDeriveTrait},
-    parse::{parse_sanitizer_kind, parse_validator_kind, ParseableAttributes},
+    parse::{
+        parse_number_or_expr, parse_sanitizer_kind, parse_validator_kind, ParseValidators,
+        ParseableAttributes,
+    },
+    validate::{
+        validate_enumerate_is_not_set, validate_eq_mode_is_not_set, validate_ord_mode_is_not_set,
+        validate_raw_validators_are_not_set, validate_repr_transparent_is_not_set,
+        validate_serde_lenient_is_not_set,
+    },
 };
 use proc_macro2::TokenStream;
 use syn::{
     parse::{Parse, ParseStream},
+    spanned::Spanned,
     Token,
 };
 
@@ -24,10 +33,39 @@ pub fn parse_attributes(
     let ParseableAttributes {
         sanitizers,
         validators,
+        raw_validators,
         new_unchecked,
         default,
+        non_exhaustive_error,
+        collect_errors,
+        allow_unused,
+        repr_transparent,
+        enumerate,
+        display,
+        new_fn,
+        inner_vis,
+        eq,
+        ord,
+        serde_repr,
+        schema_example,
+        from_env,
+        from_encoding,
+        sanitize_then_return_changed,
+        transparent_debug,
+        skip_new,
+        serde_lenient,
+        builder,
+        crate_path,
+        context,
+        async_validators,
         derive_traits,
     } = attrs;
+    let repr_transparent = validate_repr_transparent_is_not_set(repr_transparent)?;
+    let enumerate = validate_enumerate_is_not_set(enumerate)?;
+    let serde_lenient = validate_serde_lenient_is_not_set(serde_lenient)?;
+    let eq = validate_eq_mode_is_not_set(eq)?;
+    let ord = validate_ord_mode_is_not_set(ord)?;
+    validate_raw_validators_are_not_set(&raw_validators)?;
     let raw_guard = AnyRawGuard {
         sanitizers,
         validators,
@@ -37,6 +75,28 @@ pub fn parse_attributes(
         new_unchecked,
         guard,
         default,
+        non_exhaustive_error,
+        collect_errors,
+        allow_unused,
+        repr_transparent,
+        enumerate,
+        display,
+        new_fn,
+        inner_vis,
+        eq,
+        ord,
+        serde_repr,
+        schema_example,
+        from_env,
+        from_encoding,
+        sanitize_then_return_changed,
+        transparent_debug,
+        skip_new,
+        serde_lenient,
+        builder,
+        crate_path,
+        context,
+        async_validators,
         derive_traits,
     })
 }
@@ -55,15 +115,78 @@ impl Parse for SpannedAnySanitizer {
                     span,
                 })
             }
+            AnySanitizerKind::TryWith => {
+                let _eq: Token![=] = input.parse()?;
+                let span = input.span();
+                let custom_function: CustomFunction = input.parse()?;
+                Ok(SpannedAnySanitizer {
+                    item: AnySanitizer::TryWith(custom_function),
+                    span,
+                })
+            }
         }
     }
 }
 
+impl ParseValidators for SpannedAnyValidator {}
+
 impl Parse for SpannedAnyValidator {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let (kind, _ident) = parse_validator_kind(input)?;
+        let (kind, ident) = parse_validator_kind(input)?;
 
         match kind {
+            AnyValidatorKind::LenMin => {
+                let _: Token![=] = input.parse()?;
+                let (min_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedAnyValidator {
+                    item: AnyValidator::LenMin(min_len),
+                    span,
+                })
+            }
+            AnyValidatorKind::LenMax => {
+                let _: Token![=] = input.parse()?;
+                let (max_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedAnyValidator {
+                    item: AnyValidator::LenMax(max_len),
+                    span,
+                })
+            }
+            AnyValidatorKind::NotEmpty => Ok(SpannedAnyValidator {
+                item: AnyValidator::NotEmpty,
+                span: ident.span(),
+            }),
+            AnyValidatorKind::Greater => {
+                let _: Token![=] = input.parse()?;
+                let expr: syn::Expr = input.parse()?;
+                Ok(SpannedAnyValidator {
+                    span: expr.span(),
+                    item: AnyValidator::Greater(expr),
+                })
+            }
+            AnyValidatorKind::GreaterOrEqual => {
+                let _: Token![=] = input.parse()?;
+                let expr: syn::Expr = input.parse()?;
+                Ok(SpannedAnyValidator {
+                    span: expr.span(),
+                    item: AnyValidator::GreaterOrEqual(expr),
+                })
+            }
+            AnyValidatorKind::Less => {
+                let _: Token![=] = input.parse()?;
+                let expr: syn::Expr = input.parse()?;
+                Ok(SpannedAnyValidator {
+                    span: expr.span(),
+                    item: AnyValidator::Less(expr),
+                })
+            }
+            AnyValidatorKind::LessOrEqual => {
+                let _: Token![=] = input.parse()?;
+                let expr: syn::Expr = input.parse()?;
+                Ok(SpannedAnyValidator {
+                    span: expr.span(),
+                    item: AnyValidator::LessOrEqual(expr),
+                })
+            }
             AnyValidatorKind::Predicate => {
                 let _eq: Token![=] = input.parse()?;
                 let span = input.span();
@@ -73,6 +196,15 @@ impl Parse for SpannedAnyValidator {
                     span,
                 })
             }
+            AnyValidatorKind::WithResult => {
+                let _eq: Token![=] = input.parse()?;
+                let span = input.span();
+                let custom_function: CustomFunction = input.parse()?;
+                Ok(SpannedAnyValidator {
+                    item: AnyValidator::WithResult(custom_function),
+                    span,
+                })
+            }
         }
     }
 }
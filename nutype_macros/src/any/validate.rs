@@ -1,15 +1,16 @@
 use std::collections::HashSet;
 
+use cfg_if::cfg_if;
 use proc_macro2::Span;
 
 use crate::common::{
-    models::{DeriveTrait, SpannedDeriveTrait},
+    models::{has_fallible_sanitizers, DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
     validate::validate_duplicates,
 };
 
 use super::models::{
-    AnyDeriveTrait, AnyGuard, AnyRawGuard, AnySanitizer, AnyValidator, SpannedAnySanitizer,
-    SpannedAnyValidator,
+    AnyDeriveTrait, AnyGuard, AnyInnerType, AnyRawGuard, AnySanitizer, AnyValidator,
+    AnyValidatorKind, SpannedAnySanitizer, SpannedAnyValidator,
 };
 
 pub fn validate_any_guard(raw_guard: AnyRawGuard) -> Result<AnyGuard, syn::Error> {
@@ -21,12 +22,98 @@ pub fn validate_any_guard(raw_guard: AnyRawGuard) -> Result<AnyGuard, syn::Error
     let validators = validate_validators(validators)?;
     let sanitizers = validate_sanitizers(sanitizers)?;
 
-    if validators.is_empty() {
+    if validators.is_empty() && !has_fallible_sanitizers(&sanitizers) {
         Ok(AnyGuard::WithoutValidation { sanitizers })
     } else {
         Ok(AnyGuard::WithValidation {
             sanitizers,
             validators,
+            raw_validators: Vec::new(),
+        })
+    }
+}
+
+/// Resolve the boundaries of `chrono` based types given as string literals
+/// (e.g. `greater = "2024-01-01"`) into the values parsed with `FromStr`.
+/// The literals are verified at compile time, so parsing them at runtime never fails.
+pub fn resolve_date_literals(
+    guard: AnyGuard,
+    inner_type: &AnyInnerType,
+) -> Result<AnyGuard, syn::Error> {
+    let Some(chrono_type) = inner_type.chrono_type() else {
+        return Ok(guard);
+    };
+    let AnyGuard::WithValidation {
+        sanitizers,
+        validators,
+        raw_validators,
+    } = guard
+    else {
+        return Ok(guard);
+    };
+
+    let resolve = |expr: syn::Expr| -> Result<syn::Expr, syn::Error> {
+        let syn::Expr::Lit(syn::ExprLit {
+            lit: syn::Lit::Str(lit_str),
+            ..
+        }) = &expr
+        else {
+            return Ok(expr);
+        };
+        cfg_if! {
+            if #[cfg(feature = "chrono")] {
+                chrono_literals::verify_date_literal(lit_str, chrono_type)?;
+                Ok(syn::parse_quote!(
+                    <#inner_type as ::core::str::FromStr>::from_str(#lit_str)
+                        .expect("nutype verified the date literal at compile time")
+                ))
+            } else {
+                let _ = chrono_type;
+                let msg = "To use date and time literals, the feature `chrono` of the crate `nutype` must be enabled.";
+                Err(syn::Error::new(lit_str.span(), msg))
+            }
+        }
+    };
+    let validators = validators
+        .into_iter()
+        .map(|validator| {
+            Ok(match validator {
+                AnyValidator::Greater(expr) => AnyValidator::Greater(resolve(expr)?),
+                AnyValidator::GreaterOrEqual(expr) => AnyValidator::GreaterOrEqual(resolve(expr)?),
+                AnyValidator::Less(expr) => AnyValidator::Less(resolve(expr)?),
+                AnyValidator::LessOrEqual(expr) => AnyValidator::LessOrEqual(resolve(expr)?),
+                other => other,
+            })
+        })
+        .collect::<Result<Vec<_>, syn::Error>>()?;
+
+    Ok(AnyGuard::WithValidation {
+        sanitizers,
+        validators,
+        raw_validators,
+    })
+}
+
+#[cfg(feature = "chrono")]
+mod chrono_literals {
+    use crate::any::models::ChronoType;
+
+    pub fn verify_date_literal(
+        lit: &syn::LitStr,
+        chrono_type: ChronoType,
+    ) -> Result<(), syn::Error> {
+        let value = lit.value();
+        let result = match chrono_type {
+            ChronoType::NaiveDate => value.parse::<chrono::NaiveDate>().map(drop),
+            ChronoType::NaiveDateTime => value.parse::<chrono::NaiveDateTime>().map(drop),
+            ChronoType::NaiveTime => value.parse::<chrono::NaiveTime>().map(drop),
+            ChronoType::DateTime => value
+                .parse::<chrono::DateTime<chrono::FixedOffset>>()
+                .map(drop),
+        };
+        result.map_err(|err| {
+            let msg = format!("{value:?} cannot be parsed into `{chrono_type:?}`: {err}.");
+            syn::Error::new(lit.span(), msg)
         })
     }
 }
@@ -38,6 +125,49 @@ fn validate_validators(
         format!("Duplicated validators `{kind}`.\nOh, maybe it's a time to take a break?")
     })?;
 
+    // len_min VS len_max
+    //
+    let maybe_len_min = validators.iter().find_map(|v| match v.item {
+        AnyValidator::LenMin(ValueOrExpr::Value(len)) => Some(len),
+        _ => None,
+    });
+    let maybe_len_max = validators.iter().find_map(|v| match v.item {
+        AnyValidator::LenMax(ValueOrExpr::Value(len)) => Some((v.span, len)),
+        _ => None,
+    });
+    if let (Some(len_min), Some((len_max_span, len_max))) = (maybe_len_min, maybe_len_max) {
+        if len_min > len_max {
+            let msg = "`len_min` cannot be greater than `len_max`.\nIt happens to the best of us.";
+            return Err(syn::Error::new(len_max_span, msg));
+        }
+    }
+
+    // greater VS greater_or_equal, less VS less_or_equal
+    //
+    // The boundaries are arbitrary expressions, so unlike with numbers they cannot be compared
+    // at compile time.
+    let find_span = |kind: AnyValidatorKind| {
+        validators
+            .iter()
+            .find(|v| v.item.kind() == kind)
+            .map(|v| v.span)
+    };
+    if let (Some(_), Some(span)) = (
+        find_span(AnyValidatorKind::Greater),
+        find_span(AnyValidatorKind::GreaterOrEqual),
+    ) {
+        let msg = "The lower bound can be specified with EITHER `greater` OR `greater_or_equal`, but not both.";
+        return Err(syn::Error::new(span, msg));
+    }
+    if let (Some(_), Some(span)) = (
+        find_span(AnyValidatorKind::Less),
+        find_span(AnyValidatorKind::LessOrEqual),
+    ) {
+        let msg =
+            "The upper bound can be specified with EITHER `less` OR `less_or_equal`, but not both.";
+        return Err(syn::Error::new(span, msg));
+    }
+
     let validators: Vec<AnyValidator> = validators.into_iter().map(|v| v.item).collect();
     Ok(validators)
 }
@@ -66,6 +196,17 @@ pub fn validate_any_derive_traits(
         traits.insert(string_derive_trait);
     }
 
+    // `From` already provides `TryFrom<Inner>` with `Infallible` error through the blanket
+    // implementation in `core`, so deriving both must not generate a conflicting implementation.
+    if !has_validation && traits.contains(&AnyDeriveTrait::From) {
+        traits.remove(&AnyDeriveTrait::TryFrom);
+    }
+
+    // `PartialOrd<Inner>` requires `PartialEq<Inner>`, so `OrdInner` brings `EqInner` along.
+    if traits.contains(&AnyDeriveTrait::OrdInner) {
+        traits.insert(AnyDeriveTrait::EqInner);
+    }
+
     Ok(traits)
 }
 
@@ -87,16 +228,50 @@ fn to_any_derive_trait(
         DeriveTrait::Into => Ok(AnyDeriveTrait::Into),
         DeriveTrait::From => Ok(AnyDeriveTrait::From),
         DeriveTrait::Deref => Ok(AnyDeriveTrait::Deref),
+        DeriveTrait::EqInner => Ok(AnyDeriveTrait::EqInner),
+        DeriveTrait::OrdInner => Ok(AnyDeriveTrait::OrdInner),
+        DeriveTrait::Nutype => Ok(AnyDeriveTrait::Nutype),
+        DeriveTrait::Sum | DeriveTrait::Product => {
+            let msg = "`Sum` and `Product` can be derived only for integer and float based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::CheckedArith => {
+            let msg = "`CheckedArith` can be derived only for integer and float based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Neg => {
+            let msg = "`Neg` can be derived only for integer and float based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::TryFromWide => {
+            let msg = "`TryFromWide` can be derived only for integer based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::FromIterator => Err(syn::Error::new(
+            span,
+            "`FromIterator` can be derived only for String based types",
+        )),
         DeriveTrait::Borrow => Ok(AnyDeriveTrait::Borrow),
         DeriveTrait::FromStr => Ok(AnyDeriveTrait::FromStr),
         DeriveTrait::TryFrom => Ok(AnyDeriveTrait::TryFrom),
         DeriveTrait::Default => Ok(AnyDeriveTrait::Default),
         DeriveTrait::SerdeSerialize => Ok(AnyDeriveTrait::SerdeSerialize),
         DeriveTrait::SerdeDeserialize => Ok(AnyDeriveTrait::SerdeDeserialize),
+        DeriveTrait::SqlxJson => Ok(AnyDeriveTrait::SqlxJson),
+        DeriveTrait::RedisToRedisArgs => Ok(AnyDeriveTrait::RedisToRedisArgs),
+        DeriveTrait::RedisFromRedisValue => Ok(AnyDeriveTrait::RedisFromRedisValue),
+        DeriveTrait::BincodeEncode => Ok(AnyDeriveTrait::BincodeEncode),
+        DeriveTrait::BincodeDecode => Ok(AnyDeriveTrait::BincodeDecode),
+        DeriveTrait::NumTraitsZero | DeriveTrait::NumTraitsOne | DeriveTrait::NumTraitsBounded => {
+            let msg = "`Zero`, `One` and `Bounded` from `num-traits` can be derived only for integer and float based types";
+            Err(syn::Error::new(span, msg))
+        }
         DeriveTrait::Hash => Ok(AnyDeriveTrait::Hash),
         DeriveTrait::ArbitraryArbitrary => Ok(AnyDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::ProptestArbitrary => Ok(AnyDeriveTrait::ProptestArbitrary),
+        DeriveTrait::RandDistribution => Ok(AnyDeriveTrait::RandDistribution),
         DeriveTrait::DieselNewType => Ok(AnyDeriveTrait::DieselNewType),
-        DeriveTrait::SchemarsJsonSchema => {
+        DeriveTrait::SchemarsJsonSchema | DeriveTrait::RkyvArchive => {
             let msg =
                 format!("Deriving of trait `{tr:?}` is not (yet) supported for an arbitrary type");
             Err(syn::Error::new(span, msg))
@@ -0,0 +1,73 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::Generics;
+
+use crate::common::models::{BuilderTypeName, ConstructorName, ErrorTypeName, TypeName};
+
+/// Generate a name for the builder which is returned by `builder()` function.
+pub fn gen_builder_type_name(type_name: &TypeName) -> BuilderTypeName {
+    let ident = format_ident!("{type_name}Builder");
+    BuilderTypeName::new(ident)
+}
+
+/// Generate `builder()` function together with the builder it returns.
+///
+/// The builder holds a raw inner value, which is changed step by step with `update()` and
+/// is passed through the constructor only in `build()`, so the sanitizers and validators are
+/// applied once to the final value.
+pub fn gen_builder(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    convert_into_inner_type: bool,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let builder_type_name = gen_builder_type_name(type_name);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (input_type, raw_value) = if convert_into_inner_type {
+        (quote!(impl Into<#inner_type>), quote!(raw_value.into()))
+    } else {
+        (quote!(#inner_type), quote!(raw_value))
+    };
+    let doc_builder =
+        format!("Builder of [`{type_name}`], created with [`{type_name}::builder()`].");
+    let build_fn = match maybe_error_type_name {
+        Some(error_type_name) => quote! {
+            pub fn build(self) -> ::core::result::Result<#type_name #ty_generics, #error_type_name> {
+                #type_name::#constructor_name(self.raw_value)
+            }
+        },
+        None => quote! {
+            pub fn build(self) -> #type_name #ty_generics {
+                #type_name::#constructor_name(self.raw_value)
+            }
+        },
+    };
+
+    quote! {
+        #[doc = #doc_builder]
+        pub struct #builder_type_name #impl_generics #where_clause {
+            raw_value: #inner_type,
+        }
+
+        impl #impl_generics #builder_type_name #ty_generics #where_clause {
+            /// Change the raw value. The change is not validated until `build()` is called.
+            pub fn update(mut self, f: impl ::core::ops::FnOnce(&mut #inner_type)) -> Self {
+                f(&mut self.raw_value);
+                self
+            }
+
+            #build_fn
+        }
+
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            pub fn builder(raw_value: #input_type) -> #builder_type_name #ty_generics {
+                #builder_type_name {
+                    raw_value: #raw_value,
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use proc_macro2::{Group, Ident, Spacing, TokenStream, TokenTree};
+use quote::ToTokens;
+use syn::Path;
+
+/// Crates the generated code refers to with absolute paths, e.g. `::serde::Serialize`.
+const DEPENDENCY_CRATES: &[&str] = &[
+    "nutype",
+    "serde",
+    "regex",
+    "lazy_static",
+    "schemars",
+    "rkyv",
+    "sqlx",
+    "arbitrary",
+    "proptest",
+    "redis",
+    "num_traits",
+    "bincode",
+    "rand",
+    "diesel_derive_newtype",
+];
+
+/// Put the path set with `crate = ` in front of the absolute paths to the dependencies, e.g.
+/// with `crate = my_crate::reexports` the path `::serde::Serialize` becomes
+/// `my_crate::reexports::serde::Serialize`.
+pub fn gen_with_crate_path(tokens: TokenStream, crate_path: &Path) -> TokenStream {
+    let ts: Vec<TokenTree> = tokens.into_iter().collect();
+    let mut output: Vec<TokenTree> = Vec::with_capacity(ts.len());
+
+    for (pos, token) in ts.iter().enumerate() {
+        match token {
+            TokenTree::Group(group) => {
+                let stream = gen_with_crate_path(group.stream(), crate_path);
+                let mut new_group = Group::new(group.delimiter(), stream);
+                new_group.set_span(group.span());
+                output.push(TokenTree::Group(new_group));
+            }
+            _ => {
+                if starts_dependency_path(&ts, pos) {
+                    output.extend(crate_path.to_token_stream());
+                }
+                output.push(token.clone());
+            }
+        }
+    }
+
+    output.into_iter().collect()
+}
+
+/// Check whether the tokens at the position are `::` followed by a name of a dependency
+/// and the `::` starts a new path, rather than continues one (e.g. `my_mod::serde`).
+fn starts_dependency_path(ts: &[TokenTree], pos: usize) -> bool {
+    let is_path_separator = match (&ts[pos], ts.get(pos + 1)) {
+        (TokenTree::Punct(first), Some(TokenTree::Punct(second))) => {
+            first.as_char() == ':' && first.spacing() == Spacing::Joint && second.as_char() == ':'
+        }
+        _ => false,
+    };
+    let is_dependency = match ts.get(pos + 2) {
+        Some(TokenTree::Ident(ident)) => DEPENDENCY_CRATES.iter().any(|name| ident == name),
+        _ => false,
+    };
+    let continues_path = match pos.checked_sub(1).map(|prev| (prev, &ts[prev])) {
+        Some((prev, TokenTree::Ident(ident))) => is_path_segment(ident) && !is_lifetime(ts, prev),
+        _ => false,
+    };
+    is_path_separator && is_dependency && !continues_path
+}
+
+/// Keywords like `as`, `impl` or `for` may be followed by an absolute path,
+/// unlike identifiers and the keywords that are path segments themselves.
+fn is_path_segment(ident: &Ident) -> bool {
+    ident == "crate"
+        || ident == "self"
+        || ident == "super"
+        || ident == "Self"
+        || syn::parse2::<Ident>(ident.to_token_stream()).is_ok()
+}
+
+/// Check whether the identifier at the position is a name of a lifetime, e.g. `'de`.
+fn is_lifetime(ts: &[TokenTree], pos: usize) -> bool {
+    match pos.checked_sub(1).map(|prev| &ts[prev]) {
+        Some(TokenTree::Punct(punct)) => punct.as_char() == '\'',
+        _ => false,
+    }
+}
@@ -2,7 +2,10 @@ use cfg_if::cfg_if;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
 
-use crate::common::models::{ErrorTypeName, TypeName};
+use crate::common::{
+    gen::gen_alloc_crate,
+    models::{ErrorTypeName, TypeName},
+};
 
 pub fn gen_error_type_name(type_name: &TypeName) -> ErrorTypeName {
     let ident = format_ident!("{type_name}Error");
@@ -27,3 +30,116 @@ pub fn gen_impl_error_trait(error_type_name: &ErrorTypeName) -> TokenStream {
         }
     }
 }
+
+/// Generate implementation of `nutype::ValidationError` trait.
+/// `constraint_arms` map every validation variant to the name of its validator.
+pub fn gen_impl_validation_error_trait(
+    type_name: &TypeName,
+    error_type_name: &ErrorTypeName,
+    constraint_arms: Vec<TokenStream>,
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
+) -> TokenStream {
+    let sanitization_failed_arm = if has_fallible_sanitizers {
+        quote!(#error_type_name::SanitizationFailed(_) => "try_with",)
+    } else {
+        quote!()
+    };
+    // `Multiple` is never empty: it's returned only when more than one validation fails.
+    let multiple_arm = if collect_errors {
+        quote! {
+            #error_type_name::Multiple(errors) => errors
+                .first()
+                .map_or("collect_errors", ::nutype::ValidationError::constraint),
+        }
+    } else {
+        quote!()
+    };
+
+    quote! {
+        impl ::nutype::ValidationError for #error_type_name {
+            fn kind(&self) -> &'static str {
+                stringify!(#type_name)
+            }
+
+            fn constraint(&self) -> &'static str {
+                match self {
+                    #(#constraint_arms,)*
+                    #sanitization_failed_arm
+                    #multiple_arm
+                }
+            }
+        }
+    }
+}
+
+/// Generate `#[non_exhaustive]` attribute for the error enum if it's requested with
+/// `non_exhaustive_error`.
+pub fn gen_non_exhaustive_attr(non_exhaustive_error: bool) -> TokenStream {
+    if non_exhaustive_error {
+        quote!(#[non_exhaustive])
+    } else {
+        quote!()
+    }
+}
+
+/// Generate `Multiple` variant of the error enum if it's requested with `collect_errors`.
+pub fn gen_multiple_errors_variant(
+    error_type_name: &ErrorTypeName,
+    collect_errors: bool,
+) -> TokenStream {
+    if collect_errors {
+        let alloc = gen_alloc_crate();
+        quote!(Multiple(#alloc::vec::Vec<#error_type_name>),)
+    } else {
+        quote!()
+    }
+}
+
+/// Generate `SanitizationFailed` variant of the error enum if any of the sanitizers may fail.
+pub fn gen_sanitization_failed_variant(has_fallible_sanitizers: bool) -> TokenStream {
+    if has_fallible_sanitizers {
+        let alloc = gen_alloc_crate();
+        quote!(SanitizationFailed(#alloc::string::String),)
+    } else {
+        quote!()
+    }
+}
+
+/// Generate match arm of `Display` implementation for `SanitizationFailed` variant.
+pub fn gen_sanitization_failed_display_arm(
+    type_name: &TypeName,
+    error_type_name: &ErrorTypeName,
+    has_fallible_sanitizers: bool,
+) -> TokenStream {
+    if has_fallible_sanitizers {
+        quote! {
+            #error_type_name::SanitizationFailed(message) => write!(f, "{} could not be sanitized: {}", stringify!(#type_name), message),
+        }
+    } else {
+        quote!()
+    }
+}
+
+/// Generate match arm of `Display` implementation for `Multiple` variant, which prints
+/// all the errors separated by a space.
+pub fn gen_multiple_errors_display_arm(
+    error_type_name: &ErrorTypeName,
+    collect_errors: bool,
+) -> TokenStream {
+    if collect_errors {
+        quote! {
+            #error_type_name::Multiple(errors) => {
+                for (index, error) in errors.iter().enumerate() {
+                    if index > 0 {
+                        f.write_str(" ")?;
+                    }
+                    ::core::fmt::Display::fmt(error, f)?;
+                }
+                Ok(())
+            },
+        }
+    } else {
+        quote!()
+    }
+}
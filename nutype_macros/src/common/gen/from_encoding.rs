@@ -0,0 +1,98 @@
+use cfg_if::cfg_if;
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::common::models::{ByteEncoding, ConstructorName, ErrorTypeName, TypeName};
+
+use super::parse_error::gen_parse_error_name;
+
+/// Generate `FromStr` implementation, that decodes bytes from a string set by `from_hex` or
+/// `from_base64` and passes them to the constructor, together with the error it returns.
+///
+/// The error distinguishes a string that cannot be decoded and decoded bytes that violate the
+/// validation rules (only if the type has validation).
+pub fn gen_impl_from_encoding(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    encoding: ByteEncoding,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let parse_error_type_name = gen_parse_error_name(type_name);
+    let type_name_str = type_name.to_string();
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let (module, encoding_name) = match encoding {
+        ByteEncoding::Hex => (quote!(::nutype::hex), "hex"),
+        ByteEncoding::Base64 => (quote!(::nutype::base64), "base64"),
+    };
+
+    let mut variants = vec![quote!(
+        /// The string is not a valid encoding of bytes.
+        Decode(#module::DecodeError)
+    )];
+    let mut display_arms = vec![quote!(
+        #parse_error_type_name::Decode(err) => write!(f, "Failed to decode {} from {}: {}", #type_name_str, #encoding_name, err)
+    )];
+    let construct = if let Some(error_type_name) = maybe_error_type_name {
+        variants.push(quote!(
+            /// The decoded bytes violate the validation rules.
+            Validate(#error_type_name)
+        ));
+        display_arms.push(quote!(
+            #parse_error_type_name::Validate(err) => write!(f, "Failed to parse {}: {}", #type_name_str, err)
+        ));
+        quote!(Self::#constructor_name(raw_value).map_err(#parse_error_type_name::Validate))
+    } else {
+        quote!(Ok(Self::#constructor_name(raw_value)))
+    };
+
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            let impl_std_error = quote! {
+                impl ::std::error::Error for #parse_error_type_name {
+                    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                        match self {
+                            #parse_error_type_name::Decode(err) => Some(err),
+                            #[allow(unreachable_patterns)]
+                            _ => None,
+                        }
+                    }
+                }
+            };
+        } else {
+            // NOTE: There is no `::core::error::Error` yet in stable Rust.
+            // So for `no_std` we just don't implement `Error` trait.
+            let impl_std_error = quote! {};
+        }
+    };
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq)]
+        pub enum #parse_error_type_name {
+            #(#variants,)*
+        }
+
+        impl ::core::fmt::Display for #parse_error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#display_arms,)*
+                }
+            }
+        }
+
+        #impl_std_error
+
+        impl #impl_generics ::core::str::FromStr for #type_name #ty_generics #where_clause {
+            type Err = #parse_error_type_name;
+
+            fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
+                let bytes = #module::decode(raw_string).map_err(#parse_error_type_name::Decode)?;
+                let raw_value: #inner_type = ::core::convert::From::from(bytes);
+                #construct
+            }
+        }
+    }
+}
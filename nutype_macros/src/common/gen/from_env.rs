@@ -0,0 +1,128 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::Generics;
+
+use crate::common::models::{ErrorTypeName, FromEnvErrorTypeName, TypeName};
+
+use super::parse_error::gen_parse_error_name;
+
+/// Generate a name for the error which is returned by `from_env()` function.
+pub fn gen_from_env_error_name(type_name: &TypeName) -> FromEnvErrorTypeName {
+    let ident = format_ident!("{type_name}FromEnvError");
+    FromEnvErrorTypeName::new(ident)
+}
+
+/// Generate `from_env()` function, that reads an environment variable and passes it through
+/// `FromStr`, together with the error it returns.
+///
+/// The error distinguishes a missing variable, a value that cannot be parsed into the inner type
+/// (only if the type has a dedicated parse error) and a value that violates the validation rules.
+pub fn gen_from_env(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    has_dedicated_parse_error: bool,
+) -> TokenStream {
+    let from_env_error_type_name = gen_from_env_error_name(type_name);
+    let type_name_str = type_name.to_string();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let mut variants = vec![
+        quote!(
+            /// The environment variable is not set.
+            Missing { var: ::std::string::String }
+        ),
+        quote!(
+            /// The environment variable is set, but it is not valid unicode.
+            NotUnicode { var: ::std::string::String }
+        ),
+    ];
+    let mut display_arms = vec![
+        quote!(
+            #from_env_error_type_name::Missing { var } => write!(f, "Environment variable `{}` for {} is not set.", var, #type_name_str)
+        ),
+        quote!(
+            #from_env_error_type_name::NotUnicode { var } => write!(f, "Environment variable `{}` for {} is not valid unicode.", var, #type_name_str)
+        ),
+    ];
+    if has_dedicated_parse_error {
+        variants.push(quote!(
+            /// The value of the environment variable cannot be parsed into the inner type.
+            Parse {
+                var: ::std::string::String,
+                error: <#inner_type as ::core::str::FromStr>::Err,
+            }
+        ));
+        display_arms.push(quote!(
+            #from_env_error_type_name::Parse { var, error } => write!(f, "Failed to parse environment variable `{}` as {}: {}", var, #type_name_str, error)
+        ));
+    }
+    if let Some(error_type_name) = maybe_error_type_name {
+        variants.push(quote!(
+            /// The value of the environment variable violates the validation rules.
+            Validate {
+                var: ::std::string::String,
+                error: #error_type_name,
+            }
+        ));
+        display_arms.push(quote!(
+            #from_env_error_type_name::Validate { var, error } => write!(f, "Environment variable `{}` is not a valid {}: {}", var, #type_name_str, error)
+        ));
+    }
+
+    // Map the error of `FromStr` into the error of `from_env()`.
+    let map_parse_error = match (has_dedicated_parse_error, maybe_error_type_name) {
+        (true, Some(_)) => {
+            let parse_error_type_name = gen_parse_error_name(type_name);
+            quote!(match err {
+                #parse_error_type_name::Parse(error) => #from_env_error_type_name::Parse { var: var.to_string(), error },
+                #parse_error_type_name::Validate(error) => #from_env_error_type_name::Validate { var: var.to_string(), error },
+            })
+        }
+        (true, None) => {
+            let parse_error_type_name = gen_parse_error_name(type_name);
+            quote!(match err {
+                #parse_error_type_name::Parse(error) => #from_env_error_type_name::Parse { var: var.to_string(), error },
+            })
+        }
+        // For string based types the error of `FromStr` is the validation error.
+        (false, Some(_)) => {
+            quote!(#from_env_error_type_name::Validate { var: var.to_string(), error: err })
+        }
+        (false, None) => quote!(match err {}),
+    };
+
+    quote! {
+        #[derive(Debug)]
+        pub enum #from_env_error_type_name {
+            #(#variants,)*
+        }
+
+        impl ::core::fmt::Display for #from_env_error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#display_arms,)*
+                }
+            }
+        }
+
+        impl ::std::error::Error for #from_env_error_type_name {
+            fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                None
+            }
+        }
+
+        impl #impl_generics #type_name #ty_generics {
+            /// Reads the environment variable `var` and parses it with `FromStr`,
+            /// so the value is sanitized and validated the same way as with `parse()`.
+            pub fn from_env(var: &str) -> ::core::result::Result<Self, #from_env_error_type_name> {
+                let raw_string = ::std::env::var(var).map_err(|err| match err {
+                    ::std::env::VarError::NotPresent => #from_env_error_type_name::Missing { var: var.to_string() },
+                    ::std::env::VarError::NotUnicode(_) => #from_env_error_type_name::NotUnicode { var: var.to_string() },
+                })?;
+                raw_string.parse::<Self>().map_err(|err| #map_parse_error)
+            }
+        }
+    }
+}
@@ -1,26 +1,45 @@
+pub mod builder;
+pub mod crate_path;
 pub mod error;
+pub mod from_encoding;
+pub mod from_env;
+pub mod new_async;
 pub mod new_unchecked;
 pub mod parse_error;
 pub mod tests;
 pub mod traits;
+pub mod try_from_wide;
 
+use cfg_if::cfg_if;
 use std::{collections::HashSet, hash::Hash};
 
-use self::traits::GeneratedTraits;
+use self::traits::{
+    gen_impl_trait_transparent_debug, gen_schema_example_fn, GeneratedTraits,
+    SCHEMA_EXAMPLE_FN_NAME,
+};
 
 use super::models::{
-    ErrorTypeName, GenerateParams, Guard, NewUnchecked, ParseErrorTypeName, TypeName, TypeTrait,
+    has_fallible_sanitizers, AsyncErrorTypeName, BuilderTypeName, ConstructorName, EqMode,
+    ErrorTypeName, FallibleSanitizer, FromEnvErrorTypeName, GenerateParams, Guard, NewUnchecked,
+    OrdMode, ParseErrorTypeName, SerdeRepr, TryFromWideErrorTypeName, TypeName, TypeTrait,
 };
 use crate::common::{
     gen::{
-        error::gen_error_type_name, new_unchecked::gen_new_unchecked,
+        builder::{gen_builder, gen_builder_type_name},
+        crate_path::gen_with_crate_path,
+        error::gen_error_type_name,
+        from_encoding::gen_impl_from_encoding,
+        from_env::{gen_from_env, gen_from_env_error_name},
+        new_async::{gen_async_error_name, gen_new_async},
+        new_unchecked::gen_new_unchecked,
         parse_error::gen_parse_error_name,
+        try_from_wide::gen_try_from_wide_error_name,
     },
     models::ModuleName,
 };
-use proc_macro2::{Punct, Spacing, TokenStream, TokenTree};
-use quote::{format_ident, quote, ToTokens};
-use syn::Visibility;
+use proc_macro2::{Delimiter, Punct, Spacing, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{spanned::Spanned, Generics, Visibility};
 
 /// Inject an inner type into a closure, so compiler does not complain if the token stream matchers
 /// the expected closure pattern.
@@ -36,6 +55,13 @@ use syn::Visibility;
 ///   |mut s| s.trim().to_lowercase()
 /// Output:
 ///   |mut s: String| s.trim().to_lowercase()
+///
+/// or
+///
+/// Input:
+///   |(lat, lon)| (lat, lon % 180.0)
+/// Output:
+///   |(lat, lon): (f64, f64)| (lat, lon % 180.0)
 // TODO: consider using syn instead messing with TokenStream directly
 pub fn type_custom_closure(
     closure_or_func_path: &TokenStream,
@@ -57,6 +83,10 @@ pub fn type_custom_closure(
         // If the tokens match `|mut s|` pattern,
         // then inject the type, e.g. `|mut s: String|`
         insert_type_at_position(&mut ts, inner_type_tokens, 3);
+    } else if ts.len() >= 3 && is_pipe(&ts[0]) && is_parenthesized(&ts[1]) && is_pipe(&ts[2]) {
+        // If the tokens match `|(a, b)|` pattern (destructuring of a tuple),
+        // then inject the type, e.g. `|(a, b): (f64, f64)|`
+        insert_type_at_position(&mut ts, inner_type_tokens, 2);
     }
 
     ts.into_iter().collect()
@@ -81,6 +111,13 @@ fn is_pipe(token: &TokenTree) -> bool {
     }
 }
 
+fn is_parenthesized(token: &TokenTree) -> bool {
+    match token {
+        TokenTree::Group(group) => group.delimiter() == Delimiter::Parenthesis,
+        _ => false,
+    }
+}
+
 fn is_ident(token: &TokenTree) -> bool {
     matches!(token, TokenTree::Ident(_))
 }
@@ -97,12 +134,17 @@ pub fn gen_module_name_for_type(type_name: &TypeName) -> ModuleName {
     ModuleName::new(ident)
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_reimports(
     vis: Visibility,
     type_name: &TypeName,
     module_name: &ModuleName,
     maybe_error_type_name: Option<&ErrorTypeName>,
     maybe_parse_error_type_name: Option<&ParseErrorTypeName>,
+    maybe_from_env_error_type_name: Option<&FromEnvErrorTypeName>,
+    maybe_async_error_type_name: Option<&AsyncErrorTypeName>,
+    maybe_builder_type_name: Option<&BuilderTypeName>,
+    maybe_try_from_wide_error_type_name: Option<&TryFromWideErrorTypeName>,
 ) -> TokenStream {
     let reimport_main_type = quote! {
         #vis use #module_name::#type_name;
@@ -126,26 +168,414 @@ pub fn gen_reimports(
         }
     };
 
+    let reimport_from_env_error_type_if_needed = match maybe_from_env_error_type_name {
+        None => quote!(),
+        Some(ref from_env_error_type_name) => {
+            quote! (
+                #vis use #module_name::#from_env_error_type_name;
+            )
+        }
+    };
+
+    let reimport_async_error_type_if_needed = match maybe_async_error_type_name {
+        None => quote!(),
+        Some(ref async_error_type_name) => {
+            quote! (
+                #vis use #module_name::#async_error_type_name;
+            )
+        }
+    };
+
+    let reimport_builder_type_if_needed = match maybe_builder_type_name {
+        None => quote!(),
+        Some(ref builder_type_name) => {
+            quote! (
+                #vis use #module_name::#builder_type_name;
+            )
+        }
+    };
+
+    let reimport_try_from_wide_error_type_if_needed = match maybe_try_from_wide_error_type_name {
+        None => quote!(),
+        Some(ref try_from_wide_error_type_name) => {
+            quote! (
+                #vis use #module_name::#try_from_wide_error_type_name;
+            )
+        }
+    };
+
     quote! {
         #reimport_main_type
         #reimport_error_type_if_needed
         #reimport_parse_error_type_if_needed
+        #reimport_from_env_error_type_if_needed
+        #reimport_async_error_type_if_needed
+        #reimport_builder_type_if_needed
+        #reimport_try_from_wide_error_type_if_needed
+    }
+}
+
+/// Generate the parameter that receives the context set with `context = `, e.g. `, ctx: &Config`.
+pub fn gen_context_param(maybe_context: Option<&syn::Type>) -> TokenStream {
+    match maybe_context {
+        // Not every validation function uses the context, e.g. the one of `raw(...)` validators.
+        Some(context) => quote!(, #[allow(unused_variables)] ctx: &#context),
+        None => quote!(),
+    }
+}
+
+/// Generate the argument that passes the context further, if it's set with `context = `.
+pub fn gen_context_arg(maybe_context: Option<&syn::Type>) -> TokenStream {
+    match maybe_context {
+        Some(_) => quote!(, ctx),
+        None => quote!(),
+    }
+}
+
+/// Generate a call of a custom validation function (`predicate` or `with_result`).
+/// With `context = ` the context is passed as the second argument. The function is coerced
+/// to a function pointer first, so the types of closure arguments can be inferred.
+pub fn gen_call_custom_validation_fn(
+    custom_fn: impl ToTokens,
+    value: TokenStream,
+    value_type: TokenStream,
+    output_type: TokenStream,
+    maybe_context: Option<&syn::Type>,
+) -> TokenStream {
+    match maybe_context {
+        Some(context) => quote!({
+            let custom_fn: fn(#value_type, &#context) -> #output_type = #custom_fn;
+            custom_fn(#value, ctx)
+        }),
+        None => quote!((#custom_fn)(#value)),
     }
 }
 
-pub fn gen_impl_into_inner(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+/// Generate a check, that the type set with `inner = ` is the same as the declared type of
+/// the field (e.g. the type behind an alias), so the newtype never wraps a different type
+/// silently.
+pub fn gen_inner_type_check(
+    declared_inner_type: &syn::Type,
+    inner_type: &syn::Type,
+) -> TokenStream {
+    quote_spanned!(inner_type.span()=>
+        const _: fn(#declared_inner_type) -> #inner_type = |value| value;
+    )
+}
+
+/// Generate the path to the crate that provides `String` and `Vec` to the generated code.
+/// Without `std` it's `alloc` re-exported by `nutype` (with `alloc` feature), so the error messages
+/// and the collected errors are available in `no_std` environments that have an allocator.
+pub fn gen_alloc_crate() -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            quote!(::std)
+        } else {
+            quote!(::nutype::__private::alloc)
+        }
+    }
+}
+
+/// Generate a call of a sanitizer set with `try_with`. A failure aborts `sanitize()` with
+/// the error turned into a message.
+pub fn gen_call_fallible_sanitizer(custom_sanitizer: impl ToTokens) -> TokenStream {
+    let alloc = gen_alloc_crate();
+    quote!((#custom_sanitizer)(value).map_err(|err| #alloc::string::ToString::to_string(&err))?)
+}
+
+/// Generate the return type and the returned value of `sanitize()`, which returns `Result`
+/// if any of the sanitizers may fail.
+pub fn gen_sanitize_output(
+    inner_type: impl ToTokens,
+    is_fallible: bool,
+) -> (TokenStream, TokenStream) {
+    if is_fallible {
+        let alloc = gen_alloc_crate();
+        (
+            quote!(::core::result::Result<#inner_type, #alloc::string::String>),
+            quote!(Ok(value)),
+        )
+    } else {
+        (quote!(#inner_type), quote!(value))
+    }
+}
+
+/// Generate `#[must_use]` attribute for the newtype and its constructor unless it's opted out
+/// with `allow_unused`.
+/// The constructors get a reason, otherwise clippy complains about `double_must_use`.
+pub fn gen_must_use_attr(allow_unused: bool, maybe_reason: Option<&str>) -> TokenStream {
+    match (allow_unused, maybe_reason) {
+        (true, _) => quote!(),
+        (false, Some(reason)) => quote!(#[must_use = #reason]),
+        (false, None) => quote!(#[must_use]),
+    }
+}
+
+/// Combine the checks of the individual validators into the body of `validate()`.
+/// Every check returns early with an error. With `collect_errors` every check runs
+/// within its own closure, so the errors are accumulated and a single one is returned
+/// as it is, while several of them are wrapped into `Multiple`.
+pub fn gen_validation_checks(
+    error_type_name: &ErrorTypeName,
+    checks: Vec<TokenStream>,
+    collect_errors: bool,
+) -> TokenStream {
+    if !collect_errors {
+        return quote!(#(#checks)*);
+    }
+
+    let alloc = gen_alloc_crate();
+    quote!(
+        let mut errors: #alloc::vec::Vec<#error_type_name> = #alloc::vec::Vec::new();
+        #(
+            #[allow(clippy::redundant_closure_call)]
+            let result = (|| -> ::core::result::Result<(), #error_type_name> {
+                #checks
+                Ok(())
+            })();
+            if let Err(error) = result {
+                errors.push(error);
+            }
+        )*
+        match errors.len() {
+            0 => {}
+            1 => return Err(errors.remove(0)),
+            _ => return Err(#error_type_name::Multiple(errors)),
+        }
+    )
+}
+
+/// Generate `into_inner()` that consumes the newtype and `inner()` that borrows the inner value.
+/// Both are generated for every kind of inner type.
+pub fn gen_impl_into_inner(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
     quote! {
-        impl #type_name {
+        impl #impl_generics #type_name #ty_generics {
             #[inline]
             pub fn into_inner(self) -> #inner_type {
                 self.0
             }
+
+            #[inline]
+            pub fn inner(&self) -> &#inner_type {
+                &self.0
+            }
+        }
+    }
+}
+
+/// Generate `as_cow()` that borrows the inner value as `Cow`.
+/// The higher-ranked bound keeps the method well-formed for inner types that do not implement
+/// `Clone`: the method is simply not callable for them.
+///
+/// `Cow` is not available in `no_std` environment, so the method is not generated there.
+#[allow(unused_variables)]
+pub fn gen_impl_as_cow(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            let (impl_generics, ty_generics, _) = generics.split_for_impl();
+            quote! {
+                impl #impl_generics #type_name #ty_generics {
+                    #[inline]
+                    pub fn as_cow(&self) -> ::std::borrow::Cow<'_, #inner_type>
+                    where
+                        for<'__nutype> #inner_type: ::std::borrow::ToOwned,
+                    {
+                        ::std::borrow::Cow::Borrowed(&self.0)
+                    }
+                }
+            }
+        } else {
+            quote!{}
         }
     }
 }
 
+/// Generate `modify()` that mutates a copy of the inner value and commits it only if it passes
+/// through the constructor, so the sanitizers and validators are applied again.
+/// Like `as_cow()`, the method is not callable if the inner type does not implement `Clone`.
+pub fn gen_impl_modify(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    has_validation: bool,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    if has_validation {
+        let error_type_name = gen_error_type_name(type_name);
+        quote! {
+            impl #impl_generics #type_name #ty_generics {
+                pub fn modify(
+                    &mut self,
+                    f: impl ::core::ops::FnOnce(&mut #inner_type),
+                ) -> ::core::result::Result<(), #error_type_name>
+                where
+                    for<'__nutype> #inner_type: ::core::clone::Clone,
+                {
+                    let mut raw_value = ::core::clone::Clone::clone(&self.0);
+                    f(&mut raw_value);
+                    *self = Self::#constructor_name(raw_value)?;
+                    Ok(())
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics #type_name #ty_generics {
+                pub fn modify(&mut self, f: impl ::core::ops::FnOnce(&mut #inner_type))
+                where
+                    for<'__nutype> #inner_type: ::core::clone::Clone,
+                {
+                    let mut raw_value = ::core::clone::Clone::clone(&self.0);
+                    f(&mut raw_value);
+                    *self = Self::#constructor_name(raw_value);
+                }
+            }
+        }
+    }
+}
+
+/// Generate `try_map()` (with validation) or `map()` (without validation) that transforms the inner
+/// value and wraps the result through the constructor, so the sanitizers and validators are applied
+/// to it.
+pub fn gen_impl_map(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    has_validation: bool,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    if has_validation {
+        let error_type_name = gen_error_type_name(type_name);
+        quote! {
+            impl #impl_generics #type_name #ty_generics {
+                pub fn try_map(
+                    self,
+                    f: impl ::core::ops::FnOnce(#inner_type) -> #inner_type,
+                ) -> ::core::result::Result<Self, #error_type_name> {
+                    Self::#constructor_name(f(self.0))
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl #impl_generics #type_name #ty_generics {
+                pub fn map(self, f: impl ::core::ops::FnOnce(#inner_type) -> #inner_type) -> Self {
+                    Self::#constructor_name(f(self.0))
+                }
+            }
+        }
+    }
+}
+
+/// A boundary declared with `greater` or `less` (exclusive), `greater_or_equal` or
+/// `less_or_equal` (inclusive).
+pub struct DeclaredBound {
+    pub value: TokenStream,
+    pub is_exclusive: bool,
+}
+
+/// Generate `MIN`, `MAX`, `MIN_EXCLUSIVE` and `MAX_EXCLUSIVE` consts of integer and float based
+/// types, so the tools that describe the types (e.g. in OpenAPI) can read the declared boundaries.
+pub fn gen_impl_numeric_bound_consts(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+    maybe_lower: Option<DeclaredBound>,
+    maybe_upper: Option<DeclaredBound>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let to_consts = |maybe_bound: Option<DeclaredBound>| match maybe_bound {
+        Some(DeclaredBound {
+            value,
+            is_exclusive,
+        }) => (quote!(Some(#value)), is_exclusive),
+        None => (quote!(None), false),
+    };
+    let (min, min_exclusive) = to_consts(maybe_lower);
+    let (max, max_exclusive) = to_consts(maybe_upper);
+
+    quote! {
+        impl #impl_generics #type_name #ty_generics #where_clause {
+            /// The lower boundary set with `greater` or `greater_or_equal`.
+            pub const MIN: Option<#inner_type> = #min;
+            /// Whether `MIN` itself is not valid, which is the case for `greater`.
+            pub const MIN_EXCLUSIVE: bool = #min_exclusive;
+            /// The upper boundary set with `less` or `less_or_equal`.
+            pub const MAX: Option<#inner_type> = #max;
+            /// Whether `MAX` itself is not valid, which is the case for `less`.
+            pub const MAX_EXCLUSIVE: bool = #max_exclusive;
+        }
+    }
+}
+
+/// Generate `new_reporting()`, that constructs the value the same way as the constructor does,
+/// and reports whether the sanitizers changed the raw value.
+pub fn gen_new_reporting(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    has_validation: bool,
+    convert_into_inner_type: bool,
+    allow_unused: bool,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    let (input_type, convert_raw_value_if_necessary) = if convert_into_inner_type {
+        (
+            quote!(impl Into<#inner_type>),
+            quote!(let raw_value: #inner_type = raw_value.into();),
+        )
+    } else {
+        (quote!(#inner_type), quote!())
+    };
+
+    let (return_type, construct, wrap_result) = if has_validation {
+        let error_type_name = gen_error_type_name(type_name);
+        (
+            quote!(::core::result::Result<(Self, bool), #error_type_name>),
+            quote!(Self::#constructor_name(::core::clone::Clone::clone(&raw_value))?),
+            quote!(Ok((value, changed))),
+        )
+    } else {
+        (
+            quote!((Self, bool)),
+            quote!(Self::#constructor_name(::core::clone::Clone::clone(&raw_value))),
+            quote!((value, changed)),
+        )
+    };
+    let must_use = gen_must_use_attr(
+        allow_unused,
+        Some("the function has no side effects besides creating the value"),
+    );
+
+    quote!(
+        impl #impl_generics #type_name #ty_generics {
+            /// Construct the value the same way as the constructor does and report whether
+            /// the sanitizers changed the raw value.
+            #must_use
+            pub fn new_reporting(raw_value: #input_type) -> #return_type {
+                #convert_raw_value_if_necessary
+                let value = #construct;
+                let changed = value.0 != raw_value;
+                #wrap_result
+            }
+        }
+    )
+}
+
 pub trait GenerateNewtype {
-    type Sanitizer;
+    type Sanitizer: FallibleSanitizer;
     type Validator;
     type InnerType: ToTokens;
     type TypedTrait: Hash + TypeTrait;
@@ -155,46 +585,139 @@ pub trait GenerateNewtype {
     /// happens in 2 stages:
     /// * &str -> inner type (parsing)
     /// * inner type -> nutype (validation)
+    ///
     /// But for the String based types there is no first stage, so the parse error is the same as
     /// validation error.
     const HAS_DEDICATED_PARSE_ERROR: bool = true;
 
-    /// If it's true, then `::new()` function receives `impl Into<T>` instead of `T`.
+    /// If it's true, then the constructor (`::new()` by default) receives `impl Into<T>` instead of `T`.
     const NEW_CONVERT_INTO_INNER_TYPE: bool = false;
 
+    /// Type of the argument of the public `validate()` function.
+    fn gen_validate_input_type(
+        inner_type: &Self::InnerType,
+        _validators: &[&Self::Validator],
+    ) -> TokenStream {
+        quote!(&#inner_type)
+    }
+
     fn gen_fn_sanitize(inner_type: &Self::InnerType, sanitizers: &[Self::Sanitizer])
         -> TokenStream;
 
+    /// Generate module level items that are shared by all the validation functions
+    /// (e.g. a compiled regex).
+    fn gen_validation_statics(_validators: &[&Self::Validator]) -> TokenStream {
+        quote!()
+    }
+
     fn gen_fn_validate(
         inner_type: &Self::InnerType,
         type_name: &TypeName,
         validators: &[Self::Validator],
+        collect_errors: bool,
+        maybe_context: Option<&syn::Type>,
     ) -> TokenStream;
 
     fn gen_validation_error_type(
         type_name: &TypeName,
-        validators: &[Self::Validator],
+        validators: &[&Self::Validator],
+        non_exhaustive_error: bool,
+        collect_errors: bool,
+        has_fallible_sanitizers: bool,
     ) -> TokenStream;
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
         inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        maybe_display_format: Option<syn::LitStr>,
+        eq: EqMode,
+        ord: OrdMode,
+        serde_repr: &SerdeRepr,
+        serde_lenient: bool,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
     ) -> Result<GeneratedTraits, syn::Error>;
 
+    #[allow(clippy::too_many_arguments)]
     fn gen_new_with_validation(
         type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
         validators: &[Self::Validator],
+        raw_validators: &[Self::Validator],
+        non_exhaustive_error: bool,
+        collect_errors: bool,
+        allow_unused: bool,
+        maybe_context: Option<&syn::Type>,
+        constructor_vis: &TokenStream,
     ) -> TokenStream {
+        let must_use = gen_must_use_attr(
+            allow_unused,
+            Some("ignoring the result silently discards a validation error"),
+        );
+        let context_param = gen_context_param(maybe_context);
+        let context_arg = gen_context_arg(maybe_context);
+        // A value can be constructed only together with a context, so the constructor gets
+        // a different name, e.g. `new_with_context()`.
+        let constructor_name = match maybe_context {
+            Some(_) => &ConstructorName::new(format_ident!("{constructor_name}_with_context")),
+            None => constructor_name,
+        };
         let sanitize = Self::gen_fn_sanitize(inner_type, sanitizers);
-        let validation_error = Self::gen_validation_error_type(type_name, validators);
+        // Raw and regular validators never share a kind, so they share the error type.
+        let all_validators: Vec<&Self::Validator> =
+            raw_validators.iter().chain(validators).collect();
+        let has_fallible_sanitizers = has_fallible_sanitizers(sanitizers);
+        let validation_error = Self::gen_validation_error_type(
+            type_name,
+            &all_validators,
+            non_exhaustive_error,
+            collect_errors,
+            has_fallible_sanitizers,
+        );
         let error_type_name = gen_error_type_name(type_name);
-        let validate = Self::gen_fn_validate(inner_type, type_name, validators);
+        let sanitize_raw_value = if has_fallible_sanitizers {
+            quote!(sanitize(raw_value).map_err(#error_type_name::SanitizationFailed)?)
+        } else {
+            quote!(sanitize(raw_value))
+        };
+        let validate = Self::gen_fn_validate(
+            inner_type,
+            type_name,
+            validators,
+            collect_errors,
+            maybe_context,
+        );
+        // Validation of the raw value lives in its own block, where `validate()` refers to the
+        // raw validators only.
+        let validate_raw = |value: TokenStream| {
+            if raw_validators.is_empty() {
+                quote!()
+            } else {
+                let validate_raw = Self::gen_fn_validate(
+                    inner_type,
+                    type_name,
+                    raw_validators,
+                    collect_errors,
+                    maybe_context,
+                );
+                quote!({
+                    #validate_raw
+                    validate(#value #context_arg)?;
+                })
+            }
+        };
+        let validate_raw_value = validate_raw(quote!(&raw_value));
+        let validate_raw_arg = validate_raw(quote!(value));
+        let validate_input_type = Self::gen_validate_input_type(inner_type, &all_validators);
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
 
         let (input_type, convert_raw_value_if_necessary) = if Self::NEW_CONVERT_INTO_INNER_TYPE {
             (
@@ -205,32 +728,54 @@ pub trait GenerateNewtype {
             (quote!(#inner_type), quote!())
         };
 
+        let validation_statics = Self::gen_validation_statics(&all_validators);
+
         quote!(
             #validation_error
+            #validation_statics
 
-            impl #type_name {
-                pub fn new(raw_value: #input_type) -> ::core::result::Result<Self, #error_type_name> {
-                    // Keep sanitize() and validate() within new() so they do not overlap with outer
+            impl #impl_generics #type_name #ty_generics {
+                #must_use
+                #constructor_vis fn #constructor_name(raw_value: #input_type #context_param) -> ::core::result::Result<Self, #error_type_name> {
+                    // Keep sanitize() and validate() within the constructor so they do not overlap with outer
                     // scope imported with `use super::*`.
                     #sanitize
                     #validate
 
                     #convert_raw_value_if_necessary
 
-                    let sanitized_value: #inner_type = sanitize(raw_value);
-                    validate(&sanitized_value)?;
+                    #validate_raw_value
+                    let sanitized_value: #inner_type = #sanitize_raw_value;
+                    validate(&sanitized_value #context_arg)?;
                     Ok(#type_name(sanitized_value))
                 }
+
+                /// Check the value against the validation rules without sanitizing it and
+                /// without constructing the newtype.
+                pub fn validate(value: #validate_input_type #context_param) -> ::core::result::Result<(), #error_type_name> {
+                    #validate
+                    #validate_raw_arg
+                    validate(value #context_arg)
+                }
             }
         )
     }
 
     fn gen_new_without_validation(
         type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
         inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
+        allow_unused: bool,
+        constructor_vis: &TokenStream,
     ) -> TokenStream {
+        let must_use = gen_must_use_attr(
+            allow_unused,
+            Some("the constructor has no side effects besides creating the value"),
+        );
         let sanitize = Self::gen_fn_sanitize(inner_type, sanitizers);
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
 
         let (input_type, convert_raw_value_if_necessary) = if Self::NEW_CONVERT_INTO_INNER_TYPE {
             (
@@ -242,8 +787,9 @@ pub trait GenerateNewtype {
         };
 
         quote!(
-            impl #type_name {
-                pub fn new(raw_value: #input_type) -> Self {
+            impl #impl_generics #type_name #ty_generics {
+                #must_use
+                #constructor_vis fn #constructor_name(raw_value: #input_type) -> Self {
                     #sanitize
 
                     #convert_raw_value_if_necessary
@@ -254,27 +800,149 @@ pub trait GenerateNewtype {
         )
     }
 
+    fn gen_impl_as_cow(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+    ) -> TokenStream {
+        gen_impl_as_cow(type_name, generics, inner_type)
+    }
+
+    /// Generate methods that borrow the inner value in other forms without allocation
+    /// (e.g. `as_str()` for strings). Most of the types do not have any.
+    fn gen_impl_inner_views(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _inner_type: &Self::InnerType,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate `try_push()` (with validation) or `push()` (without validation) for collection
+    /// based types. Provided only for `Vec<T>` based types.
+    fn gen_impl_push(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _inner_type: &Self::InnerType,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate `all()` that iterates over all the valid values. Provided with `enumerate`,
+    /// which is supported only by integer based types.
+    fn gen_impl_enumerate(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _constructor_name: &ConstructorName,
+        _inner_type: &Self::InnerType,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    /// Generate associated consts that expose the boundaries declared with the validators,
+    /// e.g. `MIN_LEN` for strings. Only the consts relevant to the kind are generated.
+    fn gen_impl_bound_consts(
+        _type_name: &TypeName,
+        _generics: &Generics,
+        _inner_type: &Self::InnerType,
+        _guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> TokenStream {
+        quote!()
+    }
+
+    #[allow(clippy::too_many_arguments)]
     fn gen_implementation(
         type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
         inner_type: &Self::InnerType,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
         new_unchecked: NewUnchecked,
+        non_exhaustive_error: bool,
+        collect_errors: bool,
+        allow_unused: bool,
+        maybe_context: Option<&syn::Type>,
+        has_async_validation: bool,
+        skip_new: bool,
     ) -> TokenStream {
+        // With `async_with` a value can be constructed only with `new_async()`, which awaits
+        // the async validators, so the regular constructor is kept private.
+        // With `skip_new` it's private too, but the derived traits still call it.
+        let constructor_vis = if has_async_validation || skip_new {
+            quote!()
+        } else {
+            quote!(pub)
+        };
         let impl_new = match guard {
-            Guard::WithoutValidation { sanitizers } => {
-                Self::gen_new_without_validation(type_name, inner_type, sanitizers)
-            }
+            Guard::WithoutValidation { sanitizers } => Self::gen_new_without_validation(
+                type_name,
+                generics,
+                constructor_name,
+                inner_type,
+                sanitizers,
+                allow_unused,
+                &constructor_vis,
+            ),
             Guard::WithValidation {
                 sanitizers,
                 validators,
-            } => Self::gen_new_with_validation(type_name, inner_type, sanitizers, validators),
+                raw_validators,
+            } => Self::gen_new_with_validation(
+                type_name,
+                generics,
+                constructor_name,
+                inner_type,
+                sanitizers,
+                validators,
+                raw_validators,
+                non_exhaustive_error,
+                collect_errors,
+                allow_unused,
+                maybe_context,
+                &constructor_vis,
+            ),
         };
-        let impl_into_inner = gen_impl_into_inner(type_name, inner_type);
-        let impl_new_unchecked = gen_new_unchecked(type_name, inner_type, new_unchecked);
+        let impl_into_inner = gen_impl_into_inner(type_name, generics, inner_type);
+        let impl_as_cow = Self::gen_impl_as_cow(type_name, generics, inner_type);
+        let impl_inner_views = Self::gen_impl_inner_views(type_name, generics, inner_type);
+        let impl_bound_consts = Self::gen_impl_bound_consts(type_name, generics, inner_type, guard);
+        // `modify()`, `try_map()` and `try_push()` have no context to pass to the constructor and
+        // cannot await the async validators.
+        let (impl_modify, impl_map, impl_push) = if maybe_context.is_some() || has_async_validation
+        {
+            (quote!(), quote!(), quote!())
+        } else {
+            (
+                gen_impl_modify(
+                    type_name,
+                    generics,
+                    constructor_name,
+                    inner_type,
+                    guard.has_validation(),
+                ),
+                gen_impl_map(
+                    type_name,
+                    generics,
+                    constructor_name,
+                    inner_type,
+                    guard.has_validation(),
+                ),
+                Self::gen_impl_push(type_name, generics, inner_type, guard),
+            )
+        };
+        let impl_new_unchecked = gen_new_unchecked(type_name, generics, inner_type, new_unchecked);
 
         quote! {
             #impl_new
             #impl_into_inner
+            #impl_as_cow
+            #impl_inner_views
+            #impl_bound_consts
+            #impl_modify
+            #impl_map
+            #impl_push
             #impl_new_unchecked
         }
     }
@@ -289,33 +957,193 @@ pub trait GenerateNewtype {
     ) -> Result<TokenStream, syn::Error> {
         let GenerateParams {
             doc_attrs,
-            traits,
+            mut traits,
             vis,
             type_name,
+            generics,
             guard,
             new_unchecked,
             maybe_default_value,
+            maybe_display_format,
+            maybe_schema_example,
+            from_env,
+            maybe_byte_encoding,
+            sanitize_then_return_changed,
+            transparent_debug,
+            skip_new,
+            serde_lenient,
+            builder,
+            crate_path,
+            maybe_context,
+            async_validators,
+            non_exhaustive_error,
+            collect_errors,
+            allow_unused,
+            repr_transparent,
+            enumerate,
+            constructor_name,
+            inner_vis,
+            eq,
+            ord,
+            serde_repr,
             inner_type,
         } = params;
 
         let module_name = gen_module_name_for_type(&type_name);
-        let implementation =
-            Self::gen_implementation(&type_name, &inner_type, &guard, new_unchecked);
+        let must_use = gen_must_use_attr(allow_unused, None);
+        let repr_transparent_attr = if repr_transparent {
+            quote!(#[repr(transparent)])
+        } else {
+            quote!()
+        };
+        let (schema_example_attr, schema_example_fn) = match maybe_schema_example {
+            Some(example) => {
+                let example_fn = gen_schema_example_fn(
+                    &type_name,
+                    &constructor_name,
+                    &inner_type,
+                    &example,
+                    guard.has_validation(),
+                );
+                (
+                    quote!(#[schemars(example = #SCHEMA_EXAMPLE_FN_NAME)]),
+                    example_fn,
+                )
+            }
+            None => (quote!(), quote!()),
+        };
+        let implementation = Self::gen_implementation(
+            &type_name,
+            &generics,
+            &constructor_name,
+            &inner_type,
+            &guard,
+            new_unchecked,
+            non_exhaustive_error,
+            collect_errors,
+            allow_unused,
+            maybe_context.as_ref(),
+            !async_validators.is_empty(),
+            skip_new,
+        );
+
+        let impl_new_reporting = if sanitize_then_return_changed {
+            gen_new_reporting(
+                &type_name,
+                &generics,
+                &constructor_name,
+                &inner_type,
+                guard.has_validation(),
+                Self::NEW_CONVERT_INTO_INNER_TYPE,
+                allow_unused,
+            )
+        } else {
+            quote!()
+        };
+
+        let impl_enumerate = if enumerate {
+            Self::gen_impl_enumerate(
+                &type_name,
+                &generics,
+                &constructor_name,
+                &inner_type,
+                &guard,
+            )
+        } else {
+            quote!()
+        };
 
         let maybe_error_type_name: Option<ErrorTypeName> = match guard {
             Guard::WithoutValidation { .. } => None,
             Guard::WithValidation { .. } => Some(gen_error_type_name(&type_name)),
         };
 
+        // With validation `DieselNewType` reads the values from the database through `TryFrom`.
+        let diesel_newtype_attr =
+            if guard.has_validation() && traits.iter().any(|t| t.is_diesel_new_type()) {
+                quote!(#[diesel_newtype(try_from)])
+            } else {
+                quote!()
+            };
+
         let has_from_str_trait = traits.iter().any(|t| t.is_from_str());
-        let maybe_parse_error_type_name = if has_from_str_trait && Self::HAS_DEDICATED_PARSE_ERROR {
+        let maybe_parse_error_type_name = if (has_from_str_trait && Self::HAS_DEDICATED_PARSE_ERROR)
+            || maybe_byte_encoding.is_some()
+        {
             Some(gen_parse_error_name(&type_name))
         } else {
             None
         };
 
+        let maybe_try_from_wide_error_type_name = if traits.iter().any(|t| t.is_try_from_wide()) {
+            Some(gen_try_from_wide_error_name(&type_name))
+        } else {
+            None
+        };
+
+        let impl_from_encoding = match maybe_byte_encoding {
+            Some(encoding) => gen_impl_from_encoding(
+                &type_name,
+                &generics,
+                &constructor_name,
+                &inner_type,
+                encoding,
+                maybe_error_type_name.as_ref(),
+            ),
+            None => quote!(),
+        };
+
+        let (from_env_fn, maybe_from_env_error_type_name) = if from_env {
+            let from_env_fn = gen_from_env(
+                &type_name,
+                &generics,
+                &inner_type,
+                maybe_error_type_name.as_ref(),
+                Self::HAS_DEDICATED_PARSE_ERROR,
+            );
+            (from_env_fn, Some(gen_from_env_error_name(&type_name)))
+        } else {
+            (quote!(), None)
+        };
+
+        let (new_async_fn, maybe_async_error_type_name) = if async_validators.is_empty() {
+            (quote!(), None)
+        } else {
+            let input_type = if Self::NEW_CONVERT_INTO_INNER_TYPE {
+                quote!(impl Into<#inner_type>)
+            } else {
+                quote!(#inner_type)
+            };
+            let new_async_fn = gen_new_async(
+                &type_name,
+                &generics,
+                &constructor_name,
+                input_type,
+                // `async_with` validators do not depend on the regular ones.
+                Self::gen_validate_input_type(&inner_type, &[]),
+                maybe_error_type_name.as_ref(),
+                &async_validators,
+            );
+            (new_async_fn, Some(gen_async_error_name(&type_name)))
+        };
+
+        let (builder_fn, maybe_builder_type_name) = if builder {
+            let builder_fn = gen_builder(
+                &type_name,
+                &generics,
+                &constructor_name,
+                &inner_type,
+                Self::NEW_CONVERT_INTO_INNER_TYPE,
+                maybe_error_type_name.as_ref(),
+            );
+            (builder_fn, Some(gen_builder_type_name(&type_name)))
+        } else {
+            (quote!(), None)
+        };
+
         let tests = Self::gen_tests(
             &type_name,
+            &constructor_name,
             &inner_type,
             &maybe_default_value,
             &guard,
@@ -328,40 +1156,77 @@ pub trait GenerateNewtype {
             &module_name,
             maybe_error_type_name.as_ref(),
             maybe_parse_error_type_name.as_ref(),
+            maybe_from_env_error_type_name.as_ref(),
+            maybe_async_error_type_name.as_ref(),
+            maybe_builder_type_name.as_ref(),
+            maybe_try_from_wide_error_type_name.as_ref(),
         );
 
+        // `Debug` is implemented by hand instead of being derived, so it is not passed further.
+        let impl_transparent_debug = if transparent_debug {
+            traits.retain(|t| !t.is_debug());
+            gen_impl_trait_transparent_debug(&type_name, &generics, &inner_type)
+        } else {
+            quote!()
+        };
+
         let GeneratedTraits {
             derive_transparent_traits,
             implement_traits,
         } = Self::gen_traits(
             &type_name,
+            &generics,
+            &constructor_name,
             &inner_type,
             maybe_error_type_name,
             traits,
             maybe_default_value,
+            maybe_display_format,
+            eq,
+            ord,
+            &serde_repr,
+            serde_lenient,
             &guard,
         )?;
 
-        Ok(quote!(
+        let output = quote!(
             #[doc(hidden)]
             mod #module_name {
                 use super::*;
 
                 #(#doc_attrs)*
                 #derive_transparent_traits
-                pub struct #type_name(#inner_type);
+                #diesel_newtype_attr
+                #schema_example_attr
+                #must_use
+                #repr_transparent_attr
+                pub struct #type_name #generics (#inner_vis #inner_type);
 
                 #implementation
+                #impl_new_reporting
+                #impl_enumerate
                 #implement_traits
+                #impl_transparent_debug
+                #schema_example_fn
+                #from_env_fn
+                #impl_from_encoding
+                #new_async_fn
+                #builder_fn
 
                 #tests
             }
             #reimports
-        ))
+        );
+
+        match crate_path {
+            Some(crate_path) => Ok(gen_with_crate_path(output, &crate_path)),
+            None => Ok(output),
+        }
     }
 
     fn gen_tests(
         type_name: &TypeName,
+        constructor_name: &ConstructorName,
         inner_type: &Self::InnerType,
         maybe_default_value: &Option<syn::Expr>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
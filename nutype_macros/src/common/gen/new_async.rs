@@ -0,0 +1,113 @@
+use cfg_if::cfg_if;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::Generics;
+
+use crate::common::models::{
+    AsyncErrorTypeName, ConstructorName, CustomFunction, ErrorTypeName, TypeName,
+};
+
+/// Generate a name for the error which is returned by `new_async()` function.
+pub fn gen_async_error_name(type_name: &TypeName) -> AsyncErrorTypeName {
+    let ident = format_ident!("{type_name}AsyncError");
+    AsyncErrorTypeName::new(ident)
+}
+
+/// Generate `new_async()` function, that constructs the value with the regular constructor
+/// and then awaits the validators set with `async_with`, together with the error it returns.
+///
+/// The error distinguishes a value that violates the regular validation rules (only if the type
+/// has validation) and a value that is rejected by one of the async validators.
+pub fn gen_new_async(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    input_type: TokenStream,
+    validate_input_type: TokenStream,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    async_validators: &[CustomFunction],
+) -> TokenStream {
+    let async_error_type_name = gen_async_error_name(type_name);
+    let type_name_str = type_name.to_string();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+    let mut variants = vec![quote!(
+        /// One of the validators set with `async_with` rejected the value.
+        AsyncWithViolated(::std::string::String)
+    )];
+    let mut display_arms = vec![quote!(
+        #async_error_type_name::AsyncWithViolated(message) => write!(f, "{} is invalid: {}", #type_name_str, message)
+    )];
+    let construct = if let Some(error_type_name) = maybe_error_type_name {
+        variants.push(quote!(
+            /// The value violates the regular validation rules.
+            Validate(#error_type_name)
+        ));
+        display_arms.push(quote!(
+            #async_error_type_name::Validate(err) => ::core::fmt::Display::fmt(err, f)
+        ));
+        quote!(Self::#constructor_name(raw_value).map_err(#async_error_type_name::Validate)?)
+    } else {
+        quote!(Self::#constructor_name(raw_value))
+    };
+
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            let impl_std_error = quote! {
+                impl ::std::error::Error for #async_error_type_name {
+                    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                        None
+                    }
+                }
+            };
+        } else {
+            // NOTE: There is no `::core::error::Error` yet in stable Rust.
+            // So for `no_std` we just don't implement `Error` trait.
+            let impl_std_error = quote! {};
+        }
+    };
+
+    quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum #async_error_type_name {
+            #(#variants,)*
+        }
+
+        impl ::core::fmt::Display for #async_error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #(#display_arms,)*
+                }
+            }
+        }
+
+        #impl_std_error
+
+        impl #impl_generics #type_name #ty_generics {
+            /// Constructs the value the same way as the regular constructor does and then awaits
+            /// the validators set with `async_with`, one after another.
+            pub async fn new_async(raw_value: #input_type) -> ::core::result::Result<Self, #async_error_type_name> {
+                // The lifetime of the future is tied to the borrowed value, so a closure like
+                // `|v| async move { ... }` may hold the reference across `.await`.
+                fn call_async_validator<'a, V: ?Sized, F, Fut>(value: &'a V, validator: F) -> Fut
+                where
+                    F: FnOnce(&'a V) -> Fut,
+                    Fut: ::core::future::Future<Output = ::core::result::Result<(), ::std::string::String>>,
+                {
+                    validator(value)
+                }
+
+                let value = #construct;
+                {
+                    let inner_value: #validate_input_type = &value.0;
+                    #(
+                        call_async_validator(inner_value, #async_validators)
+                            .await
+                            .map_err(#async_error_type_name::AsyncWithViolated)?;
+                    )*
+                }
+                Ok(value)
+            }
+        }
+    }
+}
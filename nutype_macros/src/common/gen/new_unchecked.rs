@@ -1,21 +1,24 @@
 use crate::common::models::{NewUnchecked, TypeName};
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 pub fn gen_new_unchecked(
     type_name: &TypeName,
+    generics: &Generics,
     inner_type: impl ToTokens,
     new_unchecked: NewUnchecked,
 ) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
     match new_unchecked {
         NewUnchecked::Off => quote! {},
         NewUnchecked::On => quote! {
-            impl #type_name {
+            impl #impl_generics #type_name #ty_generics {
                 /// Creates a value of type skipping the sanitization and validation
                 /// rules. Generally, you should avoid using `::new_unchecked()` without a real need.
                 /// Use `::new()` instead when it's possible.
-                pub unsafe fn new_unchecked(inner_value: #inner_type) -> #type_name {
-                    #type_name(inner_value)
+                pub unsafe fn new_unchecked(inner_value: #inner_type) -> Self {
+                    Self(inner_value)
                 }
             }
         },
@@ -1,7 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
-use crate::common::models::{NumericBound, TypeName};
+use crate::common::models::{ConstructorName, NumericBound, TypeName};
 
 pub fn gen_test_should_have_consistent_lower_and_upper_boundaries<Validator>(
     type_name: &TypeName,
@@ -34,6 +34,7 @@ Note: the test is generated automatically by #[nutype] macro.
 
 pub fn gen_test_should_have_valid_default_value(
     type_name: &TypeName,
+    constructor_name: &ConstructorName,
     maybe_default_value: &Option<syn::Expr>,
     has_validation: bool,
 ) -> Option<TokenStream> {
@@ -58,7 +59,7 @@ Note: the test is generated automatically by #[nutype] macro
             let default_inner_value = #type_name::default().into_inner();
             // Typically `::default()` would already panic, but in case if the panic is removed by
             // some reason we still want the test to fail.
-            #type_name::new(default_inner_value).expect(#msg);
+            let _ = #type_name::#constructor_name(default_inner_value).expect(#msg);
         }
     ))
 }
@@ -1,9 +1,12 @@
 use std::collections::HashSet;
 
+use cfg_if::cfg_if;
+
 use proc_macro2::TokenStream;
-use quote::{quote, ToTokens};
+use quote::{format_ident, quote, ToTokens};
+use syn::Generics;
 
-use crate::common::models::{ErrorTypeName, InnerType, TypeName};
+use crate::common::models::{ConstructorName, ErrorTypeName, InnerType, SerdeRepr, TypeName};
 
 use super::parse_error::{gen_def_parse_error, gen_parse_error_name};
 
@@ -51,26 +54,36 @@ where
     }
 }
 
-pub fn gen_impl_trait_into(type_name: &TypeName, inner_type: impl Into<InnerType>) -> TokenStream {
+pub fn gen_impl_trait_into(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl Into<InnerType>,
+) -> TokenStream {
     let inner_type: InnerType = inner_type.into();
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
 
     // NOTE: We're getting blank implementation of
     //     Into<Inner> for Type
     // by implementing
     //     From<Type> for Inner
     quote! {
-        impl ::core::convert::From<#type_name> for #inner_type {
+        impl #impl_generics ::core::convert::From<#type_name #ty_generics> for #inner_type {
             #[inline]
-            fn from(value: #type_name) -> Self {
+            fn from(value: #type_name #ty_generics) -> Self {
                 value.into_inner()
             }
         }
     }
 }
 
-pub fn gen_impl_trait_as_ref(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_as_ref(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
     quote! {
-        impl ::core::convert::AsRef<#inner_type> for #type_name {
+        impl #impl_generics ::core::convert::AsRef<#inner_type> for #type_name #ty_generics {
             #[inline]
             fn as_ref(&self) -> &#inner_type {
                 &self.0
@@ -79,9 +92,14 @@ pub fn gen_impl_trait_as_ref(type_name: &TypeName, inner_type: impl ToTokens) ->
     }
 }
 
-pub fn gen_impl_trait_deref(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_deref(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
     quote! {
-        impl ::core::ops::Deref for #type_name {
+        impl #impl_generics ::core::ops::Deref for #type_name #ty_generics {
             type Target = #inner_type;
 
             #[inline]
@@ -92,9 +110,28 @@ pub fn gen_impl_trait_deref(type_name: &TypeName, inner_type: impl ToTokens) ->
     }
 }
 
-pub fn gen_impl_trait_display(type_name: &TypeName) -> TokenStream {
+/// Generate implementation of Display trait.
+/// If `display = ` format is provided, the inner value is formatted with it, otherwise
+/// the implementation is forwarded to the inner type.
+pub fn gen_impl_trait_display(
+    type_name: &TypeName,
+    generics: &Generics,
+    maybe_display_format: Option<&syn::LitStr>,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    if let Some(display_format) = maybe_display_format {
+        return quote! {
+            impl #impl_generics ::core::fmt::Display for #type_name #ty_generics {
+                #[inline]
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::write!(f, #display_format, &self.0)
+                }
+            }
+        };
+    }
+
     quote! {
-        impl ::core::fmt::Display for #type_name {
+        impl #impl_generics ::core::fmt::Display for #type_name #ty_generics {
             #[inline]
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 // A tiny wrapper function with trait boundary that improves error reporting.
@@ -111,9 +148,39 @@ pub fn gen_impl_trait_display(type_name: &TypeName) -> TokenStream {
     }
 }
 
-pub fn gen_impl_trait_borrow(type_name: &TypeName, borrowed_type: impl ToTokens) -> TokenStream {
+/// Generate implementation of Debug trait, that is forwarded to the inner type, so the value
+/// is printed without the newtype name (e.g. `"bob"` instead of `Username("bob")`).
+pub fn gen_impl_trait_transparent_debug(
+    type_name: &TypeName,
+    generics: &Generics,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    // The same as with derived `Debug`, a generic newtype is `Debug` only if its inner type is.
+    let mut generics = generics.clone();
+    generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#inner_type: ::core::fmt::Debug));
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics ::core::fmt::Debug for #type_name #ty_generics #where_clause {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                ::core::fmt::Debug::fmt(&self.0, f)
+            }
+        }
+    }
+}
+
+pub fn gen_impl_trait_borrow(
+    type_name: &TypeName,
+    generics: &Generics,
+    borrowed_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
     quote! {
-        impl ::core::borrow::Borrow<#borrowed_type> for #type_name {
+        impl #impl_generics ::core::borrow::Borrow<#borrowed_type> for #type_name #ty_generics {
             #[inline]
             fn borrow(&self) -> &#borrowed_type {
                 &self.0
@@ -122,12 +189,18 @@ pub fn gen_impl_trait_borrow(type_name: &TypeName, borrowed_type: impl ToTokens)
     }
 }
 
-pub fn gen_impl_trait_from(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+pub fn gen_impl_trait_from(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
     quote! {
-        impl ::core::convert::From<#inner_type> for #type_name {
+        impl #impl_generics ::core::convert::From<#inner_type> for #type_name #ty_generics {
             #[inline]
             fn from(raw_value: #inner_type) -> Self {
-                Self::new(raw_value)
+                Self::#constructor_name(raw_value)
             }
         }
     }
@@ -135,6 +208,7 @@ pub fn gen_impl_trait_from(type_name: &TypeName, inner_type: impl ToTokens) -> T
 
 pub fn gen_impl_trait_try_from(
     type_name: &TypeName,
+    constructor_name: &ConstructorName,
     inner_type: impl ToTokens,
     maybe_error_type_name: Option<&ErrorTypeName>,
 ) -> TokenStream {
@@ -148,7 +222,7 @@ pub fn gen_impl_trait_try_from(
 
                     #[inline]
                     fn try_from(raw_value: #inner_type) -> Result<#type_name, Self::Error> {
-                        Self::new(raw_value)
+                        Self::#constructor_name(raw_value)
                     }
                 }
             }
@@ -157,12 +231,208 @@ pub fn gen_impl_trait_try_from(
             // The case when there are no validation
             //
             quote! {
+                // Make clippy ignore the infallible implementation, since it is derived explicitly.
+                #[allow(unknown_lints, clippy::infallible_try_from)]
                 impl ::core::convert::TryFrom<#inner_type> for #type_name {
                     type Error = ::core::convert::Infallible;
 
                     #[inline]
                     fn try_from(raw_value: #inner_type) -> Result<#type_name, Self::Error> {
-                        Ok(Self::new(raw_value))
+                        Ok(Self::#constructor_name(raw_value))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generate implementations of `Sum` over the newtype and over references to it.
+/// The sum is computed on the inner values and passed through the constructor, so the
+/// sanitizers are applied to the result. It's allowed only for types without validation.
+pub fn gen_impl_trait_sum(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    quote! {
+        impl ::core::iter::Sum for #type_name {
+            fn sum<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                Self::#constructor_name(iter.map(|value| value.0).sum::<#inner_type>())
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a #type_name> for #type_name {
+            fn sum<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Self::#constructor_name(iter.map(|value| value.0).sum::<#inner_type>())
+            }
+        }
+    }
+}
+
+/// Generate implementations of `Product` over the newtype and over references to it.
+/// See `gen_impl_trait_sum()` for details.
+pub fn gen_impl_trait_product(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+) -> TokenStream {
+    quote! {
+        impl ::core::iter::Product for #type_name {
+            fn product<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+                Self::#constructor_name(iter.map(|value| value.0).product::<#inner_type>())
+            }
+        }
+
+        impl<'a> ::core::iter::Product<&'a #type_name> for #type_name {
+            fn product<I: ::core::iter::Iterator<Item = &'a Self>>(iter: I) -> Self {
+                Self::#constructor_name(iter.map(|value| value.0).product::<#inner_type>())
+            }
+        }
+    }
+}
+
+/// Generate `checked_add()` and `checked_sub()`, that perform the operation on the inner values
+/// and pass the result through the constructor, so it's sanitized and validated again.
+/// `None` is returned when the operation overflows or the result violates the validation rules.
+/// `checked_add` and `checked_sub` are expressions of type `Option<Inner>` over `self.0` and `rhs.0`.
+pub fn gen_impl_checked_arith(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    checked_add: TokenStream,
+    checked_sub: TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    let construct = if has_validation {
+        quote!(Self::#constructor_name(raw_value).ok())
+    } else {
+        quote!(Some(Self::#constructor_name(raw_value)))
+    };
+
+    quote! {
+        impl #type_name {
+            /// Adds `rhs` to the value. Returns `None` on overflow or if the sum
+            /// violates the validation rules.
+            pub fn checked_add(self, rhs: Self) -> ::core::option::Option<Self> {
+                let raw_value: #inner_type = #checked_add?;
+                #construct
+            }
+
+            /// Subtracts `rhs` from the value. Returns `None` on overflow or if the difference
+            /// violates the validation rules.
+            pub fn checked_sub(self, rhs: Self) -> ::core::option::Option<Self> {
+                let raw_value: #inner_type = #checked_sub?;
+                #construct
+            }
+        }
+    }
+}
+
+/// Generate implementation of `Neg`. The negated value goes through the constructor, so the
+/// sanitizers are applied to it. With validation `Neg` is derived only if the bounds are symmetric,
+/// so the constructor is not expected to fail.
+/// Integers are negated with `checked_neg()`, so negation of `MIN` panics with a clear message
+/// in release builds too, instead of wrapping around silently.
+pub fn gen_impl_trait_neg(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    has_validation: bool,
+    is_integer: bool,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+    let negated = if is_integer {
+        let msg = format!("Negation of {type_name_str} overflows: the minimum value of the inner type has no positive counterpart");
+        quote!(self.0.checked_neg().expect(#msg))
+    } else {
+        quote!(-self.0)
+    };
+    let construct = if has_validation {
+        quote!(
+            match Self::#constructor_name(#negated) {
+                Ok(value) => value,
+                Err(err) => panic!("Negated value of {} is invalid: {}", #type_name_str, err),
+            }
+        )
+    } else {
+        quote!(Self::#constructor_name(#negated))
+    };
+    quote! {
+        impl ::core::ops::Neg for #type_name {
+            type Output = Self;
+
+            fn neg(self) -> Self {
+                #construct
+            }
+        }
+    }
+}
+
+/// Generate implementation of `PartialEq<Inner>`, so a newtype can be compared directly
+/// with a value of the inner type.
+pub fn gen_impl_trait_eq_inner(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+    quote! {
+        impl ::core::cmp::PartialEq<#inner_type> for #type_name {
+            #[inline]
+            fn eq(&self, other: &#inner_type) -> bool {
+                self.0.eq(other)
+            }
+        }
+    }
+}
+
+/// Implement `PartialOrd` against the inner type, so a value can be compared with a literal
+/// (e.g. `port < 1024`). It relies on `PartialEq<Inner>` generated by `EqInner`.
+pub fn gen_impl_trait_ord_inner(type_name: &TypeName, inner_type: impl ToTokens) -> TokenStream {
+    quote! {
+        impl ::core::cmp::PartialOrd<#inner_type> for #type_name {
+            #[inline]
+            fn partial_cmp(&self, other: &#inner_type) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.partial_cmp(other)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `nutype::Nutype` trait.
+pub fn gen_impl_trait_nutype(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    match maybe_error_type_name {
+        Some(error_type_name) => {
+            quote! {
+                impl ::nutype::Nutype for #type_name {
+                    type Inner = #inner_type;
+                    type Error = #error_type_name;
+
+                    #[inline]
+                    fn new(raw_value: Self::Inner) -> ::core::result::Result<Self, Self::Error> {
+                        #type_name::#constructor_name(raw_value)
+                    }
+
+                    #[inline]
+                    fn into_inner(self) -> Self::Inner {
+                        #type_name::into_inner(self)
+                    }
+                }
+            }
+        }
+        None => {
+            quote! {
+                impl ::nutype::Nutype for #type_name {
+                    type Inner = #inner_type;
+                    type Error = ::core::convert::Infallible;
+
+                    #[inline]
+                    fn new(raw_value: Self::Inner) -> ::core::result::Result<Self, Self::Error> {
+                        Ok(#type_name::#constructor_name(raw_value))
+                    }
+
+                    #[inline]
+                    fn into_inner(self) -> Self::Inner {
+                        #type_name::into_inner(self)
                     }
                 }
             }
@@ -173,6 +443,7 @@ pub fn gen_impl_trait_try_from(
 /// Generate implementation of FromStr trait for non-string types (e.g. integers or floats).
 pub fn gen_impl_trait_from_str(
     type_name: &TypeName,
+    constructor_name: &ConstructorName,
     inner_type: impl Into<InnerType>,
     maybe_error_type_name: Option<&ErrorTypeName>,
 ) -> TokenStream {
@@ -195,7 +466,7 @@ pub fn gen_impl_trait_from_str(
 
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
                     let raw_value: #inner_type = raw_string.parse().map_err(#parse_error_type_name::Parse)?;
-                    Self::new(raw_value).map_err(#parse_error_type_name::Validate)
+                    Self::#constructor_name(raw_value).map_err(#parse_error_type_name::Validate)
                 }
             }
         }
@@ -209,48 +480,142 @@ pub fn gen_impl_trait_from_str(
 
                 fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
                     let value: #inner_type = raw_string.parse().map_err(#parse_error_type_name::Parse)?;
-                    Ok(#type_name::new(value))
+                    Ok(#type_name::#constructor_name(value))
                 }
             }
         }
     }
 }
 
-pub fn gen_impl_trait_serde_serialize(type_name: &TypeName) -> TokenStream {
+pub fn gen_impl_trait_serde_serialize(type_name: &TypeName, serde_repr: &SerdeRepr) -> TokenStream {
     let type_name_str = type_name.to_string();
+    let serialize_body = match serde_repr {
+        SerdeRepr::Transparent => quote! {
+            serializer.serialize_newtype_struct(#type_name_str, &self.0)
+        },
+        SerdeRepr::Object { field } => quote! {
+            let mut state = ::serde::Serializer::serialize_struct(serializer, #type_name_str, 1)?;
+            ::serde::ser::SerializeStruct::serialize_field(&mut state, #field, &self.0)?;
+            ::serde::ser::SerializeStruct::end(state)
+        },
+    };
+    let impl_from_for_json_value = gen_impl_from_for_json_value(type_name);
     quote! {
         impl ::serde::Serialize for #type_name {
             fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
             where
                 S: ::serde::Serializer
             {
-                serializer.serialize_newtype_struct(#type_name_str, &self.0)
+                #serialize_body
+            }
+        }
+
+        #impl_from_for_json_value
+    }
+}
+
+/// With `serde_json` feature a serializable newtype can be converted into `serde_json::Value`
+/// directly. The conversion goes through the `Serialize` implementation, so it respects `serde_repr`.
+#[allow(unused_variables)]
+fn gen_impl_from_for_json_value(type_name: &TypeName) -> TokenStream {
+    cfg_if! {
+        if #[cfg(feature = "serde_json")] {
+            quote! {
+                impl ::core::convert::From<#type_name> for ::serde_json::Value {
+                    fn from(value: #type_name) -> Self {
+                        ::serde_json::to_value(&value)
+                            .expect("Nutype failed to convert the value into serde_json::Value")
+                    }
+                }
             }
+        } else {
+            quote!()
         }
     }
 }
 
 pub fn gen_impl_trait_serde_deserialize(
     type_name: &TypeName,
+    constructor_name: &ConstructorName,
     inner_type: impl Into<InnerType>,
     maybe_error_type_name: Option<&ErrorTypeName>,
+    serde_repr: &SerdeRepr,
 ) -> TokenStream {
     let inner_type: InnerType = inner_type.into();
-    let raw_value_to_result: TokenStream = if maybe_error_type_name.is_some() {
-        let type_name_str = type_name.to_string();
+
+    // Integers and floats are `Copy`, so the raw value is still available to report it
+    // together with the validation error.
+    let reports_raw_value = matches!(inner_type, InnerType::Integer(_) | InnerType::Float(_));
+    let gen_raw_value_to_result = |error_type: TokenStream| -> TokenStream {
+        gen_deserialized_value_to_result(
+            type_name,
+            constructor_name,
+            maybe_error_type_name,
+            reports_raw_value,
+            error_type,
+        )
+    };
+
+    match serde_repr {
+        SerdeRepr::Transparent => gen_impl_trait_serde_deserialize_transparent(
+            type_name,
+            &inner_type,
+            gen_raw_value_to_result(quote!(DE::Error)),
+        ),
+        SerdeRepr::Object { field } => gen_impl_trait_serde_deserialize_object(
+            type_name,
+            &inner_type,
+            field,
+            gen_raw_value_to_result(quote!(__A::Error)),
+        ),
+    }
+}
+
+/// Wrap the deserialized `raw_value` with the constructor, turning a validation error into
+/// a custom error of the deserializer (`error_type`).
+pub fn gen_deserialized_value_to_result(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    reports_raw_value: bool,
+    error_type: TokenStream,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+    if maybe_error_type_name.is_some() {
+        // Add a hint about which type is causing the error.
+        // `format_args!` is used instead of `format!`, so no allocation is needed
+        // and the generated code compiles in `no_std` environment.
+        let custom_error = if reports_raw_value {
+            quote!(::core::format_args!(
+                "{} Expected valid {}, got {}",
+                validation_error,
+                #type_name_str,
+                raw_value,
+            ))
+        } else {
+            quote!(::core::format_args!(
+                "{} Expected valid {}",
+                validation_error,
+                #type_name_str,
+            ))
+        };
         quote! {
-            #type_name::new(raw_value).map_err(|validation_error| {
-                // Add a hint about which type is causing the error,
-                let err_msg = format!("{validation_error} Expected valid {}", #type_name_str);
-                <DE::Error as serde::de::Error>::custom(err_msg)
+            #type_name::#constructor_name(raw_value).map_err(|validation_error| {
+                <#error_type as ::serde::de::Error>::custom(#custom_error)
             })
         }
     } else {
         quote! {
-            Ok(#type_name::new(raw_value))
+            Ok(#type_name::#constructor_name(raw_value))
         }
-    };
+    }
+}
 
+fn gen_impl_trait_serde_deserialize_transparent(
+    type_name: &TypeName,
+    inner_type: &InnerType,
+    raw_value_to_result: TokenStream,
+) -> TokenStream {
     let expecting_str = format!("tuple struct {type_name}");
     let type_name_str = type_name.to_string();
 
@@ -258,8 +623,8 @@ pub fn gen_impl_trait_serde_deserialize(
         impl<'de> ::serde::Deserialize<'de> for #type_name {
             fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
                 struct __Visitor<'de> {
-                    marker: ::std::marker::PhantomData<#type_name>,
-                    lifetime: ::std::marker::PhantomData<&'de ()>,
+                    marker: ::core::marker::PhantomData<#type_name>,
+                    lifetime: ::core::marker::PhantomData<&'de ()>,
                 }
 
                 impl<'de> ::serde::de::Visitor<'de> for __Visitor<'de> {
@@ -294,8 +659,292 @@ pub fn gen_impl_trait_serde_deserialize(
     }
 }
 
+/// Deserialize a struct with a single `field`, like `#[derive(Deserialize)]` does for
+/// `struct T { field: Inner }`: unknown fields are ignored, a sequence of one element is accepted
+/// for non self-describing formats.
+fn gen_impl_trait_serde_deserialize_object(
+    type_name: &TypeName,
+    inner_type: &InnerType,
+    field: &str,
+    raw_value_to_result: TokenStream,
+) -> TokenStream {
+    let expecting_str = format!("struct {type_name}");
+    let type_name_str = type_name.to_string();
+
+    quote! {
+        impl<'de> ::serde::Deserialize<'de> for #type_name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                enum __Field {
+                    Value,
+                    Ignore,
+                }
+
+                impl<'de> ::serde::Deserialize<'de> for __Field {
+                    fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                        struct __FieldVisitor;
+
+                        impl<'de> ::serde::de::Visitor<'de> for __FieldVisitor {
+                            type Value = __Field;
+
+                            fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                                formatter.write_str("field identifier")
+                            }
+
+                            fn visit_u64<E: ::serde::de::Error>(self, value: u64) -> ::core::result::Result<__Field, E> {
+                                Ok(if value == 0 { __Field::Value } else { __Field::Ignore })
+                            }
+
+                            fn visit_str<E: ::serde::de::Error>(self, value: &str) -> ::core::result::Result<__Field, E> {
+                                Ok(if value == #field { __Field::Value } else { __Field::Ignore })
+                            }
+
+                            fn visit_bytes<E: ::serde::de::Error>(self, value: &[u8]) -> ::core::result::Result<__Field, E> {
+                                Ok(if value == #field.as_bytes() { __Field::Value } else { __Field::Ignore })
+                            }
+                        }
+
+                        ::serde::Deserializer::deserialize_identifier(deserializer, __FieldVisitor)
+                    }
+                }
+
+                struct __Visitor<'de> {
+                    marker: ::core::marker::PhantomData<#type_name>,
+                    lifetime: ::core::marker::PhantomData<&'de ()>,
+                }
+
+                impl<'de> ::serde::de::Visitor<'de> for __Visitor<'de> {
+                    type Value = #type_name;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        write!(formatter, #expecting_str)
+                    }
+
+                    fn visit_seq<__A>(self, mut seq: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                    where
+                        __A: ::serde::de::SeqAccess<'de>
+                    {
+                        let raw_value: #inner_type = match ::serde::de::SeqAccess::next_element(&mut seq)? {
+                            Some(val) => val,
+                            None => return Err(<__A::Error as ::serde::de::Error>::invalid_length(0, &self)),
+                        };
+                        #raw_value_to_result
+                    }
+
+                    fn visit_map<__A>(self, mut map: __A) -> ::core::result::Result<Self::Value, __A::Error>
+                    where
+                        __A: ::serde::de::MapAccess<'de>
+                    {
+                        let mut maybe_raw_value: ::core::option::Option<#inner_type> = None;
+                        while let Some(key) = ::serde::de::MapAccess::next_key::<__Field>(&mut map)? {
+                            match key {
+                                __Field::Value => {
+                                    if maybe_raw_value.is_some() {
+                                        return Err(<__A::Error as ::serde::de::Error>::duplicate_field(#field));
+                                    }
+                                    maybe_raw_value = Some(::serde::de::MapAccess::next_value(&mut map)?);
+                                }
+                                __Field::Ignore => {
+                                    let _: ::serde::de::IgnoredAny = ::serde::de::MapAccess::next_value(&mut map)?;
+                                }
+                            }
+                        }
+                        let raw_value: #inner_type = match maybe_raw_value {
+                            Some(val) => val,
+                            None => return Err(<__A::Error as ::serde::de::Error>::missing_field(#field)),
+                        };
+                        #raw_value_to_result
+                    }
+                }
+
+                ::serde::de::Deserializer::deserialize_struct(
+                    deserializer,
+                    #type_name_str,
+                    &[#field],
+                    __Visitor {
+                        marker: Default::default(),
+                        lifetime: Default::default(),
+                    }
+                )
+            }
+        }
+    }
+}
+
+/// Generate implementation of `rkyv` traits `Archive`, `Serialize` and `Deserialize`.
+/// The newtype is archived transparently as its inner type. When a value is deserialized,
+/// it goes through `new()`, so the sanitizers and validators are applied again.
+///
+/// `archived_type` must be the concrete archived type of the inner type: `Deserialize` is
+/// implemented on it and the compiler rejects `rkyv::Archived<T>` projection in the impl header
+/// as potentially overlapping.
+pub fn gen_impl_trait_rkyv(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl Into<InnerType>,
+    archived_type: TokenStream,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        quote! {
+            #type_name::#constructor_name(raw_value).map_err(<__D::Error as ::rkyv::rancor::Source>::new)
+        }
+    } else {
+        quote! {
+            Ok(#type_name::#constructor_name(raw_value))
+        }
+    };
+
+    quote! {
+        impl ::rkyv::Archive for #type_name {
+            type Archived = #archived_type;
+            type Resolver = ::rkyv::Resolver<#inner_type>;
+
+            #[inline]
+            fn resolve(&self, resolver: Self::Resolver, out: ::rkyv::Place<Self::Archived>) {
+                ::rkyv::Archive::resolve(&self.0, resolver, out)
+            }
+        }
+
+        impl<__S> ::rkyv::Serialize<__S> for #type_name
+        where
+            __S: ::rkyv::rancor::Fallible + ?Sized,
+            #inner_type: ::rkyv::Serialize<__S>,
+        {
+            #[inline]
+            fn serialize(&self, serializer: &mut __S) -> ::core::result::Result<Self::Resolver, __S::Error> {
+                ::rkyv::Serialize::serialize(&self.0, serializer)
+            }
+        }
+
+        impl<__D> ::rkyv::Deserialize<#type_name, __D> for #archived_type
+        where
+            __D: ::rkyv::rancor::Fallible + ?Sized,
+            __D::Error: ::rkyv::rancor::Source,
+            #archived_type: ::rkyv::Deserialize<#inner_type, __D>,
+        {
+            fn deserialize(&self, deserializer: &mut __D) -> ::core::result::Result<#type_name, __D::Error> {
+                let raw_value: #inner_type = ::rkyv::Deserialize::<#inner_type, __D>::deserialize(self, deserializer)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+/// Generate implementation of `sqlx` traits `Type`, `Encode` and `Decode`, which store the inner
+/// value as JSON (using `sqlx::types::Json`). Decoded values go through `new()`, so the
+/// sanitizers and validators are applied again.
+pub fn gen_impl_trait_sqlx_json(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        quote! {
+            #type_name::#constructor_name(raw_value).map_err(::core::convert::Into::into)
+        }
+    } else {
+        quote! {
+            Ok(#type_name::#constructor_name(raw_value))
+        }
+    };
+
+    quote! {
+        impl<DB: ::sqlx::Database> ::sqlx::Type<DB> for #type_name
+        where
+            ::sqlx::types::Json<#inner_type>: ::sqlx::Type<DB>,
+        {
+            fn type_info() -> <DB as ::sqlx::Database>::TypeInfo {
+                <::sqlx::types::Json<#inner_type> as ::sqlx::Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &<DB as ::sqlx::Database>::TypeInfo) -> bool {
+                <::sqlx::types::Json<#inner_type> as ::sqlx::Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'q, DB: ::sqlx::Database> ::sqlx::Encode<'q, DB> for #type_name
+        where
+            for<'a> ::sqlx::types::Json<&'a #inner_type>: ::sqlx::Encode<'q, DB>,
+        {
+            fn encode_by_ref(
+                &self,
+                buf: &mut <DB as ::sqlx::Database>::ArgumentBuffer<'q>,
+            ) -> ::core::result::Result<::sqlx::encode::IsNull, ::sqlx::error::BoxDynError> {
+                ::sqlx::Encode::<'q, DB>::encode_by_ref(&::sqlx::types::Json(&self.0), buf)
+            }
+        }
+
+        impl<'r, DB: ::sqlx::Database> ::sqlx::Decode<'r, DB> for #type_name
+        where
+            ::sqlx::types::Json<#inner_type>: ::sqlx::Decode<'r, DB>,
+        {
+            fn decode(
+                value: <DB as ::sqlx::Database>::ValueRef<'r>,
+            ) -> ::core::result::Result<Self, ::sqlx::error::BoxDynError> {
+                let ::sqlx::types::Json(raw_value) =
+                    <::sqlx::types::Json<#inner_type> as ::sqlx::Decode<'r, DB>>::decode(value)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+/// Generate the archived counterpart of a primitive numeric type in `rkyv`,
+/// e.g. `::rkyv::primitive::ArchivedU32` for `u32`.
+pub fn gen_rkyv_archived_primitive(inner_type: impl ToString) -> TokenStream {
+    let inner_type = inner_type.to_string();
+    match inner_type.as_str() {
+        // Single byte types are archived as they are
+        "u8" | "i8" => {
+            let ident = format_ident!("{inner_type}");
+            quote!(#ident)
+        }
+        _ => {
+            let ident = format_ident!("Archived{}", inner_type.to_uppercase());
+            quote!(::rkyv::primitive::#ident)
+        }
+    }
+}
+
+/// Name of the function that provides an example value for the schema derived with `JsonSchema`.
+pub const SCHEMA_EXAMPLE_FN_NAME: &str = "__nutype_schema_example";
+
+/// Generate a function that provides an example value for the schema derived with `JsonSchema`.
+/// The example is passed through the constructor, so it is sanitized and validated the same way
+/// as any other value. The schema describes the inner value, so the inner value is returned.
+pub fn gen_schema_example_fn(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    example: &syn::Expr,
+    has_validation: bool,
+) -> TokenStream {
+    let fn_name = format_ident!("{}", SCHEMA_EXAMPLE_FN_NAME);
+    let value = if has_validation {
+        let tp = type_name.to_string();
+        quote!(
+            #type_name::#constructor_name(#example)
+                .unwrap_or_else(|err| {
+                    let tp = #tp;
+                    panic!("\nSchema example for type `{tp}` is invalid.\nERROR: {err:?}\n");
+                })
+        )
+    } else {
+        quote!(#type_name::#constructor_name(#example))
+    };
+    quote!(
+        fn #fn_name() -> #inner_type {
+            #value.into_inner()
+        }
+    )
+}
+
 pub fn gen_impl_trait_default(
     type_name: &TypeName,
+    constructor_name: &ConstructorName,
     default_value: impl ToTokens,
     has_validation: bool,
 ) -> TokenStream {
@@ -304,7 +953,7 @@ pub fn gen_impl_trait_default(
         quote!(
             impl ::core::default::Default for #type_name {
                 fn default() -> Self {
-                    Self::new(#default_value)
+                    Self::#constructor_name(#default_value)
                         .unwrap_or_else(|err| {
                             let tp = #tp;
                             panic!("\nDefault value for type `{tp}` is invalid.\nERROR: {err:?}\n");
@@ -317,9 +966,323 @@ pub fn gen_impl_trait_default(
             impl ::core::default::Default for #type_name {
                 #[inline]
                 fn default() -> Self {
-                    Self::new(#default_value)
+                    Self::#constructor_name(#default_value)
+                }
+            }
+        )
+    }
+}
+
+/// Generate implementation of `num_traits::Zero`.
+/// `zero_value` must be verified to pass the validation, see `gen_const_assert_valid_value`.
+pub fn gen_impl_trait_num_traits_zero_from_value(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    zero_value: TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    let zero =
+        gen_new_from_verified_value(type_name, constructor_name, &zero_value, has_validation);
+    quote! {
+        impl ::num_traits::Zero for #type_name {
+            #[inline]
+            fn zero() -> Self {
+                #zero
+            }
+
+            #[inline]
+            fn is_zero(&self) -> bool {
+                ::num_traits::Zero::is_zero(&self.0)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `num_traits::One`.
+/// `one_value` must be verified to pass the validation, see `gen_const_assert_valid_value`.
+pub fn gen_impl_trait_num_traits_one_from_value(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    one_value: TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    let one = gen_new_from_verified_value(type_name, constructor_name, &one_value, has_validation);
+    quote! {
+        impl ::num_traits::One for #type_name {
+            #[inline]
+            fn one() -> Self {
+                #one
+            }
+        }
+    }
+}
+
+/// Generate implementation of `num_traits::Bounded`.
+/// `min_value` and `max_value` must be verified to pass the validation, see `gen_const_assert_valid_value`.
+pub fn gen_impl_trait_num_traits_bounded_from_values(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    min_value: TokenStream,
+    max_value: TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    let min = gen_new_from_verified_value(type_name, constructor_name, &min_value, has_validation);
+    let max = gen_new_from_verified_value(type_name, constructor_name, &max_value, has_validation);
+    quote! {
+        impl ::num_traits::Bounded for #type_name {
+            #[inline]
+            fn min_value() -> Self {
+                #min
+            }
+
+            #[inline]
+            fn max_value() -> Self {
+                #max
+            }
+        }
+    }
+}
+
+/// Construct a newtype from a value, which is verified at compile time to pass the validation.
+/// Sanitizers can still turn it into an invalid one, so it's not unwrapped silently.
+fn gen_new_from_verified_value(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    value: &TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    if has_validation {
+        // Braces are escaped, since the message is used as a format string.
+        let value_str = value.to_string().replace('{', "{{").replace('}', "}}");
+        let msg =
+            format!("\nValue `{value_str}` for type `{type_name}` is invalid.\nERROR: {{err:?}}\n");
+        quote!(
+            Self::#constructor_name(#value).unwrap_or_else(|err| panic!(#msg))
+        )
+    } else {
+        quote!(Self::#constructor_name(#value))
+    }
+}
+
+/// Generate a check that fails compilation if `value` does not satisfy all the `conditions`.
+/// A condition is a const expression that refers to the value as `val`.
+pub fn gen_const_assert_valid_value(
+    inner_type: impl ToTokens,
+    value: &TokenStream,
+    conditions: &[TokenStream],
+    msg: &str,
+) -> TokenStream {
+    if conditions.is_empty() {
+        return quote!();
+    }
+    let msg = msg.replace('{', "{{").replace('}', "}}");
+    quote! {
+        const _: () = {
+            let val: #inner_type = #value;
+            #(
+                assert!(#conditions, #msg);
+            )*
+        };
+    }
+}
+
+/// Generate implementation of `proptest::arbitrary::Arbitrary` trait.
+/// Values produced by `inner_strategy` go through `new()`, and if the type has validation,
+/// the values that fail it are filtered out.
+pub fn gen_impl_trait_proptest_arbitrary_from_strategy(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_strategy: TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    let construct_strategy = if has_validation {
+        let reason = format!("Generated value does not pass validation of {type_name}");
+        quote!(
+            (#inner_strategy).prop_filter_map(#reason, |inner_value| #type_name::#constructor_name(inner_value).ok())
+        )
+    } else {
+        quote!(
+            (#inner_strategy).prop_map(|inner_value| #type_name::#constructor_name(inner_value))
+        )
+    };
+
+    quote! {
+        impl ::proptest::arbitrary::Arbitrary for #type_name {
+            type Parameters = ();
+            type Strategy = ::proptest::strategy::BoxedStrategy<Self>;
+
+            fn arbitrary_with(_args: Self::Parameters) -> Self::Strategy {
+                use ::proptest::strategy::Strategy;
+                #construct_strategy.boxed()
+            }
+        }
+    }
+}
+
+/// Max number of attempts to generate a valid value in `Distribution::sample()`.
+const RAND_MAX_ATTEMPTS: usize = 1000;
+
+/// Generate implementation of `rand::distributions::Distribution<T>` for `Standard`.
+/// `sample_inner` is an expression that produces an inner value using `rng`. If the type has
+/// validation, the values that fail it are rejected and sampled again. The number of attempts is
+/// bounded, so a validation that is (almost) never satisfied causes a panic instead of an
+/// infinite loop.
+pub fn gen_impl_trait_rand_distribution_from_sampler(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    sample_inner: TokenStream,
+    has_validation: bool,
+) -> TokenStream {
+    let sample = if has_validation {
+        let msg = format!(
+            "Nutype failed to generate a valid value of {type_name} in {RAND_MAX_ATTEMPTS} attempts"
+        );
+        quote!(
+            for _ in 0..#RAND_MAX_ATTEMPTS {
+                let inner_value: #inner_type = #sample_inner;
+                if let ::core::result::Result::Ok(value) = #type_name::#constructor_name(inner_value) {
+                    return value;
                 }
             }
+            ::core::panic!(#msg)
+        )
+    } else {
+        quote!(
+            let inner_value: #inner_type = #sample_inner;
+            #type_name::#constructor_name(inner_value)
         )
+    };
+
+    quote! {
+        impl ::rand::distributions::Distribution<#type_name> for ::rand::distributions::Standard {
+            fn sample<R: ::rand::Rng + ?Sized>(&self, rng: &mut R) -> #type_name {
+                use ::rand::Rng;
+                #sample
+            }
+        }
+    }
+}
+
+/// Generate implementation of `redis` trait `ToRedisArgs`, which writes the inner value
+/// transparently.
+pub fn gen_impl_trait_redis_to_redis_args(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::redis::ToRedisArgs for #type_name {
+            fn write_redis_args<W>(&self, out: &mut W)
+            where
+                W: ?Sized + ::redis::RedisWrite,
+            {
+                ::redis::ToRedisArgs::write_redis_args(&self.0, out)
+            }
+
+            fn describe_numeric_behavior(&self) -> ::redis::NumericBehavior {
+                ::redis::ToRedisArgs::describe_numeric_behavior(&self.0)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `redis` trait `FromRedisValue`. The inner value is read first and
+/// then it goes through `new()`, so the sanitizers and validators are applied again.
+pub fn gen_impl_trait_redis_from_redis_value(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        let type_name_str = type_name.to_string();
+        quote! {
+            #type_name::#constructor_name(raw_value).map_err(|validation_error| {
+                // Add a hint about which type is causing the error,
+                let err_msg = format!("{validation_error} Expected valid {}", #type_name_str);
+                ::redis::RedisError::from((
+                    ::redis::ErrorKind::TypeError,
+                    "Response was of incompatible type",
+                    err_msg,
+                ))
+            })
+        }
+    } else {
+        quote! {
+            Ok(#type_name::#constructor_name(raw_value))
+        }
+    };
+
+    quote! {
+        impl ::redis::FromRedisValue for #type_name {
+            fn from_redis_value(value: &::redis::Value) -> ::redis::RedisResult<Self> {
+                let raw_value: #inner_type = ::redis::FromRedisValue::from_redis_value(value)?;
+                #raw_value_to_result
+            }
+        }
+    }
+}
+
+/// Generate implementation of `bincode` trait `Encode`, which encodes the inner value
+/// transparently.
+pub fn gen_impl_trait_bincode_encode(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::bincode::Encode for #type_name {
+            fn encode<__E: ::bincode::enc::Encoder>(
+                &self,
+                encoder: &mut __E,
+            ) -> ::core::result::Result<(), ::bincode::error::EncodeError> {
+                ::bincode::Encode::encode(&self.0, encoder)
+            }
+        }
+    }
+}
+
+/// Generate implementation of `bincode` traits `Decode` and `BorrowDecode`. The inner value is
+/// decoded first and then it goes through `new()`, so the sanitizers and validators are applied again.
+pub fn gen_impl_trait_bincode_decode(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl Into<InnerType>,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let inner_type: InnerType = inner_type.into();
+    let raw_value_to_result = if maybe_error_type_name.is_some() {
+        let type_name_str = type_name.to_string();
+        quote! {
+            #type_name::#constructor_name(raw_value).map_err(|validation_error| {
+                // Add a hint about which type is causing the error,
+                let err_msg = format!("{validation_error} Expected valid {}", #type_name_str);
+                ::bincode::error::DecodeError::OtherString(err_msg)
+            })
+        }
+    } else {
+        quote! {
+            Ok(#type_name::#constructor_name(raw_value))
+        }
+    };
+
+    quote! {
+        impl<__Context> ::bincode::Decode<__Context> for #type_name
+        where
+            #inner_type: ::bincode::Decode<__Context>,
+        {
+            fn decode<__D: ::bincode::de::Decoder<Context = __Context>>(
+                decoder: &mut __D,
+            ) -> ::core::result::Result<Self, ::bincode::error::DecodeError> {
+                let raw_value: #inner_type = ::bincode::Decode::decode(decoder)?;
+                #raw_value_to_result
+            }
+        }
+
+        impl<'__de, __Context> ::bincode::BorrowDecode<'__de, __Context> for #type_name
+        where
+            #inner_type: ::bincode::BorrowDecode<'__de, __Context>,
+        {
+            fn borrow_decode<__D: ::bincode::de::BorrowDecoder<'__de, Context = __Context>>(
+                decoder: &mut __D,
+            ) -> ::core::result::Result<Self, ::bincode::error::DecodeError> {
+                let raw_value: #inner_type = ::bincode::BorrowDecode::borrow_decode(decoder)?;
+                #raw_value_to_result
+            }
+        }
     }
 }
@@ -0,0 +1,123 @@
+use cfg_if::cfg_if;
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+
+use crate::common::models::{ConstructorName, ErrorTypeName, TryFromWideErrorTypeName, TypeName};
+
+/// The widest integer types, that usually come from JSON or databases.
+const WIDE_INTEGER_TYPES: [&str; 2] = ["i64", "u64"];
+
+/// Generate a name for the error which is used by `TryFrom<i64>` and `TryFrom<u64>`
+/// implementations derived with `TryFromWide`.
+pub fn gen_try_from_wide_error_name(type_name: &TypeName) -> TryFromWideErrorTypeName {
+    let ident = format_ident!("{type_name}TryFromWideError");
+    TryFromWideErrorTypeName::new(ident)
+}
+
+/// Generate `TryFrom<i64>` and `TryFrom<u64>` (except the one for the inner type itself, which is
+/// covered by `TryFrom`). A value is narrowed to the inner type first and then passed through
+/// the constructor, so the error tells whether the value is out of range or invalid.
+pub fn gen_impl_trait_try_from_wide(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let error_type_name = gen_try_from_wide_error_name(type_name);
+    let def_error = gen_def_try_from_wide_error(
+        type_name,
+        &inner_type,
+        maybe_error_type_name,
+        &error_type_name,
+    );
+    let construct = match maybe_error_type_name {
+        Some(_) => quote!(Self::#constructor_name(raw_value).map_err(#error_type_name::Validate)),
+        None => quote!(Ok(Self::#constructor_name(raw_value))),
+    };
+
+    let inner_type_str = inner_type.to_token_stream().to_string();
+    let impls = WIDE_INTEGER_TYPES
+        .iter()
+        .filter(|wide_type| **wide_type != inner_type_str)
+        .map(|wide_type| {
+            let wide_type = format_ident!("{wide_type}");
+            quote! {
+                impl ::core::convert::TryFrom<#wide_type> for #type_name {
+                    type Error = #error_type_name;
+
+                    #[inline]
+                    fn try_from(wide_value: #wide_type) -> ::core::result::Result<Self, Self::Error> {
+                        let raw_value = <#inner_type as ::core::convert::TryFrom<#wide_type>>::try_from(wide_value)
+                            .map_err(|_| #error_type_name::OutOfRange)?;
+                        #construct
+                    }
+                }
+            }
+        });
+
+    quote! {
+        #def_error
+        #(#impls)*
+    }
+}
+
+fn gen_def_try_from_wide_error(
+    type_name: &TypeName,
+    inner_type: impl ToTokens,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+    error_type_name: &TryFromWideErrorTypeName,
+) -> TokenStream {
+    let type_name_str = type_name.to_string();
+    let out_of_range_msg = format!(
+        "Failed to convert {type_name_str}: the value is out of range of `{}`",
+        inner_type.to_token_stream()
+    );
+
+    let (validate_variant, display_validate) = match maybe_error_type_name {
+        Some(validation_error_type_name) => (
+            quote!(Validate(#validation_error_type_name),),
+            quote! {
+                #error_type_name::Validate(err) => write!(f, "Failed to convert {}: {}", #type_name_str, err),
+            },
+        ),
+        None => (quote!(), quote!()),
+    };
+
+    let definition = quote! {
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        pub enum #error_type_name {
+            OutOfRange,
+            #validate_variant
+        }
+
+        impl ::core::fmt::Display for #error_type_name {
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                match self {
+                    #error_type_name::OutOfRange => write!(f, #out_of_range_msg),
+                    #display_validate
+                }
+            }
+        }
+    };
+
+    cfg_if! {
+        if #[cfg(feature = "std")] {
+            let impl_std_error = quote! {
+                impl ::std::error::Error for #error_type_name {
+                    fn source(&self) -> Option<&(dyn ::std::error::Error + 'static)> {
+                        None
+                    }
+                }
+            };
+        } else {
+            // NOTE: There is no `::core::error::Error` yet in stable Rust.
+            // So for `no_std` we just don't implement `Error` trait.
+            let impl_std_error = quote! {};
+        }
+    };
+
+    quote! {
+        #definition
+        #impl_std_error
+    }
+}
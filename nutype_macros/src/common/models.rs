@@ -1,7 +1,7 @@
 use kinded::Kinded;
 use std::{collections::HashSet, fmt::Debug};
 
-use proc_macro2::{Span, TokenStream};
+use proc_macro2::{Group, Ident, Punct, Spacing, Span, TokenStream, TokenTree};
 use quote::{quote, ToTokens};
 use syn::{
     parse::{Parse, ParseStream},
@@ -13,7 +13,15 @@ use crate::{
     string::models::StringInnerType,
 };
 
-use super::gen::type_custom_closure;
+use super::{
+    gen::type_custom_closure,
+    validate::{
+        validate_async_validators, validate_builder, validate_context, validate_diesel_new_type,
+        validate_display_format, validate_from_encoding, validate_from_env,
+        validate_sanitize_then_return_changed, validate_schema_example, validate_serde_lenient,
+        validate_serde_repr, validate_skip_new, validate_transparent_debug,
+    },
+};
 
 /// A spanned item. An item can be anything that cares a domain value.
 /// Keeping a span allows to throw good precise error messages at the validation stage.
@@ -43,6 +51,7 @@ impl<T: Kinded> Kinded for SpannedItem<T> {
 
 /// Represents the inner type of a newtype.
 #[derive(Debug, Clone, PartialEq, Eq)]
+#[allow(clippy::large_enum_variant)]
 pub enum InnerType {
     String(StringInnerType),
     Integer(IntegerInnerType),
@@ -148,13 +157,46 @@ define_ident_type!(ErrorTypeName);
 // For example, if `TypeName` is `Amount`, then this would be `AmountParseError`.
 define_ident_type!(ParseErrorTypeName);
 
+// A type that represents an error name which is returned by the generated `from_env()` function.
+// For example, if `TypeName` is `Port`, then this would be `PortFromEnvError`.
+define_ident_type!(FromEnvErrorTypeName);
+
+// A type that represents an error name which is returned by the generated `new_async()` function.
+// For example, if `TypeName` is `Username`, then this would be `UsernameAsyncError`.
+define_ident_type!(AsyncErrorTypeName);
+
+// A type that represents a builder name which is returned by the generated `builder()` function.
+// For example, if `TypeName` is `Location`, then this would be `LocationBuilder`.
+define_ident_type!(BuilderTypeName);
+
+// A type that represents an error name which is returned by `TryFrom<i64>` and `TryFrom<u64>`
+// derived with `TryFromWide`.
+// For example, if `TypeName` is `Port`, then this would be `PortTryFromWideError`.
+define_ident_type!(TryFromWideErrorTypeName);
+
 // Module name, where the type is placed.
 define_ident_type!(ModuleName);
 
+// Name of the generated constructor function.
+// It's `new` by default, but can be changed with `new_fn = `, e.g. `try_new`.
+define_ident_type!(ConstructorName);
+
+impl Default for ConstructorName {
+    fn default() -> Self {
+        Self::new(proc_macro2::Ident::new(
+            "new",
+            proc_macro2::Span::call_site(),
+        ))
+    }
+}
+
 #[derive(Debug)]
 pub struct Meta {
     pub type_name: TypeName,
+    pub generics: syn::Generics,
     pub inner_type: InnerType,
+    /// The type of the field as it's written, which may differ from the one set with `inner = `.
+    pub declared_inner_type: syn::Type,
     pub vis: syn::Visibility,
     pub doc_attrs: Vec<Attribute>,
 }
@@ -164,12 +206,15 @@ impl Meta {
         let Self {
             doc_attrs,
             type_name,
+            generics,
             inner_type,
+            declared_inner_type: _,
             vis,
         } = self;
         let typed_meta = TypedMeta {
             doc_attrs,
             type_name,
+            generics,
             attrs,
             vis,
         };
@@ -183,6 +228,9 @@ impl Meta {
 pub struct TypedMeta {
     pub type_name: TypeName,
 
+    /// Generic parameters of the newtype (at most one lifetime, e.g. `<'a>`)
+    pub generics: syn::Generics,
+
     /// Attributes given to #[nutype] macro
     pub attrs: TokenStream,
 
@@ -199,9 +247,21 @@ pub enum Guard<Sanitizer, Validator> {
     WithValidation {
         sanitizers: Vec<Sanitizer>,
         validators: Vec<Validator>,
+        /// Validators that run against the raw value before sanitization, set with `raw(...)`.
+        raw_validators: Vec<Validator>,
     },
 }
 
+/// Sanitizers that may fail (e.g. `try_with`) make the constructor fallible, even if the type
+/// has no validators.
+pub trait FallibleSanitizer {
+    fn is_fallible(&self) -> bool;
+}
+
+pub fn has_fallible_sanitizers<S: FallibleSanitizer>(sanitizers: &[S]) -> bool {
+    sanitizers.iter().any(FallibleSanitizer::is_fallible)
+}
+
 /// Parsed attributes (`sanitize`, `validate`, `new_unchecked`).
 #[derive(Debug)]
 pub struct Attributes<G, DT> {
@@ -214,12 +274,155 @@ pub struct Attributes<G, DT> {
     /// Value for Default trait. Provide with `default = `
     pub default: Option<syn::Expr>,
 
+    /// Mark the generated error enum with `#[non_exhaustive]`. Provide with `non_exhaustive_error`
+    pub non_exhaustive_error: bool,
+
+    /// Report all the violated validators at once instead of the first one. Provide with `collect_errors`
+    pub collect_errors: bool,
+
+    /// Do not mark the newtype and its constructor with `#[must_use]`. Provide with `allow_unused`
+    pub allow_unused: bool,
+
+    /// Mark the newtype with `#[repr(transparent)]`. Provide with `repr_transparent`
+    pub repr_transparent: bool,
+
+    /// Generate `all()` that iterates over all the valid values. Provide with `enumerate`
+    pub enumerate: bool,
+
+    /// Format string for Display trait. Provide with `display = `
+    pub display: Option<syn::LitStr>,
+
+    /// Name of the constructor function. Provide with `new_fn = `
+    pub new_fn: ConstructorName,
+
+    /// Visibility of the inner field, private by default. Provide with `inner_vis = `
+    pub inner_vis: syn::Visibility,
+
+    /// Semantics of the derived `PartialEq`, `Eq` and `Hash`. Provide with `eq = `
+    pub eq: EqMode,
+
+    /// Semantics of the derived `PartialOrd` and `Ord`. Provide with `ord = `
+    pub ord: OrdMode,
+
+    /// Representation used by the derived `Serialize` and `Deserialize`. Provide with `serde_repr = `
+    pub serde_repr: Option<SpannedSerdeRepr>,
+
+    /// Example value for the schema derived with `JsonSchema`. Provide with `schema_example = `
+    pub schema_example: Option<syn::Expr>,
+
+    /// Generate `from_env()` function. Provide with `from_env`
+    pub from_env: Option<proc_macro2::Span>,
+
+    /// Generate `FromStr` that decodes bytes from a string. Provide with `from_hex` or `from_base64`
+    pub from_encoding: Option<SpannedByteEncoding>,
+
+    /// Generate `new_reporting()` function, that reports whether the sanitizers changed the value.
+    /// Provide with `sanitize_then_return_changed`
+    pub sanitize_then_return_changed: Option<proc_macro2::Span>,
+
+    /// Implement `Debug` by delegating to the inner value, without the newtype name.
+    /// Provide with `transparent_debug`
+    pub transparent_debug: Option<proc_macro2::Span>,
+
+    /// Keep the constructor private, so the value is constructed only through the derived traits.
+    /// Provide with `skip_new`
+    pub skip_new: Option<proc_macro2::Span>,
+
+    /// Let the derived `Deserialize` accept numeric strings as well as numbers.
+    /// Provide with `serde_lenient`
+    pub serde_lenient: Option<proc_macro2::Span>,
+
+    /// Generate a builder that collects the inner value before it's passed to the constructor.
+    /// Provide with `builder`
+    pub builder: Option<proc_macro2::Span>,
+
+    /// Path through which the generated code refers to `nutype` and the other crates it uses.
+    /// Provide with `crate = `
+    pub crate_path: Option<syn::Path>,
+
+    /// Type of the context passed to the custom validators. Provide with `context = `
+    pub context: Option<syn::Type>,
+
+    /// Validators awaited by the generated `new_async()` function.
+    /// Provide with `async_with = ` within `validate(...)`
+    pub async_validators: Vec<SpannedItem<CustomFunction>>,
+
     pub derive_traits: Vec<DT>,
 }
 
+/// Defines how the derived `PartialEq`, `Eq` and `Hash` treat the inner value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum EqMode {
+    /// Compare the inner values as they are.
+    #[default]
+    Exact,
+
+    /// Compare lowercased inner values, e.g. `"Foo" == "fOO"`.
+    /// Set with `eq = case_insensitive`. Supported by string based types only.
+    CaseInsensitive,
+}
+
+pub type SpannedEqMode = SpannedItem<EqMode>;
+
+/// Defines how the derived `PartialOrd` and `Ord` treat the inner value.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum OrdMode {
+    /// Compare the inner values as they are.
+    #[default]
+    Exact,
+
+    /// Compare lowercased inner values, e.g. `"apple" < "Banana"`.
+    /// Set with `ord = case_insensitive`. Supported by string based types only.
+    CaseInsensitive,
+}
+
+pub type SpannedOrdMode = SpannedItem<OrdMode>;
+
+/// Defines how the derived `Serialize` and `Deserialize` represent a newtype.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum SerdeRepr {
+    /// Serialize as the inner value, e.g. `"Anna"`.
+    #[default]
+    Transparent,
+
+    /// Serialize as an object with a single field, e.g. `{ "value": "Anna" }`.
+    /// Set with `serde_repr = object` or `serde_repr = object(field = "name")`.
+    Object { field: String },
+}
+
+impl SerdeRepr {
+    pub const DEFAULT_OBJECT_FIELD: &'static str = "value";
+}
+
+pub type SpannedSerdeRepr = SpannedItem<SerdeRepr>;
+
+/// Defines the encoding of the string that `FromStr` decodes into bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ByteEncoding {
+    /// Set with `from_hex`.
+    #[cfg_attr(not(feature = "hex"), allow(dead_code))]
+    Hex,
+
+    /// Set with `from_base64`.
+    #[cfg_attr(not(feature = "base64"), allow(dead_code))]
+    Base64,
+}
+
+impl ByteEncoding {
+    /// Name of the attribute that sets the encoding.
+    pub fn attr_name(&self) -> &'static str {
+        match self {
+            Self::Hex => "from_hex",
+            Self::Base64 => "from_base64",
+        }
+    }
+}
+
+pub type SpannedByteEncoding = SpannedItem<ByteEncoding>;
+
 /// Represents a value known at compile time or an expression.
 /// Knowing value at compile time allows to run some extra validations to prevent potential errors.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub enum ValueOrExpr<T> {
     Value(T),
     Expr(syn::Expr),
@@ -246,6 +449,14 @@ impl<Sanitizer, Validator> Guard<Sanitizer, Validator> {
         }
     }
 
+    pub fn sanitizers(&self) -> &[Sanitizer] {
+        match self {
+            Self::WithValidation { sanitizers, .. } | Self::WithoutValidation { sanitizers } => {
+                sanitizers
+            }
+        }
+    }
+
     pub fn validators(&self) -> Option<&Vec<Validator>> {
         match self {
             Self::WithValidation { validators, .. } => Some(validators),
@@ -274,6 +485,7 @@ pub enum DeriveTrait {
     FromStr,
     AsRef,
     From,
+    FromIterator,
     TryFrom,
     Into,
     Hash,
@@ -281,6 +493,14 @@ pub enum DeriveTrait {
     Display,
     Default,
     Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
+    Sum,
+    Product,
+    CheckedArith,
+    Neg,
+    TryFromWide,
 
     // External crates
     //
@@ -295,6 +515,39 @@ pub enum DeriveTrait {
     #[cfg_attr(not(feature = "arbitrary"), allow(dead_code))]
     ArbitraryArbitrary,
 
+    #[cfg_attr(not(feature = "proptest"), allow(dead_code))]
+    ProptestArbitrary,
+
+    #[cfg_attr(not(feature = "rand"), allow(dead_code))]
+    RandDistribution,
+
+    #[cfg_attr(not(feature = "rkyv"), allow(dead_code))]
+    RkyvArchive,
+
+    #[cfg_attr(not(all(feature = "sqlx", feature = "serde")), allow(dead_code))]
+    SqlxJson,
+
+    #[cfg_attr(not(feature = "redis"), allow(dead_code))]
+    RedisToRedisArgs,
+
+    #[cfg_attr(not(feature = "redis"), allow(dead_code))]
+    RedisFromRedisValue,
+
+    #[cfg_attr(not(feature = "bincode"), allow(dead_code))]
+    BincodeEncode,
+
+    #[cfg_attr(not(feature = "bincode"), allow(dead_code))]
+    BincodeDecode,
+
+    #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+    NumTraitsZero,
+
+    #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+    NumTraitsOne,
+
+    #[cfg_attr(not(feature = "num-traits"), allow(dead_code))]
+    NumTraitsBounded,
+
     #[cfg_attr(not(feature = "diesel-derive-newtype"), allow(dead_code))]
     DieselNewType,
 }
@@ -304,6 +557,17 @@ pub type SpannedDeriveTrait = SpannedItem<DeriveTrait>;
 pub trait TypeTrait {
     // If this is FromStr variant?
     fn is_from_str(&self) -> bool;
+
+    // If this is DieselNewType variant?
+    fn is_diesel_new_type(&self) -> bool;
+
+    // If this is Debug variant?
+    fn is_debug(&self) -> bool;
+
+    // If this is TryFromWide variant? It's supported only by integer based types.
+    fn is_try_from_wide(&self) -> bool {
+        false
+    }
 }
 
 /// The flag the indicates that a newtype will be generated with extra constructor,
@@ -325,9 +589,32 @@ pub struct GenerateParams<IT, Trait, Guard> {
     pub traits: HashSet<Trait>,
     pub vis: syn::Visibility,
     pub type_name: TypeName,
+    pub generics: syn::Generics,
     pub guard: Guard,
     pub new_unchecked: NewUnchecked,
     pub maybe_default_value: Option<syn::Expr>,
+    pub maybe_display_format: Option<syn::LitStr>,
+    pub maybe_schema_example: Option<syn::Expr>,
+    pub from_env: bool,
+    pub maybe_byte_encoding: Option<ByteEncoding>,
+    pub sanitize_then_return_changed: bool,
+    pub transparent_debug: bool,
+    pub skip_new: bool,
+    pub serde_lenient: bool,
+    pub builder: bool,
+    pub crate_path: Option<syn::Path>,
+    pub maybe_context: Option<syn::Type>,
+    pub async_validators: Vec<CustomFunction>,
+    pub non_exhaustive_error: bool,
+    pub collect_errors: bool,
+    pub allow_unused: bool,
+    pub repr_transparent: bool,
+    pub enumerate: bool,
+    pub constructor_name: ConstructorName,
+    pub inner_vis: syn::Visibility,
+    pub eq: EqMode,
+    pub ord: OrdMode,
+    pub serde_repr: SerdeRepr,
 }
 
 pub trait Newtype {
@@ -362,6 +649,7 @@ pub trait Newtype {
         let TypedMeta {
             doc_attrs,
             type_name,
+            generics,
             attrs,
             vis,
         } = typed_meta;
@@ -369,17 +657,105 @@ pub trait Newtype {
             guard,
             new_unchecked,
             default: maybe_default_value,
+            non_exhaustive_error,
+            collect_errors,
+            allow_unused,
+            repr_transparent,
+            enumerate,
+            display: maybe_display_format,
+            new_fn: constructor_name,
+            inner_vis,
+            eq,
+            ord,
+            serde_repr: maybe_serde_repr,
+            schema_example: maybe_schema_example,
+            from_env: maybe_from_env,
+            from_encoding: maybe_from_encoding,
+            sanitize_then_return_changed: maybe_sanitize_then_return_changed,
+            transparent_debug: maybe_transparent_debug,
+            skip_new: maybe_skip_new,
+            serde_lenient: maybe_serde_lenient,
+            builder: maybe_builder,
+            crate_path,
+            context: maybe_context,
+            async_validators,
             derive_traits,
         } = Self::parse_attributes(attrs)?;
+        validate_display_format(maybe_display_format.as_ref(), &derive_traits)?;
+        validate_schema_example(maybe_schema_example.as_ref(), &derive_traits)?;
+        let serde_repr = validate_serde_repr(maybe_serde_repr, &derive_traits)?;
+        let serde_lenient =
+            validate_serde_lenient(maybe_serde_lenient, &serde_repr, &derive_traits)?;
+        let from_env = validate_from_env(maybe_from_env, &derive_traits)?;
+        let maybe_byte_encoding =
+            validate_from_encoding(maybe_from_encoding, maybe_context.as_ref(), &derive_traits)?;
+        validate_diesel_new_type(guard.has_validation(), &derive_traits)?;
+        let transparent_debug =
+            validate_transparent_debug(maybe_transparent_debug, &derive_traits)?;
+        let sanitize_then_return_changed = validate_sanitize_then_return_changed(
+            maybe_sanitize_then_return_changed,
+            !guard.sanitizers().is_empty(),
+        )?;
+        validate_context(
+            maybe_context.as_ref(),
+            guard.has_validation(),
+            maybe_schema_example.as_ref(),
+            enumerate,
+            sanitize_then_return_changed,
+            &derive_traits,
+        )?;
+        let async_validators = validate_async_validators(
+            async_validators,
+            maybe_context.as_ref(),
+            enumerate,
+            sanitize_then_return_changed,
+            maybe_byte_encoding,
+            &derive_traits,
+        )?;
+        let skip_new = validate_skip_new(
+            maybe_skip_new,
+            sanitize_then_return_changed,
+            from_env || maybe_byte_encoding.is_some() || !async_validators.is_empty(),
+            &derive_traits,
+        )?;
+        let builder = validate_builder(
+            maybe_builder,
+            maybe_context.as_ref(),
+            !async_validators.is_empty(),
+            skip_new,
+        )?;
         let traits = Self::validate(&guard, derive_traits)?;
         let generated_output = Self::generate(GenerateParams {
             doc_attrs,
             traits,
             vis,
             type_name,
+            generics,
             guard,
             new_unchecked,
             maybe_default_value,
+            maybe_display_format,
+            maybe_schema_example,
+            from_env,
+            maybe_byte_encoding,
+            sanitize_then_return_changed,
+            transparent_debug,
+            skip_new,
+            serde_lenient,
+            builder,
+            crate_path,
+            maybe_context,
+            async_validators,
+            non_exhaustive_error,
+            collect_errors,
+            allow_unused,
+            repr_transparent,
+            enumerate,
+            constructor_name,
+            inner_vis,
+            eq,
+            ord,
+            serde_repr,
             inner_type,
         })?;
         Ok(generated_output)
@@ -411,16 +787,80 @@ impl Parse for CustomFunction {
 impl CustomFunction {
     pub fn try_into_typed(self, inner_type: &syn::Type) -> syn::Result<TypedCustomFunction> {
         match self {
-            CustomFunction::Path(path) => Ok(TypedCustomFunction::Path(path)),
+            CustomFunction::Path(path) => Ok(TypedCustomFunction::Path(
+                resolve_path_from_nutype_module(path),
+            )),
             CustomFunction::Closure(closure) => {
                 // NOTE: this is a bit hacky, we're converting things to TokenStream and back.
-                let input_token_stream = quote!(#closure);
+                let input_token_stream = resolve_tokens_from_nutype_module(quote!(#closure));
                 let output_token_stream = type_custom_closure(&input_token_stream, inner_type);
                 let typed_closure: ExprClosure = syn::parse2(output_token_stream)?;
                 Ok(TypedCustomFunction::Closure(typed_closure))
             }
         }
     }
+
+    /// Unlike `try_into_typed()` the arguments of the closure are kept as they are, so their types
+    /// are inferred from the call site.
+    pub fn resolve_path(self) -> syn::Result<Self> {
+        match self {
+            CustomFunction::Path(path) => {
+                Ok(CustomFunction::Path(resolve_path_from_nutype_module(path)))
+            }
+            CustomFunction::Closure(closure) => {
+                let closure_token_stream = resolve_tokens_from_nutype_module(quote!(#closure));
+                Ok(CustomFunction::Closure(syn::parse2(closure_token_stream)?))
+            }
+        }
+    }
+}
+
+/// The generated code lives in a nested module (see `gen_module_name_for_type()`), so a path
+/// starting with `super::` needs one more `super::` to point to the same item.
+/// Paths starting with `crate::` or a name imported by `use super::*` are resolved as they are.
+fn resolve_path_from_nutype_module(mut path: Path) -> Path {
+    let starts_with_super = path.leading_colon.is_none()
+        && path
+            .segments
+            .first()
+            .is_some_and(|segment| segment.ident == "super");
+    if starts_with_super {
+        let span = path.segments[0].ident.span();
+        path.segments
+            .insert(0, syn::PathSegment::from(syn::Ident::new("super", span)));
+    }
+    path
+}
+
+/// Resolve the paths within a closure the same way as `resolve_path_from_nutype_module()` does,
+/// e.g. `|s| super::RESERVED.contains(s)` becomes `|s| super::super::RESERVED.contains(s)`.
+/// Other paths (`crate::`, `self::` or a plain name) are resolved through `use super::*` as they are,
+/// so a closure can refer to the statics and functions of the module, where the newtype is defined.
+fn resolve_tokens_from_nutype_module(tokens: TokenStream) -> TokenStream {
+    let mut output: Vec<TokenTree> = Vec::new();
+    for token in tokens {
+        match token {
+            TokenTree::Group(group) => {
+                let stream = resolve_tokens_from_nutype_module(group.stream());
+                let mut resolved_group = Group::new(group.delimiter(), stream);
+                resolved_group.set_span(group.span());
+                output.push(TokenTree::Group(resolved_group));
+            }
+            TokenTree::Ident(ref ident) if ident == "super" && !continues_path(&output) => {
+                output.push(TokenTree::Ident(Ident::new("super", ident.span())));
+                output.push(TokenTree::Punct(Punct::new(':', Spacing::Joint)));
+                output.push(TokenTree::Punct(Punct::new(':', Spacing::Alone)));
+                output.push(token);
+            }
+            _ => output.push(token),
+        }
+    }
+    output.into_iter().collect()
+}
+
+/// Check whether the next token is preceded by `::`, e.g. the second `super` in `super::super`.
+fn continues_path(preceding: &[TokenTree]) -> bool {
+    matches!(preceding.last(), Some(TokenTree::Punct(punct)) if punct.as_char() == ':')
 }
 
 impl ToTokens for CustomFunction {
@@ -20,12 +20,22 @@ impl Parse for SpannedDeriveTrait {
             "FromStr" => DeriveTrait::FromStr,
             "AsRef" => DeriveTrait::AsRef,
             "Deref" => DeriveTrait::Deref,
+            "EqInner" => DeriveTrait::EqInner,
+            "OrdInner" => DeriveTrait::OrdInner,
+            "Nutype" => DeriveTrait::Nutype,
             "TryFrom" => DeriveTrait::TryFrom,
             "From" => DeriveTrait::From,
-            "Into" => DeriveTrait::Into,
+            "FromIterator" => DeriveTrait::FromIterator,
+            // `IntoInner` is an explicit alias of `Into`: both implement `From<Newtype> for Inner`.
+            "Into" | "IntoInner" => DeriveTrait::Into,
             "Hash" => DeriveTrait::Hash,
             "Borrow" => DeriveTrait::Borrow,
             "Default" => DeriveTrait::Default,
+            "Sum" => DeriveTrait::Sum,
+            "Product" => DeriveTrait::Product,
+            "CheckedArith" => DeriveTrait::CheckedArith,
+            "Neg" => DeriveTrait::Neg,
+            "TryFromWide" => DeriveTrait::TryFromWide,
             "Serialize" => {
                 cfg_if! {
                     if #[cfg(feature = "serde")] {
@@ -71,6 +81,105 @@ impl Parse for SpannedDeriveTrait {
                     }
                 }
             }
+            "Archive" => {
+                cfg_if! {
+                    if #[cfg(feature = "rkyv")] {
+                        DeriveTrait::RkyvArchive
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Archive, the feature `rkyv` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "SqlxJson" => {
+                cfg_if! {
+                    if #[cfg(all(feature = "sqlx", feature = "serde"))] {
+                        DeriveTrait::SqlxJson
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive SqlxJson, the features `sqlx` and `serde` of the crate `nutype` need to be enabled."));
+                    }
+                }
+            }
+            "ProptestArbitrary" => {
+                cfg_if! {
+                    if #[cfg(feature = "proptest")] {
+                        DeriveTrait::ProptestArbitrary
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive ProptestArbitrary, the feature `proptest` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "RandDistribution" => {
+                cfg_if! {
+                    if #[cfg(feature = "rand")] {
+                        DeriveTrait::RandDistribution
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive RandDistribution, the feature `rand` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "ToRedisArgs" => {
+                cfg_if! {
+                    if #[cfg(feature = "redis")] {
+                        DeriveTrait::RedisToRedisArgs
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive ToRedisArgs, the feature `redis` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "FromRedisValue" => {
+                cfg_if! {
+                    if #[cfg(feature = "redis")] {
+                        DeriveTrait::RedisFromRedisValue
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive FromRedisValue, the feature `redis` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Encode" => {
+                cfg_if! {
+                    if #[cfg(feature = "bincode")] {
+                        DeriveTrait::BincodeEncode
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Encode, the feature `bincode` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Decode" => {
+                cfg_if! {
+                    if #[cfg(feature = "bincode")] {
+                        DeriveTrait::BincodeDecode
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Decode, the feature `bincode` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Zero" => {
+                cfg_if! {
+                    if #[cfg(feature = "num-traits")] {
+                        DeriveTrait::NumTraitsZero
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Zero, the feature `num-traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "One" => {
+                cfg_if! {
+                    if #[cfg(feature = "num-traits")] {
+                        DeriveTrait::NumTraitsOne
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive One, the feature `num-traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
+            "Bounded" => {
+                cfg_if! {
+                    if #[cfg(feature = "num-traits")] {
+                        DeriveTrait::NumTraitsBounded
+                    } else {
+                        return Err(syn::Error::new(ident.span(), "To derive Bounded, the feature `num-traits` of the crate `nutype` needs to be enabled."));
+                    }
+                }
+            }
             _ => {
                 return Err(syn::Error::new(
                     ident.span(),
@@ -1,6 +1,10 @@
-use proc_macro2::TokenStream;
-use quote::ToTokens;
-use syn::{spanned::Spanned, Attribute, DeriveInput, Visibility};
+use proc_macro2::{Spacing, TokenStream, TokenTree};
+use quote::{quote, ToTokens};
+use syn::{
+    parse::{ParseStream, Parser},
+    spanned::Spanned,
+    Attribute, DeriveInput, GenericParam, Generics, ItemType, Token, Visibility,
+};
 
 use crate::{
     any::models::AnyInnerType,
@@ -13,8 +17,14 @@ use crate::{
     string::models::StringInnerType,
 };
 
-pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
-    let input: DeriveInput = syn::parse(token_stream.into())?;
+/// The inner type set explicitly with `inner = ` decides which kind of newtype is generated,
+/// so it's used instead of the declared type of the field, that the macro may not recognize
+/// (e.g. an alias or a re-exported type).
+pub fn parse_meta(
+    token_stream: TokenStream,
+    maybe_explicit_inner_type: Option<syn::Type>,
+) -> Result<Meta, syn::Error> {
+    let input = parse_type_definition(token_stream)?;
 
     let input_span = input.span();
     let DeriveInput {
@@ -22,10 +32,11 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
         data,
         vis,
         ident: type_name,
-        generics: _,
+        generics,
     } = input;
 
     let type_name = TypeName::new(type_name);
+    validate_generics(&generics)?;
 
     validate_supported_attrs(&attrs)?;
 
@@ -65,46 +76,174 @@ pub fn parse_meta(token_stream: TokenStream) -> Result<Meta, syn::Error> {
     })?;
     validate_inner_field_visibility(&seg.vis)?;
 
-    let type_path = match seg.ty.clone() {
-        syn::Type::Path(tp) => tp,
+    let declared_inner_type = seg.ty.clone();
+    let seg = match maybe_explicit_inner_type {
+        Some(explicit_inner_type) => {
+            if !generics.params.is_empty() {
+                let msg = "`inner = ` cannot be used with generic newtypes.";
+                return Err(syn::Error::new(explicit_inner_type.span(), msg));
+            }
+            syn::Field {
+                ty: explicit_inner_type,
+                ..seg.clone()
+            }
+        }
+        None => seg.clone(),
+    };
+
+    let inner_type = match &seg.ty {
+        syn::Type::Path(type_path) => {
+            let type_path_str = type_path.into_token_stream().to_string();
+            match type_path_str.as_ref() {
+                "String" => InnerType::String(StringInnerType),
+                "u8" => InnerType::Integer(IntegerInnerType::U8),
+                "u16" => InnerType::Integer(IntegerInnerType::U16),
+                "u32" => InnerType::Integer(IntegerInnerType::U32),
+                "u64" => InnerType::Integer(IntegerInnerType::U64),
+                "u128" => InnerType::Integer(IntegerInnerType::U128),
+                "usize" => InnerType::Integer(IntegerInnerType::Usize),
+                "i8" => InnerType::Integer(IntegerInnerType::I8),
+                "i16" => InnerType::Integer(IntegerInnerType::I16),
+                "i32" => InnerType::Integer(IntegerInnerType::I32),
+                "i64" => InnerType::Integer(IntegerInnerType::I64),
+                "i128" => InnerType::Integer(IntegerInnerType::I128),
+                "isize" => InnerType::Integer(IntegerInnerType::Isize),
+                "f32" => InnerType::Float(FloatInnerType::F32),
+                "f64" => InnerType::Float(FloatInnerType::F64),
+                _ => InnerType::Any(AnyInnerType::new(seg.clone(), &generics)),
+            }
+        }
+        // Tuples (e.g. `(f64, f64)`) are handled as any other arbitrary type.
+        syn::Type::Tuple(type_tuple) if !type_tuple.elems.is_empty() => {
+            InnerType::Any(AnyInnerType::new(seg.clone(), &generics))
+        }
+        // References (e.g. `&'a str`) are handled as any other arbitrary type too.
+        syn::Type::Reference(_) => InnerType::Any(AnyInnerType::new(seg.clone(), &generics)),
         _ => {
             let error = syn::Error::new(
                 seg.span(),
-                "#[nutype] requires a simple inner type (e.g. String, i32, etc.)",
+                "#[nutype] requires a simple inner type (e.g. String, i32, (f64, f64), etc.)",
             );
             return Err(error);
         }
     };
 
-    let type_path_str = type_path.into_token_stream().to_string();
-
-    let inner_type = match type_path_str.as_ref() {
-        "String" => InnerType::String(StringInnerType),
-        "u8" => InnerType::Integer(IntegerInnerType::U8),
-        "u16" => InnerType::Integer(IntegerInnerType::U16),
-        "u32" => InnerType::Integer(IntegerInnerType::U32),
-        "u64" => InnerType::Integer(IntegerInnerType::U64),
-        "u128" => InnerType::Integer(IntegerInnerType::U128),
-        "usize" => InnerType::Integer(IntegerInnerType::Usize),
-        "i8" => InnerType::Integer(IntegerInnerType::I8),
-        "i16" => InnerType::Integer(IntegerInnerType::I16),
-        "i32" => InnerType::Integer(IntegerInnerType::I32),
-        "i64" => InnerType::Integer(IntegerInnerType::I64),
-        "i128" => InnerType::Integer(IntegerInnerType::I128),
-        "isize" => InnerType::Integer(IntegerInnerType::Isize),
-        "f32" => InnerType::Float(FloatInnerType::F32),
-        "f64" => InnerType::Float(FloatInnerType::F64),
-        _ => InnerType::Any(AnyInnerType::new(seg.clone())),
-    };
+    if !generics.params.is_empty() && !matches!(inner_type, InnerType::Any(_)) {
+        let msg = if generics.lifetimes().next().is_some() {
+            "A lifetime parameter can be used only with an inner type that borrows (e.g. `&'a str`)."
+        } else {
+            "A type parameter can be used only with an inner type that refers to it (e.g. `struct Meters<T: Float>(T)`)."
+        };
+        return Err(syn::Error::new(generics.span(), msg));
+    }
 
     Ok(Meta {
         doc_attrs,
         type_name,
+        generics,
         inner_type,
+        declared_inner_type,
         vis,
     })
 }
 
+/// Take `inner = Type` out of the attributes given to #[nutype], since it's needed before
+/// the attributes are parsed by the particular kind of newtype.
+pub fn extract_inner_type(
+    attrs: TokenStream,
+) -> Result<(TokenStream, Option<syn::Type>), syn::Error> {
+    let tokens: Vec<TokenTree> = attrs.into_iter().collect();
+    let Some(index) = find_inner_type_attr(&tokens) else {
+        return Ok((tokens.into_iter().collect(), None));
+    };
+
+    let parse_inner_type = |input: ParseStream| {
+        let inner_type: syn::Type = input.parse()?;
+        if !input.is_empty() {
+            let _comma: Token![,] = input.parse()?;
+        }
+        let rest: TokenStream = input.parse()?;
+        Ok((inner_type, rest))
+    };
+    let (inner_type, rest) =
+        parse_inner_type.parse2(tokens[index + 2..].iter().cloned().collect())?;
+
+    let rest: Vec<TokenTree> = rest.into_iter().collect();
+    if let Some(duplicate_index) = find_inner_type_attr(&rest) {
+        let msg = "Duplicated `inner`.\nA newtype can wrap only one type.";
+        return Err(syn::Error::new(rest[duplicate_index].span(), msg));
+    }
+
+    let mut prefix = tokens[..index].to_vec();
+    if rest.is_empty() {
+        // Drop the comma that separated `inner = Type` from the preceding attribute.
+        prefix.pop();
+    }
+    let attrs = prefix.into_iter().chain(rest).collect();
+    Ok((attrs, Some(inner_type)))
+}
+
+/// Find `inner =` at the top level of the attributes, e.g. not within `validate(...)`.
+fn find_inner_type_attr(tokens: &[TokenTree]) -> Option<usize> {
+    (0..tokens.len().saturating_sub(1)).find(|&index| {
+        let is_item_start = index == 0
+            || matches!(&tokens[index - 1], TokenTree::Punct(punct) if punct.as_char() == ',');
+        let is_inner = matches!(&tokens[index], TokenTree::Ident(ident) if ident == "inner");
+        let is_eq = matches!(
+            &tokens[index + 1],
+            TokenTree::Punct(punct) if punct.as_char() == '=' && punct.spacing() == Spacing::Alone
+        );
+        is_item_start && is_inner && is_eq
+    })
+}
+
+/// Besides a tuple struct, #[nutype] can be applied to a type alias (e.g. `type UserId = u64;`).
+/// The alias is replaced by a tuple struct with the same name, visibility, attributes and generics
+/// that wraps the aliased type (e.g. `struct UserId(u64);`).
+fn parse_type_definition(token_stream: TokenStream) -> Result<DeriveInput, syn::Error> {
+    match syn::parse2::<ItemType>(token_stream.clone()) {
+        Ok(item_type) => {
+            let ItemType {
+                attrs,
+                vis,
+                ident,
+                generics,
+                ty,
+                ..
+            } = item_type;
+            let where_clause = &generics.where_clause;
+            syn::parse2(quote!(
+                #(#attrs)*
+                #vis struct #ident #generics (#ty) #where_clause;
+            ))
+        }
+        Err(_) => syn::parse2(token_stream),
+    }
+}
+
+/// Supported are a single lifetime parameter (e.g. `struct Name<'a>(&'a str)`)
+/// and type parameters with inline bounds (e.g. `struct Meters<T: Float>(T)`).
+fn validate_generics(generics: &Generics) -> Result<(), syn::Error> {
+    if let Some(param) = generics
+        .params
+        .iter()
+        .find(|param| matches!(param, GenericParam::Const(_)))
+    {
+        let msg = "#[nutype] does not support const generic parameters.";
+        return Err(syn::Error::new(param.span(), msg));
+    }
+    if generics.lifetimes().count() > 1 {
+        let msg =
+            "#[nutype] supports at most one lifetime parameter (e.g. `struct Name<'a>(&'a str)`).";
+        return Err(syn::Error::new(generics.span(), msg));
+    }
+    if let Some(where_clause) = &generics.where_clause {
+        let msg = "#[nutype] does not support `where` clauses.";
+        return Err(syn::Error::new(where_clause.span(), msg));
+    }
+    Ok(())
+}
+
 fn validate_supported_attrs(attrs: &[syn::Attribute]) -> Result<(), syn::Error> {
     fn is_supported_attr(attr: &syn::Attribute) -> bool {
         is_doc_attribute(attr) || is_derive_attribute(attr)
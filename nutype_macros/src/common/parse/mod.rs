@@ -5,17 +5,23 @@ use std::{any::type_name, fmt::Debug, str::FromStr};
 
 use cfg_if::cfg_if;
 use proc_macro2::{Ident, Span};
+use quote::ToTokens;
 use syn::{
+    ext::IdentExt,
     parenthesized,
-    parse::{Parse, ParseStream},
+    parse::{Parse, ParseStream, Parser},
     spanned::Spanned,
     token::Paren,
-    Expr, Lit, Token,
+    Expr, Lit, LitStr, Token, Visibility,
 };
 
-use crate::common::models::SpannedDeriveTrait;
+use crate::common::models::{SpannedDeriveTrait, SpannedItem};
 
-use super::models::{CustomFunction, NewUnchecked, TypedCustomFunction, ValueOrExpr};
+use super::models::{
+    ByteEncoding, ConstructorName, CustomFunction, EqMode, NewUnchecked, OrdMode, SerdeRepr,
+    SpannedByteEncoding, SpannedEqMode, SpannedOrdMode, SpannedSerdeRepr, TypedCustomFunction,
+    ValueOrExpr,
+};
 
 pub fn is_doc_attribute(attribute: &syn::Attribute) -> bool {
     match attribute.path().segments.first() {
@@ -56,35 +62,186 @@ pub struct ParseableAttributes<Sanitizer, Validator> {
     /// Parsed from `validate(...)` attribute
     pub validators: Vec<Validator>,
 
+    /// Parsed from `raw(...)` within `validate(...)` attribute
+    pub raw_validators: Vec<Validator>,
+
     /// Parsed from `new_unchecked` attribute
     pub new_unchecked: NewUnchecked,
 
-    /// Parsed from `new_unchecked` attribute
+    /// Parsed from `default` attribute
     pub default: Option<Expr>,
 
+    /// Parsed from `non_exhaustive_error` attribute
+    pub non_exhaustive_error: bool,
+
+    /// Parsed from `collect_errors` attribute
+    pub collect_errors: bool,
+
+    /// Parsed from `allow_unused` attribute
+    pub allow_unused: bool,
+
+    /// Parsed from `repr_transparent` attribute
+    pub repr_transparent: Option<Span>,
+
+    /// Parsed from `enumerate` attribute
+    pub enumerate: Option<Span>,
+
+    /// Parsed from `display` attribute
+    pub display: Option<LitStr>,
+
+    /// Parsed from `new_fn` attribute
+    pub new_fn: ConstructorName,
+
+    /// Parsed from `inner_vis` attribute
+    pub inner_vis: Visibility,
+
+    /// Parsed from `eq` attribute
+    pub eq: Option<SpannedEqMode>,
+
+    /// Parsed from `ord` attribute
+    pub ord: Option<SpannedOrdMode>,
+
+    /// Parsed from `serde_repr` attribute
+    pub serde_repr: Option<SpannedSerdeRepr>,
+
+    /// Parsed from `schema_example` attribute
+    pub schema_example: Option<Expr>,
+
+    /// Parsed from `from_env` attribute
+    pub from_env: Option<Span>,
+
+    /// Parsed from `from_hex` or `from_base64` attribute
+    pub from_encoding: Option<SpannedByteEncoding>,
+
+    /// Parsed from `sanitize_then_return_changed` attribute
+    pub sanitize_then_return_changed: Option<Span>,
+
+    /// Parsed from `transparent_debug` attribute
+    pub transparent_debug: Option<Span>,
+
+    /// Parsed from `skip_new` attribute
+    pub skip_new: Option<Span>,
+
+    /// Parsed from `serde_lenient` attribute
+    pub serde_lenient: Option<Span>,
+
+    /// Parsed from `builder` attribute
+    pub builder: Option<Span>,
+
+    /// Parsed from `crate` attribute
+    pub crate_path: Option<syn::Path>,
+
+    /// Parsed from `context` attribute
+    pub context: Option<syn::Type>,
+
+    /// Parsed from `async_with = ` within `validate(...)` attribute
+    pub async_validators: Vec<SpannedItem<CustomFunction>>,
+
     /// Parsed from `derive(...)` attribute
     pub derive_traits: Vec<SpannedDeriveTrait>,
 }
 
+/// Parse an item within `validate(...)` into validators.
+/// Usually an item is a single validator, but a shorthand (e.g. `len_char = 3..=20`) may expand
+/// into several of them.
+pub trait ParseValidators: Parse {
+    fn parse_validators(input: ParseStream) -> syn::Result<Vec<Self>> {
+        Ok(vec![Self::parse(input)?])
+    }
+}
+
+/// An item within `validate(...)` attribute.
+enum ValidateItem<Validator> {
+    /// Regular validators, that run against the sanitized value.
+    Validators(Vec<Validator>),
+
+    /// Validators within `raw(...)`, that run against the raw value before sanitization.
+    Raw(Vec<Validator>),
+
+    /// A validator set with `async_with = `, that is awaited by `new_async()`.
+    #[cfg_attr(not(feature = "async"), allow(dead_code))]
+    Async(SpannedItem<CustomFunction>),
+}
+
+impl<Validator: ParseValidators> Parse for ValidateItem<Validator> {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let fork = input.fork();
+        let is_raw = fork
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident == "raw" && fork.peek(Paren));
+        let fork = input.fork();
+        let is_async = fork
+            .parse::<Ident>()
+            .is_ok_and(|ident| ident == "async_with" && fork.peek(Token![=]));
+        if is_raw {
+            let _raw: Ident = input.parse()?;
+            let content;
+            parenthesized!(content in input);
+            let items = content.parse_terminated(Validator::parse_validators, Token![,])?;
+            Ok(Self::Raw(items.into_iter().flatten().collect()))
+        } else if is_async {
+            let ident: Ident = input.parse()?;
+            cfg_if! {
+                if #[cfg(feature = "async")] {
+                    let _eq: Token![=] = input.parse()?;
+                    let custom_function: CustomFunction = input.parse()?;
+                    Ok(Self::Async(SpannedItem::new(custom_function, ident.span())))
+                } else {
+                    let msg = "To use `async_with`, the feature `async` of the crate `nutype` must be enabled.";
+                    Err(syn::Error::new(ident.span(), msg))
+                }
+            }
+        } else {
+            Validator::parse_validators(input).map(Self::Validators)
+        }
+    }
+}
+
 // By some reason Default cannot be derived.
 impl<Sanitizer, Validator> Default for ParseableAttributes<Sanitizer, Validator> {
     fn default() -> Self {
         Self {
             sanitizers: vec![],
             validators: vec![],
+            raw_validators: vec![],
             new_unchecked: NewUnchecked::Off,
             default: None,
+            non_exhaustive_error: false,
+            collect_errors: false,
+            allow_unused: false,
+            repr_transparent: None,
+            enumerate: None,
+            display: None,
+            new_fn: ConstructorName::default(),
+            inner_vis: Visibility::Inherited,
+            eq: None,
+            ord: None,
+            serde_repr: None,
+            schema_example: None,
+            from_env: None,
+            from_encoding: None,
+            sanitize_then_return_changed: None,
+            transparent_debug: None,
+            skip_new: None,
+            serde_lenient: None,
+            builder: None,
+            crate_path: None,
+            context: None,
+            async_validators: vec![],
             derive_traits: vec![],
         }
     }
 }
 
-impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer, Validator> {
+impl<Sanitizer: Parse, Validator: ParseValidators> Parse
+    for ParseableAttributes<Sanitizer, Validator>
+{
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut attrs = ParseableAttributes::default();
 
         while !input.is_empty() {
-            let ident: Ident = input.parse()?;
+            // `Ident::parse_any` accepts keywords, so `crate = ...` can be parsed.
+            let ident: Ident = input.call(Ident::parse_any)?;
             if ident == "sanitize" {
                 if input.peek(Paren) {
                     let content;
@@ -103,8 +260,21 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
                 if input.peek(Paren) {
                     let content;
                     parenthesized!(content in input);
-                    let items = content.parse_terminated(Validator::parse, Token![,])?;
-                    attrs.validators = items.into_iter().collect();
+                    let items =
+                        content.parse_terminated(ValidateItem::<Validator>::parse, Token![,])?;
+                    for item in items {
+                        match item {
+                            ValidateItem::Validators(validators) => {
+                                attrs.validators.extend(validators)
+                            }
+                            ValidateItem::Raw(validators) => {
+                                attrs.raw_validators.extend(validators)
+                            }
+                            ValidateItem::Async(validator) => {
+                                attrs.async_validators.push(validator)
+                            }
+                        }
+                    }
                 } else {
                     let msg = concat!(
                         "`validate` must be used with parenthesis.\n",
@@ -131,6 +301,82 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
                 let _eq: Token![=] = input.parse()?;
                 let default_expr: Expr = input.parse()?;
                 attrs.default = Some(default_expr);
+            } else if ident == "display" {
+                let _eq: Token![=] = input.parse()?;
+                let display_format: LitStr = input.parse()?;
+                attrs.display = Some(display_format);
+            } else if ident == "new_fn" {
+                let _eq: Token![=] = input.parse()?;
+                let new_fn: Ident = input.parse()?;
+                attrs.new_fn = ConstructorName::new(new_fn);
+            } else if ident == "inner_vis" {
+                let _eq: Token![=] = input.parse()?;
+                let inner_vis: Visibility = input.parse()?;
+                attrs.inner_vis = validate_inner_vis(inner_vis, ident.span())?;
+            } else if ident == "eq" {
+                let _eq: Token![=] = input.parse()?;
+                let eq_mode: SpannedEqMode = input.parse()?;
+                attrs.eq = Some(eq_mode);
+            } else if ident == "ord" {
+                let _eq: Token![=] = input.parse()?;
+                let ord_mode: SpannedOrdMode = input.parse()?;
+                attrs.ord = Some(ord_mode);
+            } else if ident == "serde_repr" {
+                let _eq: Token![=] = input.parse()?;
+                let serde_repr: SpannedSerdeRepr = input.parse()?;
+                attrs.serde_repr = Some(serde_repr);
+            } else if ident == "schema_example" {
+                let _eq: Token![=] = input.parse()?;
+                let schema_example: Expr = input.parse()?;
+                attrs.schema_example = Some(schema_example);
+            } else if ident == "non_exhaustive_error" {
+                attrs.non_exhaustive_error = true;
+            } else if ident == "collect_errors" {
+                attrs.collect_errors = true;
+            } else if ident == "allow_unused" {
+                attrs.allow_unused = true;
+            } else if ident == "context" {
+                let _eq: Token![=] = input.parse()?;
+                let context: syn::Type = input.parse()?;
+                attrs.context = Some(context);
+            } else if ident == "from_env" {
+                attrs.from_env = Some(ident.span());
+            } else if ident == "from_hex" {
+                cfg_if! {
+                    if #[cfg(feature = "hex")] {
+                        attrs.from_encoding = Some(parse_from_encoding(attrs.from_encoding, ByteEncoding::Hex, ident.span())?);
+                    } else {
+                        let msg = "To use `from_hex`, the feature `hex` of the crate `nutype` must be enabled.";
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
+            } else if ident == "from_base64" {
+                cfg_if! {
+                    if #[cfg(feature = "base64")] {
+                        attrs.from_encoding = Some(parse_from_encoding(attrs.from_encoding, ByteEncoding::Base64, ident.span())?);
+                    } else {
+                        let msg = "To use `from_base64`, the feature `base64` of the crate `nutype` must be enabled.";
+                        return Err(syn::Error::new(ident.span(), msg));
+                    }
+                }
+            } else if ident == "sanitize_then_return_changed" {
+                attrs.sanitize_then_return_changed = Some(ident.span());
+            } else if ident == "transparent_debug" {
+                attrs.transparent_debug = Some(ident.span());
+            } else if ident == "skip_new" {
+                attrs.skip_new = Some(ident.span());
+            } else if ident == "serde_lenient" {
+                attrs.serde_lenient = Some(ident.span());
+            } else if ident == "builder" {
+                attrs.builder = Some(ident.span());
+            } else if ident == "crate" {
+                let _eq: Token![=] = input.parse()?;
+                let crate_path: syn::Path = input.call(syn::Path::parse_mod_style)?;
+                attrs.crate_path = Some(crate_path);
+            } else if ident == "repr_transparent" {
+                attrs.repr_transparent = Some(ident.span());
+            } else if ident == "enumerate" {
+                attrs.enumerate = Some(ident.span());
             } else if ident == "new_unchecked" {
                 cfg_if! {
                     if #[cfg(feature = "new_unchecked")] {
@@ -159,6 +405,105 @@ impl<Sanitizer: Parse, Validator: Parse> Parse for ParseableAttributes<Sanitizer
     }
 }
 
+/// A string can be decoded into bytes only in one way, so `from_hex` and `from_base64` exclude
+/// each other.
+#[cfg_attr(not(any(feature = "hex", feature = "base64")), allow(dead_code))]
+fn parse_from_encoding(
+    maybe_current: Option<SpannedByteEncoding>,
+    encoding: ByteEncoding,
+    span: Span,
+) -> syn::Result<SpannedByteEncoding> {
+    if let Some(current) = maybe_current {
+        let msg = format!(
+            "`{}` cannot be used together with `{}`.",
+            encoding.attr_name(),
+            current.item.attr_name()
+        );
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(SpannedByteEncoding::new(encoding, span))
+}
+
+/// The newtype is defined in a generated module, so `pub(super)` and `pub(in path)` would not
+/// mean what they seem to mean. Only `pub` and `pub(crate)` are accepted.
+fn validate_inner_vis(inner_vis: Visibility, attr_span: Span) -> syn::Result<Visibility> {
+    match &inner_vis {
+        Visibility::Public(_) => Ok(inner_vis),
+        Visibility::Restricted(restricted)
+            if restricted.in_token.is_none() && restricted.path.is_ident("crate") =>
+        {
+            Ok(inner_vis)
+        }
+        Visibility::Restricted(_) | Visibility::Inherited => {
+            let msg = "Unsupported `inner_vis` value.\nPossible values are `pub` and `pub(crate)`.";
+            let span = match &inner_vis {
+                Visibility::Inherited => attr_span,
+                _ => inner_vis.span(),
+            };
+            Err(syn::Error::new(span, msg))
+        }
+    }
+}
+
+impl Parse for SpannedEqMode {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "case_insensitive" {
+            Ok(SpannedEqMode::new(EqMode::CaseInsensitive, ident.span()))
+        } else {
+            let msg =
+                format!("Unknown `eq` mode `{ident}`.\nPossible values are `case_insensitive`.");
+            Err(syn::Error::new(ident.span(), msg))
+        }
+    }
+}
+
+impl Parse for SpannedOrdMode {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "case_insensitive" {
+            Ok(SpannedOrdMode::new(OrdMode::CaseInsensitive, ident.span()))
+        } else {
+            let msg =
+                format!("Unknown `ord` mode `{ident}`.\nPossible values are `case_insensitive`.");
+            Err(syn::Error::new(ident.span(), msg))
+        }
+    }
+}
+
+impl Parse for SpannedSerdeRepr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident: Ident = input.parse()?;
+        if ident == "transparent" {
+            Ok(SpannedSerdeRepr::new(SerdeRepr::Transparent, ident.span()))
+        } else if ident == "object" {
+            let field = if input.peek(Paren) {
+                let content;
+                parenthesized!(content in input);
+                let field_ident: Ident = content.parse()?;
+                if field_ident != "field" {
+                    let msg = format!("Unknown `serde_repr = object` parameter `{field_ident}`.\nPossible parameters are `field`.");
+                    return Err(syn::Error::new(field_ident.span(), msg));
+                }
+                let _eq: Token![=] = content.parse()?;
+                let field: LitStr = content.parse()?;
+                field.value()
+            } else {
+                SerdeRepr::DEFAULT_OBJECT_FIELD.to_string()
+            };
+            Ok(SpannedSerdeRepr::new(
+                SerdeRepr::Object { field },
+                ident.span(),
+            ))
+        } else {
+            let msg = format!(
+                "Unknown `serde_repr` value `{ident}`.\nPossible values are `transparent` and `object`."
+            );
+            Err(syn::Error::new(ident.span(), msg))
+        }
+    }
+}
+
 pub fn parse_number<T>(input: ParseStream) -> syn::Result<(T, Span)>
 where
     T: FromStr,
@@ -170,16 +515,19 @@ where
     }
 
     let lit: Lit = input.parse()?;
+    // `base10_digits()` strips underscores and suffixes, and converts hex (`0x`), octal (`0o`)
+    // and binary (`0b`) integer literals to decimal. Float literals keep their exponent
+    // (e.g. `1.5e-3`), which is understood by `FromStr` of floats.
     let lit_str = match &lit {
-        Lit::Float(lf) => lf.to_string(),
-        Lit::Int(li) => li.to_string(),
+        Lit::Float(lf) => lf.base10_digits().to_string(),
+        Lit::Int(li) => li.base10_digits().to_string(),
         _ => {
             let msg = "Expected number literal";
             return Err(syn::Error::new(lit.span(), msg));
         }
     };
 
-    number_str.push_str(&lit_str.replace('_', ""));
+    number_str.push_str(&lit_str);
 
     let number: T = number_str.parse::<T>().map_err(|_err| {
         let msg = format!("Expected {}, got `{}`", type_name::<T>(), number_str);
@@ -189,21 +537,129 @@ where
     Ok((number, lit.span()))
 }
 
-/// Try to parse input as a number of type T (if the value specified directly)
-/// If that fails then try to parse it as an expression (if the value is specified as an expression, a constant, etc.)
+/// Parse input as a number of type T if the value is specified as a literal (e.g. `10` or `-0x1F`),
+/// otherwise keep it as an expression (e.g. a constant or `60 * 60`).
+/// A literal that is not a valid T is an error, which points at the literal.
 pub fn parse_number_or_expr<T>(input: ParseStream) -> syn::Result<(ValueOrExpr<T>, Span)>
 where
     T: FromStr,
 {
-    if let Ok((number, span)) = parse_number::<T>(input) {
+    let expr: Expr = input.parse()?;
+    if is_literal(&expr) {
+        let (number, span) = parse_number::<T>.parse2(expr.into_token_stream())?;
         Ok((ValueOrExpr::Value(number), span))
     } else {
-        let expr: Expr = input.parse()?;
         let span = expr.span();
         Ok((ValueOrExpr::Expr(expr), span))
     }
 }
 
+/// Bounds of a range shorthand, e.g. `3..=20`, `0..100`, `10..` or `..=99`.
+pub struct RangeBounds<T> {
+    pub start: Option<(ValueOrExpr<T>, Span)>,
+    pub end: Option<(RangeEnd<T>, Span)>,
+}
+
+pub enum RangeEnd<T> {
+    /// `..=end`
+    Inclusive(ValueOrExpr<T>),
+    /// `..end`
+    Exclusive(ValueOrExpr<T>),
+}
+
+/// Check if the input starts with a shorthand `<name> = `.
+pub fn peek_shorthand(input: ParseStream, name: &str) -> bool {
+    let fork = input.fork();
+    fork.parse::<Ident>().is_ok_and(|ident| ident == name) && fork.peek(Token![=])
+}
+
+/// Parse a range of numbers, e.g. `3..=20` or `0..100`. At least one of the bounds must be set.
+pub fn parse_range<T>(input: ParseStream) -> syn::Result<RangeBounds<T>>
+where
+    T: FromStr,
+{
+    let expr: Expr = input.parse()?;
+    let Expr::Range(range) = expr else {
+        let msg = "Expected a range, e.g. `3..=20` or `0..100`";
+        return Err(syn::Error::new(expr.span(), msg));
+    };
+    let range_span = range.span();
+    let parse_bound = |bound: Expr| parse_number_or_expr::<T>.parse2(bound.into_token_stream());
+
+    let start = range.start.map(|start| parse_bound(*start)).transpose()?;
+    let end = match (range.end, range.limits) {
+        (Some(end), syn::RangeLimits::Closed(_)) => {
+            let (end, span) = parse_bound(*end)?;
+            Some((RangeEnd::Inclusive(end), span))
+        }
+        (Some(end), syn::RangeLimits::HalfOpen(_)) => {
+            let (end, span) = parse_bound(*end)?;
+            Some((RangeEnd::Exclusive(end), span))
+        }
+        (None, _) => None,
+    };
+    if start.is_none() && end.is_none() {
+        let msg = "The range must have at least one bound, e.g. `3..=20`, `10..` or `..100`";
+        return Err(syn::Error::new(range_span, msg));
+    }
+    Ok(RangeBounds { start, end })
+}
+
+/// Parse a non-empty list of values in brackets, e.g. `["USD", "EUR"]` or `[1, 2, 4, 8]`.
+/// `validator_name` is used to report an empty list.
+pub fn parse_value_list<T>(
+    input: ParseStream,
+    validator_name: &str,
+    parse_value: fn(ParseStream) -> syn::Result<T>,
+) -> syn::Result<(Vec<T>, Span)> {
+    let content;
+    let brackets = syn::bracketed!(content in input);
+    let values = content.parse_terminated(parse_value, Token![,])?;
+    let span = brackets.span.join();
+    if values.is_empty() {
+        let msg = format!("`{validator_name}` requires at least one allowed value.");
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok((values.into_iter().collect(), span))
+}
+
+/// Parse a non-empty group of validators in parentheses, that is combined by `any_of` or `all_of`,
+/// e.g. `(starts_with = "A", starts_with = "B")`.
+pub fn parse_validator_group<V: ParseValidators>(
+    input: ParseStream,
+    combinator: &str,
+) -> syn::Result<(Vec<V>, Span)> {
+    let content;
+    let parens = syn::parenthesized!(content in input);
+    let span = parens.span.join();
+    let mut validators = Vec::new();
+    while !content.is_empty() {
+        validators.extend(V::parse_validators(&content)?);
+        if content.is_empty() {
+            break;
+        }
+        let _comma: Token![,] = content.parse()?;
+    }
+    if validators.is_empty() {
+        let msg = format!("`{combinator}` requires at least one validator.");
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok((validators, span))
+}
+
+/// Literal or negated literal
+fn is_literal(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Unary(syn::ExprUnary {
+            op: syn::UnOp::Neg(_),
+            expr,
+            ..
+        }) => matches!(**expr, Expr::Lit(_)),
+        _ => false,
+    }
+}
+
 // NOTE: This is a quite hacky way to obtain a syn::Type from `T`.
 // Is there a better way?
 pub fn parse_typed_custom_function<T>(
@@ -238,6 +694,23 @@ where
     parse_kind("validator", input)
 }
 
+/// The name of a sanitizer or validator as it's written within `#[nutype]`.
+///
+/// kinded separates digits with an underscore in snake_case (e.g. `len_utf_16_max`),
+/// while the attribute keeps them together with the preceding word (e.g. `len_utf16_max`).
+pub fn kind_name(kind: impl std::fmt::Display) -> String {
+    let snake_case = kind.to_string();
+    let mut name = String::with_capacity(snake_case.len());
+    let mut chars = snake_case.chars().peekable();
+    while let Some(ch) = chars.next() {
+        let is_before_digit = chars.peek().is_some_and(char::is_ascii_digit);
+        if !(ch == '_' && is_before_digit) {
+            name.push(ch);
+        }
+    }
+    name
+}
+
 /// Parse ident from ParStream and tries to parse it further into Kind of sanitizier or validator.
 /// Build a helpful error on failure.
 fn parse_kind<K>(attr_type: &str, input: ParseStream) -> syn::Result<(K, Ident)>
@@ -247,21 +720,19 @@ where
     let ident: Ident = input.parse()?;
     let attr_name = ident.to_string();
 
-    if let Ok(kind) = attr_name.parse::<K>() {
+    if let Some(kind) = K::all().iter().find(|k| kind_name(k) == attr_name) {
+        Ok((*kind, ident))
+    } else if let Ok(kind) = attr_name.parse::<K>() {
         // kinded parses enum variants spelled in different cases (PascalCase, camelCase,
         // snake_case, etc.)
         // Here we want to enforce usage of snake_case only.
-        let strict_attr_name = kind.to_string();
-        if strict_attr_name == attr_name {
-            Ok((kind, ident))
-        } else {
-            let msg = format!("Unknown {attr_type} `{ident}`. Did you mean `{strict_attr_name}`?");
-            Err(syn::Error::new(ident.span(), msg))
-        }
+        let strict_attr_name = kind_name(kind);
+        let msg = format!("Unknown {attr_type} `{ident}`. Did you mean `{strict_attr_name}`?");
+        Err(syn::Error::new(ident.span(), msg))
     } else {
         let possible_values: String = K::all()
             .iter()
-            .map(|k| format!("`{k}`"))
+            .map(|k| format!("`{}`", kind_name(k)))
             .filter(|s| s != "`phantom`") // filter out _Phantom variant
             .collect::<Vec<_>>()
             .join(", ");
@@ -1,7 +1,13 @@
 use kinded::Kinded;
 use proc_macro2::Span;
+use quote::ToTokens;
+use syn::spanned::Spanned;
 
-use super::models::{NumericBoundValidator, SpannedItem};
+use super::models::{
+    ByteEncoding, CustomFunction, DeriveTrait, EqMode, NumericBoundValidator, OrdMode, SerdeRepr,
+    SpannedByteEncoding, SpannedDeriveTrait, SpannedEqMode, SpannedItem, SpannedOrdMode,
+    SpannedSerdeRepr, ValueOrExpr,
+};
 
 pub fn validate_duplicates<T>(
     items: &[SpannedItem<T>],
@@ -52,6 +58,26 @@ macro_rules! find_bound_variant {
     };
 }
 
+/// Whether the lower bound mirrors the upper bound, e.g. `greater_or_equal = -10` and
+/// `less_or_equal = 10`, so a negated valid value is valid as well. The bounds are compared by
+/// their tokens, so only literals can be proven to be symmetric.
+pub fn are_bounds_symmetric<T: ToTokens>(
+    maybe_lower: Option<(&ValueOrExpr<T>, bool)>,
+    maybe_upper: Option<(&ValueOrExpr<T>, bool)>,
+) -> bool {
+    match (maybe_lower, maybe_upper) {
+        (
+            Some((ValueOrExpr::Value(lower), lower_is_exclusive)),
+            Some((ValueOrExpr::Value(upper), upper_is_exclusive)),
+        ) if lower_is_exclusive == upper_is_exclusive => {
+            let lower = lower.to_token_stream().to_string();
+            let upper = upper.to_token_stream().to_string();
+            lower.strip_prefix('-') == Some(upper.as_str())
+        }
+        _ => false,
+    }
+}
+
 pub fn validate_numeric_bounds<V, T>(validators: &[SpannedItem<V>]) -> Result<(), syn::Error>
 where
     V: NumericBoundValidator<T>,
@@ -102,3 +128,461 @@ where
 
     Ok(())
 }
+
+/// Make sure that `display = ` is used only together with derived `Display`, otherwise
+/// the format string would be silently ignored.
+pub fn validate_display_format(
+    maybe_display_format: Option<&syn::LitStr>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<(), syn::Error> {
+    if let Some(display_format) = maybe_display_format {
+        let derives_display = derive_traits
+            .iter()
+            .any(|derive_trait| derive_trait.item == DeriveTrait::Display);
+        if !derives_display {
+            let msg = "`display = ` is set, but `Display` is not derived.\nAdd `Display` to `derive(...)`, for example:\n\n    derive(Display)\n\n";
+            return Err(syn::Error::new(display_format.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+/// Make sure that `schema_example = ` is used only together with derived `JsonSchema`,
+/// otherwise the example would be silently ignored.
+pub fn validate_schema_example(
+    maybe_schema_example: Option<&syn::Expr>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<(), syn::Error> {
+    if let Some(schema_example) = maybe_schema_example {
+        let derives_json_schema = derive_traits
+            .iter()
+            .any(|derive_trait| derive_trait.item == DeriveTrait::SchemarsJsonSchema);
+        if !derives_json_schema {
+            let msg = "`schema_example = ` is set, but `JsonSchema` is not derived.\nAdd `JsonSchema` to `derive(...)`, for example:\n\n    derive(JsonSchema)\n\n";
+            return Err(syn::Error::new(schema_example.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+/// `from_env` is built on top of `FromStr`, so `FromStr` must be derived.
+/// Environment variables are available only with `std`.
+pub fn validate_from_env(
+    maybe_from_env: Option<Span>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<bool, syn::Error> {
+    let Some(span) = maybe_from_env else {
+        return Ok(false);
+    };
+    if cfg!(not(feature = "std")) {
+        let msg = "`from_env` requires the feature `std` of the crate `nutype` to be enabled.";
+        return Err(syn::Error::new(span, msg));
+    }
+    let derives_from_str = derive_traits
+        .iter()
+        .any(|derive_trait| derive_trait.item == DeriveTrait::FromStr);
+    if !derives_from_str {
+        let msg = "`from_env` requires `FromStr` to be derived.\nAdd `FromStr` to `derive(...)`, for example:\n\n    derive(FromStr)\n\n";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(true)
+}
+
+/// `transparent_debug` changes how `Debug` is implemented, so `Debug` must be derived.
+pub fn validate_transparent_debug(
+    maybe_transparent_debug: Option<Span>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<bool, syn::Error> {
+    let Some(span) = maybe_transparent_debug else {
+        return Ok(false);
+    };
+    let derives_debug = derive_traits
+        .iter()
+        .any(|derive_trait| derive_trait.item == DeriveTrait::Debug);
+    if !derives_debug {
+        let msg = "`transparent_debug` requires `Debug` to be derived.\nAdd `Debug` to `derive(...)`, for example:\n\n    derive(Debug)\n\n";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(true)
+}
+
+/// With `skip_new` the value must still be constructable in some other way, and there must be
+/// no other public constructor left.
+pub fn validate_skip_new(
+    maybe_skip_new: Option<Span>,
+    sanitize_then_return_changed: bool,
+    has_other_constructors: bool,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<bool, syn::Error> {
+    let Some(span) = maybe_skip_new else {
+        return Ok(false);
+    };
+    if sanitize_then_return_changed {
+        let msg = "`skip_new` cannot be used together with `sanitize_then_return_changed`, because `new_reporting()` is a public constructor as well.";
+        return Err(syn::Error::new(span, msg));
+    }
+    let derives_constructing_trait = derive_traits
+        .iter()
+        .any(|derive_trait| constructs_value(&derive_trait.item));
+    if !has_other_constructors && !derives_constructing_trait {
+        let msg = "`skip_new` makes the constructor private, but nothing else constructs the value.\nDerive a trait that does, for example:\n\n    derive(TryFrom, FromStr)\n\n";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(true)
+}
+
+/// `build()` calls the regular constructor, so it cannot be generated when the constructor needs
+/// a context, awaits the async validators or is hidden with `skip_new`.
+pub fn validate_builder(
+    maybe_builder: Option<Span>,
+    maybe_context: Option<&syn::Type>,
+    has_async_validation: bool,
+    skip_new: bool,
+) -> Result<bool, syn::Error> {
+    let Some(span) = maybe_builder else {
+        return Ok(false);
+    };
+    if maybe_context.is_some() {
+        let msg = "`builder` cannot be used together with `context = `, because `build()` has no context to pass to the validators.";
+        return Err(syn::Error::new(span, msg));
+    }
+    if has_async_validation {
+        let msg = "`builder` cannot be used together with `async_with`, because `build()` cannot await the async validators.";
+        return Err(syn::Error::new(span, msg));
+    }
+    if skip_new {
+        let msg = "`builder` cannot be used together with `skip_new`, because `build()` is a public constructor as well.";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(true)
+}
+
+/// `from_hex` and `from_base64` generate `FromStr` themselves, so it must not be derived.
+/// The generated `FromStr` has no way to receive a context for the custom validators.
+pub fn validate_from_encoding(
+    maybe_from_encoding: Option<SpannedByteEncoding>,
+    maybe_context: Option<&syn::Type>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<Option<ByteEncoding>, syn::Error> {
+    let Some(SpannedItem {
+        item: encoding,
+        span,
+    }) = maybe_from_encoding
+    else {
+        return Ok(None);
+    };
+    let attr_name = encoding.attr_name();
+    if let Some(derive_trait) = derive_traits
+        .iter()
+        .find(|derive_trait| derive_trait.item == DeriveTrait::FromStr)
+    {
+        let msg = format!("`{attr_name}` already implements `FromStr`, so `FromStr` must not be derived.\nRemove `FromStr` from `derive(...)`.");
+        return Err(syn::Error::new(derive_trait.span(), msg));
+    }
+    if maybe_context.is_some() {
+        let msg = format!("`{attr_name}` cannot be used together with `context`, because `FromStr` cannot pass a context to the validators.");
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(Some(encoding))
+}
+
+/// `sanitize_then_return_changed` reports whether the sanitizers changed the value,
+/// which makes no sense without sanitizers.
+pub fn validate_sanitize_then_return_changed(
+    maybe_sanitize_then_return_changed: Option<Span>,
+    has_sanitizers: bool,
+) -> Result<bool, syn::Error> {
+    let Some(span) = maybe_sanitize_then_return_changed else {
+        return Ok(false);
+    };
+    if !has_sanitizers {
+        let msg = "`sanitize_then_return_changed` is set, but there are no sanitizers that could change the value.\nAdd some sanitizers, for example:\n\n    sanitize(trim)\n\n";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(true)
+}
+
+/// `DieselNewType` on a type with validation reads the values from the database through `TryFrom`,
+/// so an invalid value in the database never becomes an invalid instance of the newtype.
+pub fn validate_diesel_new_type(
+    has_validation: bool,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<(), syn::Error> {
+    let Some(diesel_new_type) = derive_traits
+        .iter()
+        .find(|derive_trait| derive_trait.item == DeriveTrait::DieselNewType)
+    else {
+        return Ok(());
+    };
+    let derives_try_from = derive_traits
+        .iter()
+        .any(|derive_trait| derive_trait.item == DeriveTrait::TryFrom);
+    if has_validation && !derives_try_from {
+        let msg = "`DieselNewType` on a type with validation requires `TryFrom` to be derived, so the values read from the database are validated.\nAdd `TryFrom` to `derive(...)`.";
+        return Err(syn::Error::new(diesel_new_type.span(), msg));
+    }
+    Ok(())
+}
+
+/// With `context = ` the value can be constructed only together with a context, so the traits
+/// that construct a value out of thin air (e.g. `TryFrom` or `Deserialize`) cannot be derived.
+pub fn validate_context(
+    maybe_context: Option<&syn::Type>,
+    has_validation: bool,
+    maybe_schema_example: Option<&syn::Expr>,
+    enumerate: bool,
+    sanitize_then_return_changed: bool,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<(), syn::Error> {
+    let Some(context) = maybe_context else {
+        return Ok(());
+    };
+    if !has_validation {
+        let msg = "`context = ` is set, but there are no validators to receive it.";
+        return Err(syn::Error::new(context.span(), msg));
+    }
+    if let Some(schema_example) = maybe_schema_example {
+        let msg = "`schema_example = ` cannot be used together with `context = `, because the example cannot be validated without a context.";
+        return Err(syn::Error::new(schema_example.span(), msg));
+    }
+    if enumerate {
+        let msg = "`enumerate` cannot be used together with `context = `, because the values cannot be validated without a context.";
+        return Err(syn::Error::new(context.span(), msg));
+    }
+    if sanitize_then_return_changed {
+        let msg = "`sanitize_then_return_changed` cannot be used together with `context = `, because the value cannot be constructed without a context.";
+        return Err(syn::Error::new(context.span(), msg));
+    }
+    for derive_trait in derive_traits {
+        if constructs_value(&derive_trait.item) {
+            let msg = "The trait cannot be derived together with `context = `, because it would construct a value without a context.";
+            return Err(syn::Error::new(derive_trait.span(), msg));
+        }
+    }
+    Ok(())
+}
+
+/// Whether the derived trait constructs a value (e.g. `TryFrom` or `Deserialize`), so it
+/// would bypass validation that the trait implementation cannot run.
+fn constructs_value(derive_trait: &DeriveTrait) -> bool {
+    !matches!(
+        derive_trait,
+        DeriveTrait::Debug
+            | DeriveTrait::Clone
+            | DeriveTrait::Copy
+            | DeriveTrait::PartialEq
+            | DeriveTrait::Eq
+            | DeriveTrait::PartialOrd
+            | DeriveTrait::Ord
+            | DeriveTrait::AsRef
+            | DeriveTrait::Into
+            | DeriveTrait::Hash
+            | DeriveTrait::Borrow
+            | DeriveTrait::Display
+            | DeriveTrait::Deref
+            | DeriveTrait::EqInner
+            | DeriveTrait::OrdInner
+            | DeriveTrait::SerdeSerialize
+            | DeriveTrait::SchemarsJsonSchema
+            | DeriveTrait::RedisToRedisArgs
+            | DeriveTrait::BincodeEncode
+    )
+}
+
+/// Async validators are awaited only by `new_async()`, so everything else that constructs
+/// a value must be ruled out, otherwise it would skip them.
+pub fn validate_async_validators(
+    async_validators: Vec<SpannedItem<CustomFunction>>,
+    maybe_context: Option<&syn::Type>,
+    enumerate: bool,
+    sanitize_then_return_changed: bool,
+    maybe_byte_encoding: Option<ByteEncoding>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<Vec<CustomFunction>, syn::Error> {
+    let Some(first) = async_validators.first() else {
+        return Ok(vec![]);
+    };
+    let span = first.span();
+    if maybe_context.is_some() {
+        let msg = "`async_with` cannot be used together with `context = `.";
+        return Err(syn::Error::new(span, msg));
+    }
+    if enumerate {
+        let msg = "`enumerate` cannot be used together with `async_with`, because the values cannot be validated without awaiting.";
+        return Err(syn::Error::new(span, msg));
+    }
+    if sanitize_then_return_changed {
+        let msg = "`sanitize_then_return_changed` cannot be used together with `async_with`, because the value can be constructed only with `new_async()`.";
+        return Err(syn::Error::new(span, msg));
+    }
+    if let Some(encoding) = maybe_byte_encoding {
+        let msg = format!(
+            "`{}` cannot be used together with `async_with`, because `FromStr` cannot await the validators.",
+            encoding.attr_name()
+        );
+        return Err(syn::Error::new(span, msg));
+    }
+    for derive_trait in derive_traits {
+        if constructs_value(&derive_trait.item) {
+            let msg = "The trait cannot be derived together with `async_with`, because it would construct a value without awaiting the validators.";
+            return Err(syn::Error::new(derive_trait.span(), msg));
+        }
+    }
+    async_validators
+        .into_iter()
+        .map(|validator| validator.item.resolve_path())
+        .collect()
+}
+
+/// `eq = ` is supported only by string based types, other types always compare inner values
+/// as they are.
+pub fn validate_eq_mode_is_not_set(maybe_eq: Option<SpannedEqMode>) -> Result<EqMode, syn::Error> {
+    match maybe_eq {
+        None => Ok(EqMode::Exact),
+        Some(eq) => {
+            let msg = "`eq = ` is supported only by String based types.";
+            Err(syn::Error::new(eq.span(), msg))
+        }
+    }
+}
+
+/// `ord = ` is supported only by string based types, other types always compare inner values
+/// as they are.
+pub fn validate_ord_mode_is_not_set(
+    maybe_ord: Option<SpannedOrdMode>,
+) -> Result<OrdMode, syn::Error> {
+    match maybe_ord {
+        None => Ok(OrdMode::Exact),
+        Some(ord) => {
+            let msg = "`ord = ` is supported only by String based types.";
+            Err(syn::Error::new(ord.span(), msg))
+        }
+    }
+}
+
+/// `repr_transparent` is supported only by integer and float based types, which wrap a primitive,
+/// so the layout of the newtype is guaranteed to be the one of a primitive as well.
+pub fn validate_repr_transparent_is_not_set(
+    maybe_repr_transparent: Option<Span>,
+) -> Result<bool, syn::Error> {
+    match maybe_repr_transparent {
+        None => Ok(false),
+        Some(span) => {
+            let msg = "`repr_transparent` is supported only by integer and float based types.";
+            Err(syn::Error::new(span, msg))
+        }
+    }
+}
+
+/// `from_hex` and `from_base64` decode a string into bytes, so they are supported only by
+/// the types that wrap bytes, e.g. `Vec<u8>`.
+pub fn validate_from_encoding_is_not_set(
+    maybe_from_encoding: Option<SpannedByteEncoding>,
+) -> Result<Option<SpannedByteEncoding>, syn::Error> {
+    match maybe_from_encoding {
+        None => Ok(None),
+        Some(SpannedItem { item, span }) => {
+            let msg = format!(
+                "`{}` is supported only by byte based types, e.g. `Vec<u8>`.",
+                item.attr_name()
+            );
+            Err(syn::Error::new(span, msg))
+        }
+    }
+}
+
+/// `enumerate` is supported only by integer based types, since only their values can be listed
+/// one by one.
+pub fn validate_enumerate_is_not_set(maybe_enumerate: Option<Span>) -> Result<bool, syn::Error> {
+    match maybe_enumerate {
+        None => Ok(false),
+        Some(span) => {
+            let msg = "`enumerate` is supported only by integer based types.";
+            Err(syn::Error::new(span, msg))
+        }
+    }
+}
+
+/// `serde_lenient` is supported only by integer based types, since only their values are
+/// commonly sent as numeric strings (e.g. by JavaScript clients).
+pub fn validate_serde_lenient_is_not_set(
+    maybe_serde_lenient: Option<Span>,
+) -> Result<Option<Span>, syn::Error> {
+    match maybe_serde_lenient {
+        None => Ok(None),
+        Some(span) => {
+            let msg = "`serde_lenient` is supported only by integer based types.";
+            Err(syn::Error::new(span, msg))
+        }
+    }
+}
+
+/// `raw(...)` validators are supported only by string based types, which have sanitizers
+/// (e.g. `trim`) that can hide a violation of the raw value.
+pub fn validate_raw_validators_are_not_set<T>(
+    raw_validators: &[SpannedItem<T>],
+) -> Result<(), syn::Error> {
+    match raw_validators.first() {
+        None => Ok(()),
+        Some(validator) => {
+            let msg = "`raw(...)` validators are supported only by string based types.";
+            Err(syn::Error::new(validator.span(), msg))
+        }
+    }
+}
+
+/// `serde_lenient` changes the derived `Deserialize`, so it must be derived.
+/// Lenient deserialization of an object is not supported.
+pub fn validate_serde_lenient(
+    maybe_serde_lenient: Option<Span>,
+    serde_repr: &SerdeRepr,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<bool, syn::Error> {
+    let Some(span) = maybe_serde_lenient else {
+        return Ok(false);
+    };
+    let has_deserialize = derive_traits
+        .iter()
+        .any(|dt| dt.item == DeriveTrait::SerdeDeserialize);
+    if !has_deserialize {
+        let msg = "`serde_lenient` requires `Deserialize` to be derived.";
+        return Err(syn::Error::new(span, msg));
+    }
+    if let SerdeRepr::Object { .. } = serde_repr {
+        let msg = "`serde_lenient` cannot be used together with `serde_repr = object`.";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(true)
+}
+
+/// `serde_repr = ` makes sense only together with `Serialize` or `Deserialize`.
+/// The schema derived with `JsonSchema` describes the inner value, so it would not match
+/// the object representation.
+pub fn validate_serde_repr(
+    maybe_serde_repr: Option<SpannedSerdeRepr>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<SerdeRepr, syn::Error> {
+    let Some(serde_repr) = maybe_serde_repr else {
+        return Ok(SerdeRepr::Transparent);
+    };
+    let has_serde = derive_traits.iter().any(|dt| {
+        matches!(
+            dt.item,
+            DeriveTrait::SerdeSerialize | DeriveTrait::SerdeDeserialize
+        )
+    });
+    if !has_serde {
+        let msg = "`serde_repr = ` requires `Serialize` or `Deserialize` to be derived.";
+        return Err(syn::Error::new(serde_repr.span(), msg));
+    }
+    if let SerdeRepr::Object { .. } = serde_repr.item {
+        if let Some(json_schema) = derive_traits
+            .iter()
+            .find(|dt| dt.item == DeriveTrait::SchemarsJsonSchema)
+        {
+            let msg = "`JsonSchema` cannot be derived together with `serde_repr = object`, because the schema would describe the inner value only.";
+            return Err(syn::Error::new(json_schema.span(), msg));
+        }
+    }
+    Ok(serde_repr.item)
+}
@@ -2,33 +2,67 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 use crate::common::{
-    gen::error::{gen_error_type_name, gen_impl_error_trait},
+    gen::{
+        error::{
+            gen_error_type_name, gen_impl_error_trait, gen_impl_validation_error_trait,
+            gen_multiple_errors_display_arm, gen_multiple_errors_variant, gen_non_exhaustive_attr,
+            gen_sanitization_failed_display_arm, gen_sanitization_failed_variant,
+        },
+        gen_alloc_crate,
+    },
     models::{ErrorTypeName, TypeName},
+    parse::kind_name,
 };
 
 use super::super::models::FloatValidator;
 
 pub fn gen_validation_error_type<T: ToTokens>(
     type_name: &TypeName,
-    validators: &[FloatValidator<T>],
+    validators: &[&FloatValidator<T>],
+    non_exhaustive_error: bool,
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
+    let definition = gen_definition(
+        &error_type_name,
+        validators,
+        collect_errors,
+        has_fallible_sanitizers,
+    );
+    let impl_display_trait = gen_impl_display_trait(
+        type_name,
+        &error_type_name,
+        validators,
+        collect_errors,
+        has_fallible_sanitizers,
+    );
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
+    let impl_validation_error_trait = gen_impl_validation_error_trait(
+        type_name,
+        &error_type_name,
+        gen_constraint_arms(&error_type_name, validators),
+        collect_errors,
+        has_fallible_sanitizers,
+    );
+    let non_exhaustive_attr = gen_non_exhaustive_attr(non_exhaustive_error);
 
     quote! {
         #[derive(Debug, Clone, PartialEq, Eq)]
+        #non_exhaustive_attr
         #definition
 
         #impl_display_trait
         #impl_error_trait
+        #impl_validation_error_trait
     }
 }
 
 fn gen_definition<T>(
     error_type_name: &ErrorTypeName,
-    validators: &[FloatValidator<T>],
+    validators: &[&FloatValidator<T>],
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
@@ -48,16 +82,25 @@ fn gen_definition<T>(
             FloatValidator::Predicate(_) => {
                 quote!(PredicateViolated,)
             }
+            FloatValidator::WithResult(_) => {
+                let alloc = gen_alloc_crate();
+                quote!(WithResultViolated(#alloc::string::String),)
+            }
             FloatValidator::Finite => {
                 quote!(FiniteViolated,)
             }
         })
         .collect();
 
+    let multiple_variant = gen_multiple_errors_variant(error_type_name, collect_errors);
+    let sanitization_failed_variant = gen_sanitization_failed_variant(has_fallible_sanitizers);
+
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
             #error_variants
+            #sanitization_failed_variant
+            #multiple_variant
         }
     }
 }
@@ -65,7 +108,9 @@ fn gen_definition<T>(
 fn gen_impl_display_trait<T: ToTokens>(
     type_name: &TypeName,
     error_type_name: &ErrorTypeName,
-    validators: &[FloatValidator<T>],
+    validators: &[&FloatValidator<T>],
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
         FloatValidator::Greater(val) => quote! {
@@ -83,18 +128,52 @@ fn gen_impl_display_trait<T: ToTokens>(
         FloatValidator::Predicate(_) => quote! {
              #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
+        FloatValidator::WithResult(_) => quote! {
+             #error_type_name::WithResultViolated(message) => write!(f, "{} is invalid: {}", stringify!(#type_name), message)
+        },
         FloatValidator::Finite => quote! {
              #error_type_name::FiniteViolated => write!(f, "{} is not finite.", stringify!(#type_name))
         },
     });
 
+    let sanitization_failed_arm =
+        gen_sanitization_failed_display_arm(type_name, error_type_name, has_fallible_sanitizers);
+    let multiple_arm = gen_multiple_errors_display_arm(error_type_name, collect_errors);
+
     quote! {
         impl ::core::fmt::Display for #error_type_name {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 match self {
                     #(#match_arms,)*
+                    #sanitization_failed_arm
+                    #multiple_arm
                 }
             }
         }
     }
 }
+
+/// Map every error variant to the name of the validator that produces it.
+fn gen_constraint_arms<T>(
+    error_type_name: &ErrorTypeName,
+    validators: &[&FloatValidator<T>],
+) -> Vec<TokenStream> {
+    validators
+        .iter()
+        .map(|validator| {
+            let constraint = kind_name(validator.kind());
+            let pattern = match validator {
+                FloatValidator::Greater(_) => quote!(#error_type_name::GreaterViolated),
+                FloatValidator::GreaterOrEqual(_) => {
+                    quote!(#error_type_name::GreaterOrEqualViolated)
+                }
+                FloatValidator::Less(_) => quote!(#error_type_name::LessViolated),
+                FloatValidator::LessOrEqual(_) => quote!(#error_type_name::LessOrEqualViolated),
+                FloatValidator::Finite => quote!(#error_type_name::FiniteViolated),
+                FloatValidator::Predicate(_) => quote!(#error_type_name::PredicateViolated),
+                FloatValidator::WithResult(_) => quote!(#error_type_name::WithResultViolated(_)),
+            };
+            quote!(#pattern => #constraint)
+        })
+        .collect()
+}
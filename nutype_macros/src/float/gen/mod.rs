@@ -5,6 +5,7 @@ use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use self::error::gen_validation_error_type;
 use super::{
@@ -15,14 +16,20 @@ use crate::{
     common::{
         gen::{
             error::gen_error_type_name,
+            gen_alloc_crate, gen_call_custom_validation_fn, gen_call_fallible_sanitizer,
+            gen_context_param, gen_impl_numeric_bound_consts, gen_sanitize_output,
+            gen_validation_checks,
             tests::{
                 gen_test_should_have_consistent_lower_and_upper_boundaries,
                 gen_test_should_have_valid_default_value,
             },
             traits::GeneratedTraits,
-            GenerateNewtype,
+            DeclaredBound, GenerateNewtype,
+        },
+        models::{
+            has_fallible_sanitizers, ConstructorName, EqMode, ErrorTypeName, Guard, OrdMode,
+            SerdeRepr, TypeName,
         },
-        models::{ErrorTypeName, Guard, TypeName},
     },
     float::models::FloatInnerType,
 };
@@ -49,16 +56,24 @@ where
                         value = (#custom_sanitizer)(value);
                     )
                 }
+                FloatSanitizer::TryWith(custom_sanitizer) => {
+                    let call = gen_call_fallible_sanitizer(custom_sanitizer);
+                    quote!(
+                        value = #call;
+                    )
+                }
                 FloatSanitizer::_Phantom(_) => {
                     unreachable!("float::gen FloatSanitizer::_Phantom must not be used")
                 }
             })
             .collect();
+        let (output_type, output_value) =
+            gen_sanitize_output(inner_type, has_fallible_sanitizers(sanitizers));
 
         quote!(
-            fn sanitize(mut value: #inner_type) -> #inner_type {
+            fn sanitize(mut value: #inner_type) -> #output_type {
                 #transformations
-                value
+                #output_value
             }
         )
     }
@@ -67,10 +82,13 @@ where
         inner_type: &Self::InnerType,
         type_name: &TypeName,
         validators: &[Self::Validator],
+        collect_errors: bool,
+        maybe_context: Option<&syn::Type>,
     ) -> TokenStream {
         let error_name = gen_error_type_name(type_name);
+        let context_param = gen_context_param(maybe_context);
 
-        let validations: TokenStream = validators
+        let checks: Vec<TokenStream> = validators
             .iter()
             .map(|validator| match validator {
                 FloatValidator::Less(exclusive_upper_bound) => {
@@ -102,12 +120,35 @@ where
                     )
                 }
                 FloatValidator::Predicate(custom_is_valid_fn) => {
+                    let is_valid = gen_call_custom_validation_fn(
+                        custom_is_valid_fn,
+                        quote!(&val),
+                        quote!(&#inner_type),
+                        quote!(bool),
+                        maybe_context,
+                    );
                     quote!(
-                        if !(#custom_is_valid_fn)(&val) {
+                        if !#is_valid {
                             return Err(#error_name::PredicateViolated);
                         }
                     )
                 }
+                FloatValidator::WithResult(custom_validate_fn) => {
+                    let alloc = gen_alloc_crate();
+                    let result = gen_call_custom_validation_fn(
+                        custom_validate_fn,
+                        quote!(&val),
+                        quote!(&#inner_type),
+                        quote!(::core::result::Result<(), #alloc::string::String>),
+                        maybe_context,
+                    );
+                    quote!(
+                        let result: ::core::result::Result<(), #alloc::string::String> = #result;
+                        if let Err(message) = result {
+                            return Err(#error_name::WithResultViolated(message));
+                        }
+                    )
+                }
                 FloatValidator::Finite => {
                     quote!(
                         if !val.is_finite() {
@@ -117,9 +158,10 @@ where
                 }
             })
             .collect();
+        let validations = gen_validation_checks(&error_name, checks, collect_errors);
 
         quote!(
-            fn validate(val: &#inner_type) -> core::result::Result<(), #error_name> {
+            fn validate(val: &#inner_type #context_param) -> core::result::Result<(), #error_name> {
                 let val = *val;
                 #validations
                 Ok(())
@@ -129,31 +171,84 @@ where
 
     fn gen_validation_error_type(
         type_name: &TypeName,
-        validators: &[Self::Validator],
+        validators: &[&Self::Validator],
+        non_exhaustive_error: bool,
+        collect_errors: bool,
+        has_fallible_sanitizers: bool,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(
+            type_name,
+            validators,
+            non_exhaustive_error,
+            collect_errors,
+            has_fallible_sanitizers,
+        )
     }
 
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
         inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        maybe_display_format: Option<syn::LitStr>,
+        _eq: EqMode,
+        _ord: OrdMode,
+        serde_repr: &SerdeRepr,
+        _serde_lenient: bool,
         guard: &FloatGuard<T>,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
+            generics,
+            constructor_name,
             inner_type,
             maybe_error_type_name,
             maybe_default_value,
+            maybe_display_format,
             traits,
+            serde_repr,
             guard,
         )
     }
 
+    fn gen_impl_bound_consts(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> TokenStream {
+        let validators = guard.validators().map(Vec::as_slice).unwrap_or_default();
+        let lower = validators.iter().find_map(|validator| match validator {
+            FloatValidator::Greater(bound) => Some(DeclaredBound {
+                value: quote!(#bound),
+                is_exclusive: true,
+            }),
+            FloatValidator::GreaterOrEqual(bound) => Some(DeclaredBound {
+                value: quote!(#bound),
+                is_exclusive: false,
+            }),
+            _ => None,
+        });
+        let upper = validators.iter().find_map(|validator| match validator {
+            FloatValidator::Less(bound) => Some(DeclaredBound {
+                value: quote!(#bound),
+                is_exclusive: true,
+            }),
+            FloatValidator::LessOrEqual(bound) => Some(DeclaredBound {
+                value: quote!(#bound),
+                is_exclusive: false,
+            }),
+            _ => None,
+        });
+        gen_impl_numeric_bound_consts(type_name, generics, inner_type, lower, upper)
+    }
+
     fn gen_tests(
         type_name: &TypeName,
+        constructor_name: &ConstructorName,
         _inner_type: &Self::InnerType,
         maybe_default_value: &Option<syn::Expr>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
@@ -165,6 +260,7 @@ where
 
         let test_valid_default_value = gen_test_should_have_valid_default_value(
             type_name,
+            constructor_name,
             maybe_default_value,
             guard.has_validation(),
         );
@@ -2,7 +2,7 @@ use proc_macro2::{Span, TokenStream};
 use quote::{quote, ToTokens};
 
 use crate::{
-    common::models::TypeName,
+    common::models::{ConstructorName, TypeName},
     float::models::{
         FloatGuard, FloatInnerType, FloatSanitizer, FloatSanitizerKind, FloatValidator,
         FloatValidatorKind,
@@ -12,6 +12,7 @@ use crate::{
 
 pub fn gen_impl_trait_arbitrary<T: ToTokens>(
     type_name: &TypeName,
+    constructor_name: &ConstructorName,
     inner_type: &FloatInnerType,
     guard: &FloatGuard<T>,
 ) -> Result<TokenStream, syn::Error> {
@@ -23,13 +24,13 @@ pub fn gen_impl_trait_arbitrary<T: ToTokens>(
             });
         let type_name = type_name.to_string();
         quote!(
-            Self::new(inner_value).unwrap_or_else(|err| {
+            Self::#constructor_name(inner_value).unwrap_or_else(|err| {
                 // Panic with the maximum details about what went wrong
                 panic!("\nArbitrary generated an invalid value for {}.\nInvalid inner value: {:?}\nValidation error: {:?}\n\n{}", #type_name, inner_value, err, #report_issue_msg);
             })
         )
     } else {
-        quote!(Self::new(inner_value))
+        quote!(Self::#constructor_name(inner_value))
     };
 
     let generate_inner_value = gen_generate_valid_inner_value(inner_type, guard)?;
@@ -66,6 +67,7 @@ fn gen_generate_valid_inner_value<T: ToTokens>(
         FloatGuard::WithValidation {
             sanitizers,
             validators,
+            raw_validators: _,
         } => {
             // When there is validation, then we need to generate a valid value.
             gen_generate_valid_inner_value_with_validators(inner_type, sanitizers, validators)
@@ -86,6 +88,11 @@ fn gen_generate_valid_inner_value_with_validators<T: ToTokens>(
         let msg = "It's not possible to derive `Arbitrary` trait for a type with `predicate` validator.\nYou have to implement `Arbitrary` trait on you own.";
         return Err(syn::Error::new(span, msg));
     }
+    if validator_kinds.contains(&FloatValidatorKind::WithResult) {
+        let span = Span::call_site();
+        let msg = "It's not possible to derive `Arbitrary` trait for a type with `with_result` validator.\nYou have to implement `Arbitrary` trait on you own.";
+        return Err(syn::Error::new(span, msg));
+    }
     if sanitizer_kinds.contains(&FloatSanitizerKind::With) {
         let span = Span::call_site();
         let msg = "It's not possible to derive `Arbitrary` trait for a type with `with` sanitizer and validations.\nYou have to implement `Arbitrary` trait on you own.";
@@ -309,7 +316,9 @@ fn compute_boundaries<T: ToTokens>(validators: &[FloatValidator<T>]) -> Boundari
                     is_inclusive,
                 });
             }
-            FloatValidator::Finite | FloatValidator::Predicate(..) => {
+            FloatValidator::Finite
+            | FloatValidator::Predicate(..)
+            | FloatValidator::WithResult(..) => {
                 // We don't care about these validators here.
             }
         }
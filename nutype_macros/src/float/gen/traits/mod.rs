@@ -1,21 +1,30 @@
 pub mod arbitrary;
+pub mod num_traits;
+pub mod proptest;
+mod rand;
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::{
         gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+            gen_impl_checked_arith, gen_impl_trait_as_ref, gen_impl_trait_bincode_decode,
+            gen_impl_trait_bincode_encode, gen_impl_trait_borrow, gen_impl_trait_default,
+            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_eq_inner,
+            gen_impl_trait_from, gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_neg,
+            gen_impl_trait_nutype, gen_impl_trait_ord_inner, gen_impl_trait_product,
+            gen_impl_trait_redis_from_redis_value, gen_impl_trait_redis_to_redis_args,
+            gen_impl_trait_rkyv, gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+            gen_impl_trait_sqlx_json, gen_impl_trait_sum, gen_impl_trait_try_from,
+            gen_rkyv_archived_primitive, split_into_generatable_traits, GeneratableTrait,
+            GeneratableTraits, GeneratedTraits,
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ConstructorName, ErrorTypeName, SerdeRepr, TypeName},
     },
-    float::models::{FloatDeriveTrait, FloatGuard, FloatInnerType},
+    float::models::{FloatDeriveTrait, FloatGuard, FloatInnerType, FloatValidator},
 };
 
 type FloatGeneratableTrait = GeneratableTrait<FloatTransparentTrait, FloatIrregularTrait>;
@@ -39,17 +48,36 @@ enum FloatIrregularTrait {
     FromStr,
     AsRef,
     Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
+    Sum,
+    Product,
+    CheckedArith,
+    Neg,
     Into,
     From,
     Eq,
     Ord,
+    Hash,
     TryFrom,
     Borrow,
     Display,
     Default,
     SerdeSerialize,
     SerdeDeserialize,
+    RkyvArchive,
+    SqlxJson,
+    RedisToRedisArgs,
+    RedisFromRedisValue,
+    BincodeEncode,
+    BincodeDecode,
+    NumTraitsZero,
+    NumTraitsOne,
+    NumTraitsBounded,
     ArbitraryArbitrary,
+    ProptestArbitrary,
+    RandDistribution,
 }
 
 impl From<FloatDeriveTrait> for FloatGeneratableTrait {
@@ -72,10 +100,28 @@ impl From<FloatDeriveTrait> for FloatGeneratableTrait {
                 FloatGeneratableTrait::Transparent(FloatTransparentTrait::PartialOrd)
             }
             FloatDeriveTrait::Ord => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Ord),
+            FloatDeriveTrait::Hash => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Hash),
             FloatDeriveTrait::FromStr => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::FromStr)
             }
             FloatDeriveTrait::AsRef => FloatGeneratableTrait::Irregular(FloatIrregularTrait::AsRef),
+            FloatDeriveTrait::Nutype => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::Nutype)
+            }
+            FloatDeriveTrait::Sum => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Sum),
+            FloatDeriveTrait::Product => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::Product)
+            }
+            FloatDeriveTrait::CheckedArith => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::CheckedArith)
+            }
+            FloatDeriveTrait::Neg => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Neg),
+            FloatDeriveTrait::EqInner => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::EqInner)
+            }
+            FloatDeriveTrait::OrdInner => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::OrdInner)
+            }
             FloatDeriveTrait::Deref => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Deref),
             FloatDeriveTrait::From => FloatGeneratableTrait::Irregular(FloatIrregularTrait::From),
             FloatDeriveTrait::Into => FloatGeneratableTrait::Irregular(FloatIrregularTrait::Into),
@@ -94,12 +140,45 @@ impl From<FloatDeriveTrait> for FloatGeneratableTrait {
             FloatDeriveTrait::SerdeSerialize => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::SerdeSerialize)
             }
+            FloatDeriveTrait::RedisFromRedisValue => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::RedisFromRedisValue)
+            }
+            FloatDeriveTrait::BincodeEncode => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::BincodeEncode)
+            }
+            FloatDeriveTrait::BincodeDecode => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::BincodeDecode)
+            }
+            FloatDeriveTrait::RedisToRedisArgs => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::RedisToRedisArgs)
+            }
+            FloatDeriveTrait::NumTraitsZero => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsZero)
+            }
+            FloatDeriveTrait::NumTraitsOne => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsOne)
+            }
+            FloatDeriveTrait::NumTraitsBounded => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::NumTraitsBounded)
+            }
+            FloatDeriveTrait::SqlxJson => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::SqlxJson)
+            }
+            FloatDeriveTrait::RkyvArchive => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::RkyvArchive)
+            }
             FloatDeriveTrait::SerdeDeserialize => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::SerdeDeserialize)
             }
             FloatDeriveTrait::ArbitraryArbitrary => {
                 FloatGeneratableTrait::Irregular(FloatIrregularTrait::ArbitraryArbitrary)
             }
+            FloatDeriveTrait::ProptestArbitrary => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::ProptestArbitrary)
+            }
+            FloatDeriveTrait::RandDistribution => {
+                FloatGeneratableTrait::Irregular(FloatIrregularTrait::RandDistribution)
+            }
             FloatDeriveTrait::SchemarsJsonSchema => {
                 FloatGeneratableTrait::Transparent(FloatTransparentTrait::SchemarsJsonSchema)
             }
@@ -125,12 +204,17 @@ impl ToTokens for FloatTransparentTrait {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_traits<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
     inner_type: &FloatInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_display_format: Option<syn::LitStr>,
     traits: HashSet<FloatDeriveTrait>,
+    serde_repr: &SerdeRepr,
     guard: &FloatGuard<T>,
 ) -> Result<GeneratedTraits, syn::Error> {
     let GeneratableTraits {
@@ -146,10 +230,14 @@ pub fn gen_traits<T: ToTokens>(
 
     let implement_traits = gen_implemented_traits(
         type_name,
+        generics,
+        constructor_name,
         inner_type,
         maybe_error_type_name,
         maybe_default_value,
+        maybe_display_format,
         irregular_traits,
+        serde_repr,
         guard,
     )?;
 
@@ -159,33 +247,52 @@ pub fn gen_traits<T: ToTokens>(
     })
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_implemented_traits<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
     inner_type: &FloatInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_display_format: Option<syn::LitStr>,
     impl_traits: Vec<FloatIrregularTrait>,
+    serde_repr: &SerdeRepr,
     guard: &FloatGuard<T>,
 ) -> Result<TokenStream, syn::Error> {
     impl_traits
         .iter()
         .map(|t| match t {
-            FloatIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, inner_type)),
-            FloatIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
+            FloatIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            FloatIrregularTrait::Nutype => Ok(gen_impl_trait_nutype(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            FloatIrregularTrait::EqInner => Ok(gen_impl_trait_eq_inner(type_name, inner_type)),
+            FloatIrregularTrait::OrdInner => Ok(gen_impl_trait_ord_inner(type_name, inner_type)),
+            FloatIrregularTrait::Sum => Ok(gen_impl_trait_sum(type_name, constructor_name, inner_type)),
+            FloatIrregularTrait::Product => Ok(gen_impl_trait_product(type_name, constructor_name, inner_type)),
+            FloatIrregularTrait::Neg => Ok(gen_impl_trait_neg(type_name, constructor_name, maybe_error_type_name.is_some(), false)),
+            FloatIrregularTrait::CheckedArith => Ok(gen_impl_checked_arith(
+                type_name,
+                constructor_name,
+                inner_type,
+                gen_checked_float_op(quote!(+)),
+                gen_checked_float_op(quote!(-)),
+                maybe_error_type_name.is_some(),
+            )),
+            FloatIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
             FloatIrregularTrait::FromStr => {
-                Ok(gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref()))
+                Ok(gen_impl_trait_from_str(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref()))
             }
-            FloatIrregularTrait::From => Ok(gen_impl_trait_from(type_name, inner_type)),
-            FloatIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, inner_type)),
+            FloatIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, constructor_name, inner_type)),
+            FloatIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type)),
             FloatIrregularTrait::TryFrom => {
-                Ok(gen_impl_trait_try_from(type_name, inner_type, maybe_error_type_name.as_ref()))
+                Ok(gen_impl_trait_try_from(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref()))
             }
-            FloatIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, inner_type)),
-            FloatIrregularTrait::Display => Ok(gen_impl_trait_display(type_name)),
+            FloatIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, generics, inner_type)),
+            FloatIrregularTrait::Display => Ok(gen_impl_trait_display(type_name, generics, maybe_display_format.as_ref())),
             FloatIrregularTrait::Default => match maybe_default_value {
                 Some(ref default_value) => {
                     let has_validation = maybe_error_type_name.is_some();
-                    Ok(gen_impl_trait_default(type_name, default_value, has_validation))
+                    Ok(gen_impl_trait_default(type_name, constructor_name, default_value, has_validation))
                 }
                 None => {
                     let span = proc_macro2::Span::call_site();
@@ -193,16 +300,28 @@ fn gen_implemented_traits<T: ToTokens>(
                     Err(syn::Error::new(span, msg))
                 }
             },
-            FloatIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name)),
-            FloatIrregularTrait::SerdeDeserialize => Ok(gen_impl_trait_serde_deserialize(
-                type_name,
-                inner_type,
-                maybe_error_type_name.as_ref(),
-            )),
+            FloatIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name, serde_repr)),
+            FloatIrregularTrait::BincodeEncode => Ok(gen_impl_trait_bincode_encode(type_name)),
+            FloatIrregularTrait::BincodeDecode => Ok(gen_impl_trait_bincode_decode(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            FloatIrregularTrait::RedisFromRedisValue => Ok(gen_impl_trait_redis_from_redis_value(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            FloatIrregularTrait::RedisToRedisArgs => Ok(gen_impl_trait_redis_to_redis_args(type_name)),
+            FloatIrregularTrait::NumTraitsZero => num_traits::gen_impl_trait_num_traits_zero(type_name, constructor_name, inner_type, guard),
+            FloatIrregularTrait::NumTraitsOne => num_traits::gen_impl_trait_num_traits_one(type_name, constructor_name, inner_type, guard),
+            FloatIrregularTrait::NumTraitsBounded => num_traits::gen_impl_trait_num_traits_bounded(type_name, constructor_name, inner_type, guard),
+            FloatIrregularTrait::SqlxJson => Ok(gen_impl_trait_sqlx_json(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            FloatIrregularTrait::RkyvArchive => Ok(gen_impl_trait_rkyv(type_name, constructor_name, inner_type, gen_rkyv_archived_primitive(inner_type), maybe_error_type_name.as_ref())),
+            FloatIrregularTrait::SerdeDeserialize => Ok(gen_impl_trait_serde_deserialize(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref(), serde_repr)),
             FloatIrregularTrait::Eq => Ok(gen_impl_trait_eq(type_name)),
             FloatIrregularTrait::Ord => Ok(gen_impl_trait_ord(type_name)),
+            FloatIrregularTrait::Hash => Ok(gen_impl_trait_hash(type_name, inner_type)),
             FloatIrregularTrait::ArbitraryArbitrary => {
-                arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard)
+                arbitrary::gen_impl_trait_arbitrary(type_name, constructor_name, inner_type, guard)
+            }
+            FloatIrregularTrait::ProptestArbitrary => {
+                proptest::gen_impl_trait_proptest_arbitrary(type_name, constructor_name, inner_type, guard)
+            }
+            FloatIrregularTrait::RandDistribution => {
+                rand::gen_impl_trait_rand_distribution(type_name, constructor_name, inner_type, guard)
             }
         })
         .collect()
@@ -234,3 +353,57 @@ fn gen_impl_trait_ord(type_name: &TypeName) -> TokenStream {
         }
     }
 }
+
+// The bit pattern of a float is hashed. `NaN` values are excluded by `finite` validation rule,
+// and `-0.0` is hashed as `0.0`, because they are equal, so `Hash` is consistent with `PartialEq`.
+// Without `finite` validation deriving `Hash` is not allowed.
+fn gen_impl_trait_hash(type_name: &TypeName, inner_type: &FloatInnerType) -> TokenStream {
+    quote! {
+        impl ::core::hash::Hash for #type_name {
+            fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                let value: #inner_type = if self.0 == 0.0 { 0.0 } else { self.0 };
+                value.to_bits().hash(state);
+            }
+        }
+    }
+}
+
+/// Get the lower and the upper boundaries set by the validators (inclusive or exclusive).
+fn guard_to_bounds<T: ToTokens>(
+    guard: &FloatGuard<T>,
+) -> (Option<TokenStream>, Option<TokenStream>) {
+    let mut lower = None;
+    let mut upper = None;
+
+    if let FloatGuard::WithValidation { validators, .. } = guard {
+        for validator in validators {
+            match validator {
+                FloatValidator::Greater(value) | FloatValidator::GreaterOrEqual(value) => {
+                    lower = Some(quote!(#value));
+                }
+                FloatValidator::Less(value) | FloatValidator::LessOrEqual(value) => {
+                    upper = Some(quote!(#value));
+                }
+                FloatValidator::Finite
+                | FloatValidator::Predicate(_)
+                | FloatValidator::WithResult(_) => {}
+            }
+        }
+    }
+
+    (lower, upper)
+}
+
+/// Floats do not overflow, but turn into infinity instead. So the result is considered
+/// an overflow when it's not finite while both operands are.
+fn gen_checked_float_op(op: TokenStream) -> TokenStream {
+    quote!({
+        let (lhs, rhs) = (self.0, rhs.0);
+        let value = lhs #op rhs;
+        if value.is_finite() || !lhs.is_finite() || !rhs.is_finite() {
+            Some(value)
+        } else {
+            None
+        }
+    })
+}
@@ -0,0 +1,140 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::{
+        gen::traits::{
+            gen_const_assert_valid_value, gen_impl_trait_num_traits_bounded_from_values,
+            gen_impl_trait_num_traits_one_from_value, gen_impl_trait_num_traits_zero_from_value,
+        },
+        models::{ConstructorName, TypeName},
+    },
+    float::models::{FloatGuard, FloatInnerType, FloatValidator},
+};
+
+pub fn gen_impl_trait_num_traits_zero<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let zero = quote!(0.0);
+    let check = gen_check_valid_value(type_name, "Zero", inner_type, &zero, guard)?;
+    let implementation = gen_impl_trait_num_traits_zero_from_value(
+        type_name,
+        constructor_name,
+        zero,
+        guard.has_validation(),
+    );
+    Ok(quote!(
+        #check
+        #implementation
+    ))
+}
+
+pub fn gen_impl_trait_num_traits_one<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let one = quote!(1.0);
+    let check = gen_check_valid_value(type_name, "One", inner_type, &one, guard)?;
+    let implementation = gen_impl_trait_num_traits_one_from_value(
+        type_name,
+        constructor_name,
+        one,
+        guard.has_validation(),
+    );
+    Ok(quote!(
+        #check
+        #implementation
+    ))
+}
+
+/// `min_value()` and `max_value()` are the boundaries set by the validators,
+/// or the finite boundaries of the inner type if there are none.
+/// There is no closest value to an exclusive boundary, so `greater` and `less` are rejected.
+pub fn gen_impl_trait_num_traits_bounded<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let mut min = quote!(#inner_type::MIN);
+    let mut max = quote!(#inner_type::MAX);
+
+    if let FloatGuard::WithValidation { validators, .. } = guard {
+        for validator in validators {
+            match validator {
+                FloatValidator::GreaterOrEqual(gte) => min = quote!(#gte),
+                FloatValidator::LessOrEqual(lte) => max = quote!(#lte),
+                FloatValidator::Greater(_) | FloatValidator::Less(_) => {
+                    let msg = format!(
+                        "Cannot derive `Bounded` for a float based type with `{}` validator, because an exclusive boundary is not a valid value.\nUse `greater_or_equal` or `less_or_equal` instead.",
+                        validator.kind()
+                    );
+                    return Err(syn::Error::new(Span::call_site(), msg));
+                }
+                FloatValidator::Finite
+                | FloatValidator::Predicate(_)
+                | FloatValidator::WithResult(_) => {}
+            }
+        }
+    }
+
+    let check_min = gen_check_valid_value(type_name, "Bounded", inner_type, &min, guard)?;
+    let check_max = gen_check_valid_value(type_name, "Bounded", inner_type, &max, guard)?;
+    let implementation = gen_impl_trait_num_traits_bounded_from_values(
+        type_name,
+        constructor_name,
+        min,
+        max,
+        guard.has_validation(),
+    );
+    Ok(quote!(
+        #check_min
+        #check_max
+        #implementation
+    ))
+}
+
+/// Verify at compile time that `value` passes the validation.
+/// Custom validators cannot be evaluated at compile time, so deriving the trait is rejected.
+fn gen_check_valid_value<T: ToTokens>(
+    type_name: &TypeName,
+    trait_name: &str,
+    inner_type: &FloatInnerType,
+    value: &TokenStream,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let FloatGuard::WithValidation { validators, .. } = guard else {
+        return Ok(quote!());
+    };
+
+    let conditions = validators
+        .iter()
+        .map(|validator| match validator {
+            FloatValidator::Greater(gt) => Ok(quote!(val > #gt)),
+            FloatValidator::GreaterOrEqual(gte) => Ok(quote!(val >= #gte)),
+            FloatValidator::Less(lt) => Ok(quote!(val < #lt)),
+            FloatValidator::LessOrEqual(lte) => Ok(quote!(val <= #lte)),
+            FloatValidator::Finite => Ok(quote!(val.is_finite())),
+            FloatValidator::Predicate(_) | FloatValidator::WithResult(_) => {
+                let msg = format!(
+                    "Cannot derive `{trait_name}` for a type with `{}` validator, because it's not possible to verify at compile time that `{value}` passes it.",
+                    validator.kind()
+                );
+                Err(syn::Error::new(Span::call_site(), msg))
+            }
+        })
+        .collect::<Result<Vec<_>, syn::Error>>()?;
+
+    let msg = format!("`{trait_name}` cannot be derived for `{type_name}`, because `{value}` does not pass the validation");
+    Ok(gen_const_assert_valid_value(
+        inner_type,
+        value,
+        &conditions,
+        &msg,
+    ))
+}
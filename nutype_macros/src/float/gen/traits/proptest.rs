@@ -0,0 +1,44 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::{
+        gen::traits::gen_impl_trait_proptest_arbitrary_from_strategy,
+        models::{ConstructorName, TypeName},
+    },
+    float::models::{FloatGuard, FloatInnerType},
+};
+
+use super::guard_to_bounds;
+
+pub fn gen_impl_trait_proptest_arbitrary<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let inner_strategy = gen_inner_strategy(inner_type, guard);
+    Ok(gen_impl_trait_proptest_arbitrary_from_strategy(
+        type_name,
+        constructor_name,
+        inner_strategy,
+        guard.has_validation(),
+    ))
+}
+
+/// Generate a strategy that produces values within the boundaries set by the validators.
+/// Exclusive boundaries, `finite` and `predicate` validators are respected by filtering.
+fn gen_inner_strategy<T: ToTokens>(
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> TokenStream {
+    let (lower, upper) = guard_to_bounds(guard);
+
+    if lower.is_none() && upper.is_none() {
+        return quote!(::proptest::arbitrary::any::<#inner_type>());
+    }
+
+    let lower = lower.unwrap_or_else(|| quote!(#inner_type::MIN));
+    let upper = upper.unwrap_or_else(|| quote!(#inner_type::MAX));
+    quote!(::core::ops::RangeInclusive::<#inner_type>::new(#lower, #upper))
+}
@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::{
+        gen::traits::gen_impl_trait_rand_distribution_from_sampler,
+        models::{ConstructorName, TypeName},
+    },
+    float::models::{FloatGuard, FloatInnerType},
+};
+
+use super::guard_to_bounds;
+
+pub fn gen_impl_trait_rand_distribution<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &FloatInnerType,
+    guard: &FloatGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let (lower, upper) = guard_to_bounds(guard);
+    let lower = lower.unwrap_or_else(|| quote!(#inner_type::MIN));
+    let upper = upper.unwrap_or_else(|| quote!(#inner_type::MAX));
+
+    // Interpolate between the boundaries instead of computing `upper - lower`,
+    // which overflows to infinity for wide ranges (e.g. `MIN..MAX`).
+    // Exclusive boundaries, `finite` and `predicate` validators are respected by rejection.
+    let sample_inner = quote!({
+        let lower: #inner_type = #lower;
+        let upper: #inner_type = #upper;
+        let t: #inner_type = rng.gen();
+        lower * (1.0 - t) + upper * t
+    });
+    Ok(gen_impl_trait_rand_distribution_from_sampler(
+        type_name,
+        constructor_name,
+        inner_type,
+        sample_inner,
+        guard.has_validation(),
+    ))
+}
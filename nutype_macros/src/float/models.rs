@@ -2,8 +2,8 @@ use kinded::Kinded;
 use proc_macro2::TokenStream;
 
 use crate::common::models::{
-    impl_numeric_bound_on_vec_of, impl_numeric_bound_validator, Guard, RawGuard, SpannedItem,
-    TypeTrait, TypedCustomFunction, ValueOrExpr,
+    impl_numeric_bound_on_vec_of, impl_numeric_bound_validator, FallibleSanitizer, Guard, RawGuard,
+    SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
 };
 
 // Sanitizer
@@ -13,11 +13,18 @@ use crate::common::models::{
 #[kinded(display = "snake_case", derive(Hash))]
 pub enum FloatSanitizer<T> {
     With(TypedCustomFunction),
+    TryWith(TypedCustomFunction),
     _Phantom(std::marker::PhantomData<T>),
 }
 
 pub type SpannedFloatSanitizer<T> = SpannedItem<FloatSanitizer<T>>;
 
+impl<T> FallibleSanitizer for FloatSanitizer<T> {
+    fn is_fallible(&self) -> bool {
+        matches!(self, Self::TryWith(_))
+    }
+}
+
 // Validator
 //
 
@@ -29,6 +36,7 @@ pub enum FloatValidator<T> {
     Less(ValueOrExpr<T>),
     LessOrEqual(ValueOrExpr<T>),
     Predicate(TypedCustomFunction),
+    WithResult(TypedCustomFunction),
     Finite,
 }
 
@@ -49,6 +57,7 @@ pub enum FloatDeriveTrait {
     Eq,
     PartialOrd,
     Ord,
+    Hash,
     FromStr,
     AsRef,
     Into,
@@ -58,12 +67,30 @@ pub enum FloatDeriveTrait {
     Display,
     Default,
     Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
+    Sum,
+    Product,
+    CheckedArith,
+    Neg,
 
     // External crates
     SerdeSerialize,
     SerdeDeserialize,
+    RkyvArchive,
+    SqlxJson,
+    RedisToRedisArgs,
+    RedisFromRedisValue,
+    BincodeEncode,
+    BincodeDecode,
+    NumTraitsZero,
+    NumTraitsOne,
+    NumTraitsBounded,
     SchemarsJsonSchema,
     ArbitraryArbitrary,
+    ProptestArbitrary,
+    RandDistribution,
     DieselNewType,
 }
 
@@ -71,12 +98,21 @@ impl TypeTrait for FloatDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &FloatDeriveTrait::FromStr
     }
+
+    fn is_diesel_new_type(&self) -> bool {
+        self == &FloatDeriveTrait::DieselNewType
+    }
+
+    fn is_debug(&self) -> bool {
+        self == &FloatDeriveTrait::Debug
+    }
 }
 
 pub type FloatRawGuard<T> = RawGuard<SpannedFloatSanitizer<T>, SpannedFloatValidator<T>>;
 pub type FloatGuard<T> = Guard<FloatSanitizer<T>, FloatValidator<T>>;
 
 pub trait FloatType {
+    #[allow(dead_code)]
     fn float_inner_type() -> FloatInnerType;
 }
 
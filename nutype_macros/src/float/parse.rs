@@ -7,7 +7,12 @@ use crate::common::{
     models::{Attributes, SpannedDeriveTrait},
     parse::{
         parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function,
-        parse_validator_kind, ParseableAttributes,
+        parse_validator_kind, ParseValidators, ParseableAttributes,
+    },
+    validate::{
+        validate_enumerate_is_not_set, validate_eq_mode_is_not_set,
+        validate_from_encoding_is_not_set, validate_ord_mode_is_not_set,
+        validate_raw_validators_are_not_set, validate_serde_lenient_is_not_set,
     },
 };
 use proc_macro2::TokenStream;
@@ -37,10 +42,39 @@ where
     let ParseableAttributes {
         sanitizers,
         validators,
+        raw_validators,
         new_unchecked,
         default,
+        non_exhaustive_error,
+        collect_errors,
+        allow_unused,
+        repr_transparent,
+        enumerate,
+        display,
+        new_fn,
+        inner_vis,
+        eq,
+        ord,
+        serde_repr,
+        schema_example,
+        from_env,
+        from_encoding,
+        sanitize_then_return_changed,
+        transparent_debug,
+        skip_new,
+        serde_lenient,
+        builder,
+        crate_path,
+        context,
+        async_validators,
         derive_traits,
     } = attrs;
+    let eq = validate_eq_mode_is_not_set(eq)?;
+    let ord = validate_ord_mode_is_not_set(ord)?;
+    validate_raw_validators_are_not_set(&raw_validators)?;
+    let from_encoding = validate_from_encoding_is_not_set(from_encoding)?;
+    let enumerate = validate_enumerate_is_not_set(enumerate)?;
+    let serde_lenient = validate_serde_lenient_is_not_set(serde_lenient)?;
     let raw_guard = FloatRawGuard {
         sanitizers,
         validators,
@@ -50,10 +84,39 @@ where
         new_unchecked,
         guard,
         default,
+        non_exhaustive_error,
+        collect_errors,
+        allow_unused,
+        repr_transparent: repr_transparent.is_some(),
+        enumerate,
+        display,
+        new_fn,
+        inner_vis,
+        eq,
+        ord,
+        serde_repr,
+        schema_example,
+        from_env,
+        from_encoding,
+        sanitize_then_return_changed,
+        transparent_debug,
+        skip_new,
+        serde_lenient,
+        builder,
+        crate_path,
+        context,
+        async_validators,
         derive_traits,
     })
 }
 
+impl<T> ParseValidators for SpannedFloatValidator<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+}
+
 impl<T> Parse for SpannedFloatValidator<T>
 where
     T: FromStr,
@@ -103,6 +166,14 @@ where
                     span,
                 })
             }
+            FloatValidatorKind::WithResult => {
+                let _eq: Token![=] = input.parse()?;
+                let (typed_custom_function, span) = parse_typed_custom_function::<&T>(input)?;
+                Ok(SpannedFloatValidator {
+                    item: FloatValidator::WithResult(typed_custom_function),
+                    span,
+                })
+            }
             FloatValidatorKind::Finite => {
                 let validator = FloatValidator::Finite;
                 Ok(SpannedFloatValidator {
@@ -131,6 +202,14 @@ where
                     span,
                 })
             }
+            FloatSanitizerKind::TryWith => {
+                let _eq: Token![=] = input.parse()?;
+                let (typed_custom_function, span) = parse_typed_custom_function::<T>(input)?;
+                Ok(SpannedFloatSanitizer {
+                    item: FloatSanitizer::TryWith(typed_custom_function),
+                    span,
+                })
+            }
             FloatSanitizerKind::_Phantom => {
                 let msg = format!("Unknown validator `{ident}`");
                 Err(syn::Error::new(ident.span(), msg))
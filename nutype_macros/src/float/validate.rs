@@ -1,9 +1,10 @@
 use proc_macro2::Span;
+use quote::ToTokens;
 use std::collections::HashSet;
 
 use crate::common::{
-    models::{DeriveTrait, SpannedDeriveTrait},
-    validate::{validate_duplicates, validate_numeric_bounds},
+    models::{has_fallible_sanitizers, DeriveTrait, SpannedDeriveTrait},
+    validate::{are_bounds_symmetric, validate_duplicates, validate_numeric_bounds},
 };
 
 use super::models::{
@@ -23,12 +24,13 @@ where
     let validators = validate_validators(validators)?;
     let sanitizers = validate_sanitizers(sanitizers)?;
 
-    if validators.is_empty() {
+    if validators.is_empty() && !has_fallible_sanitizers(&sanitizers) {
         Ok(FloatGuard::WithoutValidation { sanitizers })
     } else {
         Ok(FloatGuard::WithValidation {
             sanitizers,
             validators,
+            raw_validators: Vec::new(),
         })
     }
 }
@@ -91,7 +93,7 @@ impl ValidationInfo {
     }
 }
 
-pub fn validate_float_derive_traits<T>(
+pub fn validate_float_derive_traits<T: ToTokens>(
     spanned_derive_traits: Vec<SpannedDeriveTrait>,
     guard: &FloatGuard<T>,
 ) -> Result<HashSet<FloatDeriveTrait>, syn::Error> {
@@ -100,12 +102,26 @@ pub fn validate_float_derive_traits<T>(
     let mut traits = HashSet::with_capacity(24);
 
     for spanned_trait in spanned_derive_traits.iter() {
+        if spanned_trait.item == DeriveTrait::Neg {
+            validate_neg(guard, spanned_trait.span)?;
+        }
         let normal_trait = spanned_trait.item;
         let string_derive_trait =
             to_float_derive_trait(normal_trait, validation, spanned_trait.span)?;
         traits.insert(string_derive_trait);
     }
 
+    // `From` already provides `TryFrom<Inner>` with `Infallible` error through the blanket
+    // implementation in `core`, so deriving both must not generate a conflicting implementation.
+    if !validation.has_validation && traits.contains(&FloatDeriveTrait::From) {
+        traits.remove(&FloatDeriveTrait::TryFrom);
+    }
+
+    // `PartialOrd<Inner>` requires `PartialEq<Inner>`, so `OrdInner` brings `EqInner` along.
+    if traits.contains(&FloatDeriveTrait::OrdInner) {
+        traits.insert(FloatDeriveTrait::EqInner);
+    }
+
     // Get a span of a given trait, so we can render a better message below
     // when we validate inter trait dependencies.
     let get_span_for = |needle: DeriveTrait| -> Span {
@@ -144,6 +160,40 @@ pub fn validate_float_derive_traits<T>(
     Ok(traits)
 }
 
+/// `Neg` cannot fail, so with validation it's derived only if a negated valid value is
+/// valid as well: the bounds must be symmetric. `finite` does not care about the sign.
+fn validate_neg<T: ToTokens>(guard: &FloatGuard<T>, span: Span) -> Result<(), syn::Error> {
+    let FloatGuard::WithValidation {
+        sanitizers,
+        validators,
+        ..
+    } = guard
+    else {
+        return Ok(());
+    };
+    let mut lower = None;
+    let mut upper = None;
+    let mut has_other_validators = has_fallible_sanitizers(sanitizers);
+    for validator in validators {
+        match validator {
+            FloatValidator::Greater(value) => lower = Some((value, true)),
+            FloatValidator::GreaterOrEqual(value) => lower = Some((value, false)),
+            FloatValidator::Less(value) => upper = Some((value, true)),
+            FloatValidator::LessOrEqual(value) => upper = Some((value, false)),
+            FloatValidator::Finite => {}
+            FloatValidator::Predicate(_) | FloatValidator::WithResult(_) => {
+                has_other_validators = true
+            }
+        }
+    }
+    let has_bounds = lower.is_some() || upper.is_some();
+    if has_other_validators || (has_bounds && !are_bounds_symmetric(lower, upper)) {
+        let msg = "`Neg` cannot be derived, because a negated valid value is not necessarily valid.\nIt's allowed only together with symmetric bounds (set with literals) and `finite`, for example:\n    validate(greater_or_equal = -100.0, less_or_equal = 100.0)";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(())
+}
+
 fn to_float_derive_trait(
     tr: DeriveTrait,
     validation: ValidationInfo,
@@ -176,10 +226,47 @@ fn to_float_derive_trait(
         DeriveTrait::FromStr => Ok(FloatDeriveTrait::FromStr),
         DeriveTrait::AsRef => Ok(FloatDeriveTrait::AsRef),
         DeriveTrait::Deref => Ok(FloatDeriveTrait::Deref),
-        DeriveTrait::Hash => Err(syn::Error::new(
+        DeriveTrait::EqInner => Ok(FloatDeriveTrait::EqInner),
+        DeriveTrait::OrdInner => Ok(FloatDeriveTrait::OrdInner),
+        DeriveTrait::Nutype => Ok(FloatDeriveTrait::Nutype),
+        DeriveTrait::Sum => {
+            if validation.has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `Sum` trait, because there is validation defined and the sum of valid values is not necessarily valid.",
+                ))
+            } else {
+                Ok(FloatDeriveTrait::Sum)
+            }
+        }
+        DeriveTrait::Product => {
+            if validation.has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `Product` trait, because there is validation defined and the product of valid values is not necessarily valid.",
+                ))
+            } else {
+                Ok(FloatDeriveTrait::Product)
+            }
+        }
+        DeriveTrait::CheckedArith => Ok(FloatDeriveTrait::CheckedArith),
+        DeriveTrait::Neg => Ok(FloatDeriveTrait::Neg),
+        DeriveTrait::TryFromWide => {
+            let msg = "`TryFromWide` can be derived only for integer based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::FromIterator => Err(syn::Error::new(
             span,
-            "#[nutype] cannot derive `Hash` trait for float types.",
+            "`FromIterator` can be derived only for String based types",
         )),
+        DeriveTrait::Hash => {
+            if validation.has_nan_validation {
+                Ok(FloatDeriveTrait::Hash)
+            } else {
+                let msg = "To derive Hash trait on float-based type there must be validation that proves that inner value is not NaN.\nConsider adding:\n    validate(finite)";
+                Err(syn::Error::new(span, msg))
+            }
+        }
         DeriveTrait::Borrow => Ok(FloatDeriveTrait::Borrow),
         DeriveTrait::Copy => Ok(FloatDeriveTrait::Copy),
         DeriveTrait::From => {
@@ -195,8 +282,19 @@ fn to_float_derive_trait(
         DeriveTrait::TryFrom => Ok(FloatDeriveTrait::TryFrom),
         DeriveTrait::SerdeSerialize => Ok(FloatDeriveTrait::SerdeSerialize),
         DeriveTrait::SerdeDeserialize => Ok(FloatDeriveTrait::SerdeDeserialize),
+        DeriveTrait::RkyvArchive => Ok(FloatDeriveTrait::RkyvArchive),
+        DeriveTrait::SqlxJson => Ok(FloatDeriveTrait::SqlxJson),
+        DeriveTrait::RedisToRedisArgs => Ok(FloatDeriveTrait::RedisToRedisArgs),
+        DeriveTrait::RedisFromRedisValue => Ok(FloatDeriveTrait::RedisFromRedisValue),
+        DeriveTrait::BincodeEncode => Ok(FloatDeriveTrait::BincodeEncode),
+        DeriveTrait::BincodeDecode => Ok(FloatDeriveTrait::BincodeDecode),
+        DeriveTrait::NumTraitsZero => Ok(FloatDeriveTrait::NumTraitsZero),
+        DeriveTrait::NumTraitsOne => Ok(FloatDeriveTrait::NumTraitsOne),
+        DeriveTrait::NumTraitsBounded => Ok(FloatDeriveTrait::NumTraitsBounded),
         DeriveTrait::SchemarsJsonSchema => Ok(FloatDeriveTrait::SchemarsJsonSchema),
         DeriveTrait::ArbitraryArbitrary => Ok(FloatDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::ProptestArbitrary => Ok(FloatDeriveTrait::ProptestArbitrary),
+        DeriveTrait::RandDistribution => Ok(FloatDeriveTrait::RandDistribution),
         DeriveTrait::DieselNewType => Ok(FloatDeriveTrait::DieselNewType),
     }
 }
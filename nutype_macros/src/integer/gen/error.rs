@@ -3,31 +3,65 @@ use quote::{quote, ToTokens};
 
 use super::super::models::IntegerValidator;
 use crate::common::{
-    gen::error::{gen_error_type_name, gen_impl_error_trait},
+    gen::{
+        error::{
+            gen_error_type_name, gen_impl_error_trait, gen_impl_validation_error_trait,
+            gen_multiple_errors_display_arm, gen_multiple_errors_variant, gen_non_exhaustive_attr,
+            gen_sanitization_failed_display_arm, gen_sanitization_failed_variant,
+        },
+        gen_alloc_crate,
+    },
     models::{ErrorTypeName, TypeName},
+    parse::kind_name,
 };
 
 pub fn gen_validation_error_type<T: ToTokens>(
     type_name: &TypeName,
-    validators: &[IntegerValidator<T>],
+    validators: &[&IntegerValidator<T>],
+    non_exhaustive_error: bool,
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
+    let definition = gen_definition(
+        &error_type_name,
+        validators,
+        collect_errors,
+        has_fallible_sanitizers,
+    );
+    let impl_display_trait = gen_impl_display_trait(
+        type_name,
+        &error_type_name,
+        validators,
+        collect_errors,
+        has_fallible_sanitizers,
+    );
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
+    let impl_validation_error_trait = gen_impl_validation_error_trait(
+        type_name,
+        &error_type_name,
+        gen_constraint_arms(&error_type_name, validators),
+        collect_errors,
+        has_fallible_sanitizers,
+    );
+    let non_exhaustive_attr = gen_non_exhaustive_attr(non_exhaustive_error);
 
     quote! {
         #[derive(Debug, Clone, PartialEq, Eq)]
+        #non_exhaustive_attr
         #definition
 
         #impl_display_trait
         #impl_error_trait
+        #impl_validation_error_trait
     }
 }
 
 fn gen_definition<T>(
     error_type_name: &ErrorTypeName,
-    validators: &[IntegerValidator<T>],
+    validators: &[&IntegerValidator<T>],
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
@@ -44,16 +78,34 @@ fn gen_definition<T>(
             IntegerValidator::LessOrEqual(_) => {
                 quote!(LessOrEqualViolated,)
             }
+            IntegerValidator::NonZero => {
+                quote!(NonZeroViolated,)
+            }
+            IntegerValidator::Step(_) => {
+                quote!(StepViolated,)
+            }
+            IntegerValidator::OneOf(_) => {
+                quote!(OneOfViolated,)
+            }
             IntegerValidator::Predicate(_) => {
                 quote!(PredicateViolated,)
             }
+            IntegerValidator::WithResult(_) => {
+                let alloc = gen_alloc_crate();
+                quote!(WithResultViolated(#alloc::string::String),)
+            }
         })
         .collect();
 
+    let multiple_variant = gen_multiple_errors_variant(error_type_name, collect_errors);
+    let sanitization_failed_variant = gen_sanitization_failed_variant(has_fallible_sanitizers);
+
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
             #error_variants
+            #sanitization_failed_variant
+            #multiple_variant
         }
     }
 }
@@ -61,7 +113,9 @@ fn gen_definition<T>(
 fn gen_impl_display_trait<T: ToTokens>(
     type_name: &TypeName,
     error_type_name: &ErrorTypeName,
-    validators: &[IntegerValidator<T>],
+    validators: &[&IntegerValidator<T>],
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
         IntegerValidator::Greater(val) => quote! {
@@ -76,18 +130,63 @@ fn gen_impl_display_trait<T: ToTokens>(
         IntegerValidator::LessOrEqual(val) => quote! {
              #error_type_name::LessOrEqualViolated=> write!(f, "{} is too big. The value must be less or equal to {:#?}.", stringify!(#type_name), #val)
         },
+        IntegerValidator::Step(step) => quote! {
+             #error_type_name::StepViolated => write!(f, "{} is off the step of {:#?}.", stringify!(#type_name), #step)
+        },
+        IntegerValidator::OneOf(values) => quote! {
+             #error_type_name::OneOfViolated => write!(f, "{} is not one of the allowed values {:?}.", stringify!(#type_name), [#(#values),*])
+        },
+        IntegerValidator::NonZero => quote! {
+             #error_type_name::NonZeroViolated => write!(f, "{} must not be zero.", stringify!(#type_name))
+        },
         IntegerValidator::Predicate(_) => quote! {
              #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
+        IntegerValidator::WithResult(_) => quote! {
+             #error_type_name::WithResultViolated(message) => write!(f, "{} is invalid: {}", stringify!(#type_name), message)
+        },
     });
 
+    let sanitization_failed_arm =
+        gen_sanitization_failed_display_arm(type_name, error_type_name, has_fallible_sanitizers);
+    let multiple_arm = gen_multiple_errors_display_arm(error_type_name, collect_errors);
+
     quote! {
         impl ::core::fmt::Display for #error_type_name {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 match self {
                     #(#match_arms,)*
+                    #sanitization_failed_arm
+                    #multiple_arm
                 }
             }
         }
     }
 }
+
+/// Map every error variant to the name of the validator that produces it.
+fn gen_constraint_arms<T>(
+    error_type_name: &ErrorTypeName,
+    validators: &[&IntegerValidator<T>],
+) -> Vec<TokenStream> {
+    validators
+        .iter()
+        .map(|validator| {
+            let constraint = kind_name(validator.kind());
+            let pattern = match validator {
+                IntegerValidator::Greater(_) => quote!(#error_type_name::GreaterViolated),
+                IntegerValidator::GreaterOrEqual(_) => {
+                    quote!(#error_type_name::GreaterOrEqualViolated)
+                }
+                IntegerValidator::Less(_) => quote!(#error_type_name::LessViolated),
+                IntegerValidator::LessOrEqual(_) => quote!(#error_type_name::LessOrEqualViolated),
+                IntegerValidator::NonZero => quote!(#error_type_name::NonZeroViolated),
+                IntegerValidator::Step(_) => quote!(#error_type_name::StepViolated),
+                IntegerValidator::OneOf(_) => quote!(#error_type_name::OneOfViolated),
+                IntegerValidator::Predicate(_) => quote!(#error_type_name::PredicateViolated),
+                IntegerValidator::WithResult(_) => quote!(#error_type_name::WithResultViolated(_)),
+            };
+            quote!(#pattern => #constraint)
+        })
+        .collect()
+}
@@ -5,6 +5,7 @@ use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use self::{error::gen_validation_error_type, traits::gen_traits};
 use super::{
@@ -17,14 +18,20 @@ use super::{
 use crate::common::{
     gen::{
         error::gen_error_type_name,
+        gen_alloc_crate, gen_call_custom_validation_fn, gen_call_fallible_sanitizer,
+        gen_context_param, gen_impl_numeric_bound_consts, gen_sanitize_output,
+        gen_validation_checks,
         tests::{
             gen_test_should_have_consistent_lower_and_upper_boundaries,
             gen_test_should_have_valid_default_value,
         },
         traits::GeneratedTraits,
-        GenerateNewtype,
+        DeclaredBound, GenerateNewtype,
+    },
+    models::{
+        has_fallible_sanitizers, ConstructorName, EqMode, ErrorTypeName, Guard, OrdMode, SerdeRepr,
+        TypeName,
     },
-    models::{ErrorTypeName, Guard, TypeName},
 };
 
 impl<T> GenerateNewtype for IntegerNewtype<T>
@@ -48,16 +55,66 @@ where
                         value = (#custom_sanitizer)(value);
                     )
                 }
+                IntegerSanitizer::TryWith(custom_sanitizer) => {
+                    let call = gen_call_fallible_sanitizer(custom_sanitizer);
+                    quote!(
+                        value = #call;
+                    )
+                }
+                IntegerSanitizer::Wrapping(modulus) => {
+                    quote!(
+                        value = value.rem_euclid(#modulus);
+                    )
+                }
+                IntegerSanitizer::SaturatingClamp { min, max } => {
+                    quote!(
+                        value = value.clamp(#min, #max);
+                    )
+                }
+                IntegerSanitizer::Snap { step, origin } => {
+                    // Round to the nearest point of the grid `origin + k * step` (half rounds up).
+                    // If the nearest point does not fit into the type, take the other one.
+                    // `abs_diff()` is used to avoid overflows, the same way as `step` validator does.
+                    let origin = match origin {
+                        Some(origin) => quote!(#origin),
+                        None => quote!(0),
+                    };
+                    quote!(
+                        let step = (#step).abs_diff(0);
+                        let distance = value.abs_diff(#origin) % step;
+                        let remainder = if value >= #origin || distance == 0 {
+                            distance
+                        } else {
+                            step - distance
+                        };
+                        if remainder != 0 {
+                            // The remainder is less than the step, so it fits into the inner type.
+                            #[allow(clippy::unnecessary_cast)]
+                            let remainder = remainder as #inner_type;
+                            let round_up = remainder >= #step - remainder;
+                            let down = value.checked_sub(remainder);
+                            let up = value.checked_add(#step - remainder);
+                            value = match (down, up) {
+                                (Some(down), Some(up)) => if round_up { up } else { down },
+                                (Some(down), None) => down,
+                                (None, Some(up)) => up,
+                                (None, None) => value,
+                            };
+                        }
+                    )
+                }
                 IntegerSanitizer::_Phantom(_) => {
                     unreachable!("integer::gen: IntegerSanitizer::_Phantom must not be used")
                 }
             })
             .collect();
+        let (output_type, output_value) =
+            gen_sanitize_output(inner_type, has_fallible_sanitizers(sanitizers));
 
         quote!(
-            fn sanitize(mut value: #inner_type) -> #inner_type {
+            fn sanitize(mut value: #inner_type) -> #output_type {
                 #transformations
-                value
+                #output_value
             }
         )
     }
@@ -66,10 +123,13 @@ where
         inner_type: &Self::InnerType,
         type_name: &TypeName,
         validators: &[Self::Validator],
+        collect_errors: bool,
+        maybe_context: Option<&syn::Type>,
     ) -> TokenStream {
         let error_name = gen_error_type_name(type_name);
+        let context_param = gen_context_param(maybe_context);
 
-        let validations: TokenStream = validators
+        let checks: Vec<TokenStream> = validators
             .iter()
             .map(|validator| match validator {
                 IntegerValidator::Less(exclusive_upper_bound) => {
@@ -100,18 +160,67 @@ where
                         }
                     )
                 }
+                IntegerValidator::Step(step) => {
+                    // The grid of valid values starts at the lower bound (if any).
+                    // `abs_diff()` is used to avoid overflows.
+                    let origin = lower_bound_of(validators).unwrap_or_else(|| quote!(0));
+                    quote!(
+                        if val.abs_diff(#origin) % (#step).abs_diff(0) != 0 {
+                            return Err(#error_name::StepViolated);
+                        }
+                    )
+                }
+                IntegerValidator::OneOf(values) => {
+                    quote!(
+                        const ALLOWED: &[#inner_type] = &[#(#values),*];
+                        if !ALLOWED.contains(&val) {
+                            return Err(#error_name::OneOfViolated);
+                        }
+                    )
+                }
+                IntegerValidator::NonZero => {
+                    quote!(
+                        if val == 0 {
+                            return Err(#error_name::NonZeroViolated);
+                        }
+                    )
+                }
                 IntegerValidator::Predicate(custom_is_valid_fn) => {
+                    let is_valid = gen_call_custom_validation_fn(
+                        custom_is_valid_fn,
+                        quote!(&val),
+                        quote!(&#inner_type),
+                        quote!(bool),
+                        maybe_context,
+                    );
                     quote!(
-                        if !(#custom_is_valid_fn)(&val) {
+                        if !#is_valid {
                             return Err(#error_name::PredicateViolated);
                         }
                     )
                 }
+                IntegerValidator::WithResult(custom_validate_fn) => {
+                    let alloc = gen_alloc_crate();
+                    let result = gen_call_custom_validation_fn(
+                        custom_validate_fn,
+                        quote!(&val),
+                        quote!(&#inner_type),
+                        quote!(::core::result::Result<(), #alloc::string::String>),
+                        maybe_context,
+                    );
+                    quote!(
+                        let result: ::core::result::Result<(), #alloc::string::String> = #result;
+                        if let Err(message) = result {
+                            return Err(#error_name::WithResultViolated(message));
+                        }
+                    )
+                }
             })
             .collect();
+        let validations = gen_validation_checks(&error_name, checks, collect_errors);
 
         quote!(
-            fn validate(val: &#inner_type) -> ::core::result::Result<(), #error_name> {
+            fn validate(val: &#inner_type #context_param) -> ::core::result::Result<(), #error_name> {
                 let val = *val;
                 #validations
                 Ok(())
@@ -121,31 +230,118 @@ where
 
     fn gen_validation_error_type(
         type_name: &TypeName,
-        validators: &[Self::Validator],
+        validators: &[&Self::Validator],
+        non_exhaustive_error: bool,
+        collect_errors: bool,
+        has_fallible_sanitizers: bool,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(
+            type_name,
+            validators,
+            non_exhaustive_error,
+            collect_errors,
+            has_fallible_sanitizers,
+        )
     }
 
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
         inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
+        maybe_display_format: Option<syn::LitStr>,
+        _eq: EqMode,
+        _ord: OrdMode,
+        serde_repr: &SerdeRepr,
+        serde_lenient: bool,
         guard: &IntegerGuard<T>,
     ) -> Result<GeneratedTraits, syn::Error> {
         gen_traits(
             type_name,
+            generics,
+            constructor_name,
             inner_type,
             maybe_error_type_name,
             traits,
             maybe_default_value,
+            maybe_display_format,
+            serde_repr,
+            serde_lenient,
             guard,
         )
     }
 
+    fn gen_impl_enumerate(
+        type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
+        inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> TokenStream {
+        let validators = guard.validators().map(Vec::as_slice).unwrap_or_default();
+        // Both boundaries are guaranteed by `validate_enumerate()`.
+        let (Some(lower), Some(upper)) = (lower_bound_of(validators), upper_bound_of(validators))
+        else {
+            return quote!();
+        };
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        // The boundaries are taken inclusively, the exclusive ones are rejected by the constructor.
+        quote! {
+            impl #impl_generics #type_name #ty_generics #where_clause {
+                /// Iterates over all the valid values in ascending order.
+                pub fn all() -> impl ::core::iter::Iterator<Item = Self> {
+                    let lower: #inner_type = #lower;
+                    let upper: #inner_type = #upper;
+                    (lower..=upper).filter_map(|value| {
+                        // Skip the values rejected by the validators and the values changed by the sanitizers.
+                        Self::#constructor_name(value)
+                            .ok()
+                            .filter(|nutype| nutype.0 == value)
+                    })
+                }
+            }
+        }
+    }
+
+    fn gen_impl_bound_consts(
+        type_name: &TypeName,
+        generics: &Generics,
+        inner_type: &Self::InnerType,
+        guard: &Guard<Self::Sanitizer, Self::Validator>,
+    ) -> TokenStream {
+        let validators = guard.validators().map(Vec::as_slice).unwrap_or_default();
+        let lower = validators.iter().find_map(|validator| match validator {
+            IntegerValidator::Greater(bound) => Some(DeclaredBound {
+                value: quote!(#bound),
+                is_exclusive: true,
+            }),
+            IntegerValidator::GreaterOrEqual(bound) => Some(DeclaredBound {
+                value: quote!(#bound),
+                is_exclusive: false,
+            }),
+            _ => None,
+        });
+        let upper = validators.iter().find_map(|validator| match validator {
+            IntegerValidator::Less(bound) => Some(DeclaredBound {
+                value: quote!(#bound),
+                is_exclusive: true,
+            }),
+            IntegerValidator::LessOrEqual(bound) => Some(DeclaredBound {
+                value: quote!(#bound),
+                is_exclusive: false,
+            }),
+            _ => None,
+        });
+        gen_impl_numeric_bound_consts(type_name, generics, inner_type, lower, upper)
+    }
+
     fn gen_tests(
         type_name: &TypeName,
+        constructor_name: &ConstructorName,
         _inner_type: &Self::InnerType,
         maybe_default_value: &Option<syn::Expr>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
@@ -157,6 +353,7 @@ where
 
         let test_valid_default_value = gen_test_should_have_valid_default_value(
             type_name,
+            constructor_name,
             maybe_default_value,
             guard.has_validation(),
         );
@@ -167,3 +364,23 @@ where
         }
     }
 }
+
+/// Find the lower bound set by `greater` or `greater_or_equal` validators.
+pub fn lower_bound_of<T: ToTokens>(validators: &[IntegerValidator<T>]) -> Option<TokenStream> {
+    validators.iter().find_map(|validator| match validator {
+        IntegerValidator::Greater(bound) | IntegerValidator::GreaterOrEqual(bound) => {
+            Some(quote!(#bound))
+        }
+        _ => None,
+    })
+}
+
+/// Find the upper bound set by `less` or `less_or_equal` validators.
+pub fn upper_bound_of<T: ToTokens>(validators: &[IntegerValidator<T>]) -> Option<TokenStream> {
+    validators.iter().find_map(|validator| match validator {
+        IntegerValidator::Less(bound) | IntegerValidator::LessOrEqual(bound) => {
+            Some(quote!(#bound))
+        }
+        _ => None,
+    })
+}
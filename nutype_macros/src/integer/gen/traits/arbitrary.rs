@@ -2,18 +2,33 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 use crate::{
-    common::models::TypeName,
+    common::models::{ConstructorName, TypeName},
     integer::models::{IntegerGuard, IntegerInnerType, IntegerValidator},
     utils::issue_reporter::{build_github_link_with_issue, Issue},
 };
 
 pub fn gen_impl_trait_arbitrary<T: ToTokens>(
     type_name: &TypeName,
+    constructor_name: &ConstructorName,
     inner_type: &IntegerInnerType,
     guard: &IntegerGuard<T>,
 ) -> Result<TokenStream, syn::Error> {
     let Boundary { min, max } = guard_to_boundary(inner_type, guard)?;
 
+    // With `non_zero` validator zero is shifted to one of the boundaries, which is guaranteed
+    // to be non-zero unless the validators contradict each other.
+    let skip_zero = if has_non_zero_validator(guard) {
+        quote!(
+            let inner_value = if inner_value == 0 {
+                if (#max) != 0 { #max } else { #min }
+            } else {
+                inner_value
+            };
+        )
+    } else {
+        quote!()
+    };
+
     let construct_value = if guard.has_validation() {
         // If by some reason we generate an invalid value, make it very easy for the user to report
         let report_issue_msg =
@@ -23,16 +38,17 @@ pub fn gen_impl_trait_arbitrary<T: ToTokens>(
         let error_text =
             format!("Arbitrary generated an invalid value for {type_name}.\n\n{report_issue_msg}");
         quote!(
-            Self::new(inner_value).expect(#error_text)
+            Self::#constructor_name(inner_value).expect(#error_text)
         )
     } else {
-        quote!(Self::new(inner_value))
+        quote!(Self::#constructor_name(inner_value))
     };
 
     Ok(quote!(
         impl ::arbitrary::Arbitrary<'_> for #type_name {
             fn arbitrary(u: &mut ::arbitrary::Unstructured<'_>) -> ::arbitrary::Result<Self> {
                 let inner_value: #inner_type = u.int_in_range((#min)..=(#max))?;
+                #skip_zero
                 Ok(#construct_value)
             }
         }
@@ -67,6 +83,7 @@ fn guard_to_boundary<T: ToTokens>(
         IntegerGuard::WithValidation {
             sanitizers: _,
             validators,
+            raw_validators: _,
         } => {
             // Apply the validators to the boundaries.
             // Since the validators were already validated, it's guaranteed that they're not
@@ -85,12 +102,33 @@ fn guard_to_boundary<T: ToTokens>(
                     IntegerValidator::LessOrEqual(lte) => {
                         boundary.max = quote!(#lte);
                     }
+                    IntegerValidator::NonZero => {
+                        // Handled separately, see `has_non_zero_validator`.
+                    }
+                    IntegerValidator::Step(_) => {
+                        return Err(syn::Error::new(
+                            proc_macro2::Span::call_site(),
+                            "Cannot derive trait `Arbitrary` for a type with `step` validator",
+                        ));
+                    }
+                    IntegerValidator::OneOf(_) => {
+                        return Err(syn::Error::new(
+                            proc_macro2::Span::call_site(),
+                            "Cannot derive trait `Arbitrary` for a type with `one_of` validator",
+                        ));
+                    }
                     IntegerValidator::Predicate(_) => {
                         return Err(syn::Error::new(
                             proc_macro2::Span::call_site(),
                             "Cannot derive trait `Arbitrary` for a type with `predicate` validator",
                         ));
                     }
+                    IntegerValidator::WithResult(_) => {
+                        return Err(syn::Error::new(
+                            proc_macro2::Span::call_site(),
+                            "Cannot derive trait `Arbitrary` for a type with `with_result` validator",
+                        ));
+                    }
                 }
             }
         }
@@ -98,3 +136,12 @@ fn guard_to_boundary<T: ToTokens>(
 
     Ok(boundary)
 }
+
+fn has_non_zero_validator<T>(guard: &IntegerGuard<T>) -> bool {
+    match guard {
+        IntegerGuard::WithoutValidation { .. } => false,
+        IntegerGuard::WithValidation { validators, .. } => validators
+            .iter()
+            .any(|validator| matches!(validator, IntegerValidator::NonZero)),
+    }
+}
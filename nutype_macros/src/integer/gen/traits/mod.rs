@@ -1,32 +1,52 @@
 mod arbitrary;
+mod num_traits;
+mod proptest;
+mod rand;
+mod serde_lenient;
 
 use std::collections::HashSet;
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
+use syn::Generics;
 
 use crate::{
     common::{
-        gen::traits::{
-            gen_impl_trait_as_ref, gen_impl_trait_borrow, gen_impl_trait_default,
-            gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_from,
-            gen_impl_trait_from_str, gen_impl_trait_into, gen_impl_trait_serde_deserialize,
-            gen_impl_trait_serde_serialize, gen_impl_trait_try_from, split_into_generatable_traits,
-            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+        gen::{
+            traits::{
+                gen_impl_checked_arith, gen_impl_trait_as_ref, gen_impl_trait_bincode_decode,
+                gen_impl_trait_bincode_encode, gen_impl_trait_borrow, gen_impl_trait_default,
+                gen_impl_trait_deref, gen_impl_trait_display, gen_impl_trait_eq_inner,
+                gen_impl_trait_from, gen_impl_trait_from_str, gen_impl_trait_into,
+                gen_impl_trait_neg, gen_impl_trait_nutype, gen_impl_trait_ord_inner,
+                gen_impl_trait_product, gen_impl_trait_redis_from_redis_value,
+                gen_impl_trait_redis_to_redis_args, gen_impl_trait_rkyv,
+                gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+                gen_impl_trait_sqlx_json, gen_impl_trait_sum, gen_impl_trait_try_from,
+                gen_rkyv_archived_primitive, split_into_generatable_traits, GeneratableTrait,
+                GeneratableTraits, GeneratedTraits,
+            },
+            try_from_wide::gen_impl_trait_try_from_wide,
         },
-        models::{ErrorTypeName, TypeName},
+        models::{ConstructorName, ErrorTypeName, SerdeRepr, TypeName, ValueOrExpr},
     },
-    integer::models::{IntegerDeriveTrait, IntegerGuard, IntegerInnerType},
+    integer::models::{IntegerDeriveTrait, IntegerGuard, IntegerInnerType, IntegerValidator},
 };
 
 type IntegerGeneratableTrait = GeneratableTrait<IntegerTransparentTrait, IntegerIrregularTrait>;
 
+#[allow(clippy::too_many_arguments)]
 pub fn gen_traits<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
     inner_type: &IntegerInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     traits: HashSet<IntegerDeriveTrait>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_display_format: Option<syn::LitStr>,
+    serde_repr: &SerdeRepr,
+    serde_lenient: bool,
     guard: &IntegerGuard<T>,
 ) -> Result<GeneratedTraits, syn::Error> {
     let GeneratableTraits {
@@ -42,10 +62,15 @@ pub fn gen_traits<T: ToTokens>(
 
     let implement_traits = gen_implemented_traits(
         type_name,
+        generics,
+        constructor_name,
         inner_type,
         maybe_error_type_name,
         irregular_traits,
         maybe_default_value,
+        maybe_display_format,
+        serde_repr,
+        serde_lenient,
         guard,
     )?;
 
@@ -88,6 +113,27 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::AsRef => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::AsRef)
             }
+            IntegerDeriveTrait::Nutype => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Nutype)
+            }
+            IntegerDeriveTrait::Sum => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Sum)
+            }
+            IntegerDeriveTrait::Product => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Product)
+            }
+            IntegerDeriveTrait::CheckedArith => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::CheckedArith)
+            }
+            IntegerDeriveTrait::Neg => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Neg)
+            }
+            IntegerDeriveTrait::EqInner => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::EqInner)
+            }
+            IntegerDeriveTrait::OrdInner => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::OrdInner)
+            }
             IntegerDeriveTrait::Deref => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Deref)
             }
@@ -100,6 +146,9 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::TryFrom => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::TryFrom)
             }
+            IntegerDeriveTrait::TryFromWide => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::TryFromWide)
+            }
             IntegerDeriveTrait::Borrow => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::Borrow)
             }
@@ -112,6 +161,33 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::SerdeSerialize => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SerdeSerialize)
             }
+            IntegerDeriveTrait::RedisFromRedisValue => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::RedisFromRedisValue)
+            }
+            IntegerDeriveTrait::BincodeEncode => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::BincodeEncode)
+            }
+            IntegerDeriveTrait::BincodeDecode => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::BincodeDecode)
+            }
+            IntegerDeriveTrait::RedisToRedisArgs => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::RedisToRedisArgs)
+            }
+            IntegerDeriveTrait::NumTraitsZero => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsZero)
+            }
+            IntegerDeriveTrait::NumTraitsOne => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsOne)
+            }
+            IntegerDeriveTrait::NumTraitsBounded => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::NumTraitsBounded)
+            }
+            IntegerDeriveTrait::SqlxJson => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SqlxJson)
+            }
+            IntegerDeriveTrait::RkyvArchive => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::RkyvArchive)
+            }
             IntegerDeriveTrait::SerdeDeserialize => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::SerdeDeserialize)
             }
@@ -121,6 +197,12 @@ impl From<IntegerDeriveTrait> for IntegerGeneratableTrait {
             IntegerDeriveTrait::ArbitraryArbitrary => {
                 IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::ArbitraryArbitrary)
             }
+            IntegerDeriveTrait::ProptestArbitrary => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::ProptestArbitrary)
+            }
+            IntegerDeriveTrait::RandDistribution => {
+                IntegerGeneratableTrait::Irregular(IntegerIrregularTrait::RandDistribution)
+            }
             IntegerDeriveTrait::DieselNewType => {
                 IntegerGeneratableTrait::Transparent(IntegerTransparentTrait::DieselNewType)
             }
@@ -150,15 +232,34 @@ enum IntegerIrregularTrait {
     FromStr,
     AsRef,
     Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
+    Sum,
+    Product,
+    CheckedArith,
+    Neg,
     From,
     TryFrom,
+    TryFromWide,
     Borrow,
     Into,
     Display,
     Default,
     SerdeSerialize,
     SerdeDeserialize,
+    RkyvArchive,
+    SqlxJson,
+    RedisToRedisArgs,
+    RedisFromRedisValue,
+    BincodeEncode,
+    BincodeDecode,
+    NumTraitsZero,
+    NumTraitsOne,
+    NumTraitsBounded,
     ArbitraryArbitrary,
+    ProptestArbitrary,
+    RandDistribution,
 }
 
 impl ToTokens for IntegerTransparentTrait {
@@ -179,34 +280,57 @@ impl ToTokens for IntegerTransparentTrait {
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn gen_implemented_traits<T: ToTokens>(
     type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
     inner_type: &IntegerInnerType,
     maybe_error_type_name: Option<ErrorTypeName>,
     impl_traits: Vec<IntegerIrregularTrait>,
     maybe_default_value: Option<syn::Expr>,
+    maybe_display_format: Option<syn::LitStr>,
+    serde_repr: &SerdeRepr,
+    serde_lenient: bool,
     guard: &IntegerGuard<T>,
 ) -> Result<TokenStream, syn::Error> {
     impl_traits
         .iter()
         .map(|t| match t {
-            IntegerIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, inner_type)),
-            IntegerIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, inner_type)),
+            IntegerIrregularTrait::AsRef => Ok(gen_impl_trait_as_ref(type_name, generics, inner_type)),
+            IntegerIrregularTrait::Nutype => Ok(gen_impl_trait_nutype(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            IntegerIrregularTrait::EqInner => Ok(gen_impl_trait_eq_inner(type_name, inner_type)),
+            IntegerIrregularTrait::OrdInner => Ok(gen_impl_trait_ord_inner(type_name, inner_type)),
+            IntegerIrregularTrait::Sum => Ok(gen_impl_trait_sum(type_name, constructor_name, inner_type)),
+            IntegerIrregularTrait::Product => Ok(gen_impl_trait_product(type_name, constructor_name, inner_type)),
+            IntegerIrregularTrait::Neg => Ok(gen_impl_trait_neg(type_name, constructor_name, maybe_error_type_name.is_some(), true)),
+            IntegerIrregularTrait::CheckedArith => Ok(gen_impl_checked_arith(
+                type_name,
+                constructor_name,
+                inner_type,
+                quote!(self.0.checked_add(rhs.0)),
+                quote!(self.0.checked_sub(rhs.0)),
+                maybe_error_type_name.is_some(),
+            )),
+            IntegerIrregularTrait::Deref => Ok(gen_impl_trait_deref(type_name, generics, inner_type)),
             IntegerIrregularTrait::FromStr => {
-                Ok(gen_impl_trait_from_str(type_name, inner_type, maybe_error_type_name.as_ref()))
+                Ok(gen_impl_trait_from_str(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref()))
             }
-            IntegerIrregularTrait::From => Ok(gen_impl_trait_from(type_name, inner_type)),
-            IntegerIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, inner_type)),
+            IntegerIrregularTrait::From => Ok(gen_impl_trait_from(type_name, generics, constructor_name, inner_type)),
+            IntegerIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type)),
             IntegerIrregularTrait::TryFrom => {
-                Ok(gen_impl_trait_try_from(type_name, inner_type, maybe_error_type_name.as_ref()))
+                Ok(gen_impl_trait_try_from(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref()))
             }
-            IntegerIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, inner_type)),
-            IntegerIrregularTrait::Display => Ok(gen_impl_trait_display(type_name)),
+            IntegerIrregularTrait::TryFromWide => {
+                Ok(gen_impl_trait_try_from_wide(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref()))
+            }
+            IntegerIrregularTrait::Borrow => Ok(gen_impl_trait_borrow(type_name, generics, inner_type)),
+            IntegerIrregularTrait::Display => Ok(gen_impl_trait_display(type_name, generics, maybe_display_format.as_ref())),
             IntegerIrregularTrait::Default => {
                 match maybe_default_value {
                     Some(ref default_value) => {
                         let has_validation = maybe_error_type_name.is_some();
-                        Ok(gen_impl_trait_default(type_name, default_value, has_validation))
+                        Ok(gen_impl_trait_default(type_name, constructor_name, default_value, has_validation))
                     },
                     None => {
                         let span = proc_macro2::Span::call_site();
@@ -215,15 +339,72 @@ fn gen_implemented_traits<T: ToTokens>(
                     }
                 }
             }
-            IntegerIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name)),
-            IntegerIrregularTrait::SerdeDeserialize => Ok(gen_impl_trait_serde_deserialize(
-                type_name,
-                inner_type,
-                maybe_error_type_name.as_ref(),
-            )),
+            IntegerIrregularTrait::SerdeSerialize => Ok(gen_impl_trait_serde_serialize(type_name, serde_repr)),
+            IntegerIrregularTrait::BincodeEncode => Ok(gen_impl_trait_bincode_encode(type_name)),
+            IntegerIrregularTrait::BincodeDecode => Ok(gen_impl_trait_bincode_decode(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            IntegerIrregularTrait::RedisFromRedisValue => Ok(gen_impl_trait_redis_from_redis_value(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            IntegerIrregularTrait::RedisToRedisArgs => Ok(gen_impl_trait_redis_to_redis_args(type_name)),
+            IntegerIrregularTrait::NumTraitsZero => num_traits::gen_impl_trait_num_traits_zero(type_name, constructor_name, inner_type, guard),
+            IntegerIrregularTrait::NumTraitsOne => num_traits::gen_impl_trait_num_traits_one(type_name, constructor_name, inner_type, guard),
+            IntegerIrregularTrait::NumTraitsBounded => num_traits::gen_impl_trait_num_traits_bounded(type_name, constructor_name, inner_type, guard),
+            IntegerIrregularTrait::SqlxJson => Ok(gen_impl_trait_sqlx_json(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref())),
+            IntegerIrregularTrait::RkyvArchive => Ok(gen_impl_trait_rkyv(type_name, constructor_name, inner_type, gen_rkyv_archived_primitive(inner_type), maybe_error_type_name.as_ref())),
+            IntegerIrregularTrait::SerdeDeserialize => {
+                if serde_lenient {
+                    Ok(serde_lenient::gen_impl_trait_serde_deserialize_lenient(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref()))
+                } else {
+                    Ok(gen_impl_trait_serde_deserialize(type_name, constructor_name, inner_type, maybe_error_type_name.as_ref(), serde_repr))
+                }
+            }
             IntegerIrregularTrait::ArbitraryArbitrary => {
-                arbitrary::gen_impl_trait_arbitrary(type_name, inner_type, guard)
+                arbitrary::gen_impl_trait_arbitrary(type_name, constructor_name, inner_type, guard)
+            }
+            IntegerIrregularTrait::ProptestArbitrary => {
+                proptest::gen_impl_trait_proptest_arbitrary(type_name, constructor_name, inner_type, guard)
+            }
+            IntegerIrregularTrait::RandDistribution => {
+                rand::gen_impl_trait_rand_distribution(type_name, constructor_name, inner_type, guard)
             }
         })
         .collect()
 }
+
+/// Compute the range of values that satisfies the boundary validators.
+/// Other validators (e.g. `predicate`, `with_result`, `step` or `non_zero`) are respected by filtering
+/// out the generated values.
+fn guard_to_range<T: ToTokens>(
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> (TokenStream, TokenStream) {
+    let mut min = quote!(#inner_type::MIN);
+    let mut max = quote!(#inner_type::MAX);
+
+    if let IntegerGuard::WithValidation { validators, .. } = guard {
+        for validator in validators {
+            match validator {
+                IntegerValidator::Greater(gt) => min = quote!(#gt + 1),
+                IntegerValidator::GreaterOrEqual(gte) => min = quote!(#gte),
+                IntegerValidator::Less(lt) => max = quote!(#lt - 1),
+                IntegerValidator::LessOrEqual(lte) => max = quote!(#lte),
+                IntegerValidator::NonZero
+                | IntegerValidator::Step(_)
+                | IntegerValidator::OneOf(_)
+                | IntegerValidator::Predicate(_)
+                | IntegerValidator::WithResult(_) => {}
+            }
+        }
+    }
+
+    (min, max)
+}
+
+/// Values of `one_of` validator if it is set.
+fn one_of_values<T>(guard: &IntegerGuard<T>) -> Option<&[ValueOrExpr<T>]> {
+    let IntegerGuard::WithValidation { validators, .. } = guard else {
+        return None;
+    };
+    validators.iter().find_map(|validator| match validator {
+        IntegerValidator::OneOf(values) => Some(values.as_slice()),
+        _ => None,
+    })
+}
@@ -0,0 +1,154 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::{
+        gen::traits::{
+            gen_const_assert_valid_value, gen_impl_trait_num_traits_bounded_from_values,
+            gen_impl_trait_num_traits_one_from_value, gen_impl_trait_num_traits_zero_from_value,
+        },
+        models::{ConstructorName, TypeName},
+    },
+    integer::{
+        gen::lower_bound_of,
+        models::{IntegerGuard, IntegerInnerType, IntegerValidator},
+    },
+};
+
+pub fn gen_impl_trait_num_traits_zero<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let zero = quote!(0);
+    let check = gen_check_valid_value(type_name, "Zero", inner_type, &zero, guard)?;
+    let implementation = gen_impl_trait_num_traits_zero_from_value(
+        type_name,
+        constructor_name,
+        zero,
+        guard.has_validation(),
+    );
+    Ok(quote!(
+        #check
+        #implementation
+    ))
+}
+
+pub fn gen_impl_trait_num_traits_one<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let one = quote!(1);
+    let check = gen_check_valid_value(type_name, "One", inner_type, &one, guard)?;
+    let implementation = gen_impl_trait_num_traits_one_from_value(
+        type_name,
+        constructor_name,
+        one,
+        guard.has_validation(),
+    );
+    Ok(quote!(
+        #check
+        #implementation
+    ))
+}
+
+/// `min_value()` and `max_value()` are the boundaries set by the validators,
+/// or the boundaries of the inner type if there are none.
+pub fn gen_impl_trait_num_traits_bounded<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let mut min = quote!(#inner_type::MIN);
+    let mut max = quote!(#inner_type::MAX);
+
+    if let IntegerGuard::WithValidation { validators, .. } = guard {
+        for validator in validators {
+            match validator {
+                IntegerValidator::Greater(gt) => min = quote!(#gt + 1),
+                IntegerValidator::GreaterOrEqual(gte) => min = quote!(#gte),
+                IntegerValidator::Less(lt) => max = quote!(#lt - 1),
+                IntegerValidator::LessOrEqual(lte) => max = quote!(#lte),
+                IntegerValidator::NonZero
+                | IntegerValidator::Step(_)
+                | IntegerValidator::OneOf(_)
+                | IntegerValidator::Predicate(_)
+                | IntegerValidator::WithResult(_) => {}
+            }
+        }
+    }
+
+    let check_min = gen_check_valid_value(type_name, "Bounded", inner_type, &min, guard)?;
+    let check_max = gen_check_valid_value(type_name, "Bounded", inner_type, &max, guard)?;
+    let implementation = gen_impl_trait_num_traits_bounded_from_values(
+        type_name,
+        constructor_name,
+        min,
+        max,
+        guard.has_validation(),
+    );
+    Ok(quote!(
+        #check_min
+        #check_max
+        #implementation
+    ))
+}
+
+/// Verify at compile time that `value` passes the validation.
+/// Custom validators cannot be evaluated at compile time, so deriving the trait is rejected.
+fn gen_check_valid_value<T: ToTokens>(
+    type_name: &TypeName,
+    trait_name: &str,
+    inner_type: &IntegerInnerType,
+    value: &TokenStream,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let IntegerGuard::WithValidation { validators, .. } = guard else {
+        return Ok(quote!());
+    };
+
+    let conditions = validators
+        .iter()
+        .map(|validator| match validator {
+            IntegerValidator::Greater(gt) => Ok(quote!(val > #gt)),
+            IntegerValidator::GreaterOrEqual(gte) => Ok(quote!(val >= #gte)),
+            IntegerValidator::Less(lt) => Ok(quote!(val < #lt)),
+            IntegerValidator::LessOrEqual(lte) => Ok(quote!(val <= #lte)),
+            IntegerValidator::NonZero => Ok(quote!(val != 0)),
+            IntegerValidator::Step(step) => {
+                let origin = lower_bound_of(validators).unwrap_or_else(|| quote!(0));
+                Ok(quote!(val.abs_diff(#origin) % (#step).abs_diff(0) == 0))
+            }
+            // `contains()` is not a const fn, so the allowed values are checked one by one.
+            IntegerValidator::OneOf(values) => Ok(quote!({
+                let allowed: &[#inner_type] = &[#(#values),*];
+                let mut is_allowed = false;
+                let mut i = 0;
+                while i < allowed.len() {
+                    is_allowed |= allowed[i] == val;
+                    i += 1;
+                }
+                is_allowed
+            })),
+            IntegerValidator::Predicate(_) | IntegerValidator::WithResult(_) => {
+                let msg = format!(
+                    "Cannot derive `{trait_name}` for a type with `{}` validator, because it's not possible to verify at compile time that `{value}` passes it.",
+                    validator.kind()
+                );
+                Err(syn::Error::new(Span::call_site(), msg))
+            }
+        })
+        .collect::<Result<Vec<_>, syn::Error>>()?;
+
+    let msg = format!("`{trait_name}` cannot be derived for `{type_name}`, because `{value}` does not pass the validation");
+    Ok(gen_const_assert_valid_value(
+        inner_type,
+        value,
+        &conditions,
+        &msg,
+    ))
+}
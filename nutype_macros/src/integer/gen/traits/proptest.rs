@@ -0,0 +1,38 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::{
+        gen::traits::gen_impl_trait_proptest_arbitrary_from_strategy,
+        models::{ConstructorName, TypeName},
+    },
+    integer::models::{IntegerGuard, IntegerInnerType},
+};
+
+use super::{guard_to_range, one_of_values};
+
+pub fn gen_impl_trait_proptest_arbitrary<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let inner_strategy = match one_of_values(guard) {
+        // Picking one of the allowed values is much more efficient than filtering a range.
+        Some(values) => quote!(
+            ::proptest::sample::select(::std::vec![#(#values),*] as ::std::vec::Vec<#inner_type>)
+        ),
+        None => {
+            let (min, max) = guard_to_range(inner_type, guard);
+            quote!(
+                ::core::ops::RangeInclusive::<#inner_type>::new(#min, #max)
+            )
+        }
+    };
+    Ok(gen_impl_trait_proptest_arbitrary_from_strategy(
+        type_name,
+        constructor_name,
+        inner_strategy,
+        guard.has_validation(),
+    ))
+}
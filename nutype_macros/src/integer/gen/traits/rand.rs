@@ -0,0 +1,37 @@
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+
+use crate::{
+    common::{
+        gen::traits::gen_impl_trait_rand_distribution_from_sampler,
+        models::{ConstructorName, TypeName},
+    },
+    integer::models::{IntegerGuard, IntegerInnerType},
+};
+
+use super::{guard_to_range, one_of_values};
+
+pub fn gen_impl_trait_rand_distribution<T: ToTokens>(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &IntegerInnerType,
+    guard: &IntegerGuard<T>,
+) -> Result<TokenStream, syn::Error> {
+    let sample_inner = match one_of_values(guard) {
+        Some(values) => quote!({
+            let allowed: &[#inner_type] = &[#(#values),*];
+            allowed[rng.gen_range(0..allowed.len())]
+        }),
+        None => {
+            let (min, max) = guard_to_range(inner_type, guard);
+            quote!(rng.gen_range(#min..=#max))
+        }
+    };
+    Ok(gen_impl_trait_rand_distribution_from_sampler(
+        type_name,
+        constructor_name,
+        inner_type,
+        sample_inner,
+        guard.has_validation(),
+    ))
+}
@@ -0,0 +1,64 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    common::{
+        gen::traits::gen_deserialized_value_to_result,
+        models::{ConstructorName, ErrorTypeName, TypeName},
+    },
+    integer::models::IntegerInnerType,
+};
+
+/// Generate `Deserialize` that accepts both a number and a numeric string (e.g. `42` and `"42"`).
+/// The visitor relies on `deserialize_any`, so it requires a self-describing format like JSON.
+pub fn gen_impl_trait_serde_deserialize_lenient(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    inner_type: &IntegerInnerType,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let expecting_str = format!("integer or numeric string for {type_name}");
+    let raw_value_to_result = gen_deserialized_value_to_result(
+        type_name,
+        constructor_name,
+        maybe_error_type_name,
+        true,
+        quote!(E),
+    );
+
+    quote! {
+        impl<'de> ::serde::Deserialize<'de> for #type_name {
+            fn deserialize<D: ::serde::Deserializer<'de>>(deserializer: D) -> ::core::result::Result<Self, D::Error> {
+                struct __Visitor;
+
+                impl<'de> ::serde::de::Visitor<'de> for __Visitor {
+                    type Value = #type_name;
+
+                    fn expecting(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                        write!(formatter, #expecting_str)
+                    }
+
+                    fn visit_u64<E: ::serde::de::Error>(self, value: u64) -> ::core::result::Result<Self::Value, E> {
+                        let raw_value = <#inner_type as ::core::convert::TryFrom<u64>>::try_from(value)
+                            .map_err(|_| E::invalid_value(::serde::de::Unexpected::Unsigned(value), &self))?;
+                        #raw_value_to_result
+                    }
+
+                    fn visit_i64<E: ::serde::de::Error>(self, value: i64) -> ::core::result::Result<Self::Value, E> {
+                        let raw_value = <#inner_type as ::core::convert::TryFrom<i64>>::try_from(value)
+                            .map_err(|_| E::invalid_value(::serde::de::Unexpected::Signed(value), &self))?;
+                        #raw_value_to_result
+                    }
+
+                    fn visit_str<E: ::serde::de::Error>(self, value: &str) -> ::core::result::Result<Self::Value, E> {
+                        let raw_value = <#inner_type as ::core::str::FromStr>::from_str(value)
+                            .map_err(|_| E::invalid_value(::serde::de::Unexpected::Str(value), &self))?;
+                        #raw_value_to_result
+                    }
+                }
+
+                ::serde::de::Deserializer::deserialize_any(deserializer, __Visitor)
+            }
+        }
+    }
+}
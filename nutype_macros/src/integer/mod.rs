@@ -48,8 +48,7 @@ where
         guard: &Guard<Self::Sanitizer, Self::Validator>,
         derive_traits: Vec<SpannedDeriveTrait>,
     ) -> Result<HashSet<Self::TypedTrait>, syn::Error> {
-        let has_validation = guard.has_validation();
-        validate_integer_derive_traits(derive_traits, has_validation)
+        validate_integer_derive_traits(derive_traits, guard, T::integer_inner_type())
     }
 
     fn generate(
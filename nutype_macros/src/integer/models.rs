@@ -2,8 +2,8 @@ use kinded::Kinded;
 use proc_macro2::TokenStream;
 
 use crate::common::models::{
-    impl_numeric_bound_on_vec_of, impl_numeric_bound_validator, Guard, RawGuard, SpannedItem,
-    TypeTrait, TypedCustomFunction, ValueOrExpr,
+    impl_numeric_bound_on_vec_of, impl_numeric_bound_validator, FallibleSanitizer, Guard, RawGuard,
+    SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
 };
 
 // Sanitizer
@@ -13,11 +13,29 @@ use crate::common::models::{
 #[kinded(display = "snake_case")]
 pub enum IntegerSanitizer<T> {
     With(TypedCustomFunction),
+    TryWith(TypedCustomFunction),
+    Wrapping(ValueOrExpr<T>),
+    SaturatingClamp {
+        min: ValueOrExpr<T>,
+        max: ValueOrExpr<T>,
+    },
+    /// `origin` is the lower bound set by the validators (if any), so `snap` rounds to the same
+    /// grid of values that `step` accepts.
+    Snap {
+        step: ValueOrExpr<T>,
+        origin: Option<ValueOrExpr<T>>,
+    },
     _Phantom(std::marker::PhantomData<T>),
 }
 
 pub type SpannedIntegerSanitizer<T> = SpannedItem<IntegerSanitizer<T>>;
 
+impl<T> FallibleSanitizer for IntegerSanitizer<T> {
+    fn is_fallible(&self) -> bool {
+        matches!(self, Self::TryWith(_))
+    }
+}
+
 // Validator
 //
 
@@ -28,7 +46,11 @@ pub enum IntegerValidator<T> {
     GreaterOrEqual(ValueOrExpr<T>),
     Less(ValueOrExpr<T>),
     LessOrEqual(ValueOrExpr<T>),
+    NonZero,
+    Step(ValueOrExpr<T>),
+    OneOf(Vec<ValueOrExpr<T>>),
     Predicate(TypedCustomFunction),
+    WithResult(TypedCustomFunction),
 }
 
 impl_numeric_bound_validator!(IntegerValidator);
@@ -58,12 +80,31 @@ pub enum IntegerDeriveTrait {
     Display,
     Default,
     Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
+    Sum,
+    Product,
+    CheckedArith,
+    Neg,
+    TryFromWide,
 
     // External crates
     SerdeSerialize,
     SerdeDeserialize,
+    RkyvArchive,
+    SqlxJson,
+    RedisToRedisArgs,
+    RedisFromRedisValue,
+    BincodeEncode,
+    BincodeDecode,
+    NumTraitsZero,
+    NumTraitsOne,
+    NumTraitsBounded,
     SchemarsJsonSchema,
     ArbitraryArbitrary,
+    ProptestArbitrary,
+    RandDistribution,
     DieselNewType,
 }
 
@@ -71,12 +112,25 @@ impl TypeTrait for IntegerDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &IntegerDeriveTrait::FromStr
     }
+
+    fn is_diesel_new_type(&self) -> bool {
+        self == &IntegerDeriveTrait::DieselNewType
+    }
+
+    fn is_debug(&self) -> bool {
+        self == &IntegerDeriveTrait::Debug
+    }
+
+    fn is_try_from_wide(&self) -> bool {
+        self == &IntegerDeriveTrait::TryFromWide
+    }
 }
 
 pub type IntegerRawGuard<T> = RawGuard<SpannedIntegerSanitizer<T>, SpannedIntegerValidator<T>>;
 pub type IntegerGuard<T> = Guard<IntegerSanitizer<T>, IntegerValidator<T>>;
 
 pub trait IntegerType {
+    #[allow(dead_code)]
     fn integer_inner_type() -> IntegerInnerType;
 }
 
@@ -106,6 +160,15 @@ macro_rules! define_integer_inner_type {
             }
         }
 
+        impl IntegerInnerType {
+            pub fn is_signed(&self) -> bool {
+                matches!(
+                    self,
+                    Self::I8 | Self::I16 | Self::I32 | Self::I64 | Self::I128 | Self::Isize
+                )
+            }
+        }
+
         impl ::core::fmt::Display for IntegerInnerType {
             fn fmt(&self, f: &mut ::core::fmt::Formatter) -> Result<(), ::core::fmt::Error> {
                 match self {
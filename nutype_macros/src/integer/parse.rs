@@ -4,16 +4,22 @@ use std::{
 };
 
 use crate::common::{
-    models::{Attributes, SpannedDeriveTrait},
+    models::{Attributes, SpannedDeriveTrait, ValueOrExpr},
     parse::{
-        parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function,
-        parse_validator_kind, ParseableAttributes,
+        parse_number_or_expr, parse_range, parse_sanitizer_kind, parse_typed_custom_function,
+        parse_validator_kind, parse_value_list, peek_shorthand, ParseValidators,
+        ParseableAttributes, RangeBounds, RangeEnd,
+    },
+    validate::{
+        validate_eq_mode_is_not_set, validate_from_encoding_is_not_set,
+        validate_ord_mode_is_not_set, validate_raw_validators_are_not_set,
     },
 };
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::ToTokens;
 use syn::{
     parse::{Parse, ParseStream},
-    Token,
+    LitInt, Token,
 };
 
 use super::{
@@ -21,14 +27,14 @@ use super::{
         IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerSanitizerKind, IntegerValidator,
         IntegerValidatorKind, SpannedIntegerSanitizer, SpannedIntegerValidator,
     },
-    validate::validate_number_meta,
+    validate::{validate_default_value, validate_enumerate, validate_number_meta},
 };
 
 pub fn parse_attributes<T>(
     input: TokenStream,
 ) -> Result<Attributes<IntegerGuard<T>, SpannedDeriveTrait>, syn::Error>
 where
-    T: FromStr + PartialOrd + Clone,
+    T: FromStr + PartialOrd + Clone + ToTokens,
     <T as FromStr>::Err: Debug + Display,
 {
     let attrs: ParseableAttributes<SpannedIntegerSanitizer<T>, SpannedIntegerValidator<T>> =
@@ -37,30 +43,114 @@ where
     let ParseableAttributes {
         sanitizers,
         validators,
+        raw_validators,
         new_unchecked,
         default,
+        non_exhaustive_error,
+        collect_errors,
+        allow_unused,
+        repr_transparent,
+        enumerate,
+        display,
+        new_fn,
+        inner_vis,
+        eq,
+        ord,
+        serde_repr,
+        schema_example,
+        from_env,
+        from_encoding,
+        sanitize_then_return_changed,
+        transparent_debug,
+        skip_new,
+        serde_lenient,
+        builder,
+        crate_path,
+        context,
+        async_validators,
         derive_traits,
     } = attrs;
+    let eq = validate_eq_mode_is_not_set(eq)?;
+    let ord = validate_ord_mode_is_not_set(ord)?;
+    validate_raw_validators_are_not_set(&raw_validators)?;
+    let from_encoding = validate_from_encoding_is_not_set(from_encoding)?;
     let raw_guard = IntegerRawGuard {
         sanitizers,
         validators,
     };
     let guard = validate_number_meta(raw_guard)?;
+    let default = validate_default_value(default, &guard)?;
+    let enumerate = validate_enumerate(enumerate, &guard)?;
     Ok(Attributes {
         new_unchecked,
         guard,
         default,
+        non_exhaustive_error,
+        collect_errors,
+        allow_unused,
+        repr_transparent: repr_transparent.is_some(),
+        enumerate,
+        display,
+        new_fn,
+        inner_vis,
+        eq,
+        ord,
+        serde_repr,
+        schema_example,
+        from_env,
+        from_encoding,
+        sanitize_then_return_changed,
+        transparent_debug,
+        skip_new,
+        serde_lenient,
+        builder,
+        crate_path,
+        context,
+        async_validators,
         derive_traits,
     })
 }
 
+impl<T> ParseValidators for SpannedIntegerValidator<T>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    /// Besides the regular validators, parse `range = 0..=100` shorthand, that expands into
+    /// `greater_or_equal` and `less_or_equal` (or `less` for an exclusive end).
+    fn parse_validators(input: ParseStream) -> syn::Result<Vec<Self>> {
+        if !peek_shorthand(input, "range") {
+            return Ok(vec![Self::parse(input)?]);
+        }
+        let _range: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let RangeBounds { start, end } = parse_range::<T>(input)?;
+
+        let mut validators = Vec::with_capacity(2);
+        if let Some((start, span)) = start {
+            validators.push(SpannedIntegerValidator {
+                item: IntegerValidator::GreaterOrEqual(start),
+                span,
+            });
+        }
+        if let Some((end, span)) = end {
+            let item = match end {
+                RangeEnd::Inclusive(end) => IntegerValidator::LessOrEqual(end),
+                RangeEnd::Exclusive(end) => IntegerValidator::Less(end),
+            };
+            validators.push(SpannedIntegerValidator { item, span });
+        }
+        Ok(validators)
+    }
+}
+
 impl<T> Parse for SpannedIntegerValidator<T>
 where
     T: FromStr,
     <T as FromStr>::Err: Display,
 {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let (kind, _ident) = parse_validator_kind(input)?;
+        let (kind, ident) = parse_validator_kind(input)?;
 
         match kind {
             IntegerValidatorKind::Greater => {
@@ -95,6 +185,28 @@ where
                     span,
                 })
             }
+            IntegerValidatorKind::Step => {
+                let _eq: Token![=] = input.parse()?;
+                let (step, span) = parse_positive_number_or_expr::<T>(input, "`step`")?;
+                Ok(SpannedIntegerValidator {
+                    item: IntegerValidator::Step(step),
+                    span,
+                })
+            }
+            IntegerValidatorKind::OneOf => {
+                let _eq: Token![=] = input.parse()?;
+                let (values, span) = parse_value_list(input, "one_of", |input| {
+                    Ok(parse_number_or_expr::<T>(input)?.0)
+                })?;
+                Ok(SpannedIntegerValidator {
+                    item: IntegerValidator::OneOf(values),
+                    span,
+                })
+            }
+            IntegerValidatorKind::NonZero => Ok(SpannedIntegerValidator {
+                item: IntegerValidator::NonZero,
+                span: ident.span(),
+            }),
             IntegerValidatorKind::Predicate => {
                 let _eq: Token![=] = input.parse()?;
                 let (typed_custom_function, span) = parse_typed_custom_function::<&T>(input)?;
@@ -103,6 +215,14 @@ where
                     span,
                 })
             }
+            IntegerValidatorKind::WithResult => {
+                let _eq: Token![=] = input.parse()?;
+                let (typed_custom_function, span) = parse_typed_custom_function::<&T>(input)?;
+                Ok(SpannedIntegerValidator {
+                    item: IntegerValidator::WithResult(typed_custom_function),
+                    span,
+                })
+            }
         }
     }
 }
@@ -124,6 +244,56 @@ where
                     span,
                 })
             }
+            IntegerSanitizerKind::TryWith => {
+                let _eq: Token![=] = input.parse()?;
+                let (typed_custom_function, span) = parse_typed_custom_function::<T>(input)?;
+                Ok(SpannedIntegerSanitizer {
+                    item: IntegerSanitizer::TryWith(typed_custom_function),
+                    span,
+                })
+            }
+            IntegerSanitizerKind::Wrapping => {
+                // Both `wrapping(360)` and `wrapping = 360` are accepted.
+                let (modulus, span) = if input.peek(syn::token::Paren) {
+                    let content;
+                    syn::parenthesized!(content in input);
+                    let modulus =
+                        parse_positive_number_or_expr::<T>(&content, "`wrapping` modulus")?;
+                    if !content.is_empty() {
+                        return Err(content.error("Unexpected tokens after the modulus.\nExpected `wrapping(modulus)`, for example: `wrapping(360)`"));
+                    }
+                    modulus
+                } else {
+                    let _eq: Token![=] = input.parse()?;
+                    parse_positive_number_or_expr::<T>(input, "`wrapping` modulus")?
+                };
+                Ok(SpannedIntegerSanitizer {
+                    item: IntegerSanitizer::Wrapping(modulus),
+                    span,
+                })
+            }
+            IntegerSanitizerKind::SaturatingClamp => {
+                let content;
+                let parens = syn::parenthesized!(content in input);
+                let (min, _) = parse_number_or_expr::<T>(&content)?;
+                let _: Token![,] = content.parse()?;
+                let (max, _) = parse_number_or_expr::<T>(&content)?;
+                if !content.is_empty() {
+                    return Err(content.error("Unexpected tokens after the max value.\nExpected `saturating_clamp(min, max)`, for example: `saturating_clamp(0, 100)`"));
+                }
+                Ok(SpannedIntegerSanitizer {
+                    item: IntegerSanitizer::SaturatingClamp { min, max },
+                    span: parens.span.join(),
+                })
+            }
+            IntegerSanitizerKind::Snap => {
+                let _eq: Token![=] = input.parse()?;
+                let (step, span) = parse_positive_number_or_expr::<T>(input, "`snap` step")?;
+                Ok(SpannedIntegerSanitizer {
+                    item: IntegerSanitizer::Snap { step, origin: None },
+                    span,
+                })
+            }
             IntegerSanitizerKind::_Phantom => {
                 let msg = format!("Unknown validator `{ident}`");
                 Err(syn::Error::new(ident.span(), msg))
@@ -131,3 +301,28 @@ where
         }
     }
 }
+
+/// Parse a number or an expression, which is expected to be positive.
+/// Obviously invalid literals (zero or negative) are caught early, since the generated code
+/// would panic on them (e.g. division by zero in `rem_euclid()`).
+fn parse_positive_number_or_expr<T>(
+    input: ParseStream,
+    what: &str,
+) -> syn::Result<(ValueOrExpr<T>, Span)>
+where
+    T: FromStr,
+    <T as FromStr>::Err: Display,
+{
+    let is_non_positive = input.peek(Token![-])
+        || input
+            .fork()
+            .parse::<LitInt>()
+            .map(|lit| lit.base10_parse::<u128>().ok() == Some(0))
+            .unwrap_or(false);
+    let (number, span) = parse_number_or_expr::<T>(input)?;
+    if is_non_positive {
+        let msg = format!("{what} must be a positive number.");
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok((number, span))
+}
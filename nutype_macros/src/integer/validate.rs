@@ -1,15 +1,16 @@
 use std::collections::HashSet;
 
 use proc_macro2::Span;
+use quote::ToTokens;
 
 use crate::common::{
-    models::{DeriveTrait, SpannedDeriveTrait},
-    validate::{validate_duplicates, validate_numeric_bounds},
+    models::{has_fallible_sanitizers, DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
+    validate::{are_bounds_symmetric, validate_duplicates, validate_numeric_bounds},
 };
 
 use super::models::{
-    IntegerDeriveTrait, IntegerGuard, IntegerRawGuard, IntegerSanitizer, IntegerValidator,
-    SpannedIntegerSanitizer, SpannedIntegerValidator,
+    IntegerDeriveTrait, IntegerGuard, IntegerInnerType, IntegerRawGuard, IntegerSanitizer,
+    IntegerValidator, SpannedIntegerSanitizer, SpannedIntegerValidator,
 };
 
 pub fn validate_number_meta<T>(raw_meta: IntegerRawGuard<T>) -> Result<IntegerGuard<T>, syn::Error>
@@ -24,16 +25,110 @@ where
     let validators = validate_validators(validators)?;
     let sanitizers = validate_sanitizers(sanitizers)?;
 
-    if validators.is_empty() {
+    // `step` counts from the lower bound, so `snap` has to round relative to it as well.
+    let maybe_lower_bound = validators.iter().find_map(|validator| match validator {
+        IntegerValidator::Greater(bound) | IntegerValidator::GreaterOrEqual(bound) => {
+            Some(bound.clone())
+        }
+        _ => None,
+    });
+    let sanitizers: Vec<IntegerSanitizer<T>> = sanitizers
+        .into_iter()
+        .map(|sanitizer| match sanitizer {
+            IntegerSanitizer::Snap { step, .. } => IntegerSanitizer::Snap {
+                step,
+                origin: maybe_lower_bound.clone(),
+            },
+            other => other,
+        })
+        .collect();
+
+    if validators.is_empty() && !has_fallible_sanitizers(&sanitizers) {
         Ok(IntegerGuard::WithoutValidation { sanitizers })
     } else {
         Ok(IntegerGuard::WithValidation {
             sanitizers,
             validators,
+            raw_validators: Vec::new(),
         })
     }
 }
 
+/// Resolve `default = min` into the lowest value allowed by `greater_or_equal` or `greater` validator.
+/// Any other default value is kept as it is.
+pub fn validate_default_value<T>(
+    maybe_default_value: Option<syn::Expr>,
+    guard: &IntegerGuard<T>,
+) -> Result<Option<syn::Expr>, syn::Error>
+where
+    T: ToTokens,
+{
+    let Some(default_value) = maybe_default_value else {
+        return Ok(None);
+    };
+    if !is_min_keyword(&default_value) {
+        return Ok(Some(default_value));
+    }
+
+    let validators: &[IntegerValidator<T>] = match guard {
+        IntegerGuard::WithValidation { validators, .. } => validators,
+        IntegerGuard::WithoutValidation { .. } => &[],
+    };
+    let maybe_min = validators.iter().find_map(|validator| match validator {
+        IntegerValidator::GreaterOrEqual(gte) => Some(syn::parse_quote!(#gte)),
+        IntegerValidator::Greater(gt) => Some(syn::parse_quote!(#gt + 1)),
+        _ => None,
+    });
+    match maybe_min {
+        Some(min) => Ok(Some(min)),
+        None => {
+            let msg =
+                "`default = min` requires `greater_or_equal` or `greater` validator to be set.";
+            Err(syn::Error::new_spanned(default_value, msg))
+        }
+    }
+}
+
+/// `enumerate` requires both a lower bound (`greater_or_equal` or `greater`) and an upper bound
+/// (`less_or_equal` or `less`), otherwise there are too many values to iterate over.
+pub fn validate_enumerate<T>(
+    maybe_enumerate: Option<Span>,
+    guard: &IntegerGuard<T>,
+) -> Result<bool, syn::Error> {
+    let Some(span) = maybe_enumerate else {
+        return Ok(false);
+    };
+    let validators: &[IntegerValidator<T>] = match guard {
+        IntegerGuard::WithValidation { validators, .. } => validators,
+        IntegerGuard::WithoutValidation { .. } => &[],
+    };
+    let has_lower_bound = validators.iter().any(|validator| {
+        matches!(
+            validator,
+            IntegerValidator::GreaterOrEqual(_) | IntegerValidator::Greater(_)
+        )
+    });
+    let has_upper_bound = validators.iter().any(|validator| {
+        matches!(
+            validator,
+            IntegerValidator::LessOrEqual(_) | IntegerValidator::Less(_)
+        )
+    });
+    if has_lower_bound && has_upper_bound {
+        Ok(true)
+    } else {
+        let msg = "`enumerate` requires both lower and upper boundaries.\nSet `greater_or_equal` (or `greater`) and `less_or_equal` (or `less`) validators.";
+        Err(syn::Error::new(span, msg))
+    }
+}
+
+fn is_min_keyword(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Path(expr_path) => expr_path.path.is_ident("min"),
+        _ => false,
+    }
+}
+
 fn validate_validators<T>(
     validators: Vec<SpannedIntegerValidator<T>>,
 ) -> Result<Vec<IntegerValidator<T>>, syn::Error>
@@ -62,25 +157,99 @@ where
         format!("Duplicated sanitizer `{kind}`.\nIt happens, don't worry. We still love you!")
     })?;
 
+    // saturating_clamp: min VS max
+    //
+    for sanitizer in &sanitizers {
+        if let IntegerSanitizer::SaturatingClamp {
+            min: ValueOrExpr::Value(min),
+            max: ValueOrExpr::Value(max),
+        } = &sanitizer.item
+        {
+            if min > max {
+                let msg = "`saturating_clamp` min cannot be greater than max.\nDid the arguments swap places?";
+                return Err(syn::Error::new(sanitizer.span, msg));
+            }
+        }
+    }
+
     let sanitizers: Vec<_> = sanitizers.into_iter().map(|s| s.item).collect();
     Ok(sanitizers)
 }
 
-pub fn validate_integer_derive_traits(
+pub fn validate_integer_derive_traits<T: ToTokens>(
     spanned_derive_traits: Vec<SpannedDeriveTrait>,
-    has_validation: bool,
+    guard: &IntegerGuard<T>,
+    inner_type: IntegerInnerType,
 ) -> Result<HashSet<IntegerDeriveTrait>, syn::Error> {
+    let has_validation = guard.has_validation();
     let mut traits = HashSet::with_capacity(24);
 
     for spanned_trait in spanned_derive_traits {
+        if spanned_trait.item == DeriveTrait::Neg {
+            validate_neg(guard, inner_type, spanned_trait.span)?;
+        }
         let string_derive_trait =
             to_integer_derive_trait(spanned_trait.item, has_validation, spanned_trait.span)?;
         traits.insert(string_derive_trait);
     }
 
+    // `From` already provides `TryFrom<Inner>` with `Infallible` error through the blanket
+    // implementation in `core`, so deriving both must not generate a conflicting implementation.
+    if !has_validation && traits.contains(&IntegerDeriveTrait::From) {
+        traits.remove(&IntegerDeriveTrait::TryFrom);
+    }
+
+    // `PartialOrd<Inner>` requires `PartialEq<Inner>`, so `OrdInner` brings `EqInner` along.
+    if traits.contains(&IntegerDeriveTrait::OrdInner) {
+        traits.insert(IntegerDeriveTrait::EqInner);
+    }
+
     Ok(traits)
 }
 
+/// `Neg` cannot fail, so with validation it's derived only if a negated valid value is
+/// valid as well: the bounds must be symmetric and the other validators must not care about the sign.
+fn validate_neg<T: ToTokens>(
+    guard: &IntegerGuard<T>,
+    inner_type: IntegerInnerType,
+    span: Span,
+) -> Result<(), syn::Error> {
+    if !inner_type.is_signed() {
+        let msg = format!("`Neg` cannot be derived for a type based on unsigned `{inner_type}`.");
+        return Err(syn::Error::new(span, msg));
+    }
+    let IntegerGuard::WithValidation {
+        sanitizers,
+        validators,
+        ..
+    } = guard
+    else {
+        return Ok(());
+    };
+    let mut lower = None;
+    let mut upper = None;
+    let mut has_other_validators = has_fallible_sanitizers(sanitizers);
+    for validator in validators {
+        match validator {
+            IntegerValidator::Greater(value) => lower = Some((value, true)),
+            IntegerValidator::GreaterOrEqual(value) => lower = Some((value, false)),
+            IntegerValidator::Less(value) => upper = Some((value, true)),
+            IntegerValidator::LessOrEqual(value) => upper = Some((value, false)),
+            IntegerValidator::NonZero => {}
+            IntegerValidator::Step(_)
+            | IntegerValidator::OneOf(_)
+            | IntegerValidator::Predicate(_)
+            | IntegerValidator::WithResult(_) => has_other_validators = true,
+        }
+    }
+    let has_bounds = lower.is_some() || upper.is_some();
+    if has_other_validators || (has_bounds && !are_bounds_symmetric(lower, upper)) {
+        let msg = "`Neg` cannot be derived, because a negated valid value is not necessarily valid.\nIt's allowed only together with symmetric bounds (set with literals) and `non_zero`, for example:\n    validate(greater_or_equal = -100, less_or_equal = 100)";
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(())
+}
+
 fn to_integer_derive_trait(
     tr: DeriveTrait,
     has_validation: bool,
@@ -99,13 +268,54 @@ fn to_integer_derive_trait(
         DeriveTrait::FromStr => Ok(IntegerDeriveTrait::FromStr),
         DeriveTrait::AsRef => Ok(IntegerDeriveTrait::AsRef),
         DeriveTrait::Deref => Ok(IntegerDeriveTrait::Deref),
+        DeriveTrait::EqInner => Ok(IntegerDeriveTrait::EqInner),
+        DeriveTrait::OrdInner => Ok(IntegerDeriveTrait::OrdInner),
+        DeriveTrait::Nutype => Ok(IntegerDeriveTrait::Nutype),
+        DeriveTrait::Sum => {
+            if has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `Sum` trait, because there is validation defined and the sum of valid values is not necessarily valid.",
+                ))
+            } else {
+                Ok(IntegerDeriveTrait::Sum)
+            }
+        }
+        DeriveTrait::Product => {
+            if has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `Product` trait, because there is validation defined and the product of valid values is not necessarily valid.",
+                ))
+            } else {
+                Ok(IntegerDeriveTrait::Product)
+            }
+        }
+        DeriveTrait::CheckedArith => Ok(IntegerDeriveTrait::CheckedArith),
+        DeriveTrait::Neg => Ok(IntegerDeriveTrait::Neg),
+        DeriveTrait::TryFromWide => Ok(IntegerDeriveTrait::TryFromWide),
+        DeriveTrait::FromIterator => Err(syn::Error::new(
+            span,
+            "`FromIterator` can be derived only for String based types",
+        )),
         DeriveTrait::Hash => Ok(IntegerDeriveTrait::Hash),
         DeriveTrait::Borrow => Ok(IntegerDeriveTrait::Borrow),
         DeriveTrait::Copy => Ok(IntegerDeriveTrait::Copy),
         DeriveTrait::SerdeSerialize => Ok(IntegerDeriveTrait::SerdeSerialize),
         DeriveTrait::SerdeDeserialize => Ok(IntegerDeriveTrait::SerdeDeserialize),
+        DeriveTrait::RkyvArchive => Ok(IntegerDeriveTrait::RkyvArchive),
+        DeriveTrait::SqlxJson => Ok(IntegerDeriveTrait::SqlxJson),
+        DeriveTrait::RedisToRedisArgs => Ok(IntegerDeriveTrait::RedisToRedisArgs),
+        DeriveTrait::RedisFromRedisValue => Ok(IntegerDeriveTrait::RedisFromRedisValue),
+        DeriveTrait::BincodeEncode => Ok(IntegerDeriveTrait::BincodeEncode),
+        DeriveTrait::BincodeDecode => Ok(IntegerDeriveTrait::BincodeDecode),
+        DeriveTrait::NumTraitsZero => Ok(IntegerDeriveTrait::NumTraitsZero),
+        DeriveTrait::NumTraitsOne => Ok(IntegerDeriveTrait::NumTraitsOne),
+        DeriveTrait::NumTraitsBounded => Ok(IntegerDeriveTrait::NumTraitsBounded),
         DeriveTrait::SchemarsJsonSchema => Ok(IntegerDeriveTrait::SchemarsJsonSchema),
         DeriveTrait::ArbitraryArbitrary => Ok(IntegerDeriveTrait::ArbitraryArbitrary),
+        DeriveTrait::ProptestArbitrary => Ok(IntegerDeriveTrait::ProptestArbitrary),
+        DeriveTrait::RandDistribution => Ok(IntegerDeriveTrait::RandDistribution),
         DeriveTrait::DieselNewType => Ok(IntegerDeriveTrait::DieselNewType),
         DeriveTrait::TryFrom => Ok(IntegerDeriveTrait::TryFrom),
         DeriveTrait::From => {
@@ -13,12 +13,14 @@ mod utils;
 
 use any::AnyNewtype;
 use common::{
+    gen::gen_inner_type_check,
     models::{InnerType, Newtype, TypedMeta},
-    parse::meta::parse_meta,
+    parse::meta::{extract_inner_type, parse_meta},
 };
 use float::{models::FloatInnerType, FloatNewtype};
 use integer::{models::IntegerInnerType, IntegerNewtype};
 use proc_macro2::TokenStream;
+use quote::quote;
 use string::StringNewtype;
 
 /// Defines sanitizers and validators on a newtype.
@@ -38,15 +40,26 @@ fn expand_nutype(
     attrs: TokenStream,
     type_definition: TokenStream,
 ) -> Result<TokenStream, syn::Error> {
-    let meta = parse_meta(type_definition)?;
+    let (attrs, maybe_explicit_inner_type) = extract_inner_type(attrs)?;
+    let meta = parse_meta(type_definition, maybe_explicit_inner_type.clone())?;
+    let inner_type_check = match maybe_explicit_inner_type {
+        Some(explicit_inner_type) => {
+            gen_inner_type_check(&meta.declared_inner_type, &explicit_inner_type)
+        }
+        None => TokenStream::new(),
+    };
     let (typed_meta, inner_type) = meta.into_typed_meta(attrs);
 
-    match inner_type {
+    let expanded = match inner_type {
         InnerType::String(tp) => StringNewtype::expand(typed_meta, tp),
         InnerType::Integer(inner) => expand_nutype_integer(typed_meta, inner),
         InnerType::Float(inner) => expand_nutype_float(typed_meta, inner),
         InnerType::Any(any_inner_type) => AnyNewtype::expand(typed_meta, any_inner_type),
-    }
+    }?;
+    Ok(quote!(
+        #expanded
+        #inner_type_check
+    ))
 }
 
 fn expand_nutype_integer(
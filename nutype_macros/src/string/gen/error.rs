@@ -3,31 +3,65 @@ use quote::quote;
 
 use crate::{
     common::{
-        gen::error::{gen_error_type_name, gen_impl_error_trait},
+        gen::error::{
+            gen_error_type_name, gen_impl_error_trait, gen_impl_validation_error_trait,
+            gen_multiple_errors_display_arm, gen_multiple_errors_variant, gen_non_exhaustive_attr,
+            gen_sanitization_failed_display_arm, gen_sanitization_failed_variant,
+        },
         models::{ErrorTypeName, TypeName},
+        parse::kind_name,
     },
     string::models::StringValidator,
 };
 
 pub fn gen_validation_error_type(
     type_name: &TypeName,
-    validators: &[StringValidator],
+    validators: &[&StringValidator],
+    non_exhaustive_error: bool,
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let error_type_name = gen_error_type_name(type_name);
-    let definition = gen_definition(&error_type_name, validators);
-    let impl_display_trait = gen_impl_display_trait(type_name, &error_type_name, validators);
+    let definition = gen_definition(
+        &error_type_name,
+        validators,
+        collect_errors,
+        has_fallible_sanitizers,
+    );
+    let impl_display_trait = gen_impl_display_trait(
+        type_name,
+        &error_type_name,
+        validators,
+        collect_errors,
+        has_fallible_sanitizers,
+    );
     let impl_error_trait = gen_impl_error_trait(&error_type_name);
+    let impl_validation_error_trait = gen_impl_validation_error_trait(
+        type_name,
+        &error_type_name,
+        gen_constraint_arms(&error_type_name, validators),
+        collect_errors,
+        has_fallible_sanitizers,
+    );
+    let non_exhaustive_attr = gen_non_exhaustive_attr(non_exhaustive_error);
 
     quote! {
         #[derive(Debug, Clone, PartialEq, Eq)]
+        #non_exhaustive_attr
         #definition
 
         #impl_display_trait
         #impl_error_trait
+        #impl_validation_error_trait
     }
 }
 
-fn gen_definition(error_type_name: &ErrorTypeName, validators: &[StringValidator]) -> TokenStream {
+fn gen_definition(
+    error_type_name: &ErrorTypeName,
+    validators: &[&StringValidator],
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
+) -> TokenStream {
     let error_variants: TokenStream = validators
         .iter()
         .map(|validator| match validator {
@@ -37,22 +71,82 @@ fn gen_definition(error_type_name: &ErrorTypeName, validators: &[StringValidator
             StringValidator::LenCharMin(_len) => {
                 quote!(LenCharMinViolated,)
             }
+            StringValidator::LenGraphemeMax(_len) => {
+                quote!(LenGraphemeMaxViolated,)
+            }
+            StringValidator::LenUtf16Max(_len) => {
+                quote!(LenUtf16MaxViolated,)
+            }
             StringValidator::NotEmpty => {
                 quote!(NotEmptyViolated,)
             }
+            StringValidator::Ascii => {
+                quote!(AsciiViolated,)
+            }
+            StringValidator::Alphanumeric => {
+                quote!(AlphanumericViolated,)
+            }
+            StringValidator::StartsWith(_) => {
+                quote!(StartsWithViolated,)
+            }
+            StringValidator::EndsWith(_) => {
+                quote!(EndsWithViolated,)
+            }
+            StringValidator::StartsWithAny(_) => {
+                quote!(StartsWithAnyViolated,)
+            }
+            StringValidator::EndsWithAny(_) => {
+                quote!(EndsWithAnyViolated,)
+            }
+            StringValidator::Contains(_) => {
+                quote!(ContainsViolated,)
+            }
+            StringValidator::ContainsOnly(_) => {
+                quote!(ContainsOnlyViolated(char),)
+            }
+            StringValidator::Min(_) => {
+                quote!(MinViolated,)
+            }
+            StringValidator::Max(_) => {
+                quote!(MaxViolated,)
+            }
+            StringValidator::OneOf(_) => {
+                quote!(OneOfViolated,)
+            }
+            StringValidator::AnyOf(_) => {
+                quote!(AnyOfViolated,)
+            }
+            StringValidator::AllOf(_) => {
+                quote!(AllOfViolated,)
+            }
             StringValidator::Predicate(_) => {
                 quote!(PredicateViolated,)
             }
+            StringValidator::WithResult(_) => {
+                quote!(WithResultViolated(::std::string::String),)
+            }
             StringValidator::Regex(_) => {
                 quote!(RegexViolated,)
             }
+            StringValidator::Email => {
+                quote!(EmailViolated,)
+            }
+            StringValidator::Url { schemes } => match schemes {
+                Some(_) => quote!(UrlViolated, UrlSchemeViolated,),
+                None => quote!(UrlViolated,),
+            },
         })
         .collect();
 
+    let multiple_variant = gen_multiple_errors_variant(error_type_name, collect_errors);
+    let sanitization_failed_variant = gen_sanitization_failed_variant(has_fallible_sanitizers);
+
     quote! {
         #[allow(clippy::enum_variant_names)]
         pub enum #error_type_name {
             #error_variants
+            #sanitization_failed_variant
+            #multiple_variant
         }
     }
 }
@@ -60,7 +154,9 @@ fn gen_definition(error_type_name: &ErrorTypeName, validators: &[StringValidator
 fn gen_impl_display_trait(
     type_name: &TypeName,
     error_type_name: &ErrorTypeName,
-    validators: &[StringValidator],
+    validators: &[&StringValidator],
+    collect_errors: bool,
+    has_fallible_sanitizers: bool,
 ) -> TokenStream {
     let match_arms = validators.iter().map(|validator| match validator {
         StringValidator::LenCharMax(len_char_max) => quote! {
@@ -69,24 +165,143 @@ fn gen_impl_display_trait(
         StringValidator::LenCharMin(len_char_min) => quote! {
              #error_type_name::LenCharMinViolated => write!(f, "{} is too short. The value length must be more than {:#?} character(s).", stringify!(#type_name), #len_char_min)
         },
+        StringValidator::LenGraphemeMax(len_grapheme_max) => quote! {
+             #error_type_name::LenGraphemeMaxViolated => write!(f, "{} is too long. The value length must not exceed {:#?} grapheme(s).", stringify!(#type_name), #len_grapheme_max)
+        },
+        StringValidator::LenUtf16Max(len_utf16_max) => quote! {
+             #error_type_name::LenUtf16MaxViolated => write!(f, "{} is too long. The value length must not exceed {:#?} UTF-16 code unit(s).", stringify!(#type_name), #len_utf16_max)
+        },
         StringValidator::NotEmpty => quote! {
              #error_type_name::NotEmptyViolated => write!(f, "{} is empty.", stringify!(#type_name))
         },
+        StringValidator::Ascii => quote! {
+             #error_type_name::AsciiViolated => write!(f, "{} contains non-ASCII characters.", stringify!(#type_name))
+        },
+        StringValidator::Alphanumeric => quote! {
+             #error_type_name::AlphanumericViolated => write!(f, "{} contains non-alphanumeric characters.", stringify!(#type_name))
+        },
+        StringValidator::StartsWith(prefix) => quote! {
+             #error_type_name::StartsWithViolated => write!(f, "{} does not start with {:?}.", stringify!(#type_name), #prefix)
+        },
+        StringValidator::EndsWith(suffix) => quote! {
+             #error_type_name::EndsWithViolated => write!(f, "{} does not end with {:?}.", stringify!(#type_name), #suffix)
+        },
+        StringValidator::StartsWithAny(prefixes) => quote! {
+             #error_type_name::StartsWithAnyViolated => write!(f, "{} does not start with any of {:?}.", stringify!(#type_name), [#(#prefixes),*])
+        },
+        StringValidator::EndsWithAny(suffixes) => quote! {
+             #error_type_name::EndsWithAnyViolated => write!(f, "{} does not end with any of {:?}.", stringify!(#type_name), [#(#suffixes),*])
+        },
+        StringValidator::Contains(needle) => quote! {
+             #error_type_name::ContainsViolated => write!(f, "{} does not contain {:?}.", stringify!(#type_name), #needle)
+        },
+        StringValidator::Min(min) => quote! {
+             #error_type_name::MinViolated => write!(f, "{} is too small. The value must not be lexicographically less than {:?}.", stringify!(#type_name), #min)
+        },
+        StringValidator::Max(max) => quote! {
+             #error_type_name::MaxViolated => write!(f, "{} is too big. The value must not be lexicographically greater than {:?}.", stringify!(#type_name), #max)
+        },
+        StringValidator::ContainsOnly(_) => quote! {
+             #error_type_name::ContainsOnlyViolated(illegal) => write!(f, "{} contains an illegal character {:?}.", stringify!(#type_name), illegal)
+        },
+        StringValidator::OneOf(values) => quote! {
+             #error_type_name::OneOfViolated => write!(f, "{} is not one of the allowed values {:?}.", stringify!(#type_name), [#(#values),*])
+        },
+        StringValidator::AnyOf(_) => quote! {
+             #error_type_name::AnyOfViolated => write!(f, "{} does not pass any of the `any_of` validators.", stringify!(#type_name))
+        },
+        StringValidator::AllOf(_) => quote! {
+             #error_type_name::AllOfViolated => write!(f, "{} does not pass all of the `all_of` validators.", stringify!(#type_name))
+        },
         StringValidator::Predicate(_) => quote! {
              #error_type_name::PredicateViolated => write!(f, "{} failed the predicate test.", stringify!(#type_name))
         },
+        StringValidator::WithResult(_) => quote! {
+             #error_type_name::WithResultViolated(message) => write!(f, "{} is invalid: {}", stringify!(#type_name), message)
+        },
         StringValidator::Regex(_) => quote! {
              #error_type_name::RegexViolated => write!(f, "{} violated the regular expression.", stringify!(#type_name))
         },
+        StringValidator::Email => quote! {
+             #error_type_name::EmailViolated => write!(f, "{} is not a valid email address.", stringify!(#type_name))
+        },
+        StringValidator::Url { schemes } => {
+            let url_violated_arm = quote! {
+                #error_type_name::UrlViolated => write!(f, "{} is not a valid URL.", stringify!(#type_name))
+            };
+            match schemes {
+                Some(schemes) => quote! {
+                    #url_violated_arm,
+                    #error_type_name::UrlSchemeViolated => write!(f, "{} has a URL scheme that is not one of the allowed schemes {:?}.", stringify!(#type_name), [#(#schemes),*])
+                },
+                None => url_violated_arm,
+            }
+        }
     });
 
+    let sanitization_failed_arm =
+        gen_sanitization_failed_display_arm(type_name, error_type_name, has_fallible_sanitizers);
+    let multiple_arm = gen_multiple_errors_display_arm(error_type_name, collect_errors);
+
     quote! {
         impl ::core::fmt::Display for #error_type_name {
             fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
                 match self {
                     #(#match_arms,)*
+                    #sanitization_failed_arm
+                    #multiple_arm
                 }
             }
         }
     }
 }
+
+/// Map every error variant to the name of the validator that produces it.
+fn gen_constraint_arms(
+    error_type_name: &ErrorTypeName,
+    validators: &[&StringValidator],
+) -> Vec<TokenStream> {
+    validators
+        .iter()
+        .map(|validator| {
+            let constraint = kind_name(validator.kind());
+            let pattern = match validator {
+                StringValidator::LenCharMax(_) => quote!(#error_type_name::LenCharMaxViolated),
+                StringValidator::LenCharMin(_) => quote!(#error_type_name::LenCharMinViolated),
+                StringValidator::LenGraphemeMax(_) => {
+                    quote!(#error_type_name::LenGraphemeMaxViolated)
+                }
+                StringValidator::LenUtf16Max(_) => quote!(#error_type_name::LenUtf16MaxViolated),
+                StringValidator::NotEmpty => quote!(#error_type_name::NotEmptyViolated),
+                StringValidator::Ascii => quote!(#error_type_name::AsciiViolated),
+                StringValidator::Alphanumeric => quote!(#error_type_name::AlphanumericViolated),
+                StringValidator::StartsWith(_) => quote!(#error_type_name::StartsWithViolated),
+                StringValidator::EndsWith(_) => quote!(#error_type_name::EndsWithViolated),
+                StringValidator::StartsWithAny(_) => {
+                    quote!(#error_type_name::StartsWithAnyViolated)
+                }
+                StringValidator::EndsWithAny(_) => quote!(#error_type_name::EndsWithAnyViolated),
+                StringValidator::Contains(_) => quote!(#error_type_name::ContainsViolated),
+                StringValidator::ContainsOnly(_) => {
+                    quote!(#error_type_name::ContainsOnlyViolated(_))
+                }
+                StringValidator::Min(_) => quote!(#error_type_name::MinViolated),
+                StringValidator::Max(_) => quote!(#error_type_name::MaxViolated),
+                StringValidator::OneOf(_) => quote!(#error_type_name::OneOfViolated),
+                StringValidator::AnyOf(_) => quote!(#error_type_name::AnyOfViolated),
+                StringValidator::AllOf(_) => quote!(#error_type_name::AllOfViolated),
+                StringValidator::Predicate(_) => quote!(#error_type_name::PredicateViolated),
+                StringValidator::WithResult(_) => quote!(#error_type_name::WithResultViolated(_)),
+                StringValidator::Regex(_) => quote!(#error_type_name::RegexViolated),
+                StringValidator::Email => quote!(#error_type_name::EmailViolated),
+                StringValidator::Url { schemes } => match schemes {
+                    Some(_) => {
+                        quote!(#error_type_name::UrlViolated | #error_type_name::UrlSchemeViolated)
+                    }
+                    None => quote!(#error_type_name::UrlViolated),
+                },
+            };
+            quote!(#pattern => #constraint)
+        })
+        .collect()
+}
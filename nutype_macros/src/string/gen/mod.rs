@@ -4,16 +4,23 @@ pub mod traits;
 
 use std::collections::HashSet;
 
+use cfg_if::cfg_if;
 use proc_macro2::TokenStream;
 use quote::quote;
+use syn::Generics;
 
 use crate::{
     common::{
         gen::{
-            error::gen_error_type_name, tests::gen_test_should_have_valid_default_value,
-            traits::GeneratedTraits, GenerateNewtype,
+            error::gen_error_type_name, gen_call_custom_validation_fn, gen_call_fallible_sanitizer,
+            gen_context_param, gen_sanitize_output, gen_validation_checks,
+            tests::gen_test_should_have_valid_default_value, traits::GeneratedTraits,
+            GenerateNewtype,
+        },
+        models::{
+            has_fallible_sanitizers, ConstructorName, EqMode, ErrorTypeName, Guard, OrdMode,
+            SerdeRepr, TypeName,
         },
-        models::{ErrorTypeName, Guard, TypeName},
     },
     string::models::{RegexDef, StringInnerType, StringSanitizer, StringValidator},
 };
@@ -38,6 +45,32 @@ impl GenerateNewtype for StringNewtype {
     // This allows to use &str with it.
     const NEW_CONVERT_INTO_INNER_TYPE: bool = true;
 
+    // Validation needs only a borrowed `&str`, so `&String` is not required.
+    fn gen_validate_input_type(
+        _inner_type: &Self::InnerType,
+        validators: &[&Self::Validator],
+    ) -> TokenStream {
+        gen_validated_value_type(validators.iter().copied())
+    }
+
+    // A regex defined as a string literal is compiled once and shared by the constructor,
+    // `validate()` and all the other places that run the validation.
+    fn gen_validation_statics(validators: &[&Self::Validator]) -> TokenStream {
+        validators
+            .iter()
+            .filter_map(|validator| match validator {
+                StringValidator::Regex(RegexDef::StringLiteral(regex_str_lit)) => Some(quote!(
+                    ::lazy_static::lazy_static! {
+                        // Make up a sufficiently unique regex name to ensure that it does
+                        // not clashes with anything import with `use super::*`.
+                        static ref __NUTYPE_REGEX__: ::regex::Regex = ::regex::Regex::new(#regex_str_lit).expect("Nutype failed to a build a regex");
+                    }
+                )),
+                _ => None,
+            })
+            .collect()
+    }
+
     fn gen_fn_sanitize(
         _inner_type: &Self::InnerType,
         sanitizers: &[Self::Sanitizer],
@@ -62,18 +95,73 @@ impl GenerateNewtype for StringNewtype {
                         let value: String = value.to_uppercase();
                     )
                 }
+                StringSanitizer::DedupWhitespace => {
+                    // Every run of whitespace characters (including the leading and trailing ones)
+                    // becomes a single space. Removing them entirely is up to `trim`.
+                    quote!(
+                        let value: String = {
+                            let mut deduped = String::with_capacity(value.len());
+                            let mut is_prev_whitespace = false;
+                            for c in value.chars() {
+                                if c.is_whitespace() {
+                                    if !is_prev_whitespace {
+                                        deduped.push(' ');
+                                    }
+                                    is_prev_whitespace = true;
+                                } else {
+                                    deduped.push(c);
+                                    is_prev_whitespace = false;
+                                }
+                            }
+                            deduped
+                        };
+                    )
+                }
+                // The length is measured in chars, so a multi-byte char is never split.
+                StringSanitizer::Truncate(max_len) => {
+                    quote!(
+                        let value: String = {
+                            let mut value = value;
+                            let max_len: usize = #max_len;
+                            if let Some((index, _)) = value.char_indices().nth(max_len) {
+                                value.truncate(index);
+                            }
+                            value
+                        };
+                    )
+                }
+                StringSanitizer::Pad { len, fill } => {
+                    quote!(
+                        let value: String = {
+                            let mut value = value;
+                            let len: usize = #len;
+                            let fill: char = #fill;
+                            let missing = len.saturating_sub(value.chars().count());
+                            value.extend(::core::iter::repeat(fill).take(missing));
+                            value
+                        };
+                    )
+                }
                 StringSanitizer::With(typed_custom_function) => {
                     quote!(
                         let value: String = (#typed_custom_function)(value);
                     )
                 }
+                StringSanitizer::TryWith(typed_custom_function) => {
+                    let call = gen_call_fallible_sanitizer(typed_custom_function);
+                    quote!(
+                        let value: String = #call;
+                    )
+                }
             })
             .collect();
+        let (output_type, output_value) =
+            gen_sanitize_output(quote!(String), has_fallible_sanitizers(sanitizers));
 
         quote!(
-            fn sanitize(value: String) -> String {
+            fn sanitize(value: String) -> #output_type {
                 #transformations
-                value
+                #output_value
             }
         )
     }
@@ -82,72 +170,29 @@ impl GenerateNewtype for StringNewtype {
         _inner_type: &Self::InnerType,
         type_name: &TypeName,
         validators: &[Self::Validator],
+        collect_errors: bool,
+        maybe_context: Option<&syn::Type>,
     ) -> TokenStream {
         let error_name = gen_error_type_name(type_name);
+        let context_param = gen_context_param(maybe_context);
 
         // Indicates that `chars_count` variable needs to be set, which is used within
         // min_len and max_len validations.
         let mut requires_chars_count = false;
 
-        let validations: TokenStream = validators
+        let checks: Vec<TokenStream> = validators
             .iter()
-            .map(|validator| match validator {
-                StringValidator::LenCharMax(max_len) => {
-                    requires_chars_count = true;
-                    quote!(
-                        if chars_count > #max_len {
-                            return Err(#error_name::LenCharMaxViolated);
-                        }
-                    )
-                }
-                StringValidator::LenCharMin(min_len) => {
-                    requires_chars_count = true;
-                    quote!(
-                        if chars_count < #min_len {
-                            return Err(#error_name::LenCharMinViolated);
-                        }
-                    )
-                }
-                StringValidator::NotEmpty => {
-                    quote!(
-                        if val.is_empty() {
-                            return Err(#error_name::NotEmptyViolated);
-                        }
-                    )
-                }
-                StringValidator::Predicate(typed_custom_function) => {
-                    quote!(
-                        if !(#typed_custom_function)(&val) {
-                            return Err(#error_name::PredicateViolated);
-                        }
-                    )
-                }
-                StringValidator::Regex(regex_def) => {
-                    match regex_def {
-                        RegexDef::StringLiteral(regex_str_lit) => {
-                            quote!(
-                                lazy_static::lazy_static! {
-                                    // Make up a sufficiently unique regex name to ensure that it does
-                                    // not clashes with anything import with `use super::*`.
-                                    static ref __NUTYPE_REGEX__: ::regex::Regex = ::regex::Regex::new(#regex_str_lit).expect("Nutype failed to a build a regex");
-                                }
-                                if !__NUTYPE_REGEX__.is_match(&val) {
-                                    return Err(#error_name::RegexViolated);
-                                }
-                            )
-
-                        }
-                        RegexDef::Path(regex_path) => {
-                            quote!(
-                                if !#regex_path.is_match(&val) {
-                                    return Err(#error_name::RegexViolated);
-                                }
-                            )
-                        }
-                    }
-                }
+            .map(|validator| {
+                gen_check(
+                    validator,
+                    maybe_context,
+                    &mut requires_chars_count,
+                    &|variant| quote!(return Err(#error_name::#variant);),
+                )
             })
             .collect();
+        let validations = gen_validation_checks(&error_name, checks, collect_errors);
+        let val_type = gen_validated_value_type(validators.iter());
 
         let chars_count_if_required = if requires_chars_count {
             quote!(
@@ -158,7 +203,7 @@ impl GenerateNewtype for StringNewtype {
         };
 
         quote!(
-            fn validate(val: &str) -> ::core::result::Result<(), #error_name> {
+            fn validate(val: #val_type #context_param) -> ::core::result::Result<(), #error_name> {
                 #chars_count_if_required
                 #validations
                 Ok(())
@@ -166,31 +211,141 @@ impl GenerateNewtype for StringNewtype {
         )
     }
 
+    // Borrow as `Cow<str>` rather than `Cow<String>`, so it plays well with the APIs that
+    // take `Cow<str>`. Like for the other types, it's not generated in `no_std` environment.
+    #[allow(unused_variables)]
+    fn gen_impl_as_cow(
+        type_name: &TypeName,
+        generics: &Generics,
+        _inner_type: &Self::InnerType,
+    ) -> TokenStream {
+        cfg_if! {
+            if #[cfg(feature = "std")] {
+                let (impl_generics, ty_generics, _) = generics.split_for_impl();
+                quote! {
+                    impl #impl_generics #type_name #ty_generics {
+                        #[inline]
+                        pub fn as_cow(&self) -> ::std::borrow::Cow<'_, str> {
+                            ::std::borrow::Cow::Borrowed(self.0.as_str())
+                        }
+                    }
+                }
+            } else {
+                quote!{}
+            }
+        }
+    }
+
+    fn gen_impl_inner_views(
+        type_name: &TypeName,
+        generics: &Generics,
+        _inner_type: &Self::InnerType,
+    ) -> TokenStream {
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
+        quote! {
+            impl #impl_generics #type_name #ty_generics {
+                #[inline]
+                pub fn as_str(&self) -> &str {
+                    self.0.as_str()
+                }
+
+                #[inline]
+                pub fn as_bytes(&self) -> &[u8] {
+                    self.0.as_bytes()
+                }
+            }
+        }
+    }
+
+    // The length is measured in chars, the same way as `len_char_min` and `len_char_max` do.
+    fn gen_impl_bound_consts(
+        type_name: &TypeName,
+        generics: &Generics,
+        _inner_type: &Self::InnerType,
+        guard: &StringGuard,
+    ) -> TokenStream {
+        let validators = guard.validators().map(Vec::as_slice).unwrap_or_default();
+        let min_len = validators
+            .iter()
+            .find_map(|validator| match validator {
+                StringValidator::LenCharMin(min) => Some(quote!(Some(#min))),
+                _ => None,
+            })
+            .or_else(|| {
+                validators
+                    .iter()
+                    .any(|validator| matches!(validator, StringValidator::NotEmpty))
+                    .then(|| quote!(Some(1)))
+            })
+            .unwrap_or_else(|| quote!(None));
+        let max_len = validators
+            .iter()
+            .find_map(|validator| match validator {
+                StringValidator::LenCharMax(max) => Some(quote!(Some(#max))),
+                _ => None,
+            })
+            .unwrap_or_else(|| quote!(None));
+        let (impl_generics, ty_generics, _) = generics.split_for_impl();
+
+        quote! {
+            impl #impl_generics #type_name #ty_generics {
+                /// The min length in chars set with `len_char_min` (or `not_empty`).
+                pub const MIN_LEN: Option<usize> = #min_len;
+                /// The max length in chars set with `len_char_max`.
+                pub const MAX_LEN: Option<usize> = #max_len;
+            }
+        }
+    }
+
     fn gen_validation_error_type(
         type_name: &TypeName,
-        validators: &[Self::Validator],
+        validators: &[&Self::Validator],
+        non_exhaustive_error: bool,
+        collect_errors: bool,
+        has_fallible_sanitizers: bool,
     ) -> TokenStream {
-        gen_validation_error_type(type_name, validators)
+        gen_validation_error_type(
+            type_name,
+            validators,
+            non_exhaustive_error,
+            collect_errors,
+            has_fallible_sanitizers,
+        )
     }
 
     fn gen_traits(
         type_name: &TypeName,
+        generics: &Generics,
+        constructor_name: &ConstructorName,
         _inner_type: &Self::InnerType,
         maybe_error_type_name: Option<ErrorTypeName>,
         traits: HashSet<Self::TypedTrait>,
         maybe_default_value: Option<syn::Expr>,
-        _guard: &StringGuard,
+        maybe_display_format: Option<syn::LitStr>,
+        eq: EqMode,
+        ord: OrdMode,
+        serde_repr: &SerdeRepr,
+        _serde_lenient: bool,
+        guard: &StringGuard,
     ) -> Result<GeneratedTraits, syn::Error> {
-        Ok(gen_traits(
+        gen_traits(
             type_name,
+            generics,
+            constructor_name,
             maybe_error_type_name,
             traits,
             maybe_default_value,
-        ))
+            maybe_display_format,
+            eq,
+            ord,
+            serde_repr,
+            guard,
+        )
     }
 
     fn gen_tests(
         type_name: &TypeName,
+        constructor_name: &ConstructorName,
         _inner_type: &Self::InnerType,
         maybe_default_value: &Option<syn::Expr>,
         guard: &Guard<Self::Sanitizer, Self::Validator>,
@@ -202,6 +357,7 @@ impl GenerateNewtype for StringNewtype {
 
         let test_valid_default_value = gen_test_should_have_valid_default_value(
             type_name,
+            constructor_name,
             maybe_default_value,
             guard.has_validation(),
         );
@@ -212,3 +368,296 @@ impl GenerateNewtype for StringNewtype {
         }
     }
 }
+
+/// Generate the check of a single validator. When the value does not pass the validator,
+/// the check runs the code generated by `on_violation` for the error variant.
+/// Validators receive `&str` borrowed from the inner `String`, unless some closure expects
+/// `&String`. Then they all receive `&String`, so the value is not copied for that closure.
+fn gen_validated_value_type<'a>(
+    mut validators: impl Iterator<Item = &'a StringValidator>,
+) -> TokenStream {
+    if validators.any(StringValidator::takes_string_ref) {
+        quote!(&::std::string::String)
+    } else {
+        quote!(&str)
+    }
+}
+
+fn gen_check(
+    validator: &StringValidator,
+    maybe_context: Option<&syn::Type>,
+    requires_chars_count: &mut bool,
+    on_violation: &dyn Fn(TokenStream) -> TokenStream,
+) -> TokenStream {
+    match validator {
+        StringValidator::LenCharMax(max_len) => {
+            *requires_chars_count = true;
+            let violated = on_violation(quote!(LenCharMaxViolated));
+            quote!(
+                if chars_count > #max_len {
+                    #violated
+                }
+            )
+        }
+        StringValidator::LenGraphemeMax(max_len) => {
+            let violated = on_violation(quote!(LenGraphemeMaxViolated));
+            quote!(
+                if ::nutype::grapheme::grapheme_count(val) > #max_len {
+                    #violated
+                }
+            )
+        }
+        // The length of a JavaScript string, which is measured in UTF-16 code units.
+        StringValidator::LenUtf16Max(max_len) => {
+            let violated = on_violation(quote!(LenUtf16MaxViolated));
+            quote!(
+                if val.encode_utf16().count() > #max_len {
+                    #violated
+                }
+            )
+        }
+        StringValidator::LenCharMin(min_len) => {
+            *requires_chars_count = true;
+            let violated = on_violation(quote!(LenCharMinViolated));
+            quote!(
+                if chars_count < #min_len {
+                    #violated
+                }
+            )
+        }
+        StringValidator::NotEmpty => {
+            let violated = on_violation(quote!(NotEmptyViolated));
+            quote!(
+                if val.is_empty() {
+                    #violated
+                }
+            )
+        }
+        StringValidator::Ascii => {
+            let violated = on_violation(quote!(AsciiViolated));
+            quote!(
+                if !val.is_ascii() {
+                    #violated
+                }
+            )
+        }
+        StringValidator::Alphanumeric => {
+            let violated = on_violation(quote!(AlphanumericViolated));
+            quote!(
+                if !val.chars().all(char::is_alphanumeric) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::StartsWith(prefix) => {
+            let violated = on_violation(quote!(StartsWithViolated));
+            quote!(
+                if !val.starts_with(#prefix) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::EndsWith(suffix) => {
+            let violated = on_violation(quote!(EndsWithViolated));
+            quote!(
+                if !val.ends_with(#suffix) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::StartsWithAny(prefixes) => {
+            let violated = on_violation(quote!(StartsWithAnyViolated));
+            quote!(
+                const PREFIXES: &[&str] = &[#(#prefixes),*];
+                if !PREFIXES.iter().any(|prefix| val.starts_with(prefix)) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::EndsWithAny(suffixes) => {
+            let violated = on_violation(quote!(EndsWithAnyViolated));
+            quote!(
+                const SUFFIXES: &[&str] = &[#(#suffixes),*];
+                if !SUFFIXES.iter().any(|suffix| val.ends_with(suffix)) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::Contains(needle) => {
+            let violated = on_violation(quote!(ContainsViolated));
+            quote!(
+                if !val.contains(#needle) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::Min(min) => {
+            let violated = on_violation(quote!(MinViolated));
+            quote!(
+                let min: &str = #min;
+                if val < min {
+                    #violated
+                }
+            )
+        }
+        StringValidator::Max(max) => {
+            let violated = on_violation(quote!(MaxViolated));
+            quote!(
+                let max: &str = #max;
+                if val > max {
+                    #violated
+                }
+            )
+        }
+        StringValidator::ContainsOnly(charset) => {
+            let violated = on_violation(quote!(ContainsOnlyViolated(illegal)));
+            quote!(
+                let allowed: &str = #charset;
+                if let Some(illegal) = val.chars().find(|c| !allowed.contains(*c)) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::OneOf(values) => {
+            let violated = on_violation(quote!(OneOfViolated));
+            quote!(
+                const ALLOWED: &[&str] = &[#(#values),*];
+                if !ALLOWED.contains(&val) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::AnyOf(validators) => {
+            let alternatives = gen_nested_checks(validators, maybe_context, requires_chars_count);
+            let violated = on_violation(quote!(AnyOfViolated));
+            quote!(
+                // `||` skips the rest of the alternatives as soon as one of them passes.
+                #[allow(clippy::redundant_closure_call)]
+                let is_valid = #(#alternatives)||*;
+                if !is_valid {
+                    #violated
+                }
+            )
+        }
+        StringValidator::AllOf(validators) => {
+            let requirements = gen_nested_checks(validators, maybe_context, requires_chars_count);
+            let violated = on_violation(quote!(AllOfViolated));
+            quote!(
+                #[allow(clippy::redundant_closure_call)]
+                let is_valid = #(#requirements)&&*;
+                if !is_valid {
+                    #violated
+                }
+            )
+        }
+        StringValidator::Predicate(typed_custom_function) => {
+            let value_type = gen_validated_value_type(std::iter::once(validator));
+            let is_valid = gen_call_custom_validation_fn(
+                typed_custom_function,
+                quote!(val),
+                value_type,
+                quote!(bool),
+                maybe_context,
+            );
+            let violated = on_violation(quote!(PredicateViolated));
+            quote!(
+                if !#is_valid {
+                    #violated
+                }
+            )
+        }
+        StringValidator::WithResult(custom_validate_fn) => {
+            let value_type = gen_validated_value_type(std::iter::once(validator));
+            let result = gen_call_custom_validation_fn(
+                custom_validate_fn,
+                quote!(val),
+                value_type,
+                quote!(::core::result::Result<(), ::std::string::String>),
+                maybe_context,
+            );
+            let violated = on_violation(quote!(WithResultViolated(message)));
+            quote!(
+                let result: ::core::result::Result<(), ::std::string::String> = #result;
+                if let Err(message) = result {
+                    #violated
+                }
+            )
+        }
+        StringValidator::Regex(regex_def) => {
+            let violated = on_violation(quote!(RegexViolated));
+            match regex_def {
+                RegexDef::StringLiteral(_) => {
+                    // The regex is compiled once into a static, see `gen_validation_statics()`.
+                    quote!(
+                        if !__NUTYPE_REGEX__.is_match(&val) {
+                            #violated
+                        }
+                    )
+                }
+                RegexDef::Path(regex_path) => {
+                    quote!(
+                        if !#regex_path.is_match(&val) {
+                            #violated
+                        }
+                    )
+                }
+            }
+        }
+        StringValidator::Email => {
+            let violated = on_violation(quote!(EmailViolated));
+            quote!(
+                if !::nutype::email::is_valid_email(val) {
+                    #violated
+                }
+            )
+        }
+        StringValidator::Url { schemes } => {
+            let url_violated = on_violation(quote!(UrlViolated));
+            match schemes {
+                Some(schemes) => {
+                    let scheme_violated = on_violation(quote!(UrlSchemeViolated));
+                    quote!(
+                        const ALLOWED_SCHEMES: &[&str] = &[#(#schemes),*];
+                        match ::nutype::url::parse_url_scheme(val) {
+                            None => { #url_violated }
+                            Some(scheme) => {
+                                if !ALLOWED_SCHEMES.contains(&scheme.as_str()) {
+                                    #scheme_violated
+                                }
+                            }
+                        }
+                    )
+                }
+                None => quote!(
+                    if ::nutype::url::parse_url_scheme(val).is_none() {
+                        #url_violated
+                    }
+                ),
+            }
+        }
+    }
+}
+
+/// Generate the validators nested within `any_of` or `all_of` as boolean expressions.
+/// Every nested check runs within its own closure, that returns `false` on violation.
+fn gen_nested_checks(
+    validators: &[StringValidator],
+    maybe_context: Option<&syn::Type>,
+    requires_chars_count: &mut bool,
+) -> Vec<TokenStream> {
+    validators
+        .iter()
+        .map(|validator| {
+            let check = gen_check(
+                validator,
+                maybe_context,
+                requires_chars_count,
+                &|_variant| quote!(return false;),
+            );
+            quote!((|| -> bool {
+                #check
+                true
+            })())
+        })
+        .collect()
+}
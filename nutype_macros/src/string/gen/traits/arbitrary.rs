@@ -0,0 +1,199 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    common::models::{ConstructorName, TypeName},
+    string::models::{StringGuard, StringValidator},
+};
+
+pub fn gen_impl_trait_arbitrary(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    guard: &StringGuard,
+) -> TokenStream {
+    let (generate_inner, construct_value) = match guard {
+        StringGuard::WithoutValidation { .. } => (
+            quote!(u.arbitrary()?),
+            quote!(Ok(Self::#constructor_name(inner_value))),
+        ),
+        StringGuard::WithValidation { validators, .. } => (
+            gen_generator_for_validators(validators),
+            // Not every validator can be respected by construction (e.g. `regex` or `predicate`),
+            // so the input that produces an invalid value is rejected, the same way as a fuzzer
+            // rejects the input that does not fit the expected format.
+            quote!(Self::#constructor_name(inner_value)
+                .map_err(|_| ::arbitrary::Error::IncorrectFormat)),
+        ),
+    };
+
+    quote!(
+        impl ::arbitrary::Arbitrary<'_> for #type_name {
+            fn arbitrary(u: &mut ::arbitrary::Unstructured<'_>) -> ::arbitrary::Result<Self> {
+                let inner_value: ::std::string::String = #generate_inner;
+                #construct_value
+            }
+        }
+    )
+}
+
+/// Generate a string that satisfies most of the validators by construction:
+/// * `one_of` picks one of the allowed values
+/// * `starts_with`, `contains` and `ends_with` are glued to the generated string
+/// * length boundaries define the length of the generated string
+/// * `ascii`, `alphanumeric` and `contains_only` narrow down the characters
+///
+/// Everything else (e.g. `regex`, `email` or `predicate`) is respected by rejection.
+fn gen_generator_for_validators(validators: &[StringValidator]) -> TokenStream {
+    if let Some(values) = validators.iter().find_map(|v| match v {
+        StringValidator::OneOf(values) => Some(values),
+        _ => None,
+    }) {
+        return quote!({
+            let allowed: &[&str] = &[#(#values),*];
+            ::std::string::ToString::to_string(*u.choose(allowed)?)
+        });
+    }
+
+    let mut min_len = quote!(0usize);
+    let mut has_min_len = false;
+    let mut max_len = None;
+    let mut prefix = None;
+    let mut needle = None;
+    let mut suffix = None;
+    let mut is_ascii = false;
+    let mut is_alphanumeric = false;
+    let mut charset = None;
+
+    for validator in validators {
+        match validator {
+            StringValidator::LenCharMin(min) => {
+                min_len = quote!(#min);
+                has_min_len = true;
+            }
+            StringValidator::NotEmpty => {
+                if !has_min_len {
+                    min_len = quote!(1usize);
+                }
+            }
+            // A grapheme consists of at least one char, so limiting chars limits graphemes too.
+            StringValidator::LenCharMax(max) | StringValidator::LenGraphemeMax(max) => {
+                max_len = Some(match max_len {
+                    Some(current_max) => quote!(::core::cmp::min(#current_max, #max)),
+                    None => quote!(#max),
+                });
+            }
+            // A char takes at most two UTF-16 code units.
+            StringValidator::LenUtf16Max(max) => {
+                let max = quote!((#max) / 2);
+                max_len = Some(match max_len {
+                    Some(current_max) => quote!(::core::cmp::min(#current_max, #max)),
+                    None => max,
+                });
+            }
+            StringValidator::Ascii => is_ascii = true,
+            StringValidator::Alphanumeric => is_alphanumeric = true,
+            StringValidator::StartsWith(expr) => prefix = Some(expr),
+            StringValidator::Contains(expr) => needle = Some(expr),
+            StringValidator::ContainsOnly(expr) => charset = Some(expr),
+            StringValidator::EndsWith(expr) => suffix = Some(expr),
+            // Any of the listed values passes, so the first one is used.
+            StringValidator::StartsWithAny(exprs) => prefix = exprs.first(),
+            StringValidator::EndsWithAny(exprs) => suffix = exprs.first(),
+            StringValidator::OneOf(_)
+            | StringValidator::Min(_)
+            | StringValidator::Max(_)
+            | StringValidator::AnyOf(_)
+            | StringValidator::AllOf(_)
+            | StringValidator::Predicate(_)
+            | StringValidator::WithResult(_)
+            | StringValidator::Regex(_)
+            | StringValidator::Email
+            | StringValidator::Url { .. } => {}
+        }
+    }
+
+    let char_generator = match (charset, is_ascii, is_alphanumeric) {
+        (Some(charset), _, _) => CharGenerator::Charset(charset),
+        (None, false, false) => CharGenerator::Any,
+        (None, true, false) => CharGenerator::Ascii,
+        (None, _, true) => CharGenerator::Alphanumeric,
+    };
+
+    if prefix.is_none() && needle.is_none() && suffix.is_none() {
+        return gen_generator_for_len(char_generator, min_len, max_len);
+    }
+
+    let to_string = |maybe_expr: Option<&syn::Expr>| match maybe_expr {
+        Some(expr) => quote!(::std::string::ToString::to_string(&#expr)),
+        None => quote!(::std::string::String::new()),
+    };
+    let prefix = to_string(prefix);
+    let needle = to_string(needle);
+    let suffix = to_string(suffix);
+
+    // The fixed parts take some of the length budget, so the generated middle part is shorter.
+    let middle_min_len = quote!((#min_len).saturating_sub(fixed_len));
+    let middle_max_len = max_len.map(|max| quote!((#max).saturating_sub(fixed_len)));
+    let middle = gen_generator_for_len(char_generator, middle_min_len, middle_max_len);
+
+    quote!({
+        let prefix: ::std::string::String = #prefix;
+        let needle: ::std::string::String = #needle;
+        let suffix: ::std::string::String = #suffix;
+        let fixed_len =
+            prefix.chars().count() + needle.chars().count() + suffix.chars().count();
+        let middle: ::std::string::String = #middle;
+        ::std::format!("{}{}{}{}", prefix, middle, needle, suffix)
+    })
+}
+
+/// Defines which characters are generated.
+#[derive(Debug, Clone, Copy)]
+enum CharGenerator<'a> {
+    /// Any unicode character.
+    Any,
+    /// ASCII characters.
+    Ascii,
+    /// ASCII letters and digits, which are both ASCII and alphanumeric.
+    Alphanumeric,
+    /// Characters of the string given to `contains_only`.
+    Charset(&'a syn::Expr),
+}
+
+/// How many characters more than the min length are generated when there is no max length.
+const DEFAULT_EXTRA_LEN: usize = 32;
+
+fn gen_generator_for_len(
+    char_generator: CharGenerator<'_>,
+    min_len: TokenStream,
+    maybe_max_len: Option<TokenStream>,
+) -> TokenStream {
+    let generate_char = match char_generator {
+        CharGenerator::Any => quote!(u.arbitrary::<char>()?),
+        CharGenerator::Ascii => quote!(char::from(u.int_in_range(0u8..=0x7F)?)),
+        CharGenerator::Alphanumeric => quote!({
+            const ALPHANUMERIC: &[u8] =
+                b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789";
+            char::from(*u.choose(ALPHANUMERIC)?)
+        }),
+        CharGenerator::Charset(charset) => quote!({
+            let charset: &str = #charset;
+            let index = u.choose_index(charset.chars().count())?;
+            charset.chars().nth(index).expect("Nutype failed to generate a char")
+        }),
+    };
+    let max_len = match maybe_max_len {
+        Some(max_len) => quote!(#max_len),
+        None => quote!(min_len + #DEFAULT_EXTRA_LEN),
+    };
+    quote!({
+        let min_len: usize = #min_len;
+        let max_len: usize = #max_len;
+        let len = u.int_in_range(min_len..=::core::cmp::max(min_len, max_len))?;
+        let mut value = ::std::string::String::with_capacity(len);
+        for _ in 0..len {
+            value.push(#generate_char);
+        }
+        value
+    })
+}
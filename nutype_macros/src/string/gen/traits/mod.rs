@@ -0,0 +1,722 @@
+mod arbitrary;
+mod proptest;
+mod rand;
+
+use std::collections::HashSet;
+
+use proc_macro2::TokenStream;
+use quote::{quote, ToTokens};
+use syn::Generics;
+
+use crate::{
+    common::{
+        gen::traits::{
+            gen_impl_trait_as_ref, gen_impl_trait_bincode_decode, gen_impl_trait_bincode_encode,
+            gen_impl_trait_borrow, gen_impl_trait_default, gen_impl_trait_deref,
+            gen_impl_trait_display, gen_impl_trait_eq_inner, gen_impl_trait_from,
+            gen_impl_trait_into, gen_impl_trait_nutype, gen_impl_trait_ord_inner,
+            gen_impl_trait_redis_from_redis_value, gen_impl_trait_redis_to_redis_args,
+            gen_impl_trait_rkyv, gen_impl_trait_serde_deserialize, gen_impl_trait_serde_serialize,
+            gen_impl_trait_sqlx_json, gen_impl_trait_try_from, split_into_generatable_traits,
+            GeneratableTrait, GeneratableTraits, GeneratedTraits,
+        },
+        models::{ConstructorName, EqMode, ErrorTypeName, OrdMode, SerdeRepr, TypeName},
+    },
+    string::models::{StringDeriveTrait, StringGuard, StringInnerType},
+};
+
+type StringGeneratableTrait = GeneratableTrait<StringTransparentTrait, StringIrregularTrait>;
+
+/// A trait that can be automatically derived.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum StringTransparentTrait {
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Hash,
+    SchemarsJsonSchema,
+    DieselNewType,
+}
+
+/// A trait that can not be automatically derived and we need to generate
+/// an implementation for it.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+enum StringIrregularTrait {
+    FromStr,
+    AsRef,
+    Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
+    Into,
+    From,
+    FromIterator,
+    TryFrom,
+    Borrow,
+    Display,
+    Default,
+    SerdeSerialize,
+    SerdeDeserialize,
+    RkyvArchive,
+    SqlxJson,
+    RedisToRedisArgs,
+    RedisFromRedisValue,
+    BincodeEncode,
+    BincodeDecode,
+    ArbitraryArbitrary,
+    ProptestArbitrary,
+    RandDistribution,
+}
+
+impl From<StringDeriveTrait> for StringGeneratableTrait {
+    fn from(derive_trait: StringDeriveTrait) -> StringGeneratableTrait {
+        match derive_trait {
+            StringDeriveTrait::Debug => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::Debug)
+            }
+            StringDeriveTrait::Clone => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::Clone)
+            }
+            StringDeriveTrait::PartialEq => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::PartialEq)
+            }
+            StringDeriveTrait::Eq => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::Eq)
+            }
+            StringDeriveTrait::PartialOrd => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::PartialOrd)
+            }
+            StringDeriveTrait::Ord => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::Ord)
+            }
+            StringDeriveTrait::Hash => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::Hash)
+            }
+            StringDeriveTrait::FromStr => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::FromStr)
+            }
+            StringDeriveTrait::AsRef => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::AsRef)
+            }
+            StringDeriveTrait::Nutype => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::Nutype)
+            }
+            StringDeriveTrait::EqInner => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::EqInner)
+            }
+            StringDeriveTrait::OrdInner => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::OrdInner)
+            }
+            StringDeriveTrait::Deref => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::Deref)
+            }
+            StringDeriveTrait::Into => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::Into)
+            }
+            StringDeriveTrait::From => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::From)
+            }
+            StringDeriveTrait::FromIterator => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::FromIterator)
+            }
+            StringDeriveTrait::TryFrom => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::TryFrom)
+            }
+            StringDeriveTrait::Borrow => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::Borrow)
+            }
+            StringDeriveTrait::Display => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::Display)
+            }
+            StringDeriveTrait::Default => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::Default)
+            }
+            StringDeriveTrait::SerdeSerialize => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::SerdeSerialize)
+            }
+            StringDeriveTrait::ArbitraryArbitrary => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::ArbitraryArbitrary)
+            }
+            StringDeriveTrait::ProptestArbitrary => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::ProptestArbitrary)
+            }
+            StringDeriveTrait::RandDistribution => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::RandDistribution)
+            }
+            StringDeriveTrait::RedisFromRedisValue => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::RedisFromRedisValue)
+            }
+            StringDeriveTrait::BincodeEncode => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::BincodeEncode)
+            }
+            StringDeriveTrait::BincodeDecode => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::BincodeDecode)
+            }
+            StringDeriveTrait::RedisToRedisArgs => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::RedisToRedisArgs)
+            }
+            StringDeriveTrait::SqlxJson => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::SqlxJson)
+            }
+            StringDeriveTrait::RkyvArchive => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::RkyvArchive)
+            }
+            StringDeriveTrait::SerdeDeserialize => {
+                StringGeneratableTrait::Irregular(StringIrregularTrait::SerdeDeserialize)
+            }
+            StringDeriveTrait::SchemarsJsonSchema => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::SchemarsJsonSchema)
+            }
+            StringDeriveTrait::DieselNewType => {
+                StringGeneratableTrait::Transparent(StringTransparentTrait::DieselNewType)
+            }
+        }
+    }
+}
+
+impl ToTokens for StringTransparentTrait {
+    fn to_tokens(&self, token_stream: &mut TokenStream) {
+        let tokens = match self {
+            Self::Debug => quote!(Debug),
+            Self::Clone => quote!(Clone),
+            Self::PartialEq => quote!(PartialEq),
+            Self::Eq => quote!(Eq),
+            Self::PartialOrd => quote!(PartialOrd),
+            Self::Ord => quote!(Ord),
+            Self::Hash => quote!(Hash),
+            Self::SchemarsJsonSchema => quote!(::schemars::JsonSchema),
+            Self::DieselNewType => quote!(::diesel_derive_newtype::DieselNewType),
+        };
+        tokens.to_tokens(token_stream)
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn gen_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    maybe_error_type_name: Option<ErrorTypeName>,
+    traits: HashSet<StringDeriveTrait>,
+    maybe_default_value: Option<syn::Expr>,
+    maybe_display_format: Option<syn::LitStr>,
+    eq: EqMode,
+    ord: OrdMode,
+    serde_repr: &SerdeRepr,
+    guard: &StringGuard,
+) -> Result<GeneratedTraits, syn::Error> {
+    let GeneratableTraits {
+        transparent_traits,
+        irregular_traits,
+    } = split_into_generatable_traits(traits);
+
+    // With case-insensitive equality `PartialEq`, `Eq` and `Hash` cannot be derived,
+    // they are implemented on lowercased values instead.
+    let (transparent_traits, impl_case_insensitive_eq_traits) = match eq {
+        EqMode::Exact => (transparent_traits, quote!()),
+        EqMode::CaseInsensitive => {
+            let (eq_traits, transparent_traits): (Vec<_>, Vec<_>) =
+                transparent_traits.into_iter().partition(|t| {
+                    matches!(
+                        t,
+                        StringTransparentTrait::PartialEq
+                            | StringTransparentTrait::Eq
+                            | StringTransparentTrait::Hash
+                    )
+                });
+            let impls = gen_impl_case_insensitive_eq_traits(type_name, &eq_traits);
+            (transparent_traits, impls)
+        }
+    };
+
+    // With case-insensitive ordering `PartialOrd` and `Ord` are implemented on lowercased values.
+    let (transparent_traits, impl_case_insensitive_ord_traits) = match ord {
+        OrdMode::Exact => (transparent_traits, quote!()),
+        OrdMode::CaseInsensitive => {
+            let (ord_traits, transparent_traits): (Vec<_>, Vec<_>) =
+                transparent_traits.into_iter().partition(|t| {
+                    matches!(
+                        t,
+                        StringTransparentTrait::PartialOrd | StringTransparentTrait::Ord
+                    )
+                });
+            let impls = gen_impl_case_insensitive_ord_traits(type_name, &ord_traits);
+            (transparent_traits, impls)
+        }
+    };
+
+    let derive_transparent_traits = quote! {
+        #[derive(
+            #(#transparent_traits,)*
+        )]
+    };
+
+    let implement_traits = gen_implemented_traits(
+        type_name,
+        generics,
+        constructor_name,
+        maybe_error_type_name,
+        maybe_default_value,
+        maybe_display_format,
+        eq,
+        ord,
+        irregular_traits,
+        serde_repr,
+        guard,
+    )?;
+
+    Ok(GeneratedTraits {
+        derive_transparent_traits,
+        implement_traits: quote! {
+            #impl_case_insensitive_eq_traits
+            #impl_case_insensitive_ord_traits
+            #implement_traits
+        },
+    })
+}
+
+#[allow(clippy::too_many_arguments)]
+fn gen_implemented_traits(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+    maybe_error_type_name: Option<ErrorTypeName>,
+    maybe_default_value: Option<syn::Expr>,
+    maybe_display_format: Option<syn::LitStr>,
+    eq: EqMode,
+    ord: OrdMode,
+    impl_traits: Vec<StringIrregularTrait>,
+    serde_repr: &SerdeRepr,
+    guard: &StringGuard,
+) -> Result<TokenStream, syn::Error> {
+    let inner_type = StringInnerType;
+
+    impl_traits
+        .iter()
+        .map(|t| match t {
+            // Only `AsRef<str>` is implemented: it satisfies `impl AsRef<str>` bounds, and adding
+            // `AsRef<String>` would make `.as_ref()` calls ambiguous.
+            StringIrregularTrait::AsRef => {
+                Ok(gen_impl_trait_as_ref(type_name, generics, quote!(str)))
+            }
+            StringIrregularTrait::Nutype => Ok(gen_impl_trait_nutype(
+                type_name,
+                constructor_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            StringIrregularTrait::EqInner => match eq {
+                EqMode::Exact => Ok(gen_impl_eq_string_and_str(type_name)),
+                EqMode::CaseInsensitive => {
+                    Ok(gen_impl_case_insensitive_eq_string_and_str(type_name))
+                }
+            },
+            StringIrregularTrait::OrdInner => match ord {
+                OrdMode::Exact => Ok(gen_impl_ord_string_and_str(type_name)),
+                OrdMode::CaseInsensitive => {
+                    Ok(gen_impl_case_insensitive_ord_string_and_str(type_name))
+                }
+            },
+            StringIrregularTrait::Deref => {
+                Ok(gen_impl_trait_deref(type_name, generics, quote!(String)))
+            }
+            StringIrregularTrait::FromStr => Ok(gen_impl_from_str(
+                type_name,
+                constructor_name,
+                maybe_error_type_name.as_ref(),
+            )),
+            StringIrregularTrait::From => Ok(gen_impl_from_str_and_string(
+                type_name,
+                generics,
+                constructor_name,
+            )),
+            StringIrregularTrait::FromIterator => Ok(gen_impl_from_iterator(
+                type_name,
+                generics,
+                constructor_name,
+            )),
+            StringIrregularTrait::Into => Ok(gen_impl_trait_into(type_name, generics, inner_type)),
+            StringIrregularTrait::TryFrom => Ok(gen_impl_try_from(
+                type_name,
+                constructor_name,
+                maybe_error_type_name.as_ref(),
+            )),
+            StringIrregularTrait::Borrow => Ok(gen_impl_borrow_str_and_string(type_name, generics)),
+            StringIrregularTrait::Display => Ok(gen_impl_display(
+                type_name,
+                generics,
+                maybe_display_format.as_ref(),
+            )),
+            StringIrregularTrait::Default => match maybe_default_value {
+                Some(ref default_value) => {
+                    let has_validation = maybe_error_type_name.is_some();
+                    Ok(gen_impl_trait_default(
+                        type_name,
+                        constructor_name,
+                        default_value,
+                        has_validation,
+                    ))
+                }
+                None => {
+                    panic!(
+                        "Default trait is derived for type {type_name}, but `default = ` is missing"
+                    );
+                }
+            },
+            StringIrregularTrait::SerdeSerialize => {
+                Ok(gen_impl_trait_serde_serialize(type_name, serde_repr))
+            }
+            StringIrregularTrait::BincodeEncode => Ok(gen_impl_trait_bincode_encode(type_name)),
+            StringIrregularTrait::BincodeDecode => Ok(gen_impl_trait_bincode_decode(
+                type_name,
+                constructor_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            StringIrregularTrait::RedisFromRedisValue => Ok(gen_impl_trait_redis_from_redis_value(
+                type_name,
+                constructor_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            StringIrregularTrait::RedisToRedisArgs => {
+                Ok(gen_impl_trait_redis_to_redis_args(type_name))
+            }
+            StringIrregularTrait::SqlxJson => Ok(gen_impl_trait_sqlx_json(
+                type_name,
+                constructor_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+            )),
+            StringIrregularTrait::RkyvArchive => Ok(gen_impl_trait_rkyv(
+                type_name,
+                constructor_name,
+                inner_type,
+                quote!(::rkyv::string::ArchivedString),
+                maybe_error_type_name.as_ref(),
+            )),
+            StringIrregularTrait::SerdeDeserialize => Ok(gen_impl_trait_serde_deserialize(
+                type_name,
+                constructor_name,
+                inner_type,
+                maybe_error_type_name.as_ref(),
+                serde_repr,
+            )),
+            StringIrregularTrait::ArbitraryArbitrary => Ok(arbitrary::gen_impl_trait_arbitrary(
+                type_name,
+                constructor_name,
+                guard,
+            )),
+            StringIrregularTrait::ProptestArbitrary => {
+                proptest::gen_impl_trait_proptest_arbitrary(type_name, constructor_name, guard)
+            }
+            StringIrregularTrait::RandDistribution => {
+                rand::gen_impl_trait_rand_distribution(type_name, constructor_name, guard)
+            }
+        })
+        .collect()
+}
+
+fn gen_impl_from_str(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    if let Some(error_type_name) = maybe_error_type_name {
+        quote! {
+            impl core::str::FromStr for #type_name {
+                type Err = #error_type_name;
+
+                #[inline]
+                fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
+                    #type_name::#constructor_name(raw_string)
+                }
+            }
+        }
+    } else {
+        quote! {
+            impl core::str::FromStr for #type_name {
+                type Err = ::core::convert::Infallible;
+
+                #[inline]
+                fn from_str(raw_string: &str) -> ::core::result::Result<Self, Self::Err> {
+                    Ok(#type_name::#constructor_name(raw_string))
+                }
+            }
+        }
+    }
+}
+
+fn gen_impl_from_str_and_string(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+) -> TokenStream {
+    let impl_from_string =
+        gen_impl_trait_from(type_name, generics, constructor_name, quote!(String));
+    let impl_from_str = gen_impl_trait_from(type_name, generics, constructor_name, quote!(&str));
+
+    quote! {
+        #impl_from_string
+        #impl_from_str
+    }
+}
+
+/// Generate `FromIterator<char>` and `FromIterator<&str>`.
+/// `FromIterator` cannot fail, so it's derivable only for types without validation.
+fn gen_impl_from_iterator(
+    type_name: &TypeName,
+    generics: &Generics,
+    constructor_name: &ConstructorName,
+) -> TokenStream {
+    let (_, ty_generics, _) = generics.split_for_impl();
+    let gen_impl = |impl_generics: &Generics, item: TokenStream| {
+        let (impl_generics, _, _) = impl_generics.split_for_impl();
+        quote! {
+            impl #impl_generics ::core::iter::FromIterator<#item> for #type_name #ty_generics {
+                fn from_iter<I: ::core::iter::IntoIterator<Item = #item>>(iter: I) -> Self {
+                    Self::#constructor_name(String::from_iter(iter))
+                }
+            }
+        }
+    };
+    let impl_from_iterator_of_chars = gen_impl(generics, quote!(char));
+
+    let mut generics_with_str_lifetime = generics.clone();
+    generics_with_str_lifetime
+        .params
+        .insert(0, syn::parse_quote!('__nutype_str));
+    let impl_from_iterator_of_strs =
+        gen_impl(&generics_with_str_lifetime, quote!(&'__nutype_str str));
+
+    quote! {
+        #impl_from_iterator_of_chars
+        #impl_from_iterator_of_strs
+    }
+}
+
+fn gen_impl_try_from(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    maybe_error_type_name: Option<&ErrorTypeName>,
+) -> TokenStream {
+    let impl_try_from_string = gen_impl_trait_try_from(
+        type_name,
+        constructor_name,
+        quote!(String),
+        maybe_error_type_name,
+    );
+    let impl_try_from_str = gen_impl_trait_try_from(
+        type_name,
+        constructor_name,
+        quote!(&str),
+        maybe_error_type_name,
+    );
+
+    quote! {
+        #impl_try_from_string
+        #impl_try_from_str
+    }
+}
+
+/// Without a custom format the inner `str` is written straight into the formatter, so formatting
+/// never goes through the generic `Display` machinery of the inner type.
+/// `pad()` is used instead of `write_str()` to keep width, alignment and precision working,
+/// e.g. `format!("{name:>10}")`.
+fn gen_impl_display(
+    type_name: &TypeName,
+    generics: &Generics,
+    maybe_display_format: Option<&syn::LitStr>,
+) -> TokenStream {
+    if maybe_display_format.is_some() {
+        return gen_impl_trait_display(type_name, generics, maybe_display_format);
+    }
+
+    let (impl_generics, ty_generics, _) = generics.split_for_impl();
+    quote! {
+        impl #impl_generics ::core::fmt::Display for #type_name #ty_generics {
+            #[inline]
+            fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                f.pad(self.0.as_str())
+            }
+        }
+    }
+}
+
+fn gen_impl_borrow_str_and_string(type_name: &TypeName, generics: &Generics) -> TokenStream {
+    let impl_borrow_string = gen_impl_trait_borrow(type_name, generics, quote!(String));
+    let impl_borrow_str = gen_impl_trait_borrow(type_name, generics, quote!(str));
+
+    quote! {
+        #impl_borrow_string
+        #impl_borrow_str
+    }
+}
+
+fn gen_impl_eq_string_and_str(type_name: &TypeName) -> TokenStream {
+    let impl_eq_string = gen_impl_trait_eq_inner(type_name, quote!(String));
+
+    quote! {
+        #impl_eq_string
+
+        impl ::core::cmp::PartialEq<str> for #type_name {
+            #[inline]
+            fn eq(&self, other: &str) -> bool {
+                self.0.as_str().eq(other)
+            }
+        }
+
+        impl ::core::cmp::PartialEq<&str> for #type_name {
+            #[inline]
+            fn eq(&self, other: &&str) -> bool {
+                self.0.as_str().eq(*other)
+            }
+        }
+    }
+}
+
+fn gen_impl_ord_string_and_str(type_name: &TypeName) -> TokenStream {
+    let impl_ord_string = gen_impl_trait_ord_inner(type_name, quote!(String));
+
+    quote! {
+        #impl_ord_string
+
+        impl ::core::cmp::PartialOrd<str> for #type_name {
+            #[inline]
+            fn partial_cmp(&self, other: &str) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.as_str().partial_cmp(other)
+            }
+        }
+
+        impl ::core::cmp::PartialOrd<&str> for #type_name {
+            #[inline]
+            fn partial_cmp(&self, other: &&str) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.as_str().partial_cmp(*other)
+            }
+        }
+    }
+}
+
+/// Implement `PartialEq`, `Eq` and `Hash` (those of them that are derived) on lowercased values,
+/// so `Hash` stays consistent with `Eq`.
+fn gen_impl_case_insensitive_eq_traits(
+    type_name: &TypeName,
+    eq_traits: &[StringTransparentTrait],
+) -> TokenStream {
+    eq_traits
+        .iter()
+        .map(|t| match t {
+            StringTransparentTrait::PartialEq => quote! {
+                impl ::core::cmp::PartialEq for #type_name {
+                    #[inline]
+                    fn eq(&self, other: &Self) -> bool {
+                        self.0.to_lowercase() == other.0.to_lowercase()
+                    }
+                }
+            },
+            StringTransparentTrait::Eq => quote! {
+                impl ::core::cmp::Eq for #type_name {}
+            },
+            StringTransparentTrait::Hash => quote! {
+                impl ::core::hash::Hash for #type_name {
+                    #[inline]
+                    fn hash<H: ::core::hash::Hasher>(&self, state: &mut H) {
+                        ::core::hash::Hash::hash(&self.0.to_lowercase(), state)
+                    }
+                }
+            },
+            _ => unreachable!("Trait {t:?} does not depend on the equality semantics"),
+        })
+        .collect()
+}
+
+/// Implement `PartialOrd` and `Ord` (those of them that are derived) on lowercased values.
+/// With `Ord` in place, `PartialOrd` delegates to it, so the two always agree.
+fn gen_impl_case_insensitive_ord_traits(
+    type_name: &TypeName,
+    ord_traits: &[StringTransparentTrait],
+) -> TokenStream {
+    let has_ord = ord_traits.contains(&StringTransparentTrait::Ord);
+    ord_traits
+        .iter()
+        .map(|t| match t {
+            StringTransparentTrait::PartialOrd => {
+                let partial_cmp = if has_ord {
+                    quote!(::core::cmp::Ord::cmp(self, other))
+                } else {
+                    quote!(self.0.to_lowercase().cmp(&other.0.to_lowercase()))
+                };
+                quote! {
+                    impl ::core::cmp::PartialOrd for #type_name {
+                        #[inline]
+                        fn partial_cmp(&self, other: &Self) -> ::core::option::Option<::core::cmp::Ordering> {
+                            ::core::option::Option::Some(#partial_cmp)
+                        }
+                    }
+                }
+            }
+            StringTransparentTrait::Ord => quote! {
+                impl ::core::cmp::Ord for #type_name {
+                    #[inline]
+                    fn cmp(&self, other: &Self) -> ::core::cmp::Ordering {
+                        self.0.to_lowercase().cmp(&other.0.to_lowercase())
+                    }
+                }
+            },
+            _ => unreachable!("Trait {t:?} does not depend on the ordering semantics"),
+        })
+        .collect()
+}
+
+fn gen_impl_case_insensitive_eq_string_and_str(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::core::cmp::PartialEq<String> for #type_name {
+            #[inline]
+            fn eq(&self, other: &String) -> bool {
+                self.0.to_lowercase() == other.to_lowercase()
+            }
+        }
+
+        impl ::core::cmp::PartialEq<str> for #type_name {
+            #[inline]
+            fn eq(&self, other: &str) -> bool {
+                self.0.to_lowercase() == other.to_lowercase()
+            }
+        }
+
+        impl ::core::cmp::PartialEq<&str> for #type_name {
+            #[inline]
+            fn eq(&self, other: &&str) -> bool {
+                self.0.to_lowercase() == other.to_lowercase()
+            }
+        }
+    }
+}
+
+fn gen_impl_case_insensitive_ord_string_and_str(type_name: &TypeName) -> TokenStream {
+    quote! {
+        impl ::core::cmp::PartialOrd<String> for #type_name {
+            #[inline]
+            fn partial_cmp(&self, other: &String) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.to_lowercase().partial_cmp(&other.to_lowercase())
+            }
+        }
+
+        impl ::core::cmp::PartialOrd<str> for #type_name {
+            #[inline]
+            fn partial_cmp(&self, other: &str) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.to_lowercase().partial_cmp(&other.to_lowercase())
+            }
+        }
+
+        impl ::core::cmp::PartialOrd<&str> for #type_name {
+            #[inline]
+            fn partial_cmp(&self, other: &&str) -> ::core::option::Option<::core::cmp::Ordering> {
+                self.0.to_lowercase().partial_cmp(&other.to_lowercase())
+            }
+        }
+    }
+}
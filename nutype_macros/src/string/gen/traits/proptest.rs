@@ -0,0 +1,250 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+
+use crate::{
+    common::{
+        gen::traits::gen_impl_trait_proptest_arbitrary_from_strategy,
+        models::{ConstructorName, TypeName},
+    },
+    string::models::{RegexDef, StringGuard, StringValidator},
+};
+
+pub fn gen_impl_trait_proptest_arbitrary(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    guard: &StringGuard,
+) -> Result<TokenStream, syn::Error> {
+    let inner_strategy = match guard {
+        StringGuard::WithoutValidation { .. } => {
+            gen_strategy_for_len(&quote!(#ANY_CHAR), quote!(0usize), None)
+        }
+        StringGuard::WithValidation { validators, .. } => gen_strategy_for_validators(validators),
+    };
+    Ok(gen_impl_trait_proptest_arbitrary_from_strategy(
+        type_name,
+        constructor_name,
+        inner_strategy,
+        guard.has_validation(),
+    ))
+}
+
+/// Generate a strategy that satisfies most of the validators by construction:
+/// * `one_of` picks one of the allowed values
+/// * `regex` is used directly as a regex strategy
+/// * `email` is turned into a regex of simple email addresses
+/// * `url` is turned into a regex of simple URLs with one of the allowed schemes
+/// * `starts_with`, `contains` and `ends_with` are glued to the generated string
+/// * length boundaries are turned into a regex like `.{min,max}`
+/// * `ascii` and `alphanumeric` narrow down the characters of the regex (e.g. `[a-zA-Z0-9]{min,max}`)
+/// * `contains_only` turns the allowed characters into a character class of the regex
+///
+/// Everything else (e.g. `predicate`) is respected by filtering.
+fn gen_strategy_for_validators(validators: &[StringValidator]) -> TokenStream {
+    if let Some(values) = validators.iter().find_map(|v| match v {
+        StringValidator::OneOf(values) => Some(values),
+        _ => None,
+    }) {
+        return quote!(::proptest::sample::select(::std::vec![#(#values),*])
+            .prop_map(::std::string::ToString::to_string));
+    }
+
+    if let Some(regex_def) = validators.iter().find_map(|v| match v {
+        StringValidator::Regex(regex_def) => Some(regex_def),
+        _ => None,
+    }) {
+        return gen_strategy_for_regex(regex_def);
+    }
+
+    if validators
+        .iter()
+        .any(|v| matches!(v, StringValidator::Email))
+    {
+        return quote!(
+            ::proptest::string::string_regex(#EMAIL_PATTERN)
+                .expect("Nutype failed to build a string strategy for emails")
+        );
+    }
+
+    if let Some(schemes) = validators.iter().find_map(|v| match v {
+        StringValidator::Url { schemes } => Some(schemes),
+        _ => None,
+    }) {
+        let pattern = gen_url_pattern(schemes.as_deref());
+        return quote!(
+            ::proptest::string::string_regex(#pattern)
+                .expect("Nutype failed to build a string strategy for URLs")
+        );
+    }
+
+    let mut min_len = quote!(0usize);
+    let mut has_min_len = false;
+    let mut max_len = None;
+    let mut prefix = None;
+    let mut needle = None;
+    let mut suffix = None;
+    let mut is_ascii = false;
+    let mut is_alphanumeric = false;
+    let mut charset = None;
+
+    for validator in validators {
+        match validator {
+            StringValidator::LenCharMin(min) => {
+                min_len = quote!(#min);
+                has_min_len = true;
+            }
+            StringValidator::NotEmpty => {
+                if !has_min_len {
+                    min_len = quote!(1usize);
+                }
+            }
+            // A grapheme consists of at least one char, so limiting chars limits graphemes too.
+            StringValidator::LenCharMax(max) | StringValidator::LenGraphemeMax(max) => {
+                max_len = Some(match max_len {
+                    Some(current_max) => quote!(::core::cmp::min(#current_max, #max)),
+                    None => quote!(#max),
+                });
+            }
+            // A char takes at most two UTF-16 code units.
+            StringValidator::LenUtf16Max(max) => {
+                let max = quote!((#max) / 2);
+                max_len = Some(match max_len {
+                    Some(current_max) => quote!(::core::cmp::min(#current_max, #max)),
+                    None => max,
+                });
+            }
+            StringValidator::Ascii => is_ascii = true,
+            StringValidator::Alphanumeric => is_alphanumeric = true,
+            StringValidator::StartsWith(expr) => prefix = Some(expr),
+            StringValidator::Contains(expr) => needle = Some(expr),
+            StringValidator::ContainsOnly(expr) => charset = Some(expr),
+            StringValidator::EndsWith(expr) => suffix = Some(expr),
+            // Any of the listed values passes, so the first one is used.
+            StringValidator::StartsWithAny(exprs) => prefix = exprs.first(),
+            StringValidator::EndsWithAny(exprs) => suffix = exprs.first(),
+            StringValidator::OneOf(_)
+            | StringValidator::Min(_)
+            | StringValidator::Max(_)
+            | StringValidator::AnyOf(_)
+            | StringValidator::AllOf(_)
+            | StringValidator::Predicate(_)
+            | StringValidator::WithResult(_)
+            | StringValidator::Regex(_)
+            | StringValidator::Email
+            | StringValidator::Url { .. } => {}
+        }
+    }
+
+    let char_class = match charset {
+        // Every character is escaped by its code point, so it has no special meaning in the class.
+        Some(charset) => quote!({
+            let charset: &str = #charset;
+            let escaped: ::std::string::String = charset
+                .chars()
+                .map(|c| ::std::format!("\\x{{{:X}}}", u32::from(c)))
+                .collect();
+            ::std::format!("[{}]", escaped)
+        }),
+        None => {
+            let char_class = match (is_ascii, is_alphanumeric) {
+                (false, false) => ANY_CHAR,
+                (true, false) => r"[\x00-\x7F]",
+                (false, true) => r"[\p{Alphabetic}\p{N}]",
+                (true, true) => "[a-zA-Z0-9]",
+            };
+            quote!(#char_class)
+        }
+    };
+
+    if prefix.is_none() && needle.is_none() && suffix.is_none() {
+        return gen_strategy_for_len(&char_class, min_len, max_len);
+    }
+
+    let to_string = |maybe_expr: Option<&syn::Expr>| match maybe_expr {
+        Some(expr) => quote!(::std::string::ToString::to_string(&#expr)),
+        None => quote!(::std::string::String::new()),
+    };
+    let prefix = to_string(prefix);
+    let needle = to_string(needle);
+    let suffix = to_string(suffix);
+
+    // The fixed parts take some of the length budget, so the generated middle part is shorter.
+    let middle_min_len = quote!((#min_len).saturating_sub(fixed_len));
+    let middle_max_len = max_len.map(|max| quote!((#max).saturating_sub(fixed_len)));
+    let middle_strategy = gen_strategy_for_len(&char_class, middle_min_len, middle_max_len);
+
+    quote!({
+        let prefix: ::std::string::String = #prefix;
+        let needle: ::std::string::String = #needle;
+        let suffix: ::std::string::String = #suffix;
+        let fixed_len =
+            prefix.chars().count() + needle.chars().count() + suffix.chars().count();
+        (#middle_strategy).prop_map(move |middle| {
+            ::std::format!("{}{}{}{}", prefix, middle, needle, suffix)
+        })
+    })
+}
+
+/// Regex that matches any character (except a newline).
+const ANY_CHAR: &str = ".";
+
+/// Regex of email addresses accepted by `nutype::email::is_valid_email()`.
+const EMAIL_PATTERN: &str =
+    r"[a-z0-9_+-]{1,16}(\.[a-z0-9_+-]{1,16})?@[a-z0-9]{1,16}(-[a-z0-9]{1,8})?\.[a-z]{2,6}";
+
+/// Regex of the part of a URL that follows the scheme, e.g. `://example.com/foo`.
+const URL_PATTERN_AFTER_SCHEME: &str =
+    r"://[a-z0-9]{1,16}(-[a-z0-9]{1,8})?\.[a-z]{2,6}(/[a-z0-9_-]{1,16}){0,3}";
+
+/// Build a regex of URLs with one of the given schemes, or with `http` or `https` if any scheme is allowed.
+fn gen_url_pattern(maybe_schemes: Option<&[String]>) -> String {
+    let schemes: Vec<String> = match maybe_schemes {
+        // `+` and `.` are the only characters of a scheme that have a special meaning in a regex.
+        Some(schemes) => schemes
+            .iter()
+            .map(|scheme| scheme.replace('+', r"\+").replace('.', r"\."))
+            .collect(),
+        None => vec!["http".to_string(), "https".to_string()],
+    };
+    format!("({}){}", schemes.join("|"), URL_PATTERN_AFTER_SCHEME)
+}
+
+fn gen_strategy_for_len(
+    char_class: &TokenStream,
+    min_len: TokenStream,
+    maybe_max_len: Option<TokenStream>,
+) -> TokenStream {
+    let pattern = match maybe_max_len {
+        Some(max_len) => quote!({
+            let min_len: usize = #min_len;
+            let max_len: usize = #max_len;
+            ::std::format!("{}{{{},{}}}", #char_class, min_len, ::core::cmp::max(min_len, max_len))
+        }),
+        None => quote!({
+            let min_len: usize = #min_len;
+            ::std::format!("{}{{{},}}", #char_class, min_len)
+        }),
+    };
+    quote!(
+        ::proptest::string::string_regex(&#pattern)
+            .expect("Nutype failed to build a string strategy for length boundaries")
+    )
+}
+
+fn gen_strategy_for_regex(regex_def: &RegexDef) -> TokenStream {
+    let pattern = match regex_def {
+        RegexDef::StringLiteral(lit) => quote!(#lit),
+        RegexDef::Path(path) => quote!(#path.as_str()),
+    };
+    quote!({
+        // Anchors are not supported by proptest, but generated values always match
+        // the whole pattern anyway.
+        let pattern: &str = #pattern;
+        let pattern = pattern.strip_prefix('^').unwrap_or(pattern);
+        let pattern = match pattern.strip_suffix('$') {
+            Some(stripped) if !stripped.ends_with('\\') => stripped,
+            _ => pattern,
+        };
+        ::proptest::string::string_regex(pattern)
+            .expect("Nutype failed to build a string strategy from the regex")
+    })
+}
@@ -0,0 +1,251 @@
+use proc_macro2::{Span, TokenStream};
+use quote::quote;
+
+use crate::{
+    common::{
+        gen::traits::gen_impl_trait_rand_distribution_from_sampler,
+        models::{ConstructorName, TypeName},
+    },
+    string::models::{StringGuard, StringValidator},
+};
+
+pub fn gen_impl_trait_rand_distribution(
+    type_name: &TypeName,
+    constructor_name: &ConstructorName,
+    guard: &StringGuard,
+) -> Result<TokenStream, syn::Error> {
+    let sample_inner = match guard {
+        StringGuard::WithoutValidation { .. } => {
+            gen_sampler_for_len(CharSampler::Any, quote!(0usize), None)
+        }
+        StringGuard::WithValidation { validators, .. } => gen_sampler_for_validators(validators)?,
+    };
+    Ok(gen_impl_trait_rand_distribution_from_sampler(
+        type_name,
+        constructor_name,
+        quote!(::std::string::String),
+        sample_inner,
+        guard.has_validation(),
+    ))
+}
+
+/// Generate a sampler that satisfies most of the validators by construction:
+/// * `one_of` picks one of the allowed values
+/// * `email` produces simple email addresses like `x1@y2.z3`
+/// * `url` produces simple URLs like `https://x1.y2` with one of the allowed schemes
+/// * `starts_with`, `contains` and `ends_with` are glued to the generated string
+/// * length boundaries define the length of the generated string
+/// * `ascii`, `alphanumeric` and `contains_only` narrow down the characters
+///
+/// Everything else (e.g. `predicate`) is respected by rejection.
+/// `regex` is not supported, since a random string would almost never match it.
+fn gen_sampler_for_validators(validators: &[StringValidator]) -> Result<TokenStream, syn::Error> {
+    if let Some(values) = validators.iter().find_map(|v| match v {
+        StringValidator::OneOf(values) => Some(values),
+        _ => None,
+    }) {
+        return Ok(quote!({
+            let allowed: &[&str] = &[#(#values),*];
+            ::std::string::ToString::to_string(allowed[rng.gen_range(0..allowed.len())])
+        }));
+    }
+
+    if validators
+        .iter()
+        .any(|v| matches!(v, StringValidator::Regex(_)))
+    {
+        let msg = "`RandDistribution` cannot be derived for a type with `regex` validation.\nConsider deriving `ProptestArbitrary` instead.";
+        return Err(syn::Error::new(Span::call_site(), msg));
+    }
+
+    if validators
+        .iter()
+        .any(|v| matches!(v, StringValidator::Email))
+    {
+        let local_part = gen_sampler_for_len(
+            CharSampler::Alphanumeric,
+            quote!(1usize),
+            Some(quote!(16usize)),
+        );
+        let domain = gen_sampler_for_len(
+            CharSampler::Alphanumeric,
+            quote!(1usize),
+            Some(quote!(16usize)),
+        );
+        let tld = gen_sampler_for_len(
+            CharSampler::Alphanumeric,
+            quote!(2usize),
+            Some(quote!(6usize)),
+        );
+        return Ok(quote!({
+            let local_part: ::std::string::String = #local_part;
+            let domain: ::std::string::String = #domain;
+            let tld: ::std::string::String = #tld;
+            ::std::format!("{}@{}.{}", local_part, domain, tld)
+        }));
+    }
+
+    if let Some(schemes) = validators.iter().find_map(|v| match v {
+        StringValidator::Url { schemes } => Some(schemes),
+        _ => None,
+    }) {
+        let schemes = match schemes {
+            Some(schemes) => quote!(&[#(#schemes),*]),
+            None => quote!(&["http", "https"]),
+        };
+        let host = gen_sampler_for_len(
+            CharSampler::Alphanumeric,
+            quote!(1usize),
+            Some(quote!(16usize)),
+        );
+        let tld = gen_sampler_for_len(
+            CharSampler::Alphanumeric,
+            quote!(2usize),
+            Some(quote!(6usize)),
+        );
+        return Ok(quote!({
+            let schemes: &[&str] = #schemes;
+            let scheme = schemes[rng.gen_range(0..schemes.len())];
+            let host: ::std::string::String = #host;
+            let tld: ::std::string::String = #tld;
+            ::std::format!("{}://{}.{}", scheme, host, tld)
+        }));
+    }
+
+    let mut min_len = quote!(0usize);
+    let mut has_min_len = false;
+    let mut max_len = None;
+    let mut prefix = None;
+    let mut needle = None;
+    let mut suffix = None;
+    let mut is_ascii = false;
+    let mut is_alphanumeric = false;
+    let mut charset = None;
+
+    for validator in validators {
+        match validator {
+            StringValidator::LenCharMin(min) => {
+                min_len = quote!(#min);
+                has_min_len = true;
+            }
+            StringValidator::NotEmpty => {
+                if !has_min_len {
+                    min_len = quote!(1usize);
+                }
+            }
+            // A grapheme consists of at least one char, so limiting chars limits graphemes too.
+            StringValidator::LenCharMax(max) | StringValidator::LenGraphemeMax(max) => {
+                max_len = Some(match max_len {
+                    Some(current_max) => quote!(::core::cmp::min(#current_max, #max)),
+                    None => quote!(#max),
+                });
+            }
+            // A char takes at most two UTF-16 code units.
+            StringValidator::LenUtf16Max(max) => {
+                let max = quote!((#max) / 2);
+                max_len = Some(match max_len {
+                    Some(current_max) => quote!(::core::cmp::min(#current_max, #max)),
+                    None => max,
+                });
+            }
+            StringValidator::Ascii => is_ascii = true,
+            StringValidator::Alphanumeric => is_alphanumeric = true,
+            StringValidator::StartsWith(expr) => prefix = Some(expr),
+            StringValidator::Contains(expr) => needle = Some(expr),
+            StringValidator::ContainsOnly(expr) => charset = Some(expr),
+            StringValidator::EndsWith(expr) => suffix = Some(expr),
+            // Any of the listed values passes, so the first one is used.
+            StringValidator::StartsWithAny(exprs) => prefix = exprs.first(),
+            StringValidator::EndsWithAny(exprs) => suffix = exprs.first(),
+            StringValidator::OneOf(_)
+            | StringValidator::Min(_)
+            | StringValidator::Max(_)
+            | StringValidator::AnyOf(_)
+            | StringValidator::AllOf(_)
+            | StringValidator::Predicate(_)
+            | StringValidator::WithResult(_)
+            | StringValidator::Regex(_)
+            | StringValidator::Email
+            | StringValidator::Url { .. } => {}
+        }
+    }
+
+    let char_sampler = match (charset, is_ascii, is_alphanumeric) {
+        (Some(charset), _, _) => CharSampler::Charset(charset),
+        (None, false, false) => CharSampler::Any,
+        (None, true, false) => CharSampler::Ascii,
+        (None, _, true) => CharSampler::Alphanumeric,
+    };
+
+    if prefix.is_none() && needle.is_none() && suffix.is_none() {
+        return Ok(gen_sampler_for_len(char_sampler, min_len, max_len));
+    }
+
+    let to_string = |maybe_expr: Option<&syn::Expr>| match maybe_expr {
+        Some(expr) => quote!(::std::string::ToString::to_string(&#expr)),
+        None => quote!(::std::string::String::new()),
+    };
+    let prefix = to_string(prefix);
+    let needle = to_string(needle);
+    let suffix = to_string(suffix);
+
+    // The fixed parts take some of the length budget, so the generated middle part is shorter.
+    let middle_min_len = quote!((#min_len).saturating_sub(fixed_len));
+    let middle_max_len = max_len.map(|max| quote!((#max).saturating_sub(fixed_len)));
+    let middle = gen_sampler_for_len(char_sampler, middle_min_len, middle_max_len);
+
+    Ok(quote!({
+        let prefix: ::std::string::String = #prefix;
+        let needle: ::std::string::String = #needle;
+        let suffix: ::std::string::String = #suffix;
+        let fixed_len =
+            prefix.chars().count() + needle.chars().count() + suffix.chars().count();
+        let middle: ::std::string::String = #middle;
+        ::std::format!("{}{}{}{}", prefix, middle, needle, suffix)
+    }))
+}
+
+/// Defines which characters are generated.
+#[derive(Debug, Clone, Copy)]
+enum CharSampler<'a> {
+    /// Any unicode character.
+    Any,
+    /// ASCII characters.
+    Ascii,
+    /// ASCII letters and digits, which are both ASCII and alphanumeric.
+    Alphanumeric,
+    /// Characters of the string given to `contains_only`.
+    Charset(&'a syn::Expr),
+}
+
+/// How many characters more than the min length are generated when there is no max length.
+const DEFAULT_EXTRA_LEN: usize = 32;
+
+fn gen_sampler_for_len(
+    char_sampler: CharSampler<'_>,
+    min_len: TokenStream,
+    maybe_max_len: Option<TokenStream>,
+) -> TokenStream {
+    let sample_char = match char_sampler {
+        CharSampler::Any => quote!(rng.gen::<char>()),
+        CharSampler::Ascii => quote!(char::from(rng.gen_range(0u8..=0x7F))),
+        CharSampler::Alphanumeric => {
+            quote!(char::from(rng.sample(::rand::distributions::Alphanumeric)))
+        }
+        CharSampler::Charset(charset) => quote!({
+            let charset: &str = #charset;
+            let index = rng.gen_range(0..charset.chars().count());
+            charset.chars().nth(index).expect("Nutype failed to sample a char")
+        }),
+    };
+    let max_len = match maybe_max_len {
+        Some(max_len) => quote!(#max_len),
+        None => quote!(min_len + #DEFAULT_EXTRA_LEN),
+    };
+    quote!({
+        let min_len: usize = #min_len;
+        let max_len: usize = #max_len;
+        let len = rng.gen_range(min_len..=::core::cmp::max(min_len, max_len));
+        (0..len).map(|_| #sample_char).collect::<::std::string::String>()
+    })
+}
@@ -3,7 +3,7 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
 
 use crate::common::models::{
-    Guard, RawGuard, SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
+    FallibleSanitizer, Guard, RawGuard, SpannedItem, TypeTrait, TypedCustomFunction, ValueOrExpr,
 };
 
 // Sanitizer
@@ -16,7 +16,20 @@ pub enum StringSanitizer {
     Trim,
     Lowercase,
     Uppercase,
+    DedupWhitespace,
+    Truncate(ValueOrExpr<usize>),
+    Pad {
+        len: ValueOrExpr<usize>,
+        fill: syn::Expr,
+    },
     With(TypedCustomFunction),
+    TryWith(TypedCustomFunction),
+}
+
+impl FallibleSanitizer for StringSanitizer {
+    fn is_fallible(&self) -> bool {
+        matches!(self, Self::TryWith(_))
+    }
 }
 
 // Validator
@@ -29,10 +42,75 @@ pub type SpannedStringValidator = SpannedItem<StringValidator>;
 pub enum StringValidator {
     LenCharMin(ValueOrExpr<usize>),
     LenCharMax(ValueOrExpr<usize>),
+    #[cfg_attr(not(feature = "unicode-segmentation"), allow(dead_code))]
+    LenGraphemeMax(ValueOrExpr<usize>),
+    LenUtf16Max(ValueOrExpr<usize>),
     NotEmpty,
+    Ascii,
+    Alphanumeric,
+    StartsWith(syn::Expr),
+    EndsWith(syn::Expr),
+    /// Passes if the value starts with at least one of the prefixes.
+    StartsWithAny(Vec<syn::Expr>),
+    /// Passes if the value ends with at least one of the suffixes.
+    EndsWithAny(Vec<syn::Expr>),
+    Contains(syn::Expr),
+    ContainsOnly(syn::Expr),
+    /// Byte-lexicographic lower boundary (inclusive), compared with `str` ordering.
+    Min(syn::Expr),
+    /// Byte-lexicographic upper boundary (inclusive), compared with `str` ordering.
+    Max(syn::Expr),
+    OneOf(Vec<syn::Expr>),
+    /// Passes if at least one of the nested validators passes. They are checked in order,
+    /// so the rest is skipped as soon as one passes.
+    AnyOf(Vec<StringValidator>),
+    /// Passes if all of the nested validators pass, reporting a single `AllOfViolated` error.
+    AllOf(Vec<StringValidator>),
     Predicate(TypedCustomFunction),
+    WithResult(TypedCustomFunction),
     #[cfg_attr(not(feature = "regex"), allow(dead_code))]
     Regex(RegexDef),
+    #[cfg_attr(not(feature = "email"), allow(dead_code))]
+    Email,
+    /// The case, when the schemes are not restricted, is represented by `None`.
+    #[cfg_attr(not(feature = "url"), allow(dead_code))]
+    Url {
+        schemes: Option<Vec<String>>,
+    },
+}
+
+impl StringValidator {
+    /// Check if the validator is a closure that expects `&String` rather than `&str`,
+    /// e.g. `predicate = |s: &String| ..`. Then the sanitized `String` is passed to it as it is.
+    pub fn takes_string_ref(&self) -> bool {
+        match self {
+            Self::Predicate(TypedCustomFunction::Closure(closure))
+            | Self::WithResult(TypedCustomFunction::Closure(closure)) => {
+                match closure.inputs.first() {
+                    Some(syn::Pat::Type(pat_type)) => is_string_ref(&pat_type.ty),
+                    _ => false,
+                }
+            }
+            Self::AnyOf(validators) | Self::AllOf(validators) => {
+                validators.iter().any(Self::takes_string_ref)
+            }
+            _ => false,
+        }
+    }
+}
+
+fn is_string_ref(tp: &syn::Type) -> bool {
+    let syn::Type::Reference(reference) = tp else {
+        return false;
+    };
+    let syn::Type::Path(type_path) = reference.elem.as_ref() else {
+        return false;
+    };
+    type_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|segment| segment.ident == "String")
 }
 
 #[cfg_attr(not(feature = "regex"), allow(dead_code))]
@@ -61,6 +139,7 @@ pub enum StringDeriveTrait {
     FromStr,
     AsRef,
     From,
+    FromIterator,
     TryFrom,
     Into,
     Hash,
@@ -68,13 +147,24 @@ pub enum StringDeriveTrait {
     Display,
     Default,
     Deref,
+    EqInner,
+    OrdInner,
+    Nutype,
 
     // // External crates
     //
     SerdeSerialize,
     SerdeDeserialize,
+    RkyvArchive,
+    SqlxJson,
+    RedisToRedisArgs,
+    RedisFromRedisValue,
+    BincodeEncode,
+    BincodeDecode,
+    ProptestArbitrary,
+    RandDistribution,
     SchemarsJsonSchema,
-    // Arbitrary,
+    ArbitraryArbitrary,
     DieselNewType,
 }
 
@@ -82,6 +172,14 @@ impl TypeTrait for StringDeriveTrait {
     fn is_from_str(&self) -> bool {
         self == &Self::FromStr
     }
+
+    fn is_diesel_new_type(&self) -> bool {
+        self == &Self::DieselNewType
+    }
+
+    fn is_debug(&self) -> bool {
+        self == &Self::Debug
+    }
 }
 
 pub type StringRawGuard = RawGuard<SpannedStringSanitizer, SpannedStringValidator>;
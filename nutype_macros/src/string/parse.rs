@@ -1,19 +1,26 @@
 use crate::{
     common::{
-        models::{Attributes, SpannedDeriveTrait, SpannedItem},
+        models::{Attributes, SpannedDeriveTrait, SpannedItem, TypedCustomFunction, ValueOrExpr},
         parse::{
-            parse_number_or_expr, parse_sanitizer_kind, parse_typed_custom_function_raw,
-            parse_validator_kind, ParseableAttributes,
+            kind_name, parse_number_or_expr, parse_range, parse_sanitizer_kind,
+            parse_typed_custom_function_raw, parse_validator_group, parse_validator_kind,
+            parse_value_list, peek_shorthand, ParseValidators, ParseableAttributes, RangeBounds,
+            RangeEnd,
+        },
+        validate::{
+            validate_enumerate_is_not_set, validate_from_encoding_is_not_set,
+            validate_repr_transparent_is_not_set, validate_serde_lenient_is_not_set,
         },
     },
     string::models::{StringGuard, StringRawGuard, StringSanitizer, StringValidator},
 };
 use cfg_if::cfg_if;
-use proc_macro2::TokenStream;
+use proc_macro2::{Ident, Span, TokenStream};
 use syn::{
     parse::{Parse, ParseStream},
+    parse_quote,
     spanned::Spanned,
-    LitStr, Path, Token,
+    Expr, LitStr, Path, Token,
 };
 
 use super::{
@@ -21,7 +28,7 @@ use super::{
         RegexDef, SpannedStringSanitizer, SpannedStringValidator, StringSanitizerKind,
         StringValidatorKind,
     },
-    validate::validate_string_meta,
+    validate::{validate_string_eq_mode, validate_string_meta, validate_string_ord_mode},
 };
 
 pub fn parse_attributes(
@@ -33,19 +40,70 @@ pub fn parse_attributes(
     let ParseableAttributes {
         sanitizers,
         validators,
+        raw_validators,
         new_unchecked,
         default,
+        non_exhaustive_error,
+        collect_errors,
+        allow_unused,
+        repr_transparent,
+        enumerate,
+        display,
+        new_fn,
+        inner_vis,
+        eq,
+        ord,
+        serde_repr,
+        schema_example,
+        from_env,
+        from_encoding,
+        sanitize_then_return_changed,
+        transparent_debug,
+        skip_new,
+        serde_lenient,
+        builder,
+        crate_path,
+        context,
+        async_validators,
         derive_traits,
     } = attrs;
+    let repr_transparent = validate_repr_transparent_is_not_set(repr_transparent)?;
+    let from_encoding = validate_from_encoding_is_not_set(from_encoding)?;
+    let enumerate = validate_enumerate_is_not_set(enumerate)?;
+    let serde_lenient = validate_serde_lenient_is_not_set(serde_lenient)?;
+    let eq = validate_string_eq_mode(eq, ord.as_ref(), &derive_traits)?;
+    let ord = validate_string_ord_mode(ord, eq, &derive_traits)?;
     let raw_guard = StringRawGuard {
         sanitizers,
         validators,
     };
-    let guard = validate_string_meta(raw_guard)?;
+    let guard = validate_string_meta(raw_guard, raw_validators)?;
     Ok(Attributes {
         new_unchecked,
         guard,
         default,
+        non_exhaustive_error,
+        collect_errors,
+        allow_unused,
+        repr_transparent,
+        enumerate,
+        display,
+        new_fn,
+        inner_vis,
+        eq,
+        ord,
+        serde_repr,
+        schema_example,
+        from_env,
+        from_encoding,
+        sanitize_then_return_changed,
+        transparent_debug,
+        skip_new,
+        serde_lenient,
+        builder,
+        crate_path,
+        context,
+        async_validators,
         derive_traits,
     })
 }
@@ -67,6 +125,33 @@ impl Parse for SpannedStringSanitizer {
                 item: StringSanitizer::Uppercase,
                 span: ident.span(),
             }),
+            StringSanitizerKind::DedupWhitespace => Ok(SpannedStringSanitizer {
+                item: StringSanitizer::DedupWhitespace,
+                span: ident.span(),
+            }),
+            StringSanitizerKind::Truncate => {
+                let content;
+                let parens = syn::parenthesized!(content in input);
+                let len = parse_sanitizer_len(&content, "truncate")?;
+                Ok(SpannedStringSanitizer {
+                    item: StringSanitizer::Truncate(len),
+                    span: parens.span.join(),
+                })
+            }
+            StringSanitizerKind::Pad => {
+                let content;
+                let parens = syn::parenthesized!(content in input);
+                let len = parse_sanitizer_len(&content, "pad")?;
+                let _: Token![,] = content.parse()?;
+                let fill: Expr = content.parse()?;
+                if !content.is_empty() {
+                    return Err(content.error("Unexpected tokens after the fill character.\nExpected `pad(len, fill)`, for example: `pad(10, ' ')`"));
+                }
+                Ok(SpannedStringSanitizer {
+                    item: StringSanitizer::Pad { len, fill },
+                    span: parens.span.join(),
+                })
+            }
             StringSanitizerKind::With => {
                 let _eq: Token![=] = input.parse()?;
                 let (typed_custom_function, span) =
@@ -76,10 +161,62 @@ impl Parse for SpannedStringSanitizer {
                     span,
                 })
             }
+            StringSanitizerKind::TryWith => {
+                let _eq: Token![=] = input.parse()?;
+                let (typed_custom_function, span) =
+                    parse_typed_custom_function_raw(input, "String")?;
+                Ok(SpannedStringSanitizer {
+                    item: StringSanitizer::TryWith(typed_custom_function),
+                    span,
+                })
+            }
         }
     }
 }
 
+impl ParseValidators for SpannedStringValidator {
+    /// Besides the regular validators, parse `len_char = 3..=20` shorthand, that expands into
+    /// `len_char_min` and `len_char_max`.
+    fn parse_validators(input: ParseStream) -> syn::Result<Vec<Self>> {
+        if !peek_shorthand(input, "len_char") {
+            return Ok(vec![Self::parse(input)?]);
+        }
+        let _len_char: Ident = input.parse()?;
+        let _eq: Token![=] = input.parse()?;
+        let RangeBounds { start, end } = parse_range::<usize>(input)?;
+
+        let mut validators = Vec::with_capacity(2);
+        if let Some((min_len, span)) = start {
+            validators.push(SpannedStringValidator {
+                item: StringValidator::LenCharMin(min_len),
+                span,
+            });
+        }
+        if let Some((end, span)) = end {
+            let max_len = match end {
+                RangeEnd::Inclusive(max_len) => max_len,
+                RangeEnd::Exclusive(ValueOrExpr::Value(len)) => {
+                    let max_len = len.checked_sub(1).ok_or_else(|| {
+                        syn::Error::new(span, "`len_char` range cannot end with `..0`")
+                    })?;
+                    ValueOrExpr::Value(max_len)
+                }
+                // Bind the end to a variable, so clippy does not complain about `x > y - 1`
+                // in the generated code.
+                RangeEnd::Exclusive(ValueOrExpr::Expr(len)) => ValueOrExpr::Expr(parse_quote!({
+                    let end: usize = #len;
+                    end - 1
+                })),
+            };
+            validators.push(SpannedStringValidator {
+                item: StringValidator::LenCharMax(max_len),
+                span,
+            });
+        }
+        Ok(validators)
+    }
+}
+
 impl Parse for SpannedStringValidator {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let (kind, ident) = parse_validator_kind(input)?;
@@ -101,18 +238,153 @@ impl Parse for SpannedStringValidator {
                     span,
                 })
             }
+            StringValidatorKind::LenGraphemeMax => {
+                cfg_if! {
+                    if #[cfg(feature = "unicode-segmentation")] {
+                        let _: Token![=] = input.parse()?;
+                        let (max_len, span) = parse_number_or_expr::<usize>(input)?;
+                        Ok(SpannedStringValidator {
+                            item: StringValidator::LenGraphemeMax(max_len),
+                            span,
+                        })
+                    } else {
+                        let msg = "To validate the length in graphemes, the feature `unicode-segmentation` of the crate `nutype` must be enabled.";
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
+            StringValidatorKind::LenUtf16Max => {
+                let _: Token![=] = input.parse()?;
+                let (max_len, span) = parse_number_or_expr::<usize>(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::LenUtf16Max(max_len),
+                    span,
+                })
+            }
             StringValidatorKind::NotEmpty => Ok(SpannedStringValidator {
                 item: StringValidator::NotEmpty,
                 span: ident.span(),
             }),
+            StringValidatorKind::Ascii => Ok(SpannedStringValidator {
+                item: StringValidator::Ascii,
+                span: ident.span(),
+            }),
+            StringValidatorKind::Alphanumeric => Ok(SpannedStringValidator {
+                item: StringValidator::Alphanumeric,
+                span: ident.span(),
+            }),
+            StringValidatorKind::StartsWith => {
+                let _: Token![=] = input.parse()?;
+                let prefix: Expr = input.parse()?;
+                Ok(SpannedStringValidator {
+                    span: prefix.span(),
+                    item: StringValidator::StartsWith(prefix),
+                })
+            }
+            StringValidatorKind::EndsWith => {
+                let _: Token![=] = input.parse()?;
+                let suffix: Expr = input.parse()?;
+                Ok(SpannedStringValidator {
+                    span: suffix.span(),
+                    item: StringValidator::EndsWith(suffix),
+                })
+            }
+            StringValidatorKind::StartsWithAny => {
+                let _: Token![=] = input.parse()?;
+                let (prefixes, span) = parse_value_list(input, "starts_with_any", Expr::parse)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::StartsWithAny(prefixes),
+                    span,
+                })
+            }
+            StringValidatorKind::EndsWithAny => {
+                let _: Token![=] = input.parse()?;
+                let (suffixes, span) = parse_value_list(input, "ends_with_any", Expr::parse)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::EndsWithAny(suffixes),
+                    span,
+                })
+            }
+            StringValidatorKind::Contains => {
+                let _: Token![=] = input.parse()?;
+                let needle: Expr = input.parse()?;
+                Ok(SpannedStringValidator {
+                    span: needle.span(),
+                    item: StringValidator::Contains(needle),
+                })
+            }
+            StringValidatorKind::ContainsOnly => {
+                let _: Token![=] = input.parse()?;
+                let charset: Expr = input.parse()?;
+                if let Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Str(lit_str),
+                    ..
+                }) = &charset
+                {
+                    if lit_str.value().is_empty() {
+                        let msg = "`contains_only` requires at least one allowed character.";
+                        return Err(syn::Error::new(lit_str.span(), msg));
+                    }
+                }
+                Ok(SpannedStringValidator {
+                    span: charset.span(),
+                    item: StringValidator::ContainsOnly(charset),
+                })
+            }
+            StringValidatorKind::Min => {
+                let _: Token![=] = input.parse()?;
+                let min: Expr = input.parse()?;
+                Ok(SpannedStringValidator {
+                    span: min.span(),
+                    item: StringValidator::Min(min),
+                })
+            }
+            StringValidatorKind::Max => {
+                let _: Token![=] = input.parse()?;
+                let max: Expr = input.parse()?;
+                Ok(SpannedStringValidator {
+                    span: max.span(),
+                    item: StringValidator::Max(max),
+                })
+            }
+            StringValidatorKind::OneOf => {
+                let _: Token![=] = input.parse()?;
+                let (values, span) = parse_value_list(input, "one_of", Expr::parse)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::OneOf(values),
+                    span,
+                })
+            }
+            StringValidatorKind::AnyOf => {
+                let (validators, span) = parse_validator_group(input, "any_of")?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::AnyOf(validate_group_validators(validators)?),
+                    span,
+                })
+            }
+            StringValidatorKind::AllOf => {
+                let (validators, span) = parse_validator_group(input, "all_of")?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::AllOf(validate_group_validators(validators)?),
+                    span,
+                })
+            }
             StringValidatorKind::Predicate => {
                 let _eq: Token![=] = input.parse()?;
-                let (typed_custom_function, span) = parse_typed_custom_function_raw(input, "&str")?;
+                let (typed_custom_function, span) = parse_str_validator_fn(input)?;
                 Ok(SpannedStringValidator {
                     item: StringValidator::Predicate(typed_custom_function),
                     span,
                 })
             }
+            StringValidatorKind::WithResult => {
+                let _eq: Token![=] = input.parse()?;
+                let (typed_custom_function, span) = parse_str_validator_fn(input)?;
+                Ok(SpannedStringValidator {
+                    item: StringValidator::WithResult(typed_custom_function),
+                    span,
+                })
+            }
             StringValidatorKind::Regex => {
                 cfg_if! {
                     if #[cfg(feature = "regex")] {
@@ -135,7 +407,129 @@ impl Parse for SpannedStringValidator {
                     }
                 }
             }
+            StringValidatorKind::Email => {
+                cfg_if! {
+                    if #[cfg(feature = "email")] {
+                        Ok(SpannedStringValidator {
+                            item: StringValidator::Email,
+                            span: ident.span(),
+                        })
+                    } else {
+                        let msg = "To validate emails, the feature `email` of the crate `nutype` must be enabled.";
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
+            StringValidatorKind::Url => {
+                cfg_if! {
+                    if #[cfg(feature = "url")] {
+                        let (schemes, span) = if input.peek(syn::token::Paren) {
+                            let (schemes, span) = parse_url_schemes(input)?;
+                            (Some(schemes), span)
+                        } else {
+                            (None, ident.span())
+                        };
+                        Ok(SpannedStringValidator {
+                            item: StringValidator::Url { schemes },
+                            span,
+                        })
+                    } else {
+                        let msg = "To validate URLs, the feature `url` of the crate `nutype` must be enabled.";
+                        Err(syn::Error::new(ident.span(), msg))
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Custom validators receive `&str` borrowed from the inner `String`, so the value is never cloned.
+/// A closure annotated with `&String` receives the inner `String` itself, see `takes_string_ref()`.
+fn parse_str_validator_fn(input: ParseStream) -> syn::Result<(TypedCustomFunction, Span)> {
+    parse_typed_custom_function_raw(input, "&str")
+}
+
+/// Within `any_of` and `all_of` only the outcome of a nested validator matters, so the validators,
+/// that carry details in their error variants (`contains_only` and `with_result`) or need a shared
+/// static (`regex` with a string literal), are not supported there.
+fn validate_group_validators(
+    validators: Vec<SpannedStringValidator>,
+) -> syn::Result<Vec<StringValidator>> {
+    validators
+        .into_iter()
+        .map(|validator| match validator.item {
+            StringValidator::ContainsOnly(_)
+            | StringValidator::WithResult(_)
+            | StringValidator::Regex(RegexDef::StringLiteral(_)) => {
+                let msg = format!(
+                    "`{}` cannot be used within `any_of` or `all_of`.\nUse `predicate` instead.",
+                    kind_name(validator.item.kind())
+                );
+                Err(syn::Error::new(validator.span, msg))
+            }
+            item => Ok(item),
+        })
+        .collect()
+}
+
+/// Parse the length of `truncate(len)` and `pad(len, fill)`, which cannot be zero.
+fn parse_sanitizer_len(input: ParseStream, sanitizer: &str) -> syn::Result<ValueOrExpr<usize>> {
+    let (len, span) = parse_number_or_expr::<usize>(input)?;
+    if let ValueOrExpr::Value(0) = len {
+        let msg = format!("The length of `{sanitizer}` must be greater than 0.");
+        return Err(syn::Error::new(span, msg));
+    }
+    Ok(len)
+}
+
+/// Parse the arguments of `url` validator, e.g. `(schemes = ["http", "https"])`.
+/// The schemes are returned in lowercase, the same way as they are normalized by the `url` crate.
+#[cfg(feature = "url")]
+fn parse_url_schemes(input: ParseStream) -> syn::Result<(Vec<String>, proc_macro2::Span)> {
+    let content;
+    let parens = syn::parenthesized!(content in input);
+
+    let param: Ident = content.parse()?;
+    if param != "schemes" {
+        let msg = format!("Unknown `url` parameter `{param}`. Expected `schemes`.");
+        return Err(syn::Error::new(param.span(), msg));
+    }
+    let _: Token![=] = content.parse()?;
+
+    let list;
+    let brackets = syn::bracketed!(list in content);
+    let lits = list.parse_terminated(<LitStr as Parse>::parse, Token![,])?;
+    if lits.is_empty() {
+        let msg = "`schemes` requires at least one allowed scheme.";
+        return Err(syn::Error::new(brackets.span.join(), msg));
+    }
+
+    let mut schemes = Vec::with_capacity(lits.len());
+    for lit in lits {
+        let scheme = lit.value();
+        if !is_valid_url_scheme(&scheme) {
+            let msg = format!("`{scheme}` is not a valid URL scheme. A scheme starts with a letter followed by letters, digits, `+`, `-` or `.`, e.g. `https`.");
+            return Err(syn::Error::new(lit.span(), msg));
+        }
+        schemes.push(scheme.to_ascii_lowercase());
+    }
+
+    if !content.is_empty() {
+        return Err(content.error("Unexpected tokens after `schemes`."));
+    }
+
+    Ok((schemes, parens.span.join()))
+}
+
+/// Scheme syntax according to RFC 3986: `ALPHA *( ALPHA / DIGIT / "+" / "-" / "." )`
+#[cfg(feature = "url")]
+fn is_valid_url_scheme(scheme: &str) -> bool {
+    let mut chars = scheme.chars();
+    match chars.next() {
+        Some(first) if first.is_ascii_alphabetic() => {
+            chars.all(|c| c.is_ascii_alphanumeric() || c == '+' || c == '-' || c == '.')
         }
+        _ => false,
     }
 }
 
@@ -5,7 +5,11 @@ use proc_macro2::Span;
 
 use crate::{
     common::{
-        models::{DeriveTrait, SpannedDeriveTrait, ValueOrExpr},
+        models::{
+            has_fallible_sanitizers, DeriveTrait, EqMode, OrdMode, SpannedDeriveTrait,
+            SpannedEqMode, SpannedOrdMode, TypedCustomFunction, ValueOrExpr,
+        },
+        parse::kind_name,
         validate::validate_duplicates,
     },
     string::models::{StringGuard, StringRawGuard, StringSanitizer, StringValidator},
@@ -13,33 +17,125 @@ use crate::{
 
 use super::models::{
     SpannedStringSanitizer, SpannedStringValidator, StringDeriveTrait, StringSanitizerKind,
+    StringValidatorKind,
 };
 
-pub fn validate_string_meta(raw_meta: StringRawGuard) -> Result<StringGuard, syn::Error> {
+pub fn validate_string_meta(
+    raw_meta: StringRawGuard,
+    raw_validators: Vec<SpannedStringValidator>,
+) -> Result<StringGuard, syn::Error> {
     let StringRawGuard {
         sanitizers,
         validators,
     } = raw_meta;
 
-    let validators = validate_validators(validators)?;
+    // The case consistency matters only for the validators that run after the sanitizers.
+    validate_case_consistency(&sanitizers, &validators)?;
+
+    // The same validator cannot be used in both places, since it would produce the same error variant.
+    for raw_validator in &raw_validators {
+        if let Some(validator) = validators.iter().find(|v| v.kind() == raw_validator.kind()) {
+            let msg = format!(
+                "Validator `{}` cannot be used both within `raw(...)` and outside of it.",
+                kind_name(validator.kind())
+            );
+            return Err(syn::Error::new(validator.span(), msg));
+        }
+    }
+
+    // Raw and regular validators are validated together, so the length boundaries must not
+    // contradict each other.
+    let raw_validators_count = raw_validators.len();
+    let mut raw_validators =
+        validate_validators(raw_validators.into_iter().chain(validators).collect())?;
+    let validators = raw_validators.split_off(raw_validators_count);
     let sanitizers = validate_sanitizers(sanitizers)?;
 
-    if validators.is_empty() {
+    if validators.is_empty() && raw_validators.is_empty() && !has_fallible_sanitizers(&sanitizers) {
         Ok(StringGuard::WithoutValidation { sanitizers })
     } else {
         Ok(StringGuard::WithValidation {
             sanitizers,
             validators,
+            raw_validators,
         })
     }
 }
 
+/// With case-insensitive equality, a derived ordering would disagree with `PartialEq`
+/// (e.g. `"a" == "A"`, but `"a" > "A"`), so `PartialOrd`, `Ord` and `OrdInner` are rejected
+/// unless the ordering is case-insensitive too.
+pub fn validate_string_eq_mode(
+    maybe_eq: Option<SpannedEqMode>,
+    maybe_ord: Option<&SpannedOrdMode>,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<EqMode, syn::Error> {
+    let Some(eq) = maybe_eq else {
+        return Ok(EqMode::Exact);
+    };
+    let is_ord_case_insensitive = maybe_ord.is_some_and(|ord| ord.item == OrdMode::CaseInsensitive);
+    if eq.item == EqMode::CaseInsensitive && !is_ord_case_insensitive {
+        for derive_trait in derive_traits {
+            if let DeriveTrait::PartialOrd | DeriveTrait::Ord | DeriveTrait::OrdInner =
+                derive_trait.item
+            {
+                let msg = format!(
+                    "`{:?}` cannot be derived together with `eq = case_insensitive`, because the ordering would be inconsistent with the equality.\nSet `ord = case_insensitive` to compare lowercased values.",
+                    derive_trait.item,
+                );
+                return Err(syn::Error::new(derive_trait.span(), msg));
+            }
+        }
+    }
+    Ok(eq.item)
+}
+
+/// Case-insensitive ordering makes sense only with `PartialOrd`, `Ord` or `OrdInner`, and it must agree
+/// with the equality, so an exact `PartialEq` is rejected (e.g. `"a" != "A"`, but neither is less).
+pub fn validate_string_ord_mode(
+    maybe_ord: Option<SpannedOrdMode>,
+    eq: EqMode,
+    derive_traits: &[SpannedDeriveTrait],
+) -> Result<OrdMode, syn::Error> {
+    let Some(ord) = maybe_ord else {
+        return Ok(OrdMode::Exact);
+    };
+    if ord.item == OrdMode::CaseInsensitive {
+        let has_ord_trait = derive_traits.iter().any(|t| {
+            matches!(
+                t.item,
+                DeriveTrait::PartialOrd | DeriveTrait::Ord | DeriveTrait::OrdInner
+            )
+        });
+        if !has_ord_trait {
+            let msg = "`ord = case_insensitive` is set, but neither `PartialOrd` nor `Ord` is derived.\nAdd `PartialOrd` to `derive(...)`.";
+            return Err(syn::Error::new(ord.span(), msg));
+        }
+        if eq == EqMode::Exact {
+            for derive_trait in derive_traits {
+                // `OrdInner` brings an exact `EqInner` along.
+                if let DeriveTrait::PartialEq | DeriveTrait::Eq | DeriveTrait::OrdInner =
+                    derive_trait.item
+                {
+                    let msg = format!(
+                        "`{:?}` compares the values exactly, which is inconsistent with `ord = case_insensitive`.\nSet `eq = case_insensitive` as well, so the equality agrees with the ordering.",
+                        derive_trait.item,
+                    );
+                    return Err(syn::Error::new(derive_trait.span(), msg));
+                }
+            }
+        }
+    }
+    Ok(ord.item)
+}
+
 fn validate_validators(
     validators: Vec<SpannedStringValidator>,
 ) -> Result<Vec<StringValidator>, syn::Error> {
     // Check duplicates
     //
     validate_duplicates(&validators, |kind| {
+        let kind = kind_name(kind);
         format!("Duplicated validators `{kind}`.\nDon't worry, you still remain ingenious!")
     })?;
 
@@ -69,6 +165,31 @@ fn validate_validators(
         }
     }
 
+    // min VS max
+    //
+    let find_lit_str = |kind: StringValidatorKind| {
+        validators.iter().find_map(|v| match &v.item {
+            StringValidator::Min(syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            }))
+            | StringValidator::Max(syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(lit_str),
+                ..
+            })) if v.kind() == kind => Some((v.span, lit_str.value())),
+            _ => None,
+        })
+    };
+    if let (Some((_, min)), Some((max_span, max))) = (
+        find_lit_str(StringValidatorKind::Min),
+        find_lit_str(StringValidatorKind::Max),
+    ) {
+        if min > max {
+            let msg = format!("`min` cannot be greater than `max`.\n{min:?} goes after {max:?} in the lexicographic order.");
+            return Err(syn::Error::new(max_span, msg));
+        }
+    }
+
     // Validate regex
     //
     #[cfg(feature = "regex")]
@@ -113,6 +234,146 @@ fn validate_sanitizers(
     Ok(sanitizers)
 }
 
+/// Detect a `predicate` that can never pass, because it requires the characters to be in the case
+/// opposite to the one set by `lowercase` or `uppercase` sanitizer,
+/// e.g. `sanitize(uppercase), validate(predicate = |s| s.chars().all(char::is_lowercase))`.
+///
+/// Only the trivial shapes of predicate are recognized to avoid false positives:
+/// `.chars().all(char::is_lowercase)` and `.chars().all(|c| c.is_lowercase())`,
+/// including the `is_uppercase` and `is_ascii_*` counterparts.
+fn validate_case_consistency(
+    sanitizers: &[SpannedStringSanitizer],
+    validators: &[SpannedStringValidator],
+) -> Result<(), syn::Error> {
+    // A custom sanitizer may change the case again, so only the last case sanitizer counts
+    // and only if no custom sanitizer follows it.
+    let Some(case_sanitizer) = sanitizers.iter().rev().find(|s| {
+        matches!(
+            s.item,
+            StringSanitizer::Lowercase | StringSanitizer::Uppercase | StringSanitizer::With(_)
+        )
+    }) else {
+        return Ok(());
+    };
+    let sanitized_case = match case_sanitizer.item {
+        StringSanitizer::Lowercase => Case::Lower,
+        StringSanitizer::Uppercase => Case::Upper,
+        _ => return Ok(()),
+    };
+
+    for validator in validators {
+        if let StringValidator::Predicate(TypedCustomFunction::Closure(ref closure)) =
+            validator.item
+        {
+            if predicate_required_case(closure) == Some(sanitized_case.opposite()) {
+                let msg = format!(
+                    "The `predicate` requires {} characters, but `{}` sanitizer makes them {}, so the validation can never pass.\nDid you mean to use `{}` sanitizer?",
+                    sanitized_case.opposite().name(),
+                    case_sanitizer.kind(),
+                    sanitized_case.name(),
+                    sanitized_case.opposite().name(),
+                );
+                return Err(syn::Error::new(validator.span, msg));
+            }
+        }
+    }
+    Ok(())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Case {
+    Lower,
+    Upper,
+}
+
+impl Case {
+    fn opposite(self) -> Self {
+        match self {
+            Self::Lower => Self::Upper,
+            Self::Upper => Self::Lower,
+        }
+    }
+
+    fn name(self) -> &'static str {
+        match self {
+            Self::Lower => "lowercase",
+            Self::Upper => "uppercase",
+        }
+    }
+
+    fn from_char_method(ident: &syn::Ident) -> Option<Self> {
+        match ident.to_string().as_str() {
+            "is_lowercase" | "is_ascii_lowercase" => Some(Self::Lower),
+            "is_uppercase" | "is_ascii_uppercase" => Some(Self::Upper),
+            _ => None,
+        }
+    }
+}
+
+/// Recognize `|s| s.chars().all(<char case check>)` and return the case it requires.
+fn predicate_required_case(closure: &syn::ExprClosure) -> Option<Case> {
+    let syn::Expr::MethodCall(all_call) = strip_expr(&closure.body) else {
+        return None;
+    };
+    let syn::Expr::MethodCall(chars_call) = strip_expr(&all_call.receiver) else {
+        return None;
+    };
+    if all_call.method != "all"
+        || all_call.args.len() != 1
+        || chars_call.method != "chars"
+        || !chars_call.args.is_empty()
+    {
+        return None;
+    }
+
+    match strip_expr(&all_call.args[0]) {
+        // e.g. `char::is_lowercase`
+        syn::Expr::Path(expr_path) => {
+            let segments = &expr_path.path.segments;
+            if segments.len() != 2 || segments[0].ident != "char" {
+                return None;
+            }
+            Case::from_char_method(&segments[1].ident)
+        }
+        // e.g. `|c| c.is_lowercase()`
+        syn::Expr::Closure(char_closure) => {
+            let [param] = char_closure.inputs.iter().collect::<Vec<_>>()[..] else {
+                return None;
+            };
+            let param = match param {
+                syn::Pat::Type(pat_type) => pat_type.pat.as_ref(),
+                pat => pat,
+            };
+            let syn::Pat::Ident(param_ident) = param else {
+                return None;
+            };
+            let syn::Expr::MethodCall(case_call) = strip_expr(&char_closure.body) else {
+                return None;
+            };
+            let syn::Expr::Path(receiver) = strip_expr(&case_call.receiver) else {
+                return None;
+            };
+            if !case_call.args.is_empty() || !receiver.path.is_ident(&param_ident.ident) {
+                return None;
+            }
+            Case::from_char_method(&case_call.method)
+        }
+        _ => None,
+    }
+}
+
+/// Strip parentheses and blocks that consist of a single expression.
+fn strip_expr(expr: &syn::Expr) -> &syn::Expr {
+    match expr {
+        syn::Expr::Paren(paren) => strip_expr(&paren.expr),
+        syn::Expr::Block(block) if block.block.stmts.len() == 1 => match &block.block.stmts[0] {
+            syn::Stmt::Expr(inner, None) => strip_expr(inner),
+            _ => expr,
+        },
+        _ => expr,
+    }
+}
+
 pub fn validate_string_derive_traits(
     guard: &StringGuard,
     spanned_derive_traits: Vec<SpannedDeriveTrait>,
@@ -126,6 +387,18 @@ pub fn validate_string_derive_traits(
         traits.insert(string_derive_trait);
     }
 
+    // `From` already provides `TryFrom<String>` and `TryFrom<&str>` with `Infallible` error
+    // through the blanket implementation in `core`, so deriving both must not generate
+    // conflicting implementations.
+    if !has_validation && traits.contains(&StringDeriveTrait::From) {
+        traits.remove(&StringDeriveTrait::TryFrom);
+    }
+
+    // `PartialOrd<Inner>` requires `PartialEq<Inner>`, so `OrdInner` brings `EqInner` along.
+    if traits.contains(&StringDeriveTrait::OrdInner) {
+        traits.insert(StringDeriveTrait::EqInner);
+    }
+
     Ok(traits)
 }
 
@@ -146,11 +419,42 @@ fn to_string_derive_trait(
         DeriveTrait::FromStr => Ok(StringDeriveTrait::FromStr),
         DeriveTrait::AsRef => Ok(StringDeriveTrait::AsRef),
         DeriveTrait::Deref => Ok(StringDeriveTrait::Deref),
+        DeriveTrait::EqInner => Ok(StringDeriveTrait::EqInner),
+        DeriveTrait::OrdInner => Ok(StringDeriveTrait::OrdInner),
+        DeriveTrait::Nutype => Ok(StringDeriveTrait::Nutype),
+        DeriveTrait::Sum | DeriveTrait::Product => {
+            let msg = "`Sum` and `Product` can be derived only for integer and float based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::CheckedArith => {
+            let msg = "`CheckedArith` can be derived only for integer and float based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::Neg => {
+            let msg = "`Neg` can be derived only for integer and float based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::TryFromWide => {
+            let msg = "`TryFromWide` can be derived only for integer based types";
+            Err(syn::Error::new(span, msg))
+        }
         DeriveTrait::Hash => Ok(StringDeriveTrait::Hash),
         DeriveTrait::Borrow => Ok(StringDeriveTrait::Borrow),
         DeriveTrait::Into => Ok(StringDeriveTrait::Into),
         DeriveTrait::SerdeSerialize => Ok(StringDeriveTrait::SerdeSerialize),
         DeriveTrait::SerdeDeserialize => Ok(StringDeriveTrait::SerdeDeserialize),
+        DeriveTrait::RkyvArchive => Ok(StringDeriveTrait::RkyvArchive),
+        DeriveTrait::SqlxJson => Ok(StringDeriveTrait::SqlxJson),
+        DeriveTrait::RedisToRedisArgs => Ok(StringDeriveTrait::RedisToRedisArgs),
+        DeriveTrait::RedisFromRedisValue => Ok(StringDeriveTrait::RedisFromRedisValue),
+        DeriveTrait::BincodeEncode => Ok(StringDeriveTrait::BincodeEncode),
+        DeriveTrait::BincodeDecode => Ok(StringDeriveTrait::BincodeDecode),
+        DeriveTrait::NumTraitsZero | DeriveTrait::NumTraitsOne | DeriveTrait::NumTraitsBounded => {
+            let msg = "`Zero`, `One` and `Bounded` from `num-traits` can be derived only for integer and float based types";
+            Err(syn::Error::new(span, msg))
+        }
+        DeriveTrait::ProptestArbitrary => Ok(StringDeriveTrait::ProptestArbitrary),
+        DeriveTrait::RandDistribution => Ok(StringDeriveTrait::RandDistribution),
         DeriveTrait::SchemarsJsonSchema => Ok(StringDeriveTrait::SchemarsJsonSchema),
         DeriveTrait::Copy => Err(syn::Error::new(
             span,
@@ -167,11 +471,17 @@ fn to_string_derive_trait(
             }
         }
         DeriveTrait::TryFrom => Ok(StringDeriveTrait::TryFrom),
-        DeriveTrait::ArbitraryArbitrary => {
-            // TODO: Implement deriving Arbitrary
-            let msg = "Deriving Arbitrary trait for string types is not yet implemented";
-            Err(syn::Error::new(span, msg))
+        DeriveTrait::FromIterator => {
+            if has_validation {
+                Err(syn::Error::new(
+                    span,
+                    "#[nutype] cannot derive `FromIterator` trait, because there is validation defined and `FromIterator` cannot fail. Collect into `String` and use `TryFrom` instead.",
+                ))
+            } else {
+                Ok(StringDeriveTrait::FromIterator)
+            }
         }
+        DeriveTrait::ArbitraryArbitrary => Ok(StringDeriveTrait::ArbitraryArbitrary),
         DeriveTrait::DieselNewType => Ok(StringDeriveTrait::DieselNewType),
     }
 }
@@ -61,6 +61,7 @@ mod traits {
     }
 
     #[test]
+    #[allow(clippy::clone_on_copy)]
     fn test_clone() {
         let location = Location::new(Point::new(5, 8));
         let same_location = location.clone();
@@ -111,6 +112,17 @@ mod traits {
         assert_eq!(loc1.cmp(&loc2), Ordering::Less,);
     }
 
+    #[test]
+    fn test_ord_inner() {
+        #[nutype(derive(Debug, PartialEq, PartialOrd, OrdInner))]
+        pub struct Position(Point);
+
+        let position = Position::new(Point::new(1, 1));
+        assert!(position < Point::new(1, 2));
+        assert!(position > Point::new(0, 5));
+        assert_eq!(position, Point::new(1, 1));
+    }
+
     #[test]
     fn test_display() {
         let location = Location::new(Point::new(4, 7));
@@ -213,8 +225,6 @@ mod traits {
 
         #[test]
         fn test_without_validation() {
-            // Note: here we're deriving TryFrom without From, because if T implements From, then
-            // TryFrom is implemented automatically (blanket implementation)
             #[nutype(derive(Debug, TryFrom))]
             pub struct Destination(Point);
 
@@ -222,6 +232,18 @@ mod traits {
             assert_eq!(dest.into_inner(), Point::new(3, 2));
         }
 
+        #[test]
+        // `TryFrom` is provided by the blanket implementation, which relies on `From`.
+        #[allow(clippy::unnecessary_fallible_conversions)]
+        fn test_with_from() {
+            #[nutype(derive(Debug, From, TryFrom))]
+            pub struct Destination(Point);
+
+            let dest: Result<Destination, std::convert::Infallible> =
+                Destination::try_from(Point::new(3, 2));
+            assert_eq!(dest.unwrap().into_inner(), Point::new(3, 2));
+        }
+
         #[test]
         fn test_with_validation() {
             #[nutype(
@@ -364,6 +386,866 @@ fn test_sanitize_with_untyped_mut_closure() {
     assert_eq!(pos.into_inner(), Point::new(100, 91))
 }
 
+#[cfg(test)]
+mod tuple_inner_type {
+    use super::*;
+
+    #[test]
+    fn test_tuple_without_validation() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, AsRef, Into, From))]
+        pub struct LatLon((f64, f64));
+
+        let lat_lon = LatLon::from((52.52, 13.40));
+        assert_eq!(lat_lon.as_ref(), &(52.52, 13.40));
+
+        let (lat, lon): (f64, f64) = lat_lon.into();
+        assert_eq!(lat, 52.52);
+        assert_eq!(lon, 13.40);
+    }
+
+    #[test]
+    fn test_tuple_with_sanitizer_and_predicate() {
+        #[nutype(
+            derive(Debug, PartialEq, AsRef, Into),
+            sanitize(with = |(lat, lon)| (lat, (lon + 540.0) % 360.0 - 180.0)),
+            validate(predicate = |(lat, _lon)| (-90.0..=90.0).contains(lat)),
+        )]
+        pub struct LatLon((f64, f64));
+
+        assert_eq!(
+            LatLon::new((91.0, 0.0)),
+            Err(LatLonError::PredicateViolated)
+        );
+
+        let lat_lon = LatLon::new((52.5, 193.5)).unwrap();
+        assert_eq!(lat_lon.into_inner(), (52.5, -166.5));
+    }
+
+    #[test]
+    fn test_tuple_with_different_types() {
+        #[nutype(
+            derive(Debug, PartialEq),
+            validate(predicate = |(name, age)| !name.is_empty() && *age < 150),
+        )]
+        pub struct Person((String, u8));
+
+        assert_eq!(
+            Person::new((String::new(), 30)),
+            Err(PersonError::PredicateViolated)
+        );
+
+        let person = Person::new(("Anna".to_string(), 30)).unwrap();
+        assert_eq!(person.into_inner(), ("Anna".to_string(), 30));
+    }
+}
+
+#[cfg(test)]
+mod bool_inner_type {
+    use super::*;
+
+    #[test]
+    fn test_bool_without_validation() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, Display, From, Into))]
+        pub struct ConsentFlag(bool);
+
+        let consent = ConsentFlag::new(true);
+        assert_eq!(consent.to_string(), "true");
+        assert_eq!(ConsentFlag::from(false).to_string(), "false");
+
+        let inner: bool = consent.into();
+        assert!(inner);
+    }
+
+    #[test]
+    fn test_bool_with_predicate() {
+        #[nutype(
+            validate(predicate = |accepted| *accepted),
+            derive(Debug, Clone, Copy, PartialEq, Display, TryFrom, Into)
+        )]
+        pub struct AcceptedTerms(bool);
+
+        assert_eq!(
+            AcceptedTerms::new(false),
+            Err(AcceptedTermsError::PredicateViolated)
+        );
+        assert_eq!(AcceptedTerms::try_from(true).unwrap().to_string(), "true");
+    }
+}
+
+#[cfg(test)]
+mod enum_inner_type {
+    use super::*;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Color {
+        Red,
+        Green,
+        Blue,
+    }
+
+    impl std::str::FromStr for Color {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "red" => Ok(Color::Red),
+                "green" => Ok(Color::Green),
+                "blue" => Ok(Color::Blue),
+                _ => Err(format!("Unknown color `{s}`")),
+            }
+        }
+    }
+
+    impl std::fmt::Display for Color {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            let name = match self {
+                Color::Red => "red",
+                Color::Green => "green",
+                Color::Blue => "blue",
+            };
+            write!(f, "{name}")
+        }
+    }
+
+    // Only a subset of the variants is allowed
+    #[nutype(
+        validate(predicate = |c| matches!(c, Color::Red | Color::Green)),
+        derive(Debug, Clone, Copy, PartialEq, Display, FromStr)
+    )]
+    pub struct TrafficLight(Color);
+
+    #[test]
+    fn test_restricted_variants() {
+        assert_eq!(
+            TrafficLight::new(Color::Red).unwrap().into_inner(),
+            Color::Red
+        );
+        assert_eq!(
+            TrafficLight::new(Color::Blue),
+            Err(TrafficLightError::PredicateViolated)
+        );
+    }
+
+    #[test]
+    fn test_from_str_combines_parse_and_validation_errors() {
+        let light: TrafficLight = "green".parse().unwrap();
+        assert_eq!(light.into_inner(), Color::Green);
+        assert_eq!(light.to_string(), "green");
+
+        let err = "purple".parse::<TrafficLight>().unwrap_err();
+        assert!(matches!(err, TrafficLightParseError::Parse(_)));
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse TrafficLight: Unknown color `purple`"
+        );
+
+        let err = "blue".parse::<TrafficLight>().unwrap_err();
+        assert!(matches!(
+            err,
+            TrafficLightParseError::Validate(TrafficLightError::PredicateViolated)
+        ));
+    }
+}
+
+#[cfg(test)]
+mod len_validators {
+    use super::*;
+
+    #[test]
+    fn test_len_min_and_len_max() {
+        #[nutype(validate(len_min = 2, len_max = 4), derive(Debug, PartialEq))]
+        pub struct Payload(Vec<u8>);
+
+        assert_eq!(Payload::new(vec![1]), Err(PayloadError::LenMinViolated));
+        assert_eq!(
+            Payload::new(vec![1, 2, 3, 4, 5]),
+            Err(PayloadError::LenMaxViolated)
+        );
+        assert_eq!(
+            Payload::new(vec![1, 2, 3, 4]).unwrap().into_inner(),
+            vec![1, 2, 3, 4]
+        );
+
+        assert_eq!(
+            PayloadError::LenMinViolated.to_string(),
+            "Payload is too short. The length must be at least 2."
+        );
+        assert_eq!(
+            PayloadError::LenMaxViolated.to_string(),
+            "Payload is too long. The length must be at most 4."
+        );
+    }
+
+    #[test]
+    fn test_not_empty() {
+        #[nutype(validate(not_empty), derive(Debug, PartialEq))]
+        pub struct Tags(Vec<String>);
+
+        assert_eq!(Tags::new(vec![]), Err(TagsError::NotEmptyViolated));
+        assert_eq!(TagsError::NotEmptyViolated.to_string(), "Tags is empty.");
+        assert!(Tags::new(vec!["rust".to_string()]).is_ok());
+    }
+
+    const MAX_PAYLOAD_SIZE: usize = 8;
+
+    fn is_ascii(bytes: &[u8]) -> bool {
+        bytes.is_ascii()
+    }
+
+    #[test]
+    fn test_len_with_predicate_on_slice() {
+        #[nutype(
+            validate(not_empty, len_max = MAX_PAYLOAD_SIZE, predicate = is_ascii),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Payload(Vec<u8>);
+
+        assert_eq!(Payload::new(vec![]), Err(PayloadError::NotEmptyViolated));
+        assert_eq!(Payload::new(vec![0; 9]), Err(PayloadError::LenMaxViolated));
+        assert_eq!(
+            Payload::new(vec![255]),
+            Err(PayloadError::PredicateViolated)
+        );
+        assert!(Payload::new(b"ping".to_vec()).is_ok());
+    }
+
+    #[test]
+    fn test_as_slice_and_deref_slice() {
+        #[nutype(validate(len_max = 16), derive(Debug, AsRef, Deref))]
+        pub struct Payload(Vec<u8>);
+
+        fn checksum(bytes: &[u8]) -> u32 {
+            bytes.iter().map(|&b| b as u32).sum()
+        }
+
+        let payload = Payload::new(vec![1, 2, 3]).unwrap();
+
+        assert_eq!(payload.as_slice(), &[1, 2, 3]);
+        // `AsRef` is implemented only for `Vec<u8>`, so `as_ref()` needs no type annotation
+        assert_eq!(payload.as_ref(), &vec![1, 2, 3]);
+
+        // `Deref` coerces to `&[u8]` through `Vec<u8>`
+        assert_eq!(checksum(&payload), 6);
+        assert_eq!(payload.len(), 3);
+    }
+
+    #[test]
+    fn test_try_push() {
+        #[nutype(validate(len_max = 2), derive(Debug, PartialEq))]
+        pub struct Tags(Vec<String>);
+
+        let mut tags = Tags::new(vec![]).unwrap();
+        tags.try_push("rust".to_string()).unwrap();
+        tags.try_push("nutype".to_string()).unwrap();
+
+        assert_eq!(
+            tags.try_push("macros".to_string()),
+            Err(TagsError::LenMaxViolated)
+        );
+        // The rejected item is not kept
+        assert_eq!(tags.into_inner(), vec!["rust", "nutype"]);
+    }
+
+    // Not `Clone` on purpose
+    #[derive(Debug, PartialEq)]
+    pub struct Job(u32);
+
+    #[test]
+    fn test_try_push_does_not_require_clone() {
+        #[nutype(validate(len_max = 1), derive(Debug))]
+        pub struct Queue(Vec<Job>);
+
+        let mut queue = Queue::new(vec![]).unwrap();
+        queue.try_push(Job(1)).unwrap();
+        assert_eq!(queue.try_push(Job(2)), Err(QueueError::LenMaxViolated));
+        assert_eq!(queue.into_inner(), vec![Job(1)]);
+    }
+
+    #[test]
+    fn test_try_push_with_sanitizer() {
+        #[nutype(
+            sanitize(with = |mut bytes: Vec<u8>| { bytes.dedup(); bytes }),
+            validate(len_max = 2),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Payload(Vec<u8>);
+
+        let mut payload = Payload::new(vec![1, 2]).unwrap();
+        payload.try_push(2).unwrap();
+        assert_eq!(payload.inner(), &vec![1, 2]);
+        assert_eq!(payload.try_push(3), Err(PayloadError::LenMaxViolated));
+    }
+
+    #[test]
+    fn test_push_without_validation() {
+        #[nutype(derive(Debug, PartialEq))]
+        pub struct Tags(Vec<String>);
+
+        let mut tags = Tags::new(vec![]);
+        tags.push("rust".to_string());
+        assert_eq!(tags.into_inner(), vec!["rust"]);
+    }
+
+    #[test]
+    fn test_push_without_validation_does_not_require_clone() {
+        #[nutype(derive(Debug))]
+        pub struct Queue(Vec<Job>);
+
+        let mut queue = Queue::new(vec![]);
+        queue.push(Job(1));
+        assert_eq!(queue.into_inner(), vec![Job(1)]);
+    }
+}
+
+#[cfg(test)]
+mod comparison_validators {
+    use super::*;
+    use std::time::Duration;
+
+    #[nutype(
+        validate(
+            greater_or_equal = Duration::from_millis(100),
+            less_or_equal = Duration::from_secs(5)
+        ),
+        derive(Debug, Clone, Copy, PartialEq)
+    )]
+    pub struct Timeout(Duration);
+
+    #[test]
+    fn test_inclusive_bounds() {
+        assert_eq!(
+            Timeout::new(Duration::from_millis(99)),
+            Err(TimeoutError::GreaterOrEqualViolated)
+        );
+        assert_eq!(
+            Timeout::new(Duration::from_millis(100))
+                .unwrap()
+                .into_inner(),
+            Duration::from_millis(100)
+        );
+        assert_eq!(
+            Timeout::new(Duration::from_millis(5000))
+                .unwrap()
+                .into_inner(),
+            Duration::from_secs(5)
+        );
+        assert_eq!(
+            Timeout::new(Duration::from_millis(5001)),
+            Err(TimeoutError::LessOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_exclusive_bounds() {
+        #[nutype(
+            validate(greater = Point::new(0, 0), less = Point::new(10, 0)),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Position(Point);
+
+        assert_eq!(
+            Position::new(Point::new(0, 0)),
+            Err(PositionError::GreaterViolated)
+        );
+        assert_eq!(
+            Position::new(Point::new(0, 1)).unwrap().into_inner(),
+            Point::new(0, 1)
+        );
+        assert_eq!(
+            Position::new(Point::new(9, 99)).unwrap().into_inner(),
+            Point::new(9, 99)
+        );
+        assert_eq!(
+            Position::new(Point::new(10, 0)),
+            Err(PositionError::LessViolated)
+        );
+    }
+
+    #[test]
+    fn test_error_messages() {
+        assert_eq!(
+            TimeoutError::GreaterOrEqualViolated.to_string(),
+            "Timeout is too small. The value must be greater or equal to 100ms."
+        );
+        assert_eq!(
+            TimeoutError::LessOrEqualViolated.to_string(),
+            "Timeout is too big. The value must be less or equal to 5s."
+        );
+    }
+}
+
+#[cfg(test)]
+mod with_result {
+    use super::*;
+
+    fn validate_position(point: &Point) -> Result<(), String> {
+        if point.x > point.y {
+            Ok(())
+        } else {
+            Err(format!("x must be greater than y, got {point:?}"))
+        }
+    }
+
+    #[test]
+    fn test_with_function() {
+        #[nutype(derive(Debug, PartialEq), validate(with_result = validate_position))]
+        pub struct Position(Point);
+
+        assert_eq!(
+            Position::new(Point::new(2, 2)),
+            Err(PositionError::WithResultViolated(
+                "x must be greater than y, got Point { x: 2, y: 2 }".to_string()
+            ))
+        );
+        assert_eq!(
+            Position::new(Point::new(3, 2)).unwrap().into_inner(),
+            Point::new(3, 2)
+        );
+    }
+
+    #[test]
+    fn test_closure_with_no_type() {
+        #[nutype(
+            derive(Debug, FromStr),
+            validate(with_result = |p| if p.x > p.y { Ok(()) } else { Err("nope".to_string()) }),
+        )]
+        pub struct Position(Point);
+
+        let pos = "6,5".parse::<Position>().unwrap();
+        assert_eq!(pos.into_inner(), Point::new(6, 5));
+
+        let err = "5,5".parse::<Position>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse Position: Position is invalid: nope"
+        );
+    }
+
+    mod point_rules {
+        pub fn validate_in_first_quadrant(point: &super::Point) -> Result<(), String> {
+            if point.x >= 0 && point.y >= 0 {
+                Ok(())
+            } else {
+                Err("point must be in the first quadrant".to_string())
+            }
+        }
+    }
+
+    #[test]
+    fn test_with_path_relative_to_parent_module() {
+        #[nutype(
+            derive(Debug, PartialEq),
+            validate(with_result = super::with_result::point_rules::validate_in_first_quadrant)
+        )]
+        pub struct Position(Point);
+
+        assert_eq!(
+            Position::new(Point::new(-1, 2)),
+            Err(PositionError::WithResultViolated(
+                "point must be in the first quadrant".to_string()
+            ))
+        );
+        assert!(Position::new(Point::new(1, 2)).is_ok());
+    }
+}
+
+#[cfg(test)]
+mod with_lifetime {
+    use super::*;
+
+    #[test]
+    fn test_borrowed_str() {
+        #[nutype(
+            sanitize(with = |s| s.trim()),
+            validate(predicate = |s| !s.is_empty()),
+            derive(Debug, Clone, Copy, PartialEq, AsRef, Deref, Display, Into)
+        )]
+        pub struct Name<'a>(&'a str);
+
+        let input = String::from("  Anna  ");
+        let name = Name::new(&input).unwrap();
+        assert_eq!(name.into_inner(), "Anna");
+        assert_eq!(name.as_ref(), &"Anna");
+        assert_eq!(name.len(), 4);
+        assert_eq!(name.to_string(), "Anna");
+
+        let inner: &str = name.into();
+        assert_eq!(inner, "Anna");
+
+        assert_eq!(Name::new("   "), Err(NameError::PredicateViolated));
+    }
+
+    #[test]
+    fn test_lifetime_with_any_name() {
+        #[nutype(derive(Debug, PartialEq, From, Borrow))]
+        pub struct Bytes<'buf>(&'buf [u8]);
+
+        let buffer = [1, 2, 3];
+        let bytes = Bytes::from(&buffer[1..]);
+        assert_eq!(bytes.into_inner(), &[2, 3]);
+    }
+
+    #[test]
+    fn test_zero_copy_parsing() {
+        #[nutype(validate(predicate = |s| s.chars().all(|c| c.is_ascii_alphanumeric())))]
+        pub struct Word<'a>(&'a str);
+
+        let text = String::from("zero copy parsing");
+        let words: Vec<Word> = text.split(' ').map(|w| Word::new(w).unwrap()).collect();
+        let words: Vec<&str> = words.into_iter().map(Word::into_inner).collect();
+        assert_eq!(words, vec!["zero", "copy", "parsing"]);
+    }
+}
+
+#[cfg(test)]
+mod with_type_parameter {
+    use super::*;
+
+    #[test]
+    fn test_generic_over_inner_type() {
+        #[nutype(
+            validate(predicate = |v| (*v).into() >= 0.0),
+            derive(Debug, Clone, Copy, PartialEq, PartialOrd, AsRef, Deref, Display)
+        )]
+        pub struct Meters<T: Copy + Into<f64> + ::core::fmt::Display>(T);
+
+        let meters = Meters::<f32>::new(2.5).unwrap();
+        assert_eq!(meters.into_inner(), 2.5f32);
+        assert_eq!(meters.to_string(), "2.5");
+
+        let meters = Meters::<f64>::new(3.0).unwrap();
+        assert_eq!(*meters.as_ref(), 3.0);
+        assert!(meters < Meters::new(4.0).unwrap());
+
+        assert_eq!(
+            Meters::<f64>::new(-1.0),
+            Err(MetersError::PredicateViolated)
+        );
+    }
+
+    #[test]
+    fn test_generic_without_guards() {
+        #[nutype(derive(Debug, PartialEq, From, Borrow))]
+        pub struct Tagged<T>(T);
+
+        let tagged = Tagged::from(vec![1, 2, 3]);
+        assert_eq!(tagged.into_inner(), vec![1, 2, 3]);
+        assert_eq!(Tagged::new("text"), Tagged::from("text"));
+    }
+
+    #[test]
+    fn test_generic_with_transparent_debug() {
+        #[nutype(derive(Debug), transparent_debug)]
+        pub struct Tagged<T>(T);
+
+        assert_eq!(format!("{:?}", Tagged::new(vec![1, 2])), "[1, 2]");
+        assert_eq!(format!("{:?}", Tagged::new("text")), r#""text""#);
+    }
+
+    #[test]
+    fn test_type_parameter_with_lifetime() {
+        #[nutype(validate(predicate = |s| !s.is_empty()), derive(Debug))]
+        pub struct Items<'a, T>(&'a [T]);
+
+        let numbers = [1, 2, 3];
+        assert_eq!(Items::new(&numbers[1..]).unwrap().into_inner(), &[2, 3]);
+        assert!(Items::<u8>::new(&[]).is_err());
+    }
+
+    #[test]
+    fn test_generic_type_alias() {
+        #[nutype(validate(predicate = |(min, max)| min <= max), derive(Debug, PartialEq))]
+        pub type Bounds<T: PartialOrd> = (T, T);
+
+        assert_eq!(Bounds::new((1, 5)).unwrap().into_inner(), (1, 5));
+        assert_eq!(Bounds::new((0.5, 0.1)), Err(BoundsError::PredicateViolated));
+    }
+
+    #[cfg(feature = "num-traits")]
+    #[test]
+    fn test_float_type_parameter() {
+        #[nutype(
+            sanitize(with = |v| v.abs()),
+            validate(predicate = |v| v.is_finite()),
+            derive(Debug, Clone, Copy, PartialEq)
+        )]
+        pub struct Meters<T: num_traits::Float>(T);
+
+        assert_eq!(Meters::<f32>::new(-1.5).unwrap().into_inner(), 1.5f32);
+        assert_eq!(Meters::<f64>::new(-1.5).unwrap().into_inner(), 1.5f64);
+        assert_eq!(
+            Meters::new(f64::INFINITY),
+            Err(MetersError::PredicateViolated)
+        );
+    }
+}
+
+#[cfg(test)]
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_fluent_chain() {
+        #[nutype(
+            sanitize(with = |p| Point::new(p.x.abs(), p.y.abs())),
+            validate(predicate = |p| p.x <= 10 && p.y <= 10),
+            builder,
+            derive(Debug, PartialEq)
+        )]
+        pub struct Position(Point);
+
+        let position = Position::builder(Point::new(0, 0))
+            .update(|p| p.x = -3)
+            .update(|p| p.y = 4)
+            .build()
+            .unwrap();
+        assert_eq!(position.into_inner(), Point::new(3, 4));
+
+        // Only the final value is validated
+        let position = Position::builder(Point::new(0, 0))
+            .update(|p| p.x = 20)
+            .update(|p| p.x -= 15)
+            .build();
+        assert_eq!(position.unwrap().into_inner(), Point::new(5, 0));
+
+        assert_eq!(
+            Position::builder(Point::new(11, 0)).build(),
+            Err(PositionError::PredicateViolated)
+        );
+    }
+
+    #[test]
+    fn test_without_validation() {
+        #[nutype(builder, derive(Debug))]
+        pub struct Position(Point);
+
+        let position: Position = Position::builder(Point::new(1, 2))
+            .update(|p| p.y = 5)
+            .build();
+        assert_eq!(position.into_inner(), Point::new(1, 5));
+    }
+
+    #[test]
+    fn test_with_type_parameter() {
+        #[nutype(validate(predicate = |v| !v.is_empty()), builder, derive(Debug))]
+        pub struct NonEmpty<T>(Vec<T>);
+
+        let items = NonEmpty::builder(vec![1, 2])
+            .update(|v| v.push(3))
+            .build()
+            .unwrap();
+        assert_eq!(items.into_inner(), vec![1, 2, 3]);
+        assert!(NonEmpty::<u8>::builder(vec![]).build().is_err());
+    }
+}
+
+#[cfg(test)]
+mod validate_fn {
+    use super::*;
+
+    #[test]
+    fn test_validate() {
+        #[nutype(
+            validate(predicate = |p: &Point| p.x <= p.y),
+            derive(Debug, PartialEq)
+        )]
+        pub struct OrderedPoint(Point);
+
+        assert_eq!(OrderedPoint::validate(&Point::new(1, 2)), Ok(()));
+        assert_eq!(
+            OrderedPoint::validate(&Point::new(2, 1)),
+            Err(OrderedPointError::PredicateViolated)
+        );
+    }
+}
+
+#[cfg(test)]
+mod into_inner {
+    use super::*;
+
+    #[test]
+    fn test_into_inner_and_inner() {
+        #[nutype]
+        pub struct Location(Point);
+
+        let location = Location::new(Point::new(3, 4));
+        let inner: &Point = location.inner();
+        assert_eq!((inner.x, inner.y), (3, 4));
+        let inner: Point = location.into_inner();
+        assert_eq!((inner.x, inner.y), (3, 4));
+    }
+}
+
+#[cfg(test)]
+mod as_cow {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_as_cow() {
+        #[nutype]
+        pub struct Location(Point);
+
+        let location = Location::new(Point::new(3, 4));
+        let cow: Cow<'_, Point> = location.as_cow();
+        assert!(matches!(cow, Cow::Borrowed(&Point { x: 3, y: 4 })));
+    }
+
+    pub struct Handle(u32);
+
+    #[test]
+    fn test_inner_type_without_clone() {
+        // The newtype still compiles, though `as_cow()` is not available for it.
+        #[nutype]
+        pub struct Wrapper(Handle);
+
+        assert_eq!(Wrapper::new(Handle(7)).into_inner().0, 7);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rand")]
+mod derive_rand_distribution {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn test_respects_predicate() {
+        #[nutype(
+            validate(predicate = |(low, high)| low <= high),
+            derive(Debug, RandDistribution)
+        )]
+        pub struct Range((u8, u8));
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let (low, high) = rng.gen::<Range>().into_inner();
+            assert!(low <= high);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "proptest")]
+mod derive_proptest_arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[nutype(derive(Debug, ProptestArbitrary))]
+    pub struct Flags(Vec<bool>);
+
+    #[nutype(
+        validate(predicate = |pair| pair.0 < pair.1),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct OrderedPair((u8, u8));
+
+    proptest! {
+        #[test]
+        fn test_without_validation(flags: Flags) {
+            let _: Vec<bool> = flags.into_inner();
+        }
+
+        #[test]
+        fn test_respects_predicate(pair: OrderedPair) {
+            let (a, b) = pair.into_inner();
+            prop_assert!(a < b);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "hex")]
+mod from_hex {
+    use super::*;
+    use std::error::Error;
+
+    #[nutype(from_hex, validate(len_min = 4, len_max = 4), derive(Debug, PartialEq))]
+    pub struct Signature(Vec<u8>);
+
+    #[nutype(from_hex, derive(Debug, PartialEq))]
+    pub struct Payload(Vec<u8>);
+
+    #[test]
+    fn test_from_str() {
+        let signature: Signature = "c0ffee00".parse().unwrap();
+        assert_eq!(signature.into_inner(), vec![0xC0, 0xFF, 0xEE, 0x00]);
+
+        let signature: Signature = "C0FFEE00".parse().unwrap();
+        assert_eq!(signature.into_inner(), vec![0xC0, 0xFF, 0xEE, 0x00]);
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let err = "c0ffee0".parse::<Signature>().unwrap_err();
+        assert_eq!(
+            err,
+            SignatureParseError::Decode(nutype::hex::DecodeError::OddLength)
+        );
+        assert_eq!(
+            err.to_string(),
+            "Failed to decode Signature from hex: Odd number of digits"
+        );
+        assert!(err.source().is_some());
+    }
+
+    #[test]
+    fn test_validate_error() {
+        let err = "c0ffee".parse::<Signature>().unwrap_err();
+        assert_eq!(
+            err,
+            SignatureParseError::Validate(SignatureError::LenMinViolated)
+        );
+        assert!(err.source().is_none());
+    }
+
+    #[test]
+    fn test_without_validation() {
+        let payload: Payload = "".parse().unwrap();
+        assert_eq!(payload.into_inner(), Vec::<u8>::new());
+
+        let err = "xy".parse::<Payload>().unwrap_err();
+        assert!(matches!(err, PayloadParseError::Decode(_)));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "base64")]
+mod from_base64 {
+    use super::*;
+
+    #[nutype(from_base64, validate(len_min = 1), derive(Debug, PartialEq))]
+    pub struct PublicKey(Vec<u8>);
+
+    #[test]
+    fn test_from_str() {
+        let key: PublicKey = "aGVsbG8=".parse().unwrap();
+        assert_eq!(key.into_inner(), b"hello".to_vec());
+    }
+
+    #[test]
+    fn test_decode_error() {
+        let err = "aGVsbG8".parse::<PublicKey>().unwrap_err();
+        assert_eq!(
+            err,
+            PublicKeyParseError::Decode(nutype::base64::DecodeError::InvalidPadding)
+        );
+        assert_eq!(
+            err.to_string(),
+            "Failed to decode PublicKey from base64: Invalid padding"
+        );
+    }
+
+    #[test]
+    fn test_validate_error() {
+        let err = "".parse::<PublicKey>().unwrap_err();
+        assert_eq!(
+            err,
+            PublicKeyParseError::Validate(PublicKeyError::LenMinViolated)
+        );
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "new_unchecked")]
 mod new_unchecked {
@@ -382,3 +1264,93 @@ mod new_unchecked {
         assert_eq!(line_point.into_inner(), Point::new(3, 4));
     }
 }
+
+#[cfg(feature = "chrono")]
+mod chrono_inner_type {
+    use super::*;
+    use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
+
+    #[nutype(
+        validate(greater_or_equal = "2024-01-01", less = "2025-01-01"),
+        derive(Debug, Clone, Copy, PartialEq, PartialOrd, FromStr, Display)
+    )]
+    pub struct Date2024(NaiveDate);
+
+    fn date(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn test_date_literal_bounds() {
+        assert_eq!(
+            Date2024::new(date(2024, 1, 1)).unwrap().into_inner(),
+            date(2024, 1, 1)
+        );
+        assert!(Date2024::new(date(2024, 12, 31)).is_ok());
+        assert_eq!(
+            Date2024::new(date(2023, 12, 31)),
+            Err(Date2024Error::GreaterOrEqualViolated)
+        );
+        assert_eq!(
+            Date2024::new(date(2025, 1, 1)),
+            Err(Date2024Error::LessViolated)
+        );
+        assert_eq!(
+            Date2024::new(date(2025, 1, 1)).unwrap_err().to_string(),
+            "Date2024 is too big. The value must be less than 2025-01-01."
+        );
+    }
+
+    #[test]
+    fn test_from_str_and_display_delegate_to_chrono() {
+        let day: Date2024 = "2024-02-29".parse().unwrap();
+        assert_eq!(day.into_inner(), date(2024, 2, 29));
+        assert_eq!(day.to_string(), "2024-02-29");
+
+        assert!(matches!(
+            "2023-02-29".parse::<Date2024>(),
+            Err(Date2024ParseError::Parse(_))
+        ));
+        assert!(matches!(
+            "2025-02-28".parse::<Date2024>(),
+            Err(Date2024ParseError::Validate(Date2024Error::LessViolated))
+        ));
+    }
+
+    #[test]
+    fn test_time_and_date_time_literals() {
+        #[nutype(
+            validate(greater_or_equal = "09:00:00", less_or_equal = "17:30:00"),
+            derive(Debug)
+        )]
+        pub struct OfficeHours(NaiveTime);
+
+        #[nutype(validate(greater = "2024-01-01T00:00:00Z"), derive(Debug))]
+        pub struct Timestamp(DateTime<Utc>);
+
+        let nine = NaiveTime::from_hms_opt(9, 0, 0).unwrap();
+        assert_eq!(OfficeHours::new(nine).unwrap().into_inner(), nine);
+        assert!(OfficeHours::new(NaiveTime::from_hms_opt(17, 31, 0).unwrap()).is_err());
+
+        let new_year = date(2024, 1, 1).and_hms_opt(0, 0, 0).unwrap().and_utc();
+        assert!(Timestamp::new(new_year).is_err());
+        let after_new_year = new_year + chrono::Duration::seconds(1);
+        assert_eq!(
+            Timestamp::new(after_new_year).unwrap().into_inner(),
+            after_new_year
+        );
+    }
+
+    #[test]
+    fn test_predicate_against_runtime_value() {
+        #[nutype(
+            validate(predicate = |d| *d > Utc::now().date_naive()),
+            derive(Debug)
+        )]
+        pub struct FutureDate(NaiveDate);
+
+        assert!(FutureDate::new(date(2000, 1, 1)).is_err());
+        let tomorrow = Utc::now().date_naive() + chrono::Days::new(1);
+        assert_eq!(FutureDate::new(tomorrow).unwrap().into_inner(), tomorrow);
+    }
+}
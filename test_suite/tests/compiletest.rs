@@ -3,4 +3,27 @@
 fn ui() {
     let t = trybuild::TestCases::new();
     t.compile_fail("tests/ui/**/*.rs");
+
+    // These cases assert the error emitted when an optional feature is missing,
+    // so they only make sense when that feature is off.
+    #[cfg(not(feature = "email"))]
+    t.compile_fail("tests/ui_without_feature/string/validate/email_without_feature.rs");
+    #[cfg(not(feature = "url"))]
+    t.compile_fail("tests/ui_without_feature/string/validate/url_without_feature.rs");
+    #[cfg(not(feature = "unicode-segmentation"))]
+    t.compile_fail("tests/ui_without_feature/string/validate/len_grapheme_max_without_feature.rs");
+    #[cfg(not(feature = "hex"))]
+    t.compile_fail("tests/ui_without_feature/any/from_encoding/from_hex_without_feature.rs");
+    #[cfg(not(feature = "base64"))]
+    t.compile_fail("tests/ui_without_feature/any/from_encoding/from_base64_without_feature.rs");
+    #[cfg(not(feature = "chrono"))]
+    t.compile_fail("tests/ui_without_feature/any/validate/date_literal_without_chrono.rs");
+    #[cfg(not(feature = "async"))]
+    t.compile_fail("tests/ui_without_feature/common/async_with_without_feature.rs");
+
+    // These cases need an optional feature to get past the check for the feature itself.
+    #[cfg(feature = "num-traits")]
+    t.compile_fail("tests/ui_with_feature/integer/derive/num_traits_zero_with_greater_zero.rs");
+    #[cfg(feature = "num-traits")]
+    t.compile_fail("tests/ui_with_feature/float/derive/num_traits_zero_with_greater_zero.rs");
 }
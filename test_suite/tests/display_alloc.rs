@@ -0,0 +1,48 @@
+//! Lives in its own test target, so the counting global allocator does not affect other tests.
+
+use nutype::nutype;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt::Write;
+
+/// Counts allocations per thread, so the tests running in parallel do not interfere.
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[global_allocator]
+static GLOBAL: CountingAllocator = CountingAllocator;
+
+fn count_allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn test_display_does_not_allocate_beyond_the_writer() {
+    #[nutype(derive(Display))]
+    pub struct Name(String);
+
+    let name = Name::new("Serhii");
+    let mut out = String::with_capacity(64);
+    let allocations = count_allocations(|| {
+        write!(out, "{name}").unwrap();
+        write!(out, "{name:>10}").unwrap();
+    });
+    assert_eq!(allocations, 0);
+    assert_eq!(out, "Serhii    Serhii");
+}
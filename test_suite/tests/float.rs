@@ -114,7 +114,6 @@ mod validators {
         assert_eq!(Dist::new(f64::NAN), Err(DistError::FiniteViolated));
         assert_eq!(Dist::new(-1.0 / 0.0), Err(DistError::FiniteViolated));
         assert_eq!(Dist::new(1.0 / 0.0), Err(DistError::FiniteViolated));
-        assert_eq!(Dist::new(0.0 / 0.0), Err(DistError::FiniteViolated));
 
         // valid
         assert_eq!(Dist::new(12.345).unwrap().into_inner(), 12.345);
@@ -132,13 +131,64 @@ mod validators {
         // invalid
         assert_eq!(Dist::new(-1.0 / 0.0), Err(DistError::FiniteViolated));
         assert_eq!(Dist::new(1.0 / 0.0), Err(DistError::FiniteViolated));
-        assert_eq!(Dist::new(0.0 / 0.0), Err(DistError::FiniteViolated));
+        assert_eq!(Dist::new(f32::NAN), Err(DistError::FiniteViolated));
 
         // valid
         assert_eq!(Dist::new(12.345).unwrap().into_inner(), 12.345);
         assert_eq!(Dist::new(-999.12).unwrap().into_inner(), -999.12);
     }
 
+    #[test]
+    fn test_boundaries_in_scientific_notation() {
+        #[nutype(validate(greater_or_equal = -1.5e-3, less = 2.5E3), derive(Debug))]
+        struct Drift(f64);
+
+        assert_eq!(
+            Drift::new(-0.0016).unwrap_err(),
+            DriftError::GreaterOrEqualViolated
+        );
+        assert_eq!(Drift::new(-0.0015).unwrap().into_inner(), -0.0015);
+        assert_eq!(Drift::new(2499.9).unwrap().into_inner(), 2499.9);
+        assert_eq!(Drift::new(2500.0).unwrap_err(), DriftError::LessViolated);
+    }
+
+    #[test]
+    fn test_boundaries_with_underscores_and_suffixes() {
+        #[nutype(
+            validate(greater = -1_000.5_f32, less_or_equal = 1e1_f32),
+            derive(Debug)
+        )]
+        struct Level(f32);
+
+        assert_eq!(
+            Level::new(-1000.5).unwrap_err(),
+            LevelError::GreaterViolated
+        );
+        assert_eq!(Level::new(-1000.25).unwrap().into_inner(), -1000.25);
+        assert_eq!(Level::new(10.0).unwrap().into_inner(), 10.0);
+        assert_eq!(
+            Level::new(10.5).unwrap_err(),
+            LevelError::LessOrEqualViolated
+        );
+    }
+
+    #[test]
+    fn test_boundaries_with_exponent_without_fraction() {
+        #[nutype(validate(greater_or_equal = -1e-9, less_or_equal = 1e9), derive(Debug))]
+        struct Tiny(f64);
+
+        assert_eq!(
+            Tiny::new(-1e-8).unwrap_err(),
+            TinyError::GreaterOrEqualViolated
+        );
+        assert_eq!(Tiny::new(-1e-9).unwrap().into_inner(), -1e-9);
+        assert_eq!(Tiny::new(1e9).unwrap().into_inner(), 1e9);
+        assert_eq!(
+            Tiny::new(1.1e9).unwrap_err(),
+            TinyError::LessOrEqualViolated
+        );
+    }
+
     mod when_boundaries_defined_as_constants {
         use super::*;
 
@@ -216,6 +266,40 @@ mod validators {
         }
     }
 
+    #[cfg(test)]
+    mod with_result {
+        use super::*;
+
+        #[test]
+        fn test_closure_with_no_type() {
+            #[nutype(
+                validate(with_result = |&c| {
+                    if (0.0..=100.0).contains(&c) {
+                        Ok(())
+                    } else {
+                        Err(format!("{c} is not a percentage"))
+                    }
+                }),
+                derive(Debug, PartialEq),
+            )]
+            pub struct Percent(f32);
+
+            assert_eq!(
+                Percent::new(100.5),
+                Err(PercentError::WithResultViolated(
+                    "100.5 is not a percentage".to_string()
+                ))
+            );
+            assert_eq!(Percent::new(99.5).unwrap().into_inner(), 99.5);
+
+            let err = Percent::new(-0.5).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Percent is invalid: -0.5 is not a percentage"
+            );
+        }
+    }
+
     #[test]
     fn test_try_from_trait() {
         #[nutype(
@@ -239,6 +323,17 @@ mod validators {
         assert_eq!(Age::try_from(78.8).unwrap().into_inner(), 78.8);
     }
 
+    #[test]
+    // The point is to verify that `try_from()` is available.
+    #[allow(clippy::unnecessary_fallible_conversions)]
+    fn test_try_from_trait_with_from() {
+        #[nutype(derive(Debug, PartialEq, From, TryFrom))]
+        struct Age(f64);
+
+        let age: Result<Age, std::convert::Infallible> = Age::try_from(78.8);
+        assert_eq!(age.unwrap(), Age::from(78.8));
+    }
+
     #[cfg(test)]
     mod error {
         use super::*;
@@ -258,6 +353,18 @@ mod validators {
                 "Percentage is too small. The value must be greater or equal to 0.0."
             );
         }
+
+        #[test]
+        fn test_non_exhaustive_error() {
+            #[nutype(non_exhaustive_error, validate(finite), derive(Debug, PartialEq))]
+            struct Percentage(f64);
+
+            assert_eq!(
+                Percentage::new(f64::NAN),
+                Err(PercentageError::FiniteViolated)
+            );
+            assert_eq!(Percentage::new(0.5).unwrap().into_inner(), 0.5);
+        }
     }
 }
 
@@ -323,6 +430,9 @@ mod visibility {
 
         #[nutype(sanitize(with = |n: f32| n.clamp(0.0, 100.0)))]
         pub struct Percentage(f32);
+
+        #[nutype(inner_vis = pub, validate(finite), derive(Debug, Clone, Copy))]
+        pub struct Coordinate(f64);
     }
 
     #[test]
@@ -330,6 +440,32 @@ mod visibility {
         let percentage = encapsulated::Percentage::new(133.0);
         assert_eq!(percentage.into_inner(), 100.0);
     }
+
+    #[test]
+    fn test_public_inner_field() {
+        let coordinate = encapsulated::Coordinate::new(52.52).unwrap();
+        assert_eq!(coordinate.0, 52.52);
+
+        // The field is public, so the invariants are not guaranteed anymore.
+        let coordinate = encapsulated::Coordinate(f64::NAN);
+        assert!(coordinate.into_inner().is_nan());
+    }
+}
+
+#[cfg(test)]
+mod repr_transparent {
+    use super::*;
+
+    #[test]
+    fn test_transmute_to_inner_type() {
+        #[nutype(repr_transparent, validate(finite), derive(Debug, Clone, Copy))]
+        pub struct Celsius(f64);
+
+        let celsius = Celsius::new(36.6).unwrap();
+        // SAFETY: `Celsius` is `#[repr(transparent)]` over `f64`.
+        let inner: f64 = unsafe { std::mem::transmute::<Celsius, f64>(celsius) };
+        assert_eq!(inner, 36.6);
+    }
 }
 
 #[cfg(test)]
@@ -395,6 +531,18 @@ mod traits {
         assert_eq!(weight_ref, &72.650);
     }
 
+    #[test]
+    fn test_trait_ord_inner() {
+        #[nutype(derive(Debug, PartialEq, PartialOrd, OrdInner))]
+        pub struct Celsius(f64);
+
+        let temperature = Celsius::new(36.6);
+        assert!(temperature < 37.0);
+        assert!(temperature > 0.0);
+        assert_eq!(temperature, 36.6);
+        assert_eq!(temperature.partial_cmp(&f64::NAN), None);
+    }
+
     #[test]
     fn test_trait_deref() {
         #[nutype(derive(Deref))]
@@ -475,6 +623,68 @@ mod traits {
         assert_eq!(size.to_string(), "35.7");
     }
 
+    #[test]
+    fn test_trait_sum_and_product() {
+        #[nutype(derive(Debug, PartialEq, Sum, Product))]
+        pub struct Amount(f64);
+
+        let amounts = [Amount::new(1.5), Amount::new(2.0), Amount::new(4.0)];
+        assert_eq!(amounts.iter().sum::<Amount>(), Amount::new(7.5));
+        assert_eq!(amounts.iter().product::<Amount>(), Amount::new(12.0));
+        assert_eq!(amounts.into_iter().sum::<Amount>(), Amount::new(7.5));
+    }
+
+    #[test]
+    fn test_checked_arith() {
+        #[nutype(
+            validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+            derive(Debug, PartialEq, CheckedArith)
+        )]
+        pub struct Ratio(f64);
+
+        let r = |n| Ratio::new(n).unwrap();
+        assert_eq!(r(0.25).checked_add(r(0.5)), Some(r(0.75)));
+        assert_eq!(r(0.75).checked_sub(r(0.5)), Some(r(0.25)));
+        assert_eq!(r(0.75).checked_add(r(0.5)), None);
+        assert_eq!(r(0.25).checked_sub(r(0.5)), None);
+    }
+
+    #[test]
+    fn test_trait_neg() {
+        #[nutype(
+            validate(finite, greater = -273.15, less = 273.15),
+            derive(Debug, PartialEq, Neg)
+        )]
+        pub struct Celsius(f64);
+
+        let temp = Celsius::new(21.5).unwrap();
+        assert_eq!(-temp, Celsius::new(-21.5).unwrap());
+        assert_eq!((-Celsius::new(-0.5).unwrap()).into_inner(), 0.5);
+    }
+
+    #[test]
+    fn test_trait_neg_without_validation() {
+        #[nutype(derive(Debug, PartialEq, Neg))]
+        pub struct Celsius(f32);
+
+        assert_eq!(-Celsius::new(1.5), Celsius::new(-1.5));
+    }
+
+    #[test]
+    fn test_checked_arith_overflow() {
+        #[nutype(derive(Debug, PartialEq, CheckedArith))]
+        pub struct Distance(f32);
+
+        assert_eq!(
+            Distance::new(f32::MAX).checked_add(Distance::new(f32::MAX)),
+            None
+        );
+        assert_eq!(
+            Distance::new(f32::INFINITY).checked_add(Distance::new(1.0)),
+            Some(Distance::new(f32::INFINITY))
+        );
+    }
+
     #[test]
     fn test_trait_eq() {
         #[nutype(validate(finite), derive(PartialEq, Eq, Debug))]
@@ -535,6 +745,55 @@ mod traits {
             assert_eq!(sorted_raw_sizes, vec![2.0, 3.5, 5.5, 15.0, 44.5],);
         }
 
+        #[test]
+        fn test_trait_hash() {
+            use std::collections::HashSet;
+
+            #[nutype(validate(finite), derive(Debug, PartialEq, Eq, Hash))]
+            pub struct Weight(f64);
+
+            let mut weights = HashSet::new();
+            weights.insert(Weight::new(1.5).unwrap());
+            weights.insert(Weight::new(3.0 / 2.0).unwrap());
+            weights.insert(Weight::new(2.5).unwrap());
+            assert_eq!(weights.len(), 2);
+            assert!(weights.contains(&Weight::new(2.5).unwrap()));
+        }
+
+        #[test]
+        fn test_trait_hash_is_consistent_with_eq_for_zeros() {
+            use std::collections::hash_map::DefaultHasher;
+            use std::hash::{Hash, Hasher};
+
+            #[nutype(validate(finite), derive(PartialEq, Eq, Hash))]
+            pub struct Offset(f32);
+
+            fn hash_of(offset: &Offset) -> u64 {
+                let mut hasher = DefaultHasher::new();
+                offset.hash(&mut hasher);
+                hasher.finish()
+            }
+
+            let zero = Offset::new(0.0).unwrap();
+            let negative_zero = Offset::new(-0.0).unwrap();
+            assert!(zero == negative_zero);
+            assert_eq!(hash_of(&zero), hash_of(&negative_zero));
+        }
+
+        #[test]
+        fn test_trait_hash_set_with_zeros() {
+            use std::collections::HashSet;
+
+            #[nutype(validate(finite), derive(Debug, PartialEq, Eq, Hash))]
+            pub struct Offset(f64);
+
+            let mut offsets = HashSet::new();
+            assert!(offsets.insert(Offset::new(0.0).unwrap()));
+            assert!(!offsets.insert(Offset::new(-0.0).unwrap()));
+            assert!(offsets.insert(Offset::new(-1.0).unwrap()));
+            assert_eq!(offsets.len(), 2);
+        }
+
         #[cfg(test)]
         mod prop_tests {
             use super::*;
@@ -555,7 +814,7 @@ mod traits {
                     Ok(())
                 }
 
-                arbtest::builder().run(|u| prop(u));
+                arbtest::builder().run(prop);
             }
 
             #[test]
@@ -573,7 +832,7 @@ mod traits {
                     Ok(())
                 }
 
-                arbtest::builder().run(|u| prop(u));
+                arbtest::builder().run(prop);
             }
         }
     }
@@ -694,7 +953,95 @@ mod traits {
             #[nutype(validate(less_or_equal = 20.0), default = 20.1, derive(Default))]
             pub struct Number(f64);
 
-            Number::default();
+            let _ = Number::default();
+        }
+    }
+}
+
+#[cfg(test)]
+mod new_fn {
+    use super::*;
+
+    #[test]
+    fn test_new_fn() {
+        #[nutype(
+            new_fn = try_new,
+            validate(finite),
+            derive(Debug, PartialEq, TryFrom, FromStr)
+        )]
+        pub struct Weight(f64);
+
+        assert_eq!(Weight::try_new(f64::NAN), Err(WeightError::FiniteViolated));
+        assert_eq!(Weight::try_new(7.5).unwrap().into_inner(), 7.5);
+        assert_eq!(
+            "7.5".parse::<Weight>().unwrap(),
+            Weight::try_new(7.5).unwrap()
+        );
+    }
+}
+
+#[cfg(test)]
+mod into_inner {
+    use super::*;
+
+    #[test]
+    fn test_into_inner_and_inner() {
+        #[nutype(validate(finite))]
+        pub struct Ratio(f64);
+
+        let ratio = Ratio::new(0.5).unwrap();
+        let inner: &f64 = ratio.inner();
+        assert_eq!(*inner, 0.5);
+        let inner: f64 = ratio.into_inner();
+        assert_eq!(inner, 0.5);
+    }
+}
+
+#[cfg(test)]
+mod as_cow {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_as_cow() {
+        #[nutype(validate(finite))]
+        pub struct Ratio(f64);
+
+        let ratio = Ratio::new(0.5).unwrap();
+        let cow: Cow<'_, f64> = ratio.as_cow();
+        assert_eq!(cow, Cow::<f64>::Borrowed(&0.5));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rand")]
+mod derive_rand_distribution {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn test_respects_boundaries() {
+        #[nutype(
+            validate(greater = 0.0, less_or_equal = 1.5),
+            derive(Debug, RandDistribution)
+        )]
+        pub struct Weight(f64);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let value = rng.gen::<Weight>().into_inner();
+            assert!(value > 0.0 && value <= 1.5);
+        }
+    }
+
+    #[test]
+    fn test_whole_range() {
+        #[nutype(validate(finite), derive(Debug, RandDistribution))]
+        pub struct Distance(f32);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            assert!(rng.gen::<Distance>().into_inner().is_finite());
         }
     }
 }
@@ -731,6 +1078,159 @@ mod derive_schemars_json_schema {
     }
 }
 
+#[cfg(test)]
+#[cfg(feature = "rkyv")]
+mod derive_rkyv_archive {
+    use super::*;
+
+    #[test]
+    fn test_archive() {
+        #[nutype(derive(Debug, PartialEq, Archive), validate(finite))]
+        pub struct Distance(f64);
+
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&Distance::new(3.5).unwrap()).unwrap();
+
+        let archived =
+            rkyv::access::<rkyv::Archived<Distance>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(*archived, 3.5);
+
+        let distance = rkyv::deserialize::<Distance, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(distance, Distance::new(3.5).unwrap());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "bincode")]
+mod derive_bincode {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        #[nutype(
+            validate(greater_or_equal = 0.0, less_or_equal = 100.0),
+            derive(Debug, PartialEq, Encode, Decode)
+        )]
+        pub struct Percentage(f64);
+
+        let config = bincode::config::standard();
+        let percentage = Percentage::new(42.5).unwrap();
+        let bytes = bincode::encode_to_vec(&percentage, config).unwrap();
+        assert_eq!(bytes, 42.5f64.to_le_bytes());
+
+        let (decoded, _): (Percentage, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, percentage);
+
+        let bytes = bincode::encode_to_vec(142.5f64, config).unwrap();
+        assert!(bincode::decode_from_slice::<Percentage, _>(&bytes, config).is_err());
+    }
+
+    #[test]
+    fn test_round_trip_without_validation() {
+        #[nutype(derive(Debug, PartialEq, Encode, Decode))]
+        pub struct Weight(f32);
+
+        let config = bincode::config::standard();
+        let bytes = bincode::encode_to_vec(Weight::new(-1.5), config).unwrap();
+        let (decoded, _): (Weight, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, Weight::new(-1.5));
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "proptest")]
+mod derive_proptest_arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[nutype(validate(finite), derive(Debug, ProptestArbitrary))]
+    pub struct Temperature(f64);
+
+    #[nutype(
+        validate(greater = 0.0, less_or_equal = 1.0),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct Probability(f32);
+
+    #[nutype(
+        sanitize(with = |x| x.abs()),
+        validate(greater_or_equal = 100.0),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct Distance(f64);
+
+    proptest! {
+        #[test]
+        fn test_respects_finite(temperature: Temperature) {
+            prop_assert!(temperature.into_inner().is_finite());
+        }
+
+        #[test]
+        fn test_respects_boundaries(probability: Probability) {
+            let value = probability.into_inner();
+            prop_assert!(value > 0.0 && value <= 1.0);
+        }
+
+        #[test]
+        fn test_respects_lower_boundary_with_sanitizer(distance: Distance) {
+            prop_assert!(distance.into_inner() >= 100.0);
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "num-traits")]
+mod derive_num_traits {
+    use super::*;
+    use num_traits::{Bounded, One, Zero};
+    use std::ops::{Add, Mul};
+
+    #[test]
+    fn test_zero_and_one() {
+        #[nutype(validate(finite), derive(Debug, PartialEq, Zero, One))]
+        pub struct Weight(f64);
+
+        // `Zero` and `One` require `Add` and `Mul`, which nutype does not derive.
+        impl Add for Weight {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self::new(self.into_inner() + other.into_inner()).unwrap()
+            }
+        }
+
+        impl Mul for Weight {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self::new(self.into_inner() * other.into_inner()).unwrap()
+            }
+        }
+
+        assert_eq!(Weight::zero().into_inner(), 0.0);
+        assert_eq!(Weight::one().into_inner(), 1.0);
+        assert!(Weight::zero().is_zero());
+        assert!(!Weight::one().is_zero());
+    }
+
+    #[test]
+    fn test_bounded() {
+        #[nutype(derive(Debug, Bounded))]
+        pub struct Meters(f32);
+
+        assert_eq!(Meters::min_value().into_inner(), f32::MIN);
+        assert_eq!(Meters::max_value().into_inner(), f32::MAX);
+
+        #[nutype(
+            validate(greater_or_equal = 0.0, less_or_equal = 1.0),
+            derive(Debug, Bounded)
+        )]
+        pub struct Probability(f64);
+
+        assert_eq!(Probability::min_value().into_inner(), 0.0);
+        assert_eq!(Probability::max_value().into_inner(), 1.0);
+    }
+}
+
 #[cfg(test)]
 #[cfg(feature = "diesel-derive-newtype")]
 mod derive_diesel_derive_newtype {
@@ -777,3 +1277,26 @@ mod derive_diesel_derive_newtype {
         assert_eq!(inserted_obj.value.into_inner(), 12.345);
     }
 }
+
+#[cfg(test)]
+mod bound_consts {
+    use super::*;
+
+    #[test]
+    fn test_bounds() {
+        #[nutype(validate(greater = 0.0, less_or_equal = 1.5))]
+        pub struct Ratio(f64);
+
+        assert_eq!((Ratio::MIN, Ratio::MIN_EXCLUSIVE), (Some(0.0), true));
+        assert_eq!((Ratio::MAX, Ratio::MAX_EXCLUSIVE), (Some(1.5), false));
+    }
+
+    #[test]
+    fn test_without_bounds() {
+        #[nutype(validate(finite))]
+        pub struct Width(f32);
+
+        assert_eq!(Width::MIN, None);
+        assert_eq!(Width::MAX, None);
+    }
+}
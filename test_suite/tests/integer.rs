@@ -38,6 +38,215 @@ mod sanitizers {
         }
     }
 
+    #[cfg(test)]
+    mod try_with {
+        use super::*;
+
+        #[test]
+        fn test_closure() {
+            #[nutype(
+                sanitize(try_with = |n: u32| n.checked_mul(100).ok_or("overflow")),
+                derive(Debug, PartialEq)
+            )]
+            pub struct Cents(u32);
+
+            assert_eq!(Cents::new(12).unwrap().into_inner(), 1200);
+            assert_eq!(
+                Cents::new(u32::MAX),
+                Err(CentsError::SanitizationFailed("overflow".to_string()))
+            );
+        }
+
+        #[test]
+        fn test_failure_skips_validation() {
+            #[nutype(
+                sanitize(try_with = |n: i32| n.checked_neg().ok_or("overflow")),
+                validate(greater = 0),
+                derive(Debug, PartialEq, TryFrom)
+            )]
+            pub struct Positive(i32);
+
+            assert_eq!(Positive::try_from(-5).unwrap().into_inner(), 5);
+            assert_eq!(Positive::new(5), Err(PositiveError::GreaterViolated));
+            assert_eq!(
+                Positive::new(i32::MIN),
+                Err(PositiveError::SanitizationFailed("overflow".to_string()))
+            );
+        }
+    }
+
+    #[cfg(test)]
+    mod wrapping {
+        use super::*;
+
+        const DAYS_IN_WEEK: i64 = 7;
+
+        #[test]
+        fn test_wrapping() {
+            #[nutype(sanitize(wrapping = 360))]
+            pub struct Degree(i32);
+
+            assert_eq!(Degree::new(0).into_inner(), 0);
+            assert_eq!(Degree::new(90).into_inner(), 90);
+            assert_eq!(Degree::new(359).into_inner(), 359);
+            assert_eq!(Degree::new(360).into_inner(), 0);
+            assert_eq!(Degree::new(725).into_inner(), 5);
+        }
+
+        #[test]
+        fn test_wrapping_negative_values() {
+            #[nutype(sanitize(wrapping = 360))]
+            pub struct Degree(i32);
+
+            // Unlike `%`, the result is never negative
+            assert_eq!(Degree::new(-1).into_inner(), 359);
+            assert_eq!(Degree::new(-360).into_inner(), 0);
+            assert_eq!(Degree::new(-361).into_inner(), 359);
+            assert_eq!(Degree::new(i32::MIN).into_inner(), 232);
+        }
+
+        #[test]
+        fn test_wrapping_with_parentheses() {
+            #[nutype(sanitize(wrapping(360)))]
+            pub struct Degree(i16);
+
+            assert_eq!(Degree::new(-1).into_inner(), 359);
+            assert_eq!(Degree::new(-720).into_inner(), 0);
+            assert_eq!(Degree::new(i16::MIN).into_inner(), 352);
+            assert_eq!(Degree::new(i16::MAX).into_inner(), 7);
+        }
+
+        #[test]
+        fn test_wrapping_unsigned() {
+            #[nutype(sanitize(wrapping = 24))]
+            pub struct Hour(u8);
+
+            assert_eq!(Hour::new(23).into_inner(), 23);
+            assert_eq!(Hour::new(24).into_inner(), 0);
+            assert_eq!(Hour::new(u8::MAX).into_inner(), 15);
+        }
+
+        #[test]
+        fn test_wrapping_with_expression() {
+            #[nutype(sanitize(wrapping = DAYS_IN_WEEK))]
+            pub struct Weekday(i64);
+
+            assert_eq!(Weekday::new(-8).into_inner(), 6);
+        }
+
+        #[test]
+        fn test_wrapping_and_validation() {
+            #[nutype(
+                sanitize(wrapping = 10, with = |n| n - 5),
+                validate(greater_or_equal = -5, less = 5),
+            )]
+            pub struct Offset(i32);
+
+            // Wrapping is applied first, so the value always lands into [-5, 5)
+            assert_eq!(Offset::new(-1).unwrap().into_inner(), 4);
+            assert_eq!(Offset::new(12).unwrap().into_inner(), -3);
+        }
+    }
+
+    mod saturating_clamp {
+        use super::*;
+
+        #[test]
+        fn test_saturating_clamp() {
+            #[nutype(sanitize(saturating_clamp(-10, 10)))]
+            pub struct Offset(i32);
+
+            assert_eq!(Offset::new(0).into_inner(), 0);
+            assert_eq!(Offset::new(-11).into_inner(), -10);
+            assert_eq!(Offset::new(11).into_inner(), 10);
+            assert_eq!(Offset::new(i32::MIN).into_inner(), -10);
+            assert_eq!(Offset::new(i32::MAX).into_inner(), 10);
+        }
+
+        #[test]
+        fn test_saturating_clamp_and_max_validator() {
+            // The value never exceeds 100 after the sanitizer, so `less_or_equal` is never violated,
+            // while `less` still rejects 100 itself.
+            #[nutype(
+                sanitize(saturating_clamp(0, 100)),
+                validate(less = 100),
+                derive(Debug, PartialEq)
+            )]
+            pub struct Percentage(i8);
+
+            assert_eq!(Percentage::new(-1).unwrap().into_inner(), 0);
+            assert_eq!(Percentage::new(99).unwrap().into_inner(), 99);
+            assert_eq!(Percentage::new(127), Err(PercentageError::LessViolated));
+        }
+
+        #[test]
+        fn test_wrapping_then_saturating_clamp() {
+            #[nutype(sanitize(wrapping(360), saturating_clamp(0, 180)))]
+            pub struct Angle(i32);
+
+            assert_eq!(Angle::new(-90).into_inner(), 180);
+            assert_eq!(Angle::new(-270).into_inner(), 90);
+        }
+    }
+
+    mod snap {
+        use super::*;
+
+        #[test]
+        fn test_snap() {
+            #[nutype(sanitize(snap = 10))]
+            pub struct Amount(i32);
+
+            assert_eq!(Amount::new(14).into_inner(), 10);
+            assert_eq!(Amount::new(15).into_inner(), 20);
+            assert_eq!(Amount::new(20).into_inner(), 20);
+            assert_eq!(Amount::new(-14).into_inner(), -10);
+            assert_eq!(Amount::new(-15).into_inner(), -10);
+            assert_eq!(Amount::new(-16).into_inner(), -20);
+        }
+
+        #[test]
+        fn test_snap_near_type_limits() {
+            #[nutype(sanitize(snap = 10))]
+            pub struct Byte(u8);
+
+            assert_eq!(Byte::new(0).into_inner(), 0);
+            // 260 does not fit into u8, so the value is snapped down
+            assert_eq!(Byte::new(255).into_inner(), 250);
+
+            #[nutype(sanitize(snap = 100))]
+            pub struct Small(i8);
+
+            assert_eq!(Small::new(i8::MIN).into_inner(), -100);
+            assert_eq!(Small::new(i8::MAX).into_inner(), 100);
+        }
+
+        #[test]
+        fn test_snap_and_step() {
+            #[nutype(sanitize(snap = 25), validate(step = 25, less_or_equal = 100))]
+            pub struct Volume(u8);
+
+            assert_eq!(Volume::new(37).unwrap().into_inner(), 25);
+            assert_eq!(Volume::new(38).unwrap().into_inner(), 50);
+        }
+
+        #[test]
+        fn test_snap_counts_from_lower_bound_like_step() {
+            #[nutype(
+                sanitize(snap = 5),
+                validate(greater_or_equal = 2, step = 5),
+                derive(Debug, PartialEq)
+            )]
+            pub struct Slot(i32);
+
+            assert_eq!(Slot::new(7).unwrap().into_inner(), 7);
+            assert_eq!(Slot::new(8).unwrap().into_inner(), 7);
+            assert_eq!(Slot::new(10).unwrap().into_inner(), 12);
+            assert_eq!(Slot::new(2).unwrap().into_inner(), 2);
+            assert_eq!(Slot::new(-1), Err(SlotError::GreaterOrEqualViolated));
+        }
+    }
+
     #[test]
     fn test_from_trait() {
         #[nutype(
@@ -100,6 +309,99 @@ mod validators {
         assert_eq!(Age::new(25).unwrap().into_inner(), 25);
     }
 
+    #[test]
+    fn test_non_zero() {
+        #[nutype(validate(non_zero), derive(Debug))]
+        struct Divisor(i64);
+
+        assert_eq!(Divisor::new(0).unwrap_err(), DivisorError::NonZeroViolated);
+        assert_eq!(Divisor::new(-1).unwrap().into_inner(), -1);
+        assert_eq!(Divisor::new(1).unwrap().into_inner(), 1);
+    }
+
+    #[test]
+    fn test_non_zero_with_boundaries() {
+        #[nutype(validate(less_or_equal = 100, non_zero), derive(Debug))]
+        struct Percent(u8);
+
+        assert_eq!(Percent::new(0).unwrap_err(), PercentError::NonZeroViolated);
+        assert_eq!(
+            Percent::new(101).unwrap_err(),
+            PercentError::LessOrEqualViolated
+        );
+        assert_eq!(Percent::new(100).unwrap().into_inner(), 100);
+    }
+
+    #[test]
+    fn test_step() {
+        #[nutype(validate(step = 5), derive(Debug))]
+        struct Price(i32);
+
+        assert_eq!(Price::new(7).unwrap_err(), PriceError::StepViolated);
+        assert_eq!(Price::new(-5).unwrap().into_inner(), -5);
+        assert_eq!(Price::new(0).unwrap().into_inner(), 0);
+        assert_eq!(Price::new(10).unwrap().into_inner(), 10);
+    }
+
+    #[test]
+    fn test_one_of() {
+        #[nutype(validate(one_of = [1, 2, 4, 8]), derive(Debug))]
+        struct BlockSize(u8);
+
+        assert_eq!(BlockSize::new(4).unwrap().into_inner(), 4);
+        assert_eq!(
+            BlockSize::new(3).unwrap_err(),
+            BlockSizeError::OneOfViolated
+        );
+        assert_eq!(
+            BlockSize::new(3).unwrap_err().to_string(),
+            "BlockSize is not one of the allowed values [1, 2, 4, 8]."
+        );
+    }
+
+    #[test]
+    fn test_one_of_with_expressions_and_other_validators() {
+        #[nutype(validate(one_of = [-1, 1 << 10, 1 << 11], greater = 0), derive(Debug))]
+        struct CacheSize(i32);
+
+        assert_eq!(CacheSize::new(2048).unwrap().into_inner(), 2048);
+        assert_eq!(
+            CacheSize::new(-1).unwrap_err(),
+            CacheSizeError::GreaterViolated
+        );
+        assert_eq!(
+            CacheSize::new(0).unwrap_err(),
+            CacheSizeError::OneOfViolated
+        );
+        assert_eq!(
+            CacheSize::new(512).unwrap_err(),
+            CacheSizeError::OneOfViolated
+        );
+    }
+
+    #[test]
+    fn test_step_starts_at_lower_bound() {
+        #[nutype(validate(greater_or_equal = -7, less_or_equal = 20, step = 3), derive(Debug))]
+        struct Level(i8);
+
+        assert_eq!(Level::new(-7).unwrap().into_inner(), -7);
+        assert_eq!(Level::new(-4).unwrap().into_inner(), -4);
+        assert_eq!(Level::new(20).unwrap().into_inner(), 20);
+        assert_eq!(Level::new(0).unwrap_err(), LevelError::StepViolated);
+        assert_eq!(Level::new(23).unwrap_err(), LevelError::LessOrEqualViolated);
+    }
+
+    #[test]
+    fn test_step_with_extreme_values() {
+        #[nutype(validate(greater_or_equal = i8::MIN, step = 85), derive(Debug))]
+        struct Tick(i8);
+
+        assert_eq!(Tick::new(i8::MIN).unwrap().into_inner(), -128);
+        assert_eq!(Tick::new(42).unwrap().into_inner(), 42);
+        assert_eq!(Tick::new(i8::MAX).unwrap().into_inner(), 127);
+        assert_eq!(Tick::new(0).unwrap_err(), TickError::StepViolated);
+    }
+
     mod when_boundaries_defined_as_constants {
         use super::*;
 
@@ -136,6 +438,122 @@ mod validators {
         }
     }
 
+    mod range {
+        use super::*;
+
+        const MAX_PERCENT: u8 = 100;
+
+        #[test]
+        fn test_inclusive_range() {
+            #[nutype(validate(range = 0..=MAX_PERCENT), derive(Debug))]
+            struct Percent(u8);
+
+            assert_eq!(Percent::new(0).unwrap().into_inner(), 0);
+            assert_eq!(Percent::new(100).unwrap().into_inner(), 100);
+            assert_eq!(
+                Percent::new(101).unwrap_err(),
+                PercentError::LessOrEqualViolated
+            );
+        }
+
+        #[test]
+        fn test_exclusive_range() {
+            #[nutype(validate(range = -10..10), derive(Debug))]
+            struct Offset(i32);
+
+            assert_eq!(
+                Offset::new(-11).unwrap_err(),
+                OffsetError::GreaterOrEqualViolated
+            );
+            assert_eq!(Offset::new(-10).unwrap().into_inner(), -10);
+            assert_eq!(Offset::new(9).unwrap().into_inner(), 9);
+            assert_eq!(Offset::new(10).unwrap_err(), OffsetError::LessViolated);
+        }
+
+        #[test]
+        fn test_half_open_ranges() {
+            #[nutype(validate(range = 1..), derive(Debug))]
+            struct Count(u32);
+
+            assert_eq!(
+                Count::new(0).unwrap_err(),
+                CountError::GreaterOrEqualViolated
+            );
+            assert_eq!(Count::new(u32::MAX).unwrap().into_inner(), u32::MAX);
+
+            #[nutype(validate(range = ..60), derive(Debug))]
+            struct Second(u32);
+
+            assert_eq!(Second::new(59).unwrap().into_inner(), 59);
+            assert_eq!(Second::new(60).unwrap_err(), SecondError::LessViolated);
+        }
+    }
+
+    #[test]
+    fn test_boundaries_defined_as_expressions() {
+        #[nutype(validate(greater_or_equal = -60 * 60, less = 60 * 60), derive(Debug))]
+        struct Seconds(i32);
+
+        assert_eq!(
+            Seconds::new(-3601).unwrap_err(),
+            SecondsError::GreaterOrEqualViolated
+        );
+        assert_eq!(Seconds::new(-3600).unwrap().into_inner(), -3600);
+        assert_eq!(Seconds::new(3599).unwrap().into_inner(), 3599);
+        assert_eq!(Seconds::new(3600).unwrap_err(), SecondsError::LessViolated);
+    }
+
+    mod when_boundaries_defined_as_non_decimal_literals {
+        use super::*;
+
+        #[test]
+        fn test_hex() {
+            #[nutype(
+                validate(greater_or_equal = 0x10, less_or_equal = 0xFF),
+                default = 0x1_F,
+                derive(Debug, Default)
+            )]
+            struct Channel(u16);
+
+            assert_eq!(
+                Channel::new(15).unwrap_err(),
+                ChannelError::GreaterOrEqualViolated
+            );
+            assert_eq!(Channel::new(16).unwrap().into_inner(), 16);
+            assert_eq!(Channel::new(255).unwrap().into_inner(), 255);
+            assert_eq!(
+                Channel::new(256).unwrap_err(),
+                ChannelError::LessOrEqualViolated
+            );
+            assert_eq!(Channel::default().into_inner(), 31);
+        }
+
+        #[test]
+        fn test_octal() {
+            #[nutype(validate(greater = -0o10, less = 0o777), derive(Debug))]
+            struct Mode(i32);
+
+            assert_eq!(Mode::new(-8).unwrap_err(), ModeError::GreaterViolated);
+            assert_eq!(Mode::new(-7).unwrap().into_inner(), -7);
+            assert_eq!(Mode::new(510).unwrap().into_inner(), 510);
+            assert_eq!(Mode::new(511).unwrap_err(), ModeError::LessViolated);
+        }
+
+        #[test]
+        fn test_binary() {
+            #[nutype(
+                validate(greater_or_equal = 0b0000_0001, less_or_equal = 0b1000_0000u8),
+                derive(Debug)
+            )]
+            struct Flag(u8);
+
+            assert_eq!(Flag::new(0).unwrap_err(), FlagError::GreaterOrEqualViolated);
+            assert_eq!(Flag::new(1).unwrap().into_inner(), 1);
+            assert_eq!(Flag::new(128).unwrap().into_inner(), 128);
+            assert_eq!(Flag::new(129).unwrap_err(), FlagError::LessOrEqualViolated);
+        }
+    }
+
     #[cfg(test)]
     mod with {
         use super::*;
@@ -178,6 +596,72 @@ mod validators {
         }
     }
 
+    #[cfg(test)]
+    mod with_result {
+        use super::*;
+
+        #[test]
+        fn test_closure_with_no_type() {
+            #[nutype(
+                validate(with_result = |c| {
+                    if *c % 5 == 0 {
+                        Ok(())
+                    } else {
+                        Err(format!("{c} is not a multiple of 5"))
+                    }
+                }),
+                derive(Debug, PartialEq),
+            )]
+            pub struct Cent(i32);
+
+            assert_eq!(
+                Cent::new(12),
+                Err(CentError::WithResultViolated(
+                    "12 is not a multiple of 5".to_string()
+                ))
+            );
+            assert_eq!(Cent::new(15).unwrap().into_inner(), 15);
+        }
+
+        fn validate_cent(val: &i32) -> Result<(), String> {
+            if (0..=100).contains(val) {
+                Ok(())
+            } else {
+                Err(format!("{val} is out of 0..=100 range"))
+            }
+        }
+
+        #[test]
+        fn test_with_function_and_other_validators() {
+            #[nutype(
+                validate(with_result = validate_cent, non_zero),
+                derive(Debug, PartialEq),
+            )]
+            pub struct Cent(i32);
+
+            assert_eq!(Cent::new(0), Err(CentError::NonZeroViolated));
+            assert_eq!(
+                Cent::new(101),
+                Err(CentError::WithResultViolated(
+                    "101 is out of 0..=100 range".to_string()
+                ))
+            );
+            assert_eq!(Cent::new(100).unwrap().into_inner(), 100);
+        }
+
+        #[test]
+        fn test_error_display() {
+            #[nutype(validate(with_result = validate_cent), derive(Debug))]
+            pub struct Cent(i32);
+
+            let err = Cent::new(-1).unwrap_err();
+            assert_eq!(
+                err.to_string(),
+                "Cent is invalid: -1 is out of 0..=100 range"
+            );
+        }
+    }
+
     #[test]
     fn test_try_from_trait() {
         #[nutype(
@@ -203,6 +687,17 @@ mod validators {
         assert_eq!(Age::try_from(78).unwrap().into_inner(), 78);
     }
 
+    #[test]
+    // The point is to verify that `try_from()` is available.
+    #[allow(clippy::unnecessary_fallible_conversions)]
+    fn test_try_from_trait_with_from() {
+        #[nutype(derive(Debug, PartialEq, From, TryFrom))]
+        struct Age(u8);
+
+        let age: Result<Age, std::convert::Infallible> = Age::try_from(78);
+        assert_eq!(age.unwrap(), Age::from(78));
+    }
+
     #[cfg(test)]
     mod error {
         use super::*;
@@ -225,6 +720,83 @@ mod validators {
                 "Age is too small. The value must be greater or equal to 18."
             );
         }
+
+        #[test]
+        fn test_non_exhaustive_error() {
+            #[nutype(
+                non_exhaustive_error,
+                validate(greater_or_equal = 18),
+                derive(Debug, PartialEq)
+            )]
+            struct Age(u8);
+
+            assert_eq!(Age::new(17), Err(AgeError::GreaterOrEqualViolated));
+            assert_eq!(Age::new(18).unwrap().into_inner(), 18);
+        }
+
+        #[test]
+        fn test_collect_errors() {
+            #[nutype(
+                collect_errors,
+                validate(greater_or_equal = 10, predicate = |n| n % 2 == 0),
+                derive(Debug, PartialEq)
+            )]
+            struct Amount(i32);
+
+            assert_eq!(
+                Amount::new(7),
+                Err(AmountError::Multiple(vec![
+                    AmountError::GreaterOrEqualViolated,
+                    AmountError::PredicateViolated,
+                ]))
+            );
+            assert_eq!(
+                Amount::new(11),
+                Err(AmountError::PredicateViolated),
+                "A single error is not wrapped into Multiple"
+            );
+            assert_eq!(Amount::new(8), Err(AmountError::GreaterOrEqualViolated));
+            assert_eq!(Amount::new(12).unwrap().into_inner(), 12);
+
+            assert_eq!(
+                Amount::new(7).unwrap_err().to_string(),
+                "Amount is too small. The value must be greater or equal to 10. Amount failed the predicate test."
+            );
+        }
+
+        #[test]
+        fn test_validation_error_trait() {
+            use nutype::ValidationError;
+
+            #[nutype(
+                collect_errors,
+                sanitize(try_with = |n: i32| n.checked_mul(2).ok_or("overflow")),
+                validate(greater_or_equal = 10, predicate = |n| n % 4 == 0),
+                derive(Debug)
+            )]
+            struct Amount(i32);
+
+            let err = Amount::new(7).unwrap_err();
+            assert_eq!(err.kind(), "Amount");
+            assert_eq!(err.constraint(), "predicate");
+
+            let err = Amount::new(1).unwrap_err();
+            assert!(matches!(err, AmountError::Multiple(_)));
+            assert_eq!(err.constraint(), "greater_or_equal");
+
+            let err = Amount::new(i32::MAX).unwrap_err();
+            assert_eq!(err.constraint(), "try_with");
+        }
+
+        #[test]
+        #[deny(unused_must_use)]
+        fn test_allow_unused() {
+            #[nutype(allow_unused, sanitize(with = |n: u8| n.clamp(0, 120)))]
+            struct Age(u8);
+
+            // Would not compile without `allow_unused`, since the lint is denied.
+            Age::new(17);
+        }
     }
 }
 
@@ -528,11 +1100,34 @@ mod traits {
     }
 
     #[test]
-    fn test_trait_from() {
-        #[nutype(derive(From))]
-        pub struct Amount(u32);
+    fn test_trait_into_inner_together_with_from() {
+        #[nutype(derive(Debug, PartialEq, From, IntoInner))]
+        pub struct Age(u8);
 
-        let amount = Amount::from(350);
+        // inner -> newtype
+        let age = Age::from(32);
+        assert_eq!(age, Age::new(32));
+        // newtype -> inner
+        let age: u8 = age.into();
+        assert_eq!(age, 32);
+        assert_eq!(u8::from(Age::new(7)), 7);
+    }
+
+    #[test]
+    fn test_trait_into_inner_together_with_into() {
+        #[nutype(derive(Into, IntoInner))]
+        pub struct Age(u8);
+
+        let age: u8 = Age::new(32).into();
+        assert_eq!(age, 32);
+    }
+
+    #[test]
+    fn test_trait_from() {
+        #[nutype(derive(From))]
+        pub struct Amount(u32);
+
+        let amount = Amount::from(350);
         assert_eq!(amount.into_inner(), 350);
     }
 
@@ -555,6 +1150,59 @@ mod traits {
         assert_eq!(*magic, 42);
     }
 
+    #[test]
+    fn test_trait_eq_inner() {
+        #[nutype(derive(Debug, PartialEq, EqInner))]
+        pub struct Number(i32);
+
+        let magic = Number::new(42);
+        assert_eq!(magic, 42);
+        assert_ne!(magic, 43);
+
+        // PartialEq between two newtypes is not affected
+        assert_eq!(magic, Number::new(42));
+    }
+
+    #[test]
+    fn test_trait_ord_inner() {
+        #[nutype(derive(Debug, PartialEq, PartialOrd, OrdInner))]
+        pub struct Port(u16);
+
+        let port = Port::new(443);
+        assert!(port < 1024);
+        assert!(port >= 443);
+        assert!(port > 80);
+        assert_eq!(port.partial_cmp(&443), Some(std::cmp::Ordering::Equal));
+
+        // `OrdInner` brings `EqInner` along
+        assert_eq!(port, 443);
+
+        // PartialOrd between two newtypes is not affected
+        assert!(port < Port::new(8080));
+    }
+
+    #[test]
+    fn test_trait_nutype() {
+        use nutype::Nutype;
+
+        #[nutype(derive(Debug, Nutype), validate(greater = 0))]
+        pub struct Quantity(u32);
+
+        #[nutype(derive(Debug, Nutype))]
+        pub struct Number(i16);
+
+        fn roundtrip<T: Nutype>(value: T::Inner) -> Result<T::Inner, T::Error> {
+            T::new(value).map(T::into_inner)
+        }
+
+        assert_eq!(roundtrip::<Quantity>(5), Ok(5));
+        assert_eq!(
+            roundtrip::<Quantity>(0),
+            Err(QuantityError::GreaterViolated)
+        );
+        assert_eq!(roundtrip::<Number>(-3), Ok(-3));
+    }
+
     #[test]
     fn test_trait_borrow() {
         use std::borrow::Borrow;
@@ -579,6 +1227,79 @@ mod traits {
         assert_eq!(error, AmountError::LessOrEqualViolated);
     }
 
+    #[test]
+    fn test_trait_try_from_wide() {
+        #[nutype(
+            validate(greater_or_equal = 1024),
+            derive(Debug, PartialEq, TryFromWide)
+        )]
+        pub struct Port(u16);
+
+        // In range
+        assert_eq!(Port::try_from(8080_i64).unwrap().into_inner(), 8080);
+        assert_eq!(Port::try_from(65535_u64).unwrap().into_inner(), 65535);
+
+        // Out of range
+        assert_eq!(
+            Port::try_from(65536_i64),
+            Err(PortTryFromWideError::OutOfRange)
+        );
+        assert_eq!(
+            Port::try_from(-1_i64),
+            Err(PortTryFromWideError::OutOfRange)
+        );
+        assert_eq!(
+            Port::try_from(u64::MAX),
+            Err(PortTryFromWideError::OutOfRange)
+        );
+
+        // In range, but invalid
+        assert_eq!(
+            Port::try_from(80_u64),
+            Err(PortTryFromWideError::Validate(
+                PortError::GreaterOrEqualViolated
+            ))
+        );
+
+        assert_eq!(
+            PortTryFromWideError::OutOfRange.to_string(),
+            "Failed to convert Port: the value is out of range of `u16`"
+        );
+    }
+
+    #[test]
+    fn test_trait_try_from_wide_without_validation() {
+        #[nutype(sanitize(with = |n| n.abs()), derive(Debug, PartialEq, TryFromWide))]
+        pub struct Offset(i8);
+
+        assert_eq!(Offset::try_from(-100_i64).unwrap().into_inner(), 100);
+        assert_eq!(Offset::try_from(127_u64).unwrap().into_inner(), 127);
+        assert_eq!(
+            Offset::try_from(128_u64),
+            Err(OffsetTryFromWideError::OutOfRange)
+        );
+    }
+
+    #[test]
+    fn test_trait_try_from_wide_together_with_try_from() {
+        #[nutype(
+            validate(less_or_equal = 1_000_000),
+            derive(Debug, PartialEq, TryFrom, TryFromWide)
+        )]
+        pub struct Amount(i64);
+
+        assert_eq!(Amount::try_from(1000_i64).unwrap().into_inner(), 1000);
+        assert_eq!(
+            Amount::try_from(1_000_001_i64),
+            Err(AmountError::LessOrEqualViolated)
+        );
+        assert_eq!(Amount::try_from(1000_u64).unwrap().into_inner(), 1000);
+        assert_eq!(
+            Amount::try_from(u64::MAX),
+            Err(AmountTryFromWideError::OutOfRange)
+        );
+    }
+
     #[test]
     fn test_trait_from_str_without_validation() {
         #[nutype(derive(Debug, FromStr))]
@@ -626,6 +1347,111 @@ mod traits {
         assert_eq!(age.to_string(), "35");
     }
 
+    #[test]
+    fn test_trait_sum() {
+        #[nutype(derive(Debug, Clone, Copy, PartialEq, Sum))]
+        pub struct Price(u64);
+
+        let prices = [Price::new(100), Price::new(250), Price::new(50)];
+        assert_eq!(prices.iter().copied().sum::<Price>(), Price::new(400));
+        assert_eq!(prices.iter().sum::<Price>(), Price::new(400));
+        assert_eq!(
+            Vec::<Price>::new().into_iter().sum::<Price>(),
+            Price::new(0)
+        );
+    }
+
+    #[test]
+    fn test_trait_product() {
+        #[nutype(derive(Debug, PartialEq, Product))]
+        pub struct Factor(i32);
+
+        let factors = [Factor::new(2), Factor::new(-3), Factor::new(7)];
+        assert_eq!(factors.iter().product::<Factor>(), Factor::new(-42));
+        assert_eq!(factors.into_iter().product::<Factor>(), Factor::new(-42));
+    }
+
+    #[test]
+    fn test_trait_sum_applies_sanitizers() {
+        #[nutype(sanitize(with = |n| n.min(1000)), derive(Debug, PartialEq, Sum))]
+        pub struct Points(u32);
+
+        let total: Points = [Points::new(700), Points::new(600)].into_iter().sum();
+        assert_eq!(total, Points::new(1000));
+    }
+
+    #[test]
+    fn test_checked_arith() {
+        #[nutype(
+            validate(less_or_equal = 100),
+            derive(Debug, Clone, Copy, PartialEq, CheckedArith)
+        )]
+        pub struct Quantity(u8);
+
+        let q = |n| Quantity::new(n).unwrap();
+        assert_eq!(q(40).checked_add(q(60)), Some(q(100)));
+        assert_eq!(q(60).checked_sub(q(40)), Some(q(20)));
+
+        // Constraint violation
+        assert_eq!(q(60).checked_add(q(41)), None);
+
+        // Overflow
+        assert_eq!(q(40).checked_sub(q(60)), None);
+    }
+
+    #[test]
+    fn test_checked_arith_without_validation() {
+        #[nutype(derive(Debug, PartialEq, CheckedArith))]
+        pub struct Offset(i8);
+
+        assert_eq!(
+            Offset::new(-100).checked_sub(Offset::new(20)),
+            Some(Offset::new(-120))
+        );
+        assert_eq!(Offset::new(100).checked_add(Offset::new(28)), None);
+    }
+
+    #[test]
+    fn test_trait_neg() {
+        #[nutype(
+            validate(greater_or_equal = -100, less_or_equal = 100, non_zero),
+            derive(Debug, PartialEq, Neg)
+        )]
+        pub struct Offset(i32);
+
+        let offset = Offset::new(42).unwrap();
+        assert_eq!(-offset, Offset::new(-42).unwrap());
+        assert_eq!(-Offset::new(-100).unwrap(), Offset::new(100).unwrap());
+    }
+
+    #[test]
+    fn test_trait_neg_without_validation() {
+        #[nutype(sanitize(with = |n| n * 2), derive(Debug, PartialEq, Neg))]
+        pub struct Delta(i64);
+
+        // The sanitizers are applied to the negated value
+        assert_eq!((-Delta::new(3)).into_inner(), -12);
+    }
+
+    #[test]
+    #[should_panic(expected = "Negation of Delta overflows")]
+    fn test_trait_neg_of_min_value() {
+        #[nutype(derive(Debug, Neg))]
+        pub struct Delta(i32);
+
+        // Panics in release builds too, instead of wrapping around to `i32::MIN`
+        let _ = -Delta::new(i32::MIN);
+    }
+
+    #[test]
+    fn test_trait_display_with_format() {
+        #[nutype(derive(Display), display = "User #{}")]
+        pub struct UserId(u64);
+
+        let user_id = UserId::new(42);
+        assert_eq!(user_id.to_string(), "User #42");
+    }
+
     #[cfg(feature = "serde")]
     mod serialization {
         use super::*;
@@ -682,6 +1508,156 @@ mod traits {
                     assert_eq!(offset.into_inner(), 13);
                 }
             }
+
+            #[cfg(feature = "serde")]
+            #[test]
+            fn test_trait_deserialize_with_collect_errors() {
+                #[nutype(
+                    collect_errors,
+                    validate(greater_or_equal = 10, predicate = |n| n % 2 == 0),
+                    derive(Deserialize, Debug)
+                )]
+                pub struct Amount(i32);
+
+                let err = serde_json::from_str::<Amount>("7").unwrap_err();
+                assert_eq!(
+                    err.to_string(),
+                    "Amount is too small. The value must be greater or equal to 10. Amount failed the predicate test. Expected valid Amount, got 7"
+                );
+
+                let amount: Amount = serde_json::from_str("12").unwrap();
+                assert_eq!(amount.into_inner(), 12);
+            }
+
+            #[nutype(
+                validate(greater = 0),
+                default = 8080,
+                derive(Debug, PartialEq, Deserialize, Default)
+            )]
+            pub struct Port(u16);
+
+            #[derive(Debug, serde::Deserialize)]
+            struct Config {
+                #[serde(default)]
+                port: Port,
+            }
+
+            #[test]
+            fn test_trait_deserialize_with_serde_default() {
+                let config: Config = serde_json::from_str("{}").unwrap();
+                assert_eq!(config.port, Port::default());
+                assert_eq!(config.port.into_inner(), 8080);
+
+                let config: Config = serde_json::from_str(r#"{"port":3000}"#).unwrap();
+                assert_eq!(config.port.into_inner(), 3000);
+
+                // An explicit value is still validated
+                let err = serde_json::from_str::<Config>(r#"{"port":0}"#).unwrap_err();
+                assert!(err.to_string().contains("Expected valid Port"));
+            }
+
+            #[test]
+            fn test_trait_deserialize_error_within_struct() {
+                #[nutype(validate(less_or_equal = 100), derive(Debug, Deserialize))]
+                pub struct Percent(u8);
+
+                #[derive(Debug, serde::Deserialize)]
+                #[allow(dead_code)]
+                struct Discount {
+                    regular: Percent,
+                    premium: Percent,
+                }
+
+                let err = serde_json::from_str::<Discount>(r#"{"regular":10,"premium":150}"#)
+                    .unwrap_err();
+                // The message contains the violated constraint, the value and the position of the field
+                assert_eq!(
+                    err.to_string(),
+                    "Percent is too big. The value must be less or equal to 100. Expected valid Percent, got 150 at line 1 column 28"
+                );
+            }
+        }
+
+        mod object_repr {
+            use super::*;
+
+            #[test]
+            fn test_json_object() {
+                #[nutype(
+                    validate(greater_or_equal = 1),
+                    serde_repr = object(field = "amount"),
+                    derive(Serialize, Deserialize, PartialEq, Debug)
+                )]
+                pub struct Quantity(u32);
+
+                let quantity = Quantity::new(3).unwrap();
+                let json = serde_json::to_string(&quantity).unwrap();
+                assert_eq!(json, r#"{"amount":3}"#);
+                assert_eq!(serde_json::from_str::<Quantity>(&json).unwrap(), quantity);
+
+                let err = serde_json::from_str::<Quantity>(r#"{"amount":0}"#).unwrap_err();
+                assert!(err.to_string().contains("Expected valid Quantity"));
+            }
+        }
+
+        mod lenient {
+            use super::*;
+
+            #[test]
+            fn test_number_and_numeric_string() {
+                #[nutype(
+                    validate(less_or_equal = 100),
+                    serde_lenient,
+                    derive(Serialize, Deserialize, PartialEq, Debug)
+                )]
+                pub struct Percent(u8);
+
+                let expected = Percent::new(42).unwrap();
+                assert_eq!(serde_json::from_str::<Percent>("42").unwrap(), expected);
+                assert_eq!(
+                    serde_json::from_str::<Percent>(r#""42""#).unwrap(),
+                    expected
+                );
+
+                // Serialized as a number anyway
+                assert_eq!(serde_json::to_string(&expected).unwrap(), "42");
+            }
+
+            #[test]
+            fn test_negative_number_and_numeric_string() {
+                #[nutype(serde_lenient, derive(Deserialize, PartialEq, Debug))]
+                pub struct Offset(i64);
+
+                assert_eq!(
+                    serde_json::from_str::<Offset>("-7").unwrap(),
+                    Offset::new(-7)
+                );
+                assert_eq!(
+                    serde_json::from_str::<Offset>(r#""-7""#).unwrap(),
+                    Offset::new(-7)
+                );
+            }
+
+            #[test]
+            fn test_invalid_input() {
+                #[nutype(
+                    validate(less_or_equal = 100),
+                    serde_lenient,
+                    derive(Deserialize, Debug)
+                )]
+                pub struct Percent(u8);
+
+                let err = serde_json::from_str::<Percent>(r#""101""#).unwrap_err();
+                assert!(err.to_string().contains("Expected valid Percent, got 101"));
+
+                let err = serde_json::from_str::<Percent>("-1").unwrap_err();
+                assert!(err.to_string().contains("integer or numeric string"));
+
+                let err = serde_json::from_str::<Percent>(r#""forty""#).unwrap_err();
+                assert!(err.to_string().contains("integer or numeric string"));
+
+                assert!(serde_json::from_str::<Percent>("4.2").is_err());
+            }
         }
 
         mod ron_format {
@@ -745,80 +1721,834 @@ mod traits {
             #[nutype(validate(less_or_equal = 20), default = 21, derive(Default))]
             pub struct Number(i16);
 
-            Number::default();
+            let _ = Number::default();
+        }
+
+        #[test]
+        fn test_default_min_with_greater_or_equal() {
+            #[nutype(validate(greater_or_equal = 5, less_or_equal = 20), default = min, derive(Default))]
+            pub struct Number(u8);
+
+            assert_eq!(Number::default().into_inner(), 5);
+        }
+
+        #[test]
+        fn test_default_min_with_greater() {
+            #[nutype(validate(greater = -3), default = min, derive(Default))]
+            pub struct Number(i32);
+
+            assert_eq!(Number::default().into_inner(), -2);
         }
     }
 }
 
 #[cfg(test)]
-#[cfg(feature = "new_unchecked")]
-mod new_unchecked {
+mod sanitize_then_return_changed {
     use super::*;
 
     #[test]
-    fn test_new_unchecked() {
-        #[nutype(new_unchecked, validate(greater_or_equal = 50))]
-        pub struct Dist(u32);
+    fn test_new_reporting() {
+        #[nutype(
+            sanitize_then_return_changed,
+            sanitize(with = |n: i32| n.clamp(0, 100)),
+            validate(less_or_equal = 50),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Volume(i32);
 
-        let dist = unsafe { Dist::new_unchecked(3) };
-        assert_eq!(dist.into_inner(), 3);
+        let (volume, changed) = Volume::new_reporting(-5).unwrap();
+        assert_eq!(volume.into_inner(), 0);
+        assert!(changed);
+
+        let (volume, changed) = Volume::new_reporting(30).unwrap();
+        assert_eq!(volume.into_inner(), 30);
+        assert!(!changed);
+
+        assert_eq!(
+            Volume::new_reporting(200),
+            Err(VolumeError::LessOrEqualViolated)
+        );
     }
 }
 
 #[cfg(test)]
-#[cfg(feature = "schemars08")]
-mod derive_schemars_json_schema {
+mod new_fn {
     use super::*;
-    use schemars::{schema_for, JsonSchema};
 
     #[test]
-    fn test_json_schema_derive() {
-        #[nutype(derive(JsonSchema))]
-        pub struct CustomerId(i128);
+    fn test_new_fn_with_validation() {
+        #[nutype(
+            new_fn = try_new,
+            validate(greater_or_equal = 1, less_or_equal = 12),
+            default = 1,
+            derive(Debug, PartialEq, TryFrom, FromStr, Default)
+        )]
+        pub struct Month(u8);
 
-        assert_eq!(CustomerId::schema_name(), "CustomerId");
-        // Make sure it compiles
-        let _schema = schema_for!(CustomerId);
+        assert_eq!(Month::try_new(13), Err(MonthError::LessOrEqualViolated));
+        assert_eq!(Month::try_new(3).unwrap().into_inner(), 3);
+
+        // Generated trait implementations use the renamed constructor too
+        assert_eq!(Month::try_from(0), Err(MonthError::GreaterOrEqualViolated));
+        assert_eq!("7".parse::<Month>().unwrap(), Month::try_new(7).unwrap());
+        assert_eq!(Month::default(), Month::try_new(1).unwrap());
+    }
+
+    #[test]
+    fn test_new_fn_without_validation() {
+        #[nutype(new_fn = from_raw, derive(Debug, PartialEq, From))]
+        pub struct Offset(i64);
+
+        assert_eq!(Offset::from_raw(-5).into_inner(), -5);
+        assert_eq!(Offset::from(-5), Offset::from_raw(-5));
+    }
+
+    #[test]
+    fn test_skip_new() {
+        #[nutype(
+            skip_new,
+            validate(greater_or_equal = 1, less_or_equal = 12),
+            derive(Debug, PartialEq, TryFrom, FromStr)
+        )]
+        pub struct Month(u8);
+
+        // `Month::new()` is not accessible, the traits are the only way to construct a value.
+        assert_eq!(Month::try_from(13), Err(MonthError::LessOrEqualViolated));
+        assert_eq!(Month::try_from(3).unwrap().into_inner(), 3);
+        assert_eq!("7".parse::<Month>().unwrap(), Month::try_from(7).unwrap());
     }
 }
 
 #[cfg(test)]
-#[cfg(feature = "diesel-derive-newtype")]
-mod derive_diesel_derive_newtype {
+mod from_env {
     use super::*;
-    use diesel::dsl::sql;
-    use diesel::prelude::*;
-    use diesel::sqlite::SqliteConnection;
-    use test_suite::prepare_database_table_for_type;
+
+    #[nutype(
+        validate(greater_or_equal = 1024),
+        derive(Debug, PartialEq, FromStr),
+        from_env
+    )]
+    pub struct Port(u16);
 
     #[test]
-    fn test_diesel_derive_newtype() {
-        table! {
-            diesel_derive_newtype_test {
-                id -> Integer,
-                value -> Integer,
-            }
-        }
-        let mut conn = prepare_database_table_for_type!(diesel_derive_newtype_test, "INTEGER");
+    fn test_from_env() {
+        std::env::set_var("NUTYPE_TEST_INTEGER_PORT", "8080");
+        assert_eq!(
+            Port::from_env("NUTYPE_TEST_INTEGER_PORT").unwrap(),
+            Port::new(8080).unwrap()
+        );
+    }
 
-        #[nutype(derive(Debug, DieselNewType))]
-        pub struct IntegerValue(i32);
+    #[test]
+    fn test_from_env_missing() {
+        let err = Port::from_env("NUTYPE_TEST_INTEGER_PORT_MISSING").unwrap_err();
+        assert!(
+            matches!(err, PortFromEnvError::Missing { ref var } if var == "NUTYPE_TEST_INTEGER_PORT_MISSING")
+        );
+        assert_eq!(
+            err.to_string(),
+            "Environment variable `NUTYPE_TEST_INTEGER_PORT_MISSING` for Port is not set."
+        );
+    }
 
-        #[derive(Debug, Insertable, Queryable, Selectable)]
-        #[diesel(table_name = diesel_derive_newtype_test)]
-        pub struct DieselDeriveNewtypeTest {
-            pub id: i32,
-            pub value: IntegerValue,
-        }
+    #[test]
+    fn test_from_env_parse_error() {
+        std::env::set_var("NUTYPE_TEST_INTEGER_PORT_NAN", "http");
+        let err = Port::from_env("NUTYPE_TEST_INTEGER_PORT_NAN").unwrap_err();
+        assert!(matches!(err, PortFromEnvError::Parse { .. }));
+        assert_eq!(
+            err.to_string(),
+            "Failed to parse environment variable `NUTYPE_TEST_INTEGER_PORT_NAN` as Port: invalid digit found in string"
+        );
+    }
 
-        let obj = DieselDeriveNewtypeTest {
-            id: 1,
-            value: IntegerValue::new(123),
-        };
+    #[test]
+    fn test_from_env_validation_error() {
+        std::env::set_var("NUTYPE_TEST_INTEGER_PORT_LOW", "80");
+        let err = Port::from_env("NUTYPE_TEST_INTEGER_PORT_LOW").unwrap_err();
+        assert!(matches!(
+            err,
+            PortFromEnvError::Validate {
+                error: PortError::GreaterOrEqualViolated,
+                ..
+            }
+        ));
+    }
 
-        diesel::insert_into(diesel_derive_newtype_test::table)
-            .values(&obj)
-            .execute(&mut conn)
+    #[test]
+    fn test_from_env_without_validation() {
+        #[nutype(derive(Debug, PartialEq, FromStr), from_env)]
+        pub struct Workers(usize);
+
+        std::env::set_var("NUTYPE_TEST_INTEGER_WORKERS", "4");
+        assert_eq!(
+            Workers::from_env("NUTYPE_TEST_INTEGER_WORKERS").unwrap(),
+            Workers::new(4)
+        );
+        std::env::set_var("NUTYPE_TEST_INTEGER_WORKERS_NAN", "four");
+        assert!(matches!(
+            Workers::from_env("NUTYPE_TEST_INTEGER_WORKERS_NAN"),
+            Err(WorkersFromEnvError::Parse { .. })
+        ));
+    }
+}
+
+#[cfg(test)]
+mod context {
+    use super::*;
+
+    pub struct Limits {
+        pub max_connections: u32,
+    }
+
+    #[nutype(
+        context = Limits,
+        validate(greater = 0, predicate = |n, limits| *n <= limits.max_connections),
+        derive(Debug, PartialEq, Clone, Copy)
+    )]
+    pub struct Connections(u32);
+
+    #[test]
+    fn test_new_with_context() {
+        let limits = Limits { max_connections: 8 };
+        assert_eq!(
+            Connections::new_with_context(8, &limits)
+                .unwrap()
+                .into_inner(),
+            8
+        );
+        assert_eq!(
+            Connections::new_with_context(0, &limits),
+            Err(ConnectionsError::GreaterViolated)
+        );
+        assert_eq!(
+            Connections::new_with_context(9, &limits),
+            Err(ConnectionsError::PredicateViolated)
+        );
+        assert_eq!(
+            Connections::validate(
+                &9,
+                &Limits {
+                    max_connections: 16
+                }
+            ),
+            Ok(())
+        );
+    }
+}
+
+#[cfg(test)]
+mod into_inner {
+    use super::*;
+
+    #[test]
+    fn test_into_inner_and_inner() {
+        #[nutype(validate(less_or_equal = 100))]
+        pub struct Percent(u8);
+
+        let percent = Percent::new(42).unwrap();
+        let inner: &u8 = percent.inner();
+        assert_eq!(*inner, 42);
+        let inner: u8 = percent.into_inner();
+        assert_eq!(inner, 42);
+    }
+}
+
+#[cfg(test)]
+mod as_cow {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_as_cow() {
+        #[nutype(validate(less_or_equal = 100))]
+        pub struct Percent(u8);
+
+        let percent = Percent::new(42).unwrap();
+        let cow: Cow<'_, u8> = percent.as_cow();
+        assert!(matches!(cow, Cow::Borrowed(42)));
+    }
+}
+
+#[cfg(test)]
+mod modify {
+    use super::*;
+
+    #[test]
+    fn test_modify_with_validation() {
+        #[nutype(validate(less_or_equal = 100), derive(Debug, Clone, Copy, PartialEq))]
+        pub struct Percent(u8);
+
+        let mut percent = Percent::new(90).unwrap();
+
+        percent.modify(|p| *p += 10).unwrap();
+        assert_eq!(percent.into_inner(), 100);
+
+        // Invalid value is not committed
+        assert_eq!(
+            percent.modify(|p| *p += 1),
+            Err(PercentError::LessOrEqualViolated)
+        );
+        assert_eq!(percent.into_inner(), 100);
+    }
+
+    #[test]
+    fn test_modify_without_validation() {
+        #[nutype(sanitize(wrapping = 360))]
+        pub struct Degree(i32);
+
+        let mut degree = Degree::new(350);
+        degree.modify(|d| *d += 20);
+        assert_eq!(degree.into_inner(), 10);
+    }
+}
+
+#[cfg(test)]
+mod map {
+    use super::*;
+
+    #[test]
+    fn test_try_map() {
+        #[nutype(validate(less_or_equal = 100), derive(Debug, Clone, Copy, PartialEq))]
+        pub struct Percent(u8);
+
+        let percent = Percent::new(40).unwrap();
+        assert_eq!(percent.try_map(|p| p * 2), Percent::new(80));
+        assert_eq!(
+            percent.try_map(|p| p * 3),
+            Err(PercentError::LessOrEqualViolated)
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        #[nutype(sanitize(wrapping = 360), derive(Debug, PartialEq))]
+        pub struct Degree(i32);
+
+        let degree = Degree::new(350).map(|d| d + 20);
+        assert_eq!(degree, Degree::new(10));
+    }
+}
+
+#[cfg(test)]
+mod type_alias {
+    use super::*;
+
+    /// User identifier
+    #[nutype(validate(greater = 0), derive(Debug, Clone, Copy, PartialEq))]
+    pub type UserId = u64;
+
+    #[test]
+    fn test_alias_is_replaced_by_newtype() {
+        let id = UserId::new(42).unwrap();
+        assert_eq!(id.into_inner(), 42);
+        assert_eq!(UserId::new(0), Err(UserIdError::GreaterViolated));
+    }
+
+    #[test]
+    fn test_alias_is_distinct_from_inner_type() {
+        fn id_of(id: UserId) -> u64 {
+            id.into_inner()
+        }
+
+        assert_eq!(id_of(UserId::new(7).unwrap()), 7);
+    }
+}
+
+#[cfg(test)]
+mod repr_transparent {
+    use super::*;
+    use std::mem::{align_of, size_of};
+
+    #[nutype(repr_transparent, validate(greater = 0), derive(Debug, Clone, Copy))]
+    pub struct Port(u16);
+
+    #[test]
+    fn test_layout_matches_inner_type() {
+        assert_eq!(size_of::<Port>(), size_of::<u16>());
+        assert_eq!(align_of::<Port>(), align_of::<u16>());
+    }
+
+    #[test]
+    fn test_slice_of_newtypes_as_slice_of_inner_values() {
+        let ports = [Port::new(80).unwrap(), Port::new(443).unwrap()];
+        // SAFETY: `Port` is `#[repr(transparent)]` over `u16`.
+        let raw: &[u16] = unsafe { std::slice::from_raw_parts(ports.as_ptr().cast(), ports.len()) };
+        assert_eq!(raw, &[80, 443]);
+    }
+}
+
+#[cfg(test)]
+mod enumerate {
+    use super::*;
+
+    #[nutype(
+        enumerate,
+        validate(greater_or_equal = 1, less_or_equal = 6),
+        derive(Debug)
+    )]
+    pub struct Dice(u8);
+
+    #[test]
+    fn test_all_values_within_inclusive_bounds() {
+        let values: Vec<u8> = Dice::all().map(Dice::into_inner).collect();
+        assert_eq!(values, vec![1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_exclusive_bounds() {
+        #[nutype(enumerate, validate(greater = -3, less = 3))]
+        pub struct Offset(i8);
+
+        let values: Vec<i8> = Offset::all().map(Offset::into_inner).collect();
+        assert_eq!(values, vec![-2, -1, 0, 1, 2]);
+    }
+
+    #[test]
+    fn test_skips_values_rejected_by_validators() {
+        #[nutype(
+            enumerate,
+            validate(greater_or_equal = 0, less_or_equal = 20, step = 5, predicate = |n| *n != 10),
+        )]
+        pub struct Percent(u32);
+
+        let values: Vec<u32> = Percent::all().map(Percent::into_inner).collect();
+        assert_eq!(values, vec![0, 5, 15, 20]);
+    }
+
+    #[test]
+    fn test_skips_values_changed_by_sanitizers() {
+        #[nutype(
+            enumerate,
+            sanitize(with = |n| n - n % 2),
+            validate(greater_or_equal = 0, less_or_equal = 6),
+        )]
+        pub struct Even(u8);
+
+        let values: Vec<u8> = Even::all().map(Even::into_inner).collect();
+        assert_eq!(values, vec![0, 2, 4, 6]);
+    }
+
+    #[test]
+    fn test_extreme_bounds() {
+        #[nutype(enumerate, validate(greater = 250, less_or_equal = u8::MAX))]
+        pub struct High(u8);
+
+        let values: Vec<u8> = High::all().map(High::into_inner).collect();
+        assert_eq!(values, vec![251, 252, 253, 254, 255]);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "new_unchecked")]
+mod new_unchecked {
+    use super::*;
+
+    #[test]
+    fn test_new_unchecked() {
+        #[nutype(new_unchecked, validate(greater_or_equal = 50))]
+        pub struct Dist(u32);
+
+        let dist = unsafe { Dist::new_unchecked(3) };
+        assert_eq!(dist.into_inner(), 3);
+    }
+
+    #[test]
+    fn test_new_unchecked_with_skip_new() {
+        #[nutype(
+            new_unchecked,
+            skip_new,
+            validate(greater_or_equal = 50),
+            derive(TryFrom)
+        )]
+        pub struct Dist(u32);
+
+        // `new_unchecked()` remains public, since it has to be opted in explicitly.
+        let dist = unsafe { Dist::new_unchecked(3) };
+        assert_eq!(dist.into_inner(), 3);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "schemars08")]
+mod derive_schemars_json_schema {
+    use super::*;
+    use schemars::{schema_for, JsonSchema};
+
+    #[test]
+    fn test_json_schema_derive() {
+        #[nutype(derive(JsonSchema))]
+        pub struct CustomerId(i128);
+
+        assert_eq!(CustomerId::schema_name(), "CustomerId");
+        // Make sure it compiles
+        let _schema = schema_for!(CustomerId);
+    }
+
+    #[test]
+    fn test_json_schema_example() {
+        /// Age in years.
+        #[nutype(validate(less_or_equal = 150), derive(JsonSchema), schema_example = 42)]
+        pub struct Age(u8);
+
+        let metadata = schema_for!(Age).schema.metadata.unwrap();
+        assert_eq!(metadata.description.as_deref(), Some("Age in years."));
+        let examples: Vec<String> = metadata.examples.iter().map(|e| e.to_string()).collect();
+        assert_eq!(examples, vec!["42"]);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rkyv")]
+mod derive_rkyv_archive {
+    use super::*;
+
+    #[test]
+    fn test_archive_without_validation() {
+        #[nutype(derive(Debug, PartialEq, Archive))]
+        pub struct Offset(i64);
+
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&Offset::new(-280)).unwrap();
+
+        let archived = rkyv::access::<rkyv::Archived<Offset>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(*archived, -280);
+
+        let offset = rkyv::deserialize::<Offset, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(offset, Offset::new(-280));
+    }
+
+    #[test]
+    fn test_archive_with_validation() {
+        #[nutype(derive(Debug, PartialEq, Archive), validate(less_or_equal = 100))]
+        pub struct Percentage(u8);
+
+        let percentage = Percentage::new(42).unwrap();
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&percentage).unwrap();
+
+        let archived =
+            rkyv::access::<rkyv::Archived<Percentage>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(*archived, 42);
+
+        let deserialized = rkyv::deserialize::<Percentage, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(deserialized, percentage);
+    }
+
+    #[test]
+    fn test_deserialize_invalid_archived_value() {
+        #[nutype(derive(Debug, Archive), validate(less_or_equal = 100))]
+        pub struct Percentage(u8);
+
+        // Archive a raw value that violates the validation rules of Percentage
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&101u8).unwrap();
+
+        let archived =
+            rkyv::access::<rkyv::Archived<Percentage>, rkyv::rancor::Error>(&bytes).unwrap();
+        let err = rkyv::deserialize::<Percentage, rkyv::rancor::Error>(archived).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Percentage is too big. The value must be less or equal to 100."
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "proptest")]
+mod derive_proptest_arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[nutype(derive(Debug, ProptestArbitrary))]
+    pub struct Count(u64);
+
+    #[nutype(
+        validate(greater = 10, less_or_equal = 20),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct Level(i32);
+
+    #[nutype(
+        validate(non_zero, predicate = |n| n % 2 == 0),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct EvenStep(i16);
+
+    #[nutype(validate(one_of = [1, 2, 4, 8]), derive(Debug, ProptestArbitrary))]
+    pub struct BlockSize(u8);
+
+    proptest! {
+        #[test]
+        fn test_without_validation(count: Count) {
+            let _: u64 = count.into_inner();
+        }
+
+        #[test]
+        fn test_respects_boundaries(level: Level) {
+            let value = level.into_inner();
+            prop_assert!(value > 10 && value <= 20);
+        }
+
+        #[test]
+        fn test_respects_non_zero_and_predicate(step: EvenStep) {
+            let value = step.into_inner();
+            prop_assert_ne!(value, 0);
+            prop_assert_eq!(value % 2, 0);
+        }
+
+        #[test]
+        fn test_respects_one_of(block_size: BlockSize) {
+            prop_assert!([1, 2, 4, 8].contains(&block_size.into_inner()));
+        }
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rand")]
+mod derive_rand_distribution {
+    use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
+
+    #[test]
+    fn test_without_validation() {
+        #[nutype(derive(Debug, RandDistribution))]
+        pub struct Count(u64);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let _: Count = rng.gen();
+    }
+
+    #[test]
+    fn test_respects_boundaries() {
+        #[nutype(
+            validate(greater = 10, less_or_equal = 20),
+            derive(Debug, RandDistribution)
+        )]
+        pub struct Level(i32);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let value = rng.gen::<Level>().into_inner();
+            assert!(value > 10 && value <= 20);
+        }
+    }
+
+    #[test]
+    fn test_respects_predicate() {
+        #[nutype(
+            validate(non_zero, predicate = |n| n % 2 == 0),
+            derive(Debug, RandDistribution)
+        )]
+        pub struct EvenStep(i16);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let value = rng.gen::<EvenStep>().into_inner();
+            assert_ne!(value, 0);
+            assert_eq!(value % 2, 0);
+        }
+    }
+
+    #[test]
+    fn test_picks_one_of() {
+        #[nutype(validate(one_of = [1, 2, 4, 8]), derive(Debug, RandDistribution))]
+        pub struct BlockSize(u8);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let value = rng.gen::<BlockSize>().into_inner();
+            assert!([1, 2, 4, 8].contains(&value));
+        }
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "Nutype failed to generate a valid value of Impossible in 1000 attempts"
+    )]
+    fn test_bounded_attempts() {
+        #[nutype(validate(predicate = |_| false), derive(Debug, RandDistribution))]
+        pub struct Impossible(u32);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let _: Impossible = rng.gen();
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "redis")]
+mod derive_redis {
+    use super::*;
+    use redis::{ErrorKind, FromRedisValue, ToRedisArgs, Value};
+
+    #[test]
+    fn test_round_trip() {
+        #[nutype(derive(Debug, PartialEq, ToRedisArgs, FromRedisValue))]
+        pub struct UserId(u64);
+
+        let id = UserId::new(42);
+        assert_eq!(id.to_redis_args(), vec![b"42".to_vec()]);
+
+        let value = Value::BulkString(id.to_redis_args().remove(0));
+        assert_eq!(UserId::from_redis_value(&value).unwrap(), id);
+        assert_eq!(UserId::from_redis_value(&Value::Int(42)).unwrap(), id);
+    }
+
+    #[test]
+    fn test_from_redis_value_with_validation() {
+        #[nutype(
+            validate(greater_or_equal = 1, less_or_equal = 100),
+            derive(Debug, PartialEq, ToRedisArgs, FromRedisValue)
+        )]
+        pub struct Priority(i32);
+
+        assert_eq!(
+            Priority::from_redis_value(&Value::Int(7)).unwrap(),
+            Priority::new(7).unwrap()
+        );
+
+        let err = Priority::from_redis_value(&Value::Int(101)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TypeError);
+        assert!(err.to_string().contains("Expected valid Priority"));
+
+        let err = Priority::from_redis_value(&Value::Nil).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TypeError);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "num-traits")]
+mod derive_num_traits {
+    use super::*;
+    use num_traits::{Bounded, One, Zero};
+    use std::ops::{Add, Mul};
+
+    // `Zero` and `One` require `Add` and `Mul`, which nutype does not derive.
+    macro_rules! impl_add_mul {
+        ($type:ident) => {
+            impl Add for $type {
+                type Output = Self;
+
+                fn add(self, other: Self) -> Self {
+                    Self::new(self.into_inner() + other.into_inner())
+                }
+            }
+
+            impl Mul for $type {
+                type Output = Self;
+
+                fn mul(self, other: Self) -> Self {
+                    Self::new(self.into_inner() * other.into_inner())
+                }
+            }
+        };
+    }
+
+    #[test]
+    fn test_zero_and_one_without_validation() {
+        #[nutype(derive(Debug, PartialEq, Zero, One))]
+        pub struct Amount(i64);
+        impl_add_mul!(Amount);
+
+        assert_eq!(Amount::zero(), Amount::new(0));
+        assert_eq!(Amount::one(), Amount::new(1));
+        assert!(Amount::zero().is_zero());
+        assert!(!Amount::one().is_zero());
+    }
+
+    #[test]
+    fn test_zero_and_one_with_validation() {
+        #[nutype(
+            validate(greater_or_equal = 0, less_or_equal = 100),
+            derive(Debug, PartialEq, Zero, One)
+        )]
+        pub struct Percentage(u8);
+
+        impl Add for Percentage {
+            type Output = Self;
+
+            fn add(self, other: Self) -> Self {
+                Self::new(self.into_inner() + other.into_inner()).unwrap()
+            }
+        }
+
+        impl Mul for Percentage {
+            type Output = Self;
+
+            fn mul(self, other: Self) -> Self {
+                Self::new(self.into_inner() * other.into_inner()).unwrap()
+            }
+        }
+
+        assert_eq!(Percentage::zero().into_inner(), 0);
+        assert_eq!(Percentage::one().into_inner(), 1);
+    }
+
+    #[test]
+    fn test_bounded_without_validation() {
+        #[nutype(derive(Debug, PartialEq, Bounded))]
+        pub struct Offset(i16);
+
+        assert_eq!(Offset::min_value(), Offset::new(i16::MIN));
+        assert_eq!(Offset::max_value(), Offset::new(i16::MAX));
+    }
+
+    #[test]
+    fn test_bounded_with_validation() {
+        #[nutype(validate(greater = 0, less = 10), derive(Debug, Bounded))]
+        pub struct Digit(u32);
+
+        assert_eq!(Digit::min_value().into_inner(), 1);
+        assert_eq!(Digit::max_value().into_inner(), 9);
+
+        #[nutype(validate(greater_or_equal = -5, less_or_equal = 5), derive(Debug, Bounded))]
+        pub struct Balance(i32);
+
+        assert_eq!(Balance::min_value().into_inner(), -5);
+        assert_eq!(Balance::max_value().into_inner(), 5);
+
+        #[nutype(validate(greater_or_equal = 1), derive(Debug, Bounded))]
+        pub struct Count(u16);
+
+        assert_eq!(Count::min_value().into_inner(), 1);
+        assert_eq!(Count::max_value().into_inner(), u16::MAX);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "diesel-derive-newtype")]
+mod derive_diesel_derive_newtype {
+    use super::*;
+    use diesel::dsl::sql;
+    use diesel::prelude::*;
+    use diesel::sqlite::SqliteConnection;
+    use test_suite::prepare_database_table_for_type;
+
+    #[test]
+    fn test_diesel_derive_newtype() {
+        table! {
+            diesel_derive_newtype_test {
+                id -> Integer,
+                value -> Integer,
+            }
+        }
+        let mut conn = prepare_database_table_for_type!(diesel_derive_newtype_test, "INTEGER");
+
+        #[nutype(derive(Debug, DieselNewType))]
+        pub struct IntegerValue(i32);
+
+        #[derive(Debug, Insertable, Queryable, Selectable)]
+        #[diesel(table_name = diesel_derive_newtype_test)]
+        pub struct DieselDeriveNewtypeTest {
+            pub id: i32,
+            pub value: IntegerValue,
+        }
+
+        let obj = DieselDeriveNewtypeTest {
+            id: 1,
+            value: IntegerValue::new(123),
+        };
+
+        diesel::insert_into(diesel_derive_newtype_test::table)
+            .values(&obj)
+            .execute(&mut conn)
             .expect("Could not insert struct into database table");
         let inserted_obj = diesel_derive_newtype_test::table
             .select(DieselDeriveNewtypeTest::as_select())
@@ -827,4 +2557,231 @@ mod derive_diesel_derive_newtype {
 
         assert_eq!(inserted_obj.value.into_inner(), 123);
     }
+
+    #[test]
+    fn test_diesel_derive_newtype_with_validation() {
+        table! {
+            diesel_derive_newtype_validation_test {
+                id -> Integer,
+                value -> Integer,
+            }
+        }
+        let mut conn =
+            prepare_database_table_for_type!(diesel_derive_newtype_validation_test, "INTEGER");
+
+        #[nutype(
+            validate(greater_or_equal = 0, less_or_equal = 100),
+            derive(Debug, PartialEq, TryFrom, DieselNewType)
+        )]
+        pub struct Percent(i32);
+
+        #[derive(Debug, Insertable, Queryable, Selectable)]
+        #[diesel(table_name = diesel_derive_newtype_validation_test)]
+        pub struct DieselDeriveNewtypeValidationTest {
+            pub id: i32,
+            pub value: Percent,
+        }
+
+        let obj = DieselDeriveNewtypeValidationTest {
+            id: 1,
+            value: Percent::new(42).unwrap(),
+        };
+        diesel::insert_into(diesel_derive_newtype_validation_test::table)
+            .values(&obj)
+            .execute(&mut conn)
+            .expect("Could not insert struct into database table");
+
+        // An invalid value written to the database bypassing the newtype
+        diesel::sql_query("INSERT INTO diesel_derive_newtype_validation_test VALUES (2, 150)")
+            .execute(&mut conn)
+            .expect("Could not insert raw value into database table");
+
+        let valid_obj = diesel_derive_newtype_validation_test::table
+            .filter(diesel_derive_newtype_validation_test::id.eq(1))
+            .select(DieselDeriveNewtypeValidationTest::as_select())
+            .first(&mut conn)
+            .expect("Could not get struct from database table");
+        assert_eq!(valid_obj.value, Percent::new(42).unwrap());
+
+        let err = diesel_derive_newtype_validation_test::table
+            .filter(diesel_derive_newtype_validation_test::id.eq(2))
+            .select(DieselDeriveNewtypeValidationTest::as_select())
+            .first(&mut conn)
+            .unwrap_err();
+        assert!(matches!(
+            err,
+            diesel::result::Error::DeserializationError(_)
+        ));
+        assert!(err.to_string().contains("Percent is too big"));
+    }
+}
+
+#[cfg(test)]
+mod protobuf_message_conversion {
+    use super::*;
+
+    // The shape of a struct generated by `prost` for:
+    //
+    //     message Account {
+    //       int64 balance = 1;
+    //       uint32 age = 2;
+    //     }
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct AccountMessage {
+        pub balance: i64,
+        pub age: u32,
+    }
+
+    #[nutype(derive(Debug, Clone, Copy, PartialEq, Into, From))]
+    pub struct Balance(i64);
+
+    #[nutype(
+        validate(greater_or_equal = 18),
+        derive(Debug, Clone, Copy, PartialEq, Into, TryFrom)
+    )]
+    pub struct Age(u32);
+
+    #[derive(Debug, PartialEq)]
+    pub struct Account {
+        pub balance: Balance,
+        pub age: Age,
+    }
+
+    impl TryFrom<AccountMessage> for Account {
+        type Error = AgeError;
+
+        fn try_from(message: AccountMessage) -> Result<Self, Self::Error> {
+            Ok(Self {
+                balance: message.balance.into(),
+                age: message.age.try_into()?,
+            })
+        }
+    }
+
+    impl From<Account> for AccountMessage {
+        fn from(account: Account) -> Self {
+            Self {
+                balance: account.balance.into(),
+                age: account.age.into(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_message_to_domain_and_back() {
+        let message = AccountMessage {
+            balance: -42,
+            age: 30,
+        };
+        let account = Account::try_from(message.clone()).unwrap();
+        assert_eq!(account.balance.into_inner(), -42);
+        assert_eq!(account.age.into_inner(), 30);
+
+        assert_eq!(AccountMessage::from(account), message);
+    }
+
+    #[test]
+    fn test_invalid_message() {
+        // Unset scalar fields are decoded as zeros
+        let message = AccountMessage::default();
+        assert_eq!(
+            Account::try_from(message),
+            Err(AgeError::GreaterOrEqualViolated)
+        );
+    }
+}
+
+#[cfg(test)]
+mod bound_consts {
+    use super::*;
+
+    #[test]
+    fn test_inclusive_bounds() {
+        #[nutype(validate(greater_or_equal = -10, less_or_equal = 10))]
+        pub struct Offset(i32);
+
+        assert_eq!((Offset::MIN, Offset::MIN_EXCLUSIVE), (Some(-10), false));
+        assert_eq!((Offset::MAX, Offset::MAX_EXCLUSIVE), (Some(10), false));
+    }
+
+    #[test]
+    fn test_exclusive_bounds() {
+        #[nutype(validate(greater = 0, less = 1000))]
+        pub struct Millis(u16);
+
+        assert_eq!((Millis::MIN, Millis::MIN_EXCLUSIVE), (Some(0), true));
+        assert_eq!((Millis::MAX, Millis::MAX_EXCLUSIVE), (Some(1000), true));
+    }
+
+    const MAX_LEVEL: u8 = 5;
+
+    #[test]
+    fn test_range_and_constants() {
+        #[nutype(validate(range = 1..=MAX_LEVEL))]
+        pub struct Level(u8);
+
+        assert_eq!(Level::MIN, Some(1));
+        assert_eq!((Level::MAX, Level::MAX_EXCLUSIVE), (Some(MAX_LEVEL), false));
+    }
+
+    #[test]
+    fn test_without_bounds() {
+        #[nutype(validate(predicate = |n| n % 2 == 0))]
+        pub struct Even(i64);
+
+        assert_eq!(Even::MIN, None);
+        assert_eq!(Even::MAX, None);
+
+        #[nutype(sanitize(with = |n| n.abs()))]
+        pub struct Distance(i64);
+
+        assert_eq!(Distance::MIN, None);
+        assert_eq!(Distance::MAX, None);
+    }
+}
+
+#[cfg(test)]
+mod explicit_inner_type {
+    use super::*;
+
+    type Cents = u64;
+
+    #[test]
+    fn test_type_alias() {
+        #[nutype(inner = u64, validate(less_or_equal = 100), derive(Debug, Clone, Copy))]
+        pub struct Discount(Cents);
+
+        assert_eq!(Discount::new(50).unwrap().into_inner(), 50);
+        assert_eq!(Discount::MAX, Some(100));
+        assert!(Discount::new(101).is_err());
+    }
+}
+
+#[cfg(feature = "async")]
+mod new_async {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_new_async() {
+        #[nutype(
+            validate(
+                greater = 0,
+                async_with = |id| async move {
+                    if *id == 404 { Err("not found".to_string()) } else { Ok(()) }
+                },
+            ),
+            derive(Debug, PartialEq)
+        )]
+        pub struct UserId(u64);
+
+        assert_eq!(UserId::new_async(42).await.unwrap().into_inner(), 42);
+        assert_eq!(
+            UserId::new_async(0).await.unwrap_err(),
+            UserIdAsyncError::Validate(UserIdError::GreaterViolated)
+        );
+        assert_eq!(
+            UserId::new_async(404).await.unwrap_err(),
+            UserIdAsyncError::AsyncWithViolated("not found".to_string())
+        );
+    }
 }
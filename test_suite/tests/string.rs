@@ -33,6 +33,84 @@ mod sanitizers {
         assert_eq!(Name::new("Hello THERE").into_inner(), "HELLO THERE");
     }
 
+    #[test]
+    fn test_dedup_whitespace() {
+        #[nutype(sanitize(dedup_whitespace))]
+        pub struct Query(String);
+
+        assert_eq!(Query::new("").into_inner(), "");
+        assert_eq!(Query::new("rust  nutype").into_inner(), "rust nutype");
+        assert_eq!(Query::new("rust\t\tnutype").into_inner(), "rust nutype");
+        assert_eq!(Query::new("rust \n\r\n nutype").into_inner(), "rust nutype");
+        assert_eq!(
+            Query::new("rust\u{a0}\u{a0}nutype").into_inner(),
+            "rust nutype"
+        );
+
+        // Leading and trailing whitespaces are collapsed, but not removed
+        assert_eq!(Query::new("\t  rust  \n").into_inner(), " rust ");
+    }
+
+    #[test]
+    fn test_dedup_whitespace_with_trim_before_validation() {
+        #[nutype(
+            sanitize(trim, dedup_whitespace),
+            validate(len_char_max = 7),
+            derive(Debug)
+        )]
+        pub struct Query(String);
+
+        // The length is validated after the whitespaces are collapsed
+        assert_eq!(Query::new("  a    b    c  ").unwrap().into_inner(), "a b c");
+        assert_eq!(
+            Query::new("ab \t cd \t ef").unwrap_err(),
+            QueryError::LenCharMaxViolated
+        );
+    }
+
+    #[test]
+    fn test_truncate() {
+        #[nutype(sanitize(truncate(5)))]
+        pub struct Code(String);
+
+        assert_eq!(Code::new("").into_inner(), "");
+        assert_eq!(Code::new("abc").into_inner(), "abc");
+        assert_eq!(Code::new("abcde").into_inner(), "abcde");
+        assert_eq!(Code::new("abcdefgh").into_inner(), "abcde");
+
+        // Multi-byte characters are counted as chars and never split
+        assert_eq!(Code::new("привіт світ").into_inner(), "приві");
+        assert_eq!(Code::new("🦀🦀🦀🦀🦀🦀").into_inner(), "🦀🦀🦀🦀🦀");
+    }
+
+    #[test]
+    fn test_pad() {
+        #[nutype(sanitize(pad(5, '.')))]
+        pub struct Code(String);
+
+        assert_eq!(Code::new("").into_inner(), ".....");
+        assert_eq!(Code::new("ab").into_inner(), "ab...");
+        assert_eq!(Code::new("abcdefgh").into_inner(), "abcdefgh");
+        assert_eq!(Code::new("ї").into_inner(), "ї....");
+    }
+
+    const FIELD_WIDTH: usize = 8;
+    const FIELD_FILL: char = '·';
+
+    #[test]
+    fn test_truncate_and_pad_to_fixed_width() {
+        #[nutype(
+            sanitize(trim, truncate(FIELD_WIDTH), pad(FIELD_WIDTH, FIELD_FILL)),
+            validate(len_char_min = FIELD_WIDTH, len_char_max = FIELD_WIDTH),
+            derive(Debug)
+        )]
+        pub struct Field(String);
+
+        assert_eq!(Field::new("  Ada ").unwrap().into_inner(), "Ada·····");
+        assert_eq!(Field::new("Lovelace").unwrap().into_inner(), "Lovelace");
+        assert_eq!(Field::new("Grace Hopper").unwrap().into_inner(), "Grace Ho");
+    }
+
     #[cfg(test)]
     mod with {
         use super::*;
@@ -66,6 +144,66 @@ mod sanitizers {
         }
     }
 
+    #[cfg(test)]
+    mod try_with {
+        use super::*;
+
+        fn normalize_phone(raw: String) -> Result<String, String> {
+            let digits: String = raw.chars().filter(|c| !matches!(c, ' ' | '-')).collect();
+            if digits.chars().all(|c| c.is_ascii_digit()) {
+                Ok(digits)
+            } else {
+                Err(format!("unexpected characters in {raw:?}"))
+            }
+        }
+
+        #[test]
+        fn test_with_function() {
+            #[nutype(sanitize(try_with = normalize_phone), derive(Debug, PartialEq))]
+            pub struct Phone(String);
+
+            assert_eq!(Phone::new("0176 123-45").unwrap().into_inner(), "017612345");
+            assert_eq!(
+                Phone::new("0176 CALL-ME"),
+                Err(PhoneError::SanitizationFailed(
+                    "unexpected characters in \"0176 CALL-ME\"".to_string()
+                ))
+            );
+        }
+
+        #[test]
+        fn test_closure_with_any_display_error() {
+            #[nutype(
+                sanitize(trim, try_with = |s| s.parse::<u32>().map(|n| n.to_string())),
+                derive(Debug)
+            )]
+            pub struct Number(String);
+
+            assert_eq!(Number::new(" 007 ").unwrap().into_inner(), "7");
+            assert_eq!(
+                Number::new("seven").unwrap_err().to_string(),
+                "Number could not be sanitized: invalid digit found in string"
+            );
+        }
+
+        #[test]
+        fn test_runs_between_other_sanitizers_and_before_validators() {
+            #[nutype(
+                sanitize(trim, try_with = normalize_phone, truncate(5)),
+                validate(len_char_min = 5),
+                derive(Debug, PartialEq)
+            )]
+            pub struct Phone(String);
+
+            assert_eq!(Phone::new(" 0176 123 ").unwrap().into_inner(), "01761");
+            assert_eq!(Phone::new("12-3"), Err(PhoneError::LenCharMinViolated));
+            assert!(matches!(
+                Phone::new("12-x"),
+                Err(PhoneError::SanitizationFailed(_))
+            ));
+        }
+    }
+
     #[test]
     fn test_many_sanitizers() {
         #[nutype(sanitize(trim, uppercase, with = |s| s[1..=2].to_string()))]
@@ -105,6 +243,52 @@ mod validators {
         assert_eq!(Name::new("Антон").unwrap().into_inner(), "Антон");
     }
 
+    #[test]
+    fn test_len_utf16_max() {
+        #[nutype(validate(len_utf16_max = 4), derive(Debug, PartialEq))]
+        pub struct Nick(String);
+
+        assert_eq!(Nick::new("Anna").unwrap().into_inner(), "Anna");
+        assert_eq!(Nick::new("Anton"), Err(NickError::LenUtf16MaxViolated));
+
+        // Every Cyrillic char is 2 bytes, but only 1 UTF-16 code unit.
+        assert_eq!(Nick::new("Юля").unwrap().into_inner(), "Юля");
+
+        // Crab is outside of the Basic Multilingual Plane: 1 char, 2 UTF-16 code units, 4 bytes.
+        assert_eq!(Nick::new("ab🦀").unwrap().into_inner(), "ab🦀");
+        assert_eq!(Nick::new("🦀🦀🦀"), Err(NickError::LenUtf16MaxViolated));
+
+        assert_eq!(
+            Nick::new("Anton").unwrap_err().to_string(),
+            "Nick is too long. The value length must not exceed 4 UTF-16 code unit(s)."
+        );
+        assert_eq!(
+            nutype::ValidationError::constraint(&Nick::new("Anton").unwrap_err()),
+            "len_utf16_max"
+        );
+    }
+
+    #[test]
+    fn test_len_utf16_max_vs_len_char_max() {
+        #[nutype(validate(len_char_max = 3), derive(Debug, PartialEq))]
+        pub struct ByChars(String);
+
+        #[nutype(validate(len_utf16_max = 3), derive(Debug, PartialEq))]
+        pub struct ByUtf16(String);
+
+        // "𝄞" (musical symbol G clef) is 1 char, but 2 UTF-16 code units.
+        let value = "a𝄞";
+        assert_eq!(value.len(), 5);
+        assert_eq!(value.chars().count(), 2);
+        assert_eq!(value.encode_utf16().count(), 3);
+        assert!(ByChars::new(value).is_ok());
+        assert!(ByUtf16::new(value).is_ok());
+
+        let value = "a𝄞𝄞";
+        assert!(ByChars::new(value).is_ok());
+        assert_eq!(ByUtf16::new(value), Err(ByUtf16Error::LenUtf16MaxViolated));
+    }
+
     #[test]
     fn test_len_char_min() {
         #[nutype(validate(len_char_min = 6), derive(Debug, PartialEq))]
@@ -127,6 +311,51 @@ mod validators {
         assert_eq!(Name::new("Julia").unwrap().into_inner(), "Julia");
     }
 
+    #[test]
+    fn test_ascii() {
+        #[nutype(validate(ascii), derive(Debug, PartialEq))]
+        pub struct Slug(String);
+
+        assert_eq!(Slug::new("café"), Err(SlugError::AsciiViolated));
+        assert_eq!(Slug::new("hi 👋"), Err(SlugError::AsciiViolated));
+        assert_eq!(
+            Slug::new("hello world").unwrap().into_inner(),
+            "hello world"
+        );
+        assert_eq!(Slug::new("").unwrap().into_inner(), "");
+    }
+
+    #[test]
+    fn test_alphanumeric() {
+        #[nutype(validate(alphanumeric), derive(Debug, PartialEq))]
+        pub struct Word(String);
+
+        assert_eq!(
+            Word::new("hello world"),
+            Err(WordError::AlphanumericViolated)
+        );
+        assert_eq!(Word::new("hi👋"), Err(WordError::AlphanumericViolated));
+        assert_eq!(Word::new("a-b"), Err(WordError::AlphanumericViolated));
+        assert_eq!(Word::new("Straße42").unwrap().into_inner(), "Straße42");
+    }
+
+    #[test]
+    fn test_ascii_and_alphanumeric_with_length_and_sanitizers() {
+        #[nutype(
+            sanitize(trim, lowercase),
+            validate(not_empty, len_char_max = 8, ascii, alphanumeric),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Slug(String);
+
+        // Validators run after sanitizers, so surrounding spaces are fine
+        assert_eq!(Slug::new("  Rust2024 ").unwrap().into_inner(), "rust2024");
+        assert_eq!(Slug::new("   "), Err(SlugError::NotEmptyViolated));
+        assert_eq!(Slug::new("rustacean1"), Err(SlugError::LenCharMaxViolated));
+        assert_eq!(Slug::new("naïve"), Err(SlugError::AsciiViolated));
+        assert_eq!(Slug::new("a b"), Err(SlugError::AlphanumericViolated));
+    }
+
     #[test]
     fn test_many_validators() {
         #[nutype(validate(len_char_min = 3, len_char_max = 6), derive(Debug, PartialEq))]
@@ -171,6 +400,62 @@ mod validators {
             );
         }
 
+        #[test]
+        fn test_closure_with_explicit_type_receives_sanitized_str() {
+            #[nutype(
+                sanitize(trim, lowercase),
+                validate(predicate = |s: &str| s == s.trim() && !s.chars().any(char::is_uppercase)),
+                derive(Debug, PartialEq),
+            )]
+            pub struct Login(String);
+
+            assert_eq!(Login::new(" FOO ").unwrap().into_inner(), "foo");
+        }
+
+        #[test]
+        fn test_closure_with_string_ref() {
+            #[nutype(
+                sanitize(trim),
+                validate(predicate = |s: &String| !s.is_empty() && s.len() <= 5),
+                derive(Debug, PartialEq),
+            )]
+            pub struct Login(String);
+
+            assert_eq!(Login::new(" foo ").unwrap().into_inner(), "foo");
+            assert_eq!(Login::new("   "), Err(LoginError::PredicateViolated));
+            // `validate()` takes `&String` as well, since that's what the closure expects
+            assert_eq!(
+                Login::validate(&"foobar".to_string()),
+                Err(LoginError::PredicateViolated)
+            );
+        }
+
+        #[test]
+        fn test_closure_with_string_ref_receives_inner_string_without_copy() {
+            #[nutype(
+                validate(predicate = |s: &String| {
+                    SEEN_BY_PREDICATE.set(Some((s.as_ptr(), s.capacity())));
+                    true
+                }),
+            )]
+            pub struct Login(String);
+
+            let mut raw = String::with_capacity(64);
+            raw.push_str("ferris");
+            let raw_ptr = raw.as_ptr();
+
+            let login = Login::new(raw).unwrap();
+
+            // A copy would live elsewhere and have the capacity of its length
+            assert_eq!(SEEN_BY_PREDICATE.get(), Some((raw_ptr, 64)));
+            assert_eq!(login.as_str().as_ptr(), raw_ptr);
+        }
+
+        thread_local! {
+            static SEEN_BY_PREDICATE: std::cell::Cell<Option<(*const u8, usize)>> =
+                const { std::cell::Cell::new(None) };
+        }
+
         fn validate_email(val: &str) -> bool {
             val.contains('@')
         }
@@ -191,6 +476,109 @@ mod validators {
         }
     }
 
+    #[cfg(test)]
+    mod with_result {
+        use super::*;
+
+        fn validate_email(val: &str) -> Result<(), String> {
+            match val.split_once('@') {
+                None => Err("missing @".to_string()),
+                Some(("", _)) => Err("missing local part".to_string()),
+                Some((_, "")) => Err("missing domain".to_string()),
+                Some(_) => Ok(()),
+            }
+        }
+
+        #[test]
+        fn test_with_function() {
+            #[nutype(validate(with_result = validate_email), derive(Debug, PartialEq))]
+            pub struct Email(String);
+
+            assert_eq!(
+                Email::new("foo.bar.example"),
+                Err(EmailError::WithResultViolated("missing @".to_string()))
+            );
+            assert_eq!(
+                Email::new("@bar.example"),
+                Err(EmailError::WithResultViolated(
+                    "missing local part".to_string()
+                ))
+            );
+            assert_eq!(
+                Email::new("foo@bar.example").unwrap().into_inner(),
+                "foo@bar.example"
+            );
+        }
+
+        #[test]
+        fn test_closure_with_sanitizers() {
+            #[nutype(
+                sanitize(trim),
+                validate(not_empty, with_result = |s| {
+                    if s.chars().all(char::is_lowercase) {
+                        Ok(())
+                    } else {
+                        Err(format!("`{s}` must be lowercase"))
+                    }
+                }),
+                derive(Debug, PartialEq),
+            )]
+            pub struct Login(String);
+
+            assert_eq!(Login::new("  "), Err(LoginError::NotEmptyViolated));
+            assert_eq!(
+                Login::new(" Foo "),
+                Err(LoginError::WithResultViolated(
+                    "`Foo` must be lowercase".to_string()
+                ))
+            );
+            assert_eq!(Login::new(" foo ").unwrap().into_inner(), "foo");
+
+            let err = Login::new("Foo").unwrap_err();
+            assert_eq!(err.to_string(), "Login is invalid: `Foo` must be lowercase");
+        }
+
+        #[test]
+        fn test_closure_with_explicit_type_receives_sanitized_str() {
+            #[nutype(
+                sanitize(trim),
+                validate(with_result = |s: &str| {
+                    if s == s.trim() {
+                        Ok(())
+                    } else {
+                        Err(format!("`{s}` is not sanitized"))
+                    }
+                }),
+                derive(Debug, PartialEq),
+            )]
+            pub struct Login(String);
+
+            assert_eq!(Login::new(" foo ").unwrap().into_inner(), "foo");
+            assert_eq!(Login::validate("foo"), Ok(()));
+        }
+
+        #[test]
+        fn test_closure_with_string_ref() {
+            #[nutype(
+                validate(with_result = |login: &String| {
+                    if login.is_empty() {
+                        Err("login is empty".to_string())
+                    } else {
+                        Ok(())
+                    }
+                }),
+                derive(Debug, PartialEq),
+            )]
+            pub struct Login(String);
+
+            assert!(Login::new("foo").is_ok());
+            assert_eq!(
+                Login::new(""),
+                Err(LoginError::WithResultViolated("login is empty".to_string()))
+            );
+        }
+    }
+
     #[test]
     fn test_try_from_trait() {
         #[nutype(validate(not_empty), derive(Debug, PartialEq, TryFrom))]
@@ -208,6 +596,21 @@ mod validators {
         assert_eq!(Name::try_from("Tom").unwrap().into_inner(), "Tom");
     }
 
+    #[test]
+    // The point is to verify that `try_from()` is available.
+    #[allow(clippy::unnecessary_fallible_conversions)]
+    fn test_try_from_trait_with_from() {
+        #[nutype(derive(Debug, PartialEq, From, TryFrom))]
+        pub struct Name(String);
+
+        let name: Result<Name, std::convert::Infallible> = Name::try_from("Tom");
+        assert_eq!(name.unwrap(), Name::from("Tom"));
+        assert_eq!(
+            Name::try_from("Tom".to_string()).unwrap(),
+            Name::from("Tom")
+        );
+    }
+
     #[test]
     fn test_error() {
         fn ensure_type_implements_error<T: std::error::Error>() {}
@@ -226,426 +629,2693 @@ mod validators {
         assert_eq!(EmailError::NotEmptyViolated.to_string(), "Email is empty.");
     }
 
-    mod when_boundaries_defined_as_constants {
-        use super::*;
-
-        const MIN_LEN: usize = 3;
-        const MAX_LEN: usize = 10;
+    #[test]
+    fn test_starts_with() {
+        #[nutype(validate(starts_with = "sk_"), derive(Debug, PartialEq))]
+        pub struct SecretKey(String);
 
-        #[nutype(validate(len_char_min = MIN_LEN, len_char_max = MAX_LEN), derive(Debug))]
-        struct Login(String);
+        assert_eq!(
+            SecretKey::new("pk_123"),
+            Err(SecretKeyError::StartsWithViolated)
+        );
+        assert_eq!(SecretKey::new("sk_123").unwrap().into_inner(), "sk_123");
+        assert_eq!(
+            SecretKeyError::StartsWithViolated.to_string(),
+            "SecretKey does not start with \"sk_\"."
+        );
+    }
 
-        #[test]
-        fn test_boundaries_defined_as_constants() {
-            assert_eq!(
-                Login::new("ab").unwrap_err(),
-                LoginError::LenCharMinViolated,
-            );
-            assert_eq!(Login::new("abc").unwrap().into_inner(), "abc".to_string(),);
+    #[test]
+    fn test_ends_with() {
+        #[nutype(validate(ends_with = ".rs"), derive(Debug, PartialEq))]
+        pub struct RustFile(String);
 
-            assert_eq!(
-                Login::new("abcdefghijk").unwrap_err(),
-                LoginError::LenCharMaxViolated,
-            );
-            assert_eq!(
-                Login::new("abcdefghij").unwrap().into_inner(),
-                "abcdefghij".to_string(),
-            );
-        }
+        assert_eq!(
+            RustFile::new("main.py"),
+            Err(RustFileError::EndsWithViolated)
+        );
+        assert_eq!(RustFile::new("main.rs").unwrap().into_inner(), "main.rs");
     }
-}
 
-#[cfg(test)]
-mod complex {
-    use super::*;
+    #[test]
+    fn test_starts_with_any() {
+        #[nutype(validate(starts_with_any = ["sk_", "pk_"]), derive(Debug, PartialEq))]
+        pub struct ApiKey(String);
+
+        assert!(ApiKey::new("sk_123").is_ok());
+        assert!(ApiKey::new("pk_123").is_ok());
+        assert_eq!(
+            ApiKey::new("rk_123"),
+            Err(ApiKeyError::StartsWithAnyViolated)
+        );
+        assert_eq!(
+            ApiKeyError::StartsWithAnyViolated.to_string(),
+            "ApiKey does not start with any of [\"sk_\", \"pk_\"]."
+        );
+    }
 
     #[test]
-    fn test_sanitizers_and_validators() {
-        /// Some documentation for Name
-        /// goes here.
+    fn test_ends_with_any() {
         #[nutype(
-            sanitize(trim, with = |s| s.to_uppercase()),
-            validate(not_empty, len_char_max = 6),
+            validate(ends_with_any = [".png", ".jpg"]),
             derive(Debug, PartialEq)
         )]
-        pub struct Name(String);
+        pub struct ImageFile(String);
 
-        assert_eq!(Name::new("    "), Err(NameError::NotEmptyViolated));
+        assert_eq!(ImageFile::new("cat.png").unwrap().into_inner(), "cat.png");
+        assert!(ImageFile::new("cat.jpg").is_ok());
         assert_eq!(
-            Name::new("Willy Brandt"),
-            Err(NameError::LenCharMaxViolated)
+            ImageFile::new("cat.gif"),
+            Err(ImageFileError::EndsWithAnyViolated)
+        );
+        assert_eq!(
+            ImageFileError::EndsWithAnyViolated.to_string(),
+            "ImageFile does not end with any of [\".png\", \".jpg\"]."
         );
-        assert_eq!(Name::new("   Brandt  ").unwrap().into_inner(), "BRANDT");
-    }
-}
-
-#[cfg(test)]
-mod visibility {
-    mod encapsulated {
-        use nutype::nutype;
-
-        #[nutype(sanitize(lowercase))]
-        pub struct Email(String);
     }
 
     #[test]
-    fn test_public_visibility() {
-        let email = encapsulated::Email::new("FOO@bar.com");
-        assert_eq!(email.into_inner(), "foo@bar.com");
-    }
-}
+    fn test_ends_with_any_is_case_sensitive() {
+        #[nutype(validate(ends_with_any = [".png", ".jpg"]), derive(Debug))]
+        pub struct ImageFile(String);
 
-#[cfg(test)]
-mod derives {
-    use super::*;
-    use test_suite::test_helpers::traits::*;
+        assert!(ImageFile::new("CAT.PNG").is_err());
 
-    #[test]
-    fn test_without_validation() {
-        #[nutype(derive(Debug, Hash, From, FromStr, Borrow, Clone))]
-        pub struct Name(String);
+        // Lowercase first to accept any case
+        #[nutype(
+            sanitize(lowercase),
+            validate(ends_with_any = [".png", ".jpg"]),
+            derive(Debug)
+        )]
+        pub struct LowercaseImageFile(String);
 
-        should_implement_hash::<Name>();
-        should_implement_debug::<Name>();
-        should_implement_from::<Name, String>();
-        should_implement_from::<Name, &str>();
-        should_implement_from_str::<Name>();
-        should_implement_borrow::<Name, str>();
-        should_implement_borrow::<Name, String>();
-        should_implement_clone::<Name>();
+        assert_eq!(
+            LowercaseImageFile::new("CAT.PNG").unwrap().into_inner(),
+            "cat.png"
+        );
     }
 
     #[test]
-    fn test_with_validaiton() {
+    fn test_ends_with_any_overlapping_suffixes() {
         #[nutype(
-            validate(not_empty),
-            derive(Debug, Hash, TryFrom, FromStr, Borrow, Clone)
+            validate(ends_with_any = [".gz", ".tar.gz"], starts_with_any = ["backup"]),
+            derive(Debug, PartialEq)
         )]
-        pub struct Name(String);
+        pub struct Archive(String);
 
-        should_implement_hash::<Name>();
-        should_implement_debug::<Name>();
-        should_implement_try_from::<Name, String>();
-        should_implement_try_from::<Name, &str>();
-        should_implement_from_str::<Name>();
-        should_implement_borrow::<Name, str>();
-        should_implement_borrow::<Name, String>();
-        should_implement_clone::<Name>();
+        assert!(Archive::new("backup.tar.gz").is_ok());
+        assert!(Archive::new("backup.gz").is_ok());
+        assert_eq!(
+            Archive::new("backup.tar"),
+            Err(ArchiveError::EndsWithAnyViolated)
+        );
+        assert_eq!(
+            Archive::new("data.tar.gz"),
+            Err(ArchiveError::StartsWithAnyViolated)
+        );
     }
 
     #[test]
-    fn test_trait_into() {
-        #[nutype(sanitize(trim), derive(Into))]
-        pub struct Name(String);
+    fn test_contains() {
+        #[nutype(validate(contains = '@'), derive(Debug, PartialEq))]
+        pub struct Email(String);
 
-        let name = Name::new("  Anna");
-        let name: String = name.into();
-        assert_eq!(name, "Anna")
+        assert_eq!(Email::new("example.com"), Err(EmailError::ContainsViolated));
+        assert_eq!(Email::new("a@b").unwrap().into_inner(), "a@b");
     }
 
     #[test]
-    fn test_trait_from_str() {
-        #[nutype(derive(From))]
-        pub struct Name(String);
+    fn test_contains_only() {
+        #[nutype(validate(contains_only = "0123456789abcdef"), derive(Debug, PartialEq))]
+        pub struct HexId(String);
 
-        let name = Name::from("Anna");
-        assert_eq!(name.into_inner(), "Anna")
+        assert_eq!(HexId::new("deadbeef42").unwrap().into_inner(), "deadbeef42");
+        assert_eq!(HexId::new("").unwrap().into_inner(), "");
+        // The first illegal character is reported
+        assert_eq!(
+            HexId::new("beefXYZ"),
+            Err(HexIdError::ContainsOnlyViolated('X'))
+        );
+        assert_eq!(
+            HexId::new("12 34").unwrap_err().to_string(),
+            "HexId contains an illegal character ' '."
+        );
     }
 
     #[test]
-    fn test_trait_from_string() {
-        #[nutype(derive(From))]
-        pub struct Name(String);
+    fn test_contains_only_is_case_sensitive() {
+        #[nutype(
+            sanitize(lowercase),
+            validate(contains_only = "0123456789abcdef"),
+            derive(Debug, PartialEq)
+        )]
+        pub struct LowerHexId(String);
 
-        let name = Name::from("Anna".to_string());
-        assert_eq!(name.into_inner(), "Anna")
+        #[nutype(validate(contains_only = "0123456789abcdef"), derive(Debug, PartialEq))]
+        pub struct HexId(String);
+
+        assert_eq!(
+            HexId::new("DeadBeef"),
+            Err(HexIdError::ContainsOnlyViolated('D'))
+        );
+        // Sanitizers run before the validation
+        assert_eq!(
+            LowerHexId::new("DeadBeef").unwrap().into_inner(),
+            "deadbeef"
+        );
     }
 
+    const CYRILLIC_VOWELS: &str = "аеєиіїоуюя";
+
     #[test]
-    fn test_trait_as_ref() {
-        #[nutype(derive(AsRef))]
-        pub struct Name(String);
+    fn test_contains_only_with_unicode() {
+        #[nutype(validate(contains_only = CYRILLIC_VOWELS), derive(Debug, PartialEq))]
+        pub struct Vowels(String);
 
-        let name = Name::new("Anna");
-        let name_ref: &str = name.as_ref();
-        assert_eq!(name_ref, "Anna")
+        assert_eq!(
+            Vowels::new("їжа"),
+            Err(VowelsError::ContainsOnlyViolated('ж'))
+        );
+        assert_eq!(Vowels::new("юяєї").unwrap().into_inner(), "юяєї");
+        // Latin "a" looks the same as Cyrillic "а", but it's a different character
+        assert_eq!(
+            Vowels::new("а a"),
+            Err(VowelsError::ContainsOnlyViolated(' '))
+        );
+        assert_eq!(
+            Vowels::new("aа"),
+            Err(VowelsError::ContainsOnlyViolated('a'))
+        );
+        // Emoji made of multiple chars are checked char by char
+        assert_eq!(
+            Vowels::new("о👍"),
+            Err(VowelsError::ContainsOnlyViolated('👍'))
+        );
     }
 
     #[test]
-    fn test_trait_deref() {
-        #[nutype(derive(Deref))]
-        pub struct Name(String);
-
-        let name = Name::new("Anna");
+    fn test_starts_with_ends_with_and_contains_after_sanitizers() {
+        #[nutype(
+            sanitize(trim, lowercase),
+            validate(starts_with = "key:", ends_with = ";", contains = "user"),
+            derive(Debug, PartialEq)
+        )]
+        pub struct UserKey(String);
 
-        // Let's do something with deref-coercion:
-        assert_eq!(name.len(), 4);
-        assert_eq!(name.is_empty(), false);
+        assert_eq!(
+            UserKey::new("  KEY:USER:42;  ").unwrap().into_inner(),
+            "key:user:42;"
+        );
+        assert_eq!(
+            UserKey::new("id:user:42;"),
+            Err(UserKeyError::StartsWithViolated)
+        );
+        assert_eq!(
+            UserKey::new("key:user:42"),
+            Err(UserKeyError::EndsWithViolated)
+        );
+        assert_eq!(
+            UserKey::new("key:admin:42;"),
+            Err(UserKeyError::ContainsViolated)
+        );
     }
 
     #[test]
-    fn test_trait_borrow_str() {
-        use std::borrow::Borrow;
+    fn test_one_of() {
+        #[nutype(validate(one_of = ["USD", "EUR", "GBP"]), derive(Debug, PartialEq))]
+        pub struct Currency(String);
 
-        #[nutype(derive(Borrow))]
-        pub struct Name(String);
-
-        let name = Name::new("Anna");
-        let name_borrowed: &str = name.borrow();
-        assert_eq!(name_borrowed, "Anna");
+        assert_eq!(Currency::new("EUR").unwrap().into_inner(), "EUR");
+        assert_eq!(Currency::new("CHF"), Err(CurrencyError::OneOfViolated));
+        assert_eq!(
+            Currency::new("CHF").unwrap_err().to_string(),
+            r#"Currency is not one of the allowed values ["USD", "EUR", "GBP"]."#
+        );
     }
 
     #[test]
-    fn test_trait_borrow_string() {
-        use std::borrow::Borrow;
-
-        #[nutype(derive(Borrow))]
-        pub struct Name(String);
+    fn test_one_of_is_case_sensitive() {
+        #[nutype(validate(one_of = ["USD", "EUR"]), derive(Debug, PartialEq))]
+        pub struct Currency(String);
 
-        let name = Name::new("Anna");
-        let name_borrowed: &String = name.borrow();
-        assert_eq!(name_borrowed, "Anna");
+        assert_eq!(Currency::new("usd"), Err(CurrencyError::OneOfViolated));
+        assert_eq!(Currency::new(" USD"), Err(CurrencyError::OneOfViolated));
     }
 
     #[test]
-    fn test_trait_try_from_str() {
-        #[nutype(validate(not_empty), derive(Debug, TryFrom))]
-        pub struct Name(String);
+    fn test_one_of_after_lowercase_sanitizer() {
+        #[nutype(
+            sanitize(trim, lowercase),
+            validate(one_of = ["usd", "eur"]),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Currency(String);
 
-        let name = Name::try_from("Anna").unwrap();
-        assert_eq!(name.into_inner(), "Anna");
+        assert_eq!(Currency::new(" USD ").unwrap().into_inner(), "usd");
+        assert_eq!(Currency::new("Eur").unwrap().into_inner(), "eur");
+        assert_eq!(Currency::new("GBP"), Err(CurrencyError::OneOfViolated));
 
-        let error = Name::try_from("").unwrap_err();
-        assert_eq!(error, NameError::NotEmptyViolated);
+        // The allowed values are not sanitized, so an uppercase value can never pass.
+        #[nutype(sanitize(lowercase), validate(one_of = ["USD"]), derive(Debug, PartialEq))]
+        pub struct Unreachable(String);
+
+        assert_eq!(
+            Unreachable::new("USD"),
+            Err(UnreachableError::OneOfViolated)
+        );
     }
 
     #[test]
-    fn test_trait_try_from_string() {
-        #[nutype(validate(not_empty), derive(Debug, TryFrom))]
-        pub struct Name(String);
-
-        let name = Name::try_from("Anna".to_string()).unwrap();
-        assert_eq!(name.into_inner(), "Anna");
-
-        let error = Name::try_from("".to_string()).unwrap_err();
-        assert_eq!(error, NameError::NotEmptyViolated);
+    fn test_min_and_max() {
+        #[nutype(validate(min = "v1.0", max = "v2.9"), derive(Debug, PartialEq))]
+        pub struct Version(String);
+
+        assert_eq!(Version::new("v1.0").unwrap().into_inner(), "v1.0");
+        assert_eq!(Version::new("v2.9").unwrap().into_inner(), "v2.9");
+        assert_eq!(Version::new("v1.5-beta").unwrap().into_inner(), "v1.5-beta");
+        assert_eq!(Version::new("v0.9"), Err(VersionError::MinViolated));
+        assert_eq!(Version::new("v3.0"), Err(VersionError::MaxViolated));
+        assert_eq!(
+            Version::new("v0.9").unwrap_err().to_string(),
+            r#"Version is too small. The value must not be lexicographically less than "v1.0"."#
+        );
+        assert_eq!(
+            Version::new("v3.0").unwrap_err().to_string(),
+            r#"Version is too big. The value must not be lexicographically greater than "v2.9"."#
+        );
     }
 
     #[test]
-    fn test_trait_display() {
-        #[nutype(derive(Display))]
-        pub struct Name(String);
-
-        let name = Name::new("Serhii");
-        assert_eq!(name.to_string(), "Serhii");
+    fn test_min_and_max_compare_bytes_not_numbers_or_case() {
+        #[nutype(validate(min = "v1.0", max = "v2.9"), derive(Debug, PartialEq))]
+        pub struct Version(String);
+
+        // Digits are compared one by one, so "v10.0" goes before "v2.9".
+        assert_eq!(Version::new("v10.0").unwrap().into_inner(), "v10.0");
+        // A prefix goes before any longer string.
+        assert_eq!(Version::new("v1"), Err(VersionError::MinViolated));
+        assert_eq!(Version::new("v2.9.1"), Err(VersionError::MaxViolated));
+        // Uppercase ASCII letters go before the lowercase ones.
+        assert_eq!(Version::new("V1.5"), Err(VersionError::MinViolated));
+        assert_eq!(Version::new("w1.0"), Err(VersionError::MaxViolated));
     }
 
     #[cfg(test)]
-    mod trait_default {
+    mod combinators {
         use super::*;
+        use std::sync::atomic::{AtomicUsize, Ordering};
 
         #[test]
-        fn test_default_without_validation() {
-            #[nutype(default = "Anonymous", derive(Default))]
-            pub struct Name(String);
+        fn test_any_of() {
+            #[nutype(
+                validate(any_of(starts_with = "A", starts_with = "B")),
+                derive(Debug, PartialEq)
+            )]
+            pub struct Code(String);
+
+            assert_eq!(Code::new("A1").unwrap().into_inner(), "A1");
+            assert_eq!(Code::new("B2").unwrap().into_inner(), "B2");
+            assert_eq!(Code::new("C3"), Err(CodeError::AnyOfViolated));
+            assert_eq!(
+                Code::new("C3").unwrap_err().to_string(),
+                "Code does not pass any of the `any_of` validators."
+            );
+        }
 
-            assert_eq!(Name::default().into_inner(), "Anonymous");
+        #[test]
+        fn test_all_of() {
+            #[nutype(
+                validate(all_of(starts_with = "#", len_char_max = 7), not_empty),
+                derive(Debug, PartialEq)
+            )]
+            pub struct Color(String);
+
+            assert_eq!(Color::new("#ff0000").unwrap().into_inner(), "#ff0000");
+            assert_eq!(Color::new("ff0000"), Err(ColorError::AllOfViolated));
+            assert_eq!(Color::new("#ff00000"), Err(ColorError::AllOfViolated));
+            assert_eq!(Color::new(""), Err(ColorError::AllOfViolated));
+            assert_eq!(
+                Color::new("red").unwrap_err().to_string(),
+                "Color does not pass all of the `all_of` validators."
+            );
         }
 
         #[test]
-        fn test_default_with_validation_when_valid() {
-            #[nutype(validate(len_char_min = 5), default = "Anonymous", derive(Default))]
-            pub struct Name(String);
+        fn test_nested_combinators() {
+            #[nutype(
+                validate(any_of(
+                    one_of = ["localhost"],
+                    all_of(ends_with = ".com", len_char_min = 5),
+                )),
+                derive(Debug, PartialEq)
+            )]
+            pub struct Host(String);
+
+            assert!(Host::new("localhost").is_ok());
+            assert!(Host::new("rust.com").is_ok());
+            assert_eq!(Host::new(".com"), Err(HostError::AnyOfViolated));
+            assert_eq!(Host::new("rust.org"), Err(HostError::AnyOfViolated));
+        }
 
-            assert_eq!(Name::default().into_inner(), "Anonymous");
+        static FIRST_CALLS: AtomicUsize = AtomicUsize::new(0);
+        static SECOND_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+        fn is_short(s: &str) -> bool {
+            FIRST_CALLS.fetch_add(1, Ordering::SeqCst);
+            s.len() < 4
+        }
+
+        fn is_numeric(s: &str) -> bool {
+            SECOND_CALLS.fetch_add(1, Ordering::SeqCst);
+            s.chars().all(|c| c.is_ascii_digit())
         }
 
+        #[nutype(
+            validate(any_of(predicate = is_short, predicate = is_numeric)),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Pin(String);
+
         #[test]
-        #[should_panic(expected = "Default value for type `Name` is invalid")]
-        fn test_default_with_validation_when_invalid() {
-            #[nutype(validate(len_char_min = 5), default = "Nope", derive(Default))]
-            pub struct Name(String);
+        fn test_any_of_short_circuits() {
+            assert!(Pin::new("abc").is_ok());
+            assert_eq!(FIRST_CALLS.load(Ordering::SeqCst), 1);
+            assert_eq!(SECOND_CALLS.load(Ordering::SeqCst), 0);
+
+            assert!(Pin::new("123456").is_ok());
+            assert_eq!(FIRST_CALLS.load(Ordering::SeqCst), 2);
+            assert_eq!(SECOND_CALLS.load(Ordering::SeqCst), 1);
+
+            assert_eq!(Pin::new("abcdef"), Err(PinError::AnyOfViolated));
+            assert_eq!(FIRST_CALLS.load(Ordering::SeqCst), 3);
+            assert_eq!(SECOND_CALLS.load(Ordering::SeqCst), 2);
+        }
+    }
+
+    mod when_min_and_max_defined_as_constants {
+        use super::*;
+
+        const FIRST: &str = "B";
+        const LAST: &str = "Y";
 
-            Name::default();
+        #[nutype(validate(min = FIRST, max = LAST), derive(Debug, PartialEq))]
+        pub struct Grade(String);
+
+        #[test]
+        fn test_min_and_max_defined_as_constants() {
+            assert_eq!(Grade::new("C").unwrap().into_inner(), "C");
+            assert_eq!(Grade::new("A"), Err(GradeError::MinViolated));
+            assert_eq!(Grade::new("Z"), Err(GradeError::MaxViolated));
+            assert_eq!(Grade::new("b"), Err(GradeError::MaxViolated));
         }
     }
 
-    #[cfg(feature = "serde")]
-    mod serialization {
+    #[test]
+    fn test_non_exhaustive_error() {
+        #[nutype(non_exhaustive_error, validate(not_empty), derive(Debug, PartialEq))]
+        pub struct Email(String);
+
+        assert_eq!(Email::new(""), Err(EmailError::NotEmptyViolated));
+        assert_eq!(Email::new("a@b").unwrap().into_inner(), "a@b");
+    }
+
+    #[test]
+    fn test_validation_error_trait() {
+        use nutype::ValidationError;
+
+        #[nutype(
+            validate(contains_only = "abc", any_of(starts_with = "a", ends_with = "c")),
+            derive(Debug)
+        )]
+        pub struct Code(String);
+
+        let errors: Vec<Box<dyn ValidationError>> = vec![
+            Box::new(Code::new("abd").unwrap_err()),
+            Box::new(Code::new("bab").unwrap_err()),
+        ];
+        let constraints: Vec<&str> = errors.iter().map(|err| err.constraint()).collect();
+        assert_eq!(constraints, ["contains_only", "any_of"]);
+        assert!(errors.iter().all(|err| err.kind() == "Code"));
+    }
+
+    #[test]
+    fn test_collect_errors() {
+        #[nutype(
+            collect_errors,
+            validate(len_char_min = 3, len_char_max = 8, ascii, ends_with = ".rs"),
+            derive(Debug, PartialEq)
+        )]
+        pub struct FileName(String);
+
+        assert_eq!(
+            FileName::new("ü"),
+            Err(FileNameError::Multiple(vec![
+                FileNameError::LenCharMinViolated,
+                FileNameError::AsciiViolated,
+                FileNameError::EndsWithViolated,
+            ]))
+        );
+        assert_eq!(
+            FileName::new("main.rs.bak"),
+            Err(FileNameError::Multiple(vec![
+                FileNameError::LenCharMaxViolated,
+                FileNameError::EndsWithViolated,
+            ]))
+        );
+        assert_eq!(
+            FileName::new("main.py"),
+            Err(FileNameError::EndsWithViolated)
+        );
+        assert_eq!(FileName::new("main.rs").unwrap().into_inner(), "main.rs");
+    }
+
+    mod when_boundaries_defined_as_constants {
         use super::*;
 
-        mod json_format {
-            use super::*;
+        const MIN_LEN: usize = 3;
+        const MAX_LEN: usize = 10;
 
-            #[test]
-            fn test_trait_serialize() {
-                #[nutype(derive(Serialize))]
-                pub struct Email(String);
+        #[nutype(validate(len_char_min = MIN_LEN, len_char_max = MAX_LEN), derive(Debug))]
+        struct Login(String);
 
-                let email = Email::new("my@example.com");
-                let email_json = serde_json::to_string(&email).unwrap();
-                assert_eq!(email_json, "\"my@example.com\"");
-            }
+        #[test]
+        fn test_boundaries_defined_as_constants() {
+            assert_eq!(
+                Login::new("ab").unwrap_err(),
+                LoginError::LenCharMinViolated,
+            );
+            assert_eq!(Login::new("abc").unwrap().into_inner(), "abc".to_string(),);
 
-            #[test]
-            fn test_trait_deserialize_without_validation() {
-                #[nutype(derive(Deserialize))]
-                pub struct NaiveEmail(String);
+            assert_eq!(
+                Login::new("abcdefghijk").unwrap_err(),
+                LoginError::LenCharMaxViolated,
+            );
+            assert_eq!(
+                Login::new("abcdefghij").unwrap().into_inner(),
+                "abcdefghij".to_string(),
+            );
+        }
+    }
 
-                {
-                    let email: NaiveEmail = serde_json::from_str("\"foobar\"").unwrap();
-                    assert_eq!(email.into_inner(), "foobar");
-                }
-            }
+    mod len_char_range {
+        use super::*;
 
-            #[test]
-            fn test_trait_deserialize_with_validation() {
-                #[nutype(
-                    validate(predicate = |address| address.contains('@') ),
-                    derive(Deserialize),
-                )]
-                pub struct NaiveEmail(String);
+        const MAX_LEN: usize = 5;
 
-                {
-                    let res: Result<NaiveEmail, _> = serde_json::from_str("\"foobar\"");
-                    assert!(res.is_err());
-                }
+        #[test]
+        fn test_inclusive_range() {
+            #[nutype(validate(len_char = 3..=5), derive(Debug))]
+            struct Login(String);
 
-                {
-                    let email: NaiveEmail = serde_json::from_str("\"foo@bar.com\"").unwrap();
-                    assert_eq!(email.into_inner(), "foo@bar.com");
-                }
-            }
+            assert_eq!(
+                Login::new("ab").unwrap_err(),
+                LoginError::LenCharMinViolated
+            );
+            assert_eq!(Login::new("abc").unwrap().into_inner(), "abc");
+            assert_eq!(Login::new("abcde").unwrap().into_inner(), "abcde");
+            assert_eq!(
+                Login::new("abcdef").unwrap_err(),
+                LoginError::LenCharMaxViolated
+            );
         }
 
-        mod ron_format {
-            use super::*;
+        #[test]
+        fn test_exclusive_range() {
+            #[nutype(validate(len_char = 3..5), derive(Debug))]
+            struct Login(String);
 
-            #[test]
-            fn test_ron_roundtrip() {
-                #[nutype(derive(Serialize, Deserialize, PartialEq, Debug))]
-                pub struct Email(String);
+            assert_eq!(
+                Login::new("ab").unwrap_err(),
+                LoginError::LenCharMinViolated
+            );
+            assert_eq!(Login::new("abcd").unwrap().into_inner(), "abcd");
+            assert_eq!(
+                Login::new("abcde").unwrap_err(),
+                LoginError::LenCharMaxViolated
+            );
+        }
 
-                let email = Email::new("me@example.com");
+        #[test]
+        fn test_exclusive_range_with_constant() {
+            #[nutype(validate(len_char = 1..MAX_LEN), derive(Debug))]
+            struct Login(String);
 
-                let serialized = ron::to_string(&email).unwrap();
-                let deserialized: Email = ron::from_str(&serialized).unwrap();
+            assert_eq!(Login::new("").unwrap_err(), LoginError::LenCharMinViolated);
+            assert_eq!(Login::new("abcd").unwrap().into_inner(), "abcd");
+            assert_eq!(
+                Login::new("abcde").unwrap_err(),
+                LoginError::LenCharMaxViolated
+            );
+        }
 
-                assert_eq!(deserialized, email);
+        #[test]
+        fn test_half_open_ranges() {
+            #[nutype(validate(len_char = 2..), derive(Debug))]
+            struct Login(String);
+
+            assert_eq!(Login::new("a").unwrap_err(), LoginError::LenCharMinViolated);
+            assert_eq!(Login::new("a".repeat(100)).unwrap().into_inner().len(), 100);
+
+            #[nutype(validate(len_char = ..=2), derive(Debug))]
+            struct Code(String);
+
+            assert_eq!(Code::new("").unwrap().into_inner(), "");
+            assert_eq!(Code::new("abc").unwrap_err(), CodeError::LenCharMaxViolated);
+        }
+    }
+}
+
+#[cfg(test)]
+mod complex {
+    use super::*;
+
+    #[test]
+    fn test_sanitizers_and_validators() {
+        /// Some documentation for Name
+        /// goes here.
+        #[nutype(
+            sanitize(trim, with = |s| s.to_uppercase()),
+            validate(not_empty, len_char_max = 6),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Name(String);
+
+        assert_eq!(Name::new("    "), Err(NameError::NotEmptyViolated));
+        assert_eq!(
+            Name::new("Willy Brandt"),
+            Err(NameError::LenCharMaxViolated)
+        );
+        assert_eq!(Name::new("   Brandt  ").unwrap().into_inner(), "BRANDT");
+    }
+}
+
+#[cfg(test)]
+mod raw_validators {
+    use super::*;
+
+    #[test]
+    fn test_raw_validator_runs_before_sanitizers() {
+        #[nutype(
+            sanitize(trim),
+            validate(raw(len_char_max = 10), not_empty),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Title(String);
+
+        // Trimming would hide the overflow
+        assert_eq!(
+            Title::new("   Dune    "),
+            Err(TitleError::LenCharMaxViolated)
+        );
+        assert_eq!(Title::new("   Dune  ").unwrap().into_inner(), "Dune");
+        assert_eq!(Title::new("        "), Err(TitleError::NotEmptyViolated));
+    }
+
+    #[test]
+    fn test_only_raw_validators() {
+        #[nutype(
+            sanitize(lowercase),
+            validate(raw(ascii, len_char_min = 2)),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Code(String);
+
+        assert_eq!(Code::new("AB").unwrap().into_inner(), "ab");
+        assert_eq!(Code::new("A"), Err(CodeError::LenCharMinViolated));
+        assert_eq!(Code::new("ÄB"), Err(CodeError::AsciiViolated));
+    }
+
+    #[test]
+    fn test_collect_errors() {
+        #[nutype(
+            sanitize(trim),
+            validate(raw(ascii, len_char_max = 5), not_empty),
+            collect_errors,
+            derive(Debug, PartialEq)
+        )]
+        pub struct Tag(String);
+
+        assert_eq!(
+            Tag::new("  ä   "),
+            Err(TagError::Multiple(vec![
+                TagError::AsciiViolated,
+                TagError::LenCharMaxViolated,
+            ]))
+        );
+        assert_eq!(Tag::new("     "), Err(TagError::NotEmptyViolated));
+    }
+
+    #[test]
+    fn test_validate_fn_runs_raw_validators() {
+        #[nutype(
+            sanitize(trim),
+            validate(raw(len_char_max = 5), len_char_min = 2),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Nick(String);
+
+        assert_eq!(Nick::validate("abc"), Ok(()));
+        assert_eq!(Nick::validate("abcdef"), Err(NickError::LenCharMaxViolated));
+        assert_eq!(Nick::validate("a"), Err(NickError::LenCharMinViolated));
+    }
+}
+
+#[cfg(test)]
+mod visibility {
+    mod encapsulated {
+        use nutype::nutype;
+
+        #[nutype(sanitize(lowercase))]
+        pub struct Email(String);
+
+        #[nutype(inner_vis = pub(crate), sanitize(trim), derive(Debug))]
+        pub struct Username(String);
+    }
+
+    #[test]
+    fn test_public_visibility() {
+        let email = encapsulated::Email::new("FOO@bar.com");
+        assert_eq!(email.into_inner(), "foo@bar.com");
+    }
+
+    #[test]
+    fn test_crate_visible_inner_field() {
+        let mut username = encapsulated::Username::new(" ferris ");
+        assert_eq!(username.0, "ferris");
+
+        username.0.push_str("_the_crab");
+        assert_eq!(username.into_inner(), "ferris_the_crab");
+    }
+}
+
+#[cfg(test)]
+mod derives {
+    use super::*;
+    use test_suite::test_helpers::traits::*;
+
+    #[test]
+    fn test_without_validation() {
+        #[nutype(derive(Debug, Hash, From, FromStr, Borrow, Clone))]
+        pub struct Name(String);
+
+        should_implement_hash::<Name>();
+        should_implement_debug::<Name>();
+        should_implement_from::<Name, String>();
+        should_implement_from::<Name, &str>();
+        should_implement_from_str::<Name>();
+        should_implement_borrow::<Name, str>();
+        should_implement_borrow::<Name, String>();
+        should_implement_clone::<Name>();
+    }
+
+    #[test]
+    fn test_with_validaiton() {
+        #[nutype(
+            validate(not_empty),
+            derive(Debug, Hash, TryFrom, FromStr, Borrow, Clone)
+        )]
+        pub struct Name(String);
+
+        should_implement_hash::<Name>();
+        should_implement_debug::<Name>();
+        should_implement_try_from::<Name, String>();
+        should_implement_try_from::<Name, &str>();
+        should_implement_from_str::<Name>();
+        should_implement_borrow::<Name, str>();
+        should_implement_borrow::<Name, String>();
+        should_implement_clone::<Name>();
+    }
+
+    #[test]
+    fn test_trait_into() {
+        #[nutype(sanitize(trim), derive(Into))]
+        pub struct Name(String);
+
+        let name = Name::new("  Anna");
+        let name: String = name.into();
+        assert_eq!(name, "Anna")
+    }
+
+    #[test]
+    fn test_trait_into_inner() {
+        #[nutype(sanitize(trim), derive(IntoInner))]
+        pub struct Name(String);
+
+        let name: String = Name::new("  Anna").into();
+        assert_eq!(name, "Anna");
+        assert_eq!(String::from(Name::new("Bob ")), "Bob");
+    }
+
+    #[test]
+    fn test_trait_from_str() {
+        #[nutype(derive(From))]
+        pub struct Name(String);
+
+        let name = Name::from("Anna");
+        assert_eq!(name.into_inner(), "Anna")
+    }
+
+    #[test]
+    fn test_trait_from_string() {
+        #[nutype(derive(From))]
+        pub struct Name(String);
+
+        let name = Name::from("Anna".to_string());
+        assert_eq!(name.into_inner(), "Anna")
+    }
+
+    #[test]
+    fn test_trait_from_iterator() {
+        #[nutype(sanitize(trim, uppercase), derive(FromIterator))]
+        pub struct Code(String);
+
+        let code: Code = " ab-c ".chars().filter(|c| *c != '-').collect();
+        assert_eq!(code.into_inner(), "ABC");
+
+        let code: Code = ["x", "y", "z "].into_iter().collect();
+        assert_eq!(code.into_inner(), "XYZ");
+    }
+
+    #[test]
+    fn test_trait_as_ref() {
+        #[nutype(derive(AsRef))]
+        pub struct Name(String);
+
+        let name = Name::new("Anna");
+        let name_ref: &str = name.as_ref();
+        assert_eq!(name_ref, "Anna")
+    }
+
+    #[test]
+    fn test_trait_as_ref_satisfies_impl_as_ref_str() {
+        #[nutype(derive(AsRef))]
+        pub struct FileName(String);
+
+        fn count_dots(value: impl AsRef<str>) -> usize {
+            value.as_ref().matches('.').count()
+        }
+
+        let file_name = FileName::new("archive.tar.gz");
+        assert_eq!(count_dots(&file_name), 2);
+        assert_eq!(count_dots(file_name), 2);
+
+        // APIs that take `AsRef<Path>` or `AsRef<OsStr>` accept the `&str` returned by `as_ref()`.
+        let file_name = FileName::new("Cargo.toml");
+        let path = std::path::Path::new(file_name.as_ref());
+        assert_eq!(path.extension().unwrap(), "toml");
+    }
+
+    #[test]
+    fn test_trait_deref() {
+        #[nutype(derive(Deref))]
+        pub struct Name(String);
+
+        let name = Name::new("Anna");
+
+        // Let's do something with deref-coercion:
+        assert_eq!(name.len(), 4);
+        assert!(!name.is_empty());
+    }
+
+    #[test]
+    fn test_trait_eq_inner() {
+        #[nutype(derive(Debug, PartialEq, EqInner), sanitize(trim))]
+        pub struct Username(String);
+
+        let username = Username::new("  alice ");
+        assert_eq!(username, "alice");
+        assert_eq!(username, *"alice");
+        assert_eq!(username, "alice".to_string());
+        assert_ne!(username, "  alice ");
+
+        // PartialEq between two newtypes is not affected
+        assert_eq!(username, Username::new("alice"));
+    }
+
+    #[test]
+    fn test_trait_ord_inner() {
+        #[nutype(derive(Debug, PartialEq, PartialOrd, OrdInner))]
+        pub struct Username(String);
+
+        let username = Username::new("bob");
+        assert!(username > "alice");
+        assert!(username < *"carol");
+        let inner = String::from("bob");
+        assert!(username <= inner);
+        assert_eq!(username, "bob");
+
+        // PartialOrd between two newtypes is not affected
+        assert!(username < Username::new("carol"));
+    }
+
+    #[test]
+    fn test_trait_nutype() {
+        use nutype::Nutype;
+
+        #[nutype(derive(Debug, PartialEq, Nutype), sanitize(trim), validate(not_empty))]
+        pub struct Name(String);
+
+        fn parse<T: Nutype<Inner = String>>(value: &str) -> Result<T, T::Error> {
+            T::new(value.to_string())
+        }
+
+        let name: Name = parse("  Anna ").unwrap();
+        assert_eq!(name.into_inner(), "Anna");
+        assert_eq!(parse::<Name>("  "), Err(NameError::NotEmptyViolated));
+    }
+
+    #[test]
+    fn test_trait_borrow_str() {
+        use std::borrow::Borrow;
+
+        #[nutype(derive(Borrow))]
+        pub struct Name(String);
+
+        let name = Name::new("Anna");
+        let name_borrowed: &str = name.borrow();
+        assert_eq!(name_borrowed, "Anna");
+    }
+
+    #[test]
+    fn test_trait_borrow_string() {
+        use std::borrow::Borrow;
+
+        #[nutype(derive(Borrow))]
+        pub struct Name(String);
+
+        let name = Name::new("Anna");
+        let name_borrowed: &String = name.borrow();
+        assert_eq!(name_borrowed, "Anna");
+    }
+
+    #[test]
+    fn test_trait_try_from_str() {
+        #[nutype(validate(not_empty), derive(Debug, TryFrom))]
+        pub struct Name(String);
+
+        let name = Name::try_from("Anna").unwrap();
+        assert_eq!(name.into_inner(), "Anna");
+
+        let error = Name::try_from("").unwrap_err();
+        assert_eq!(error, NameError::NotEmptyViolated);
+    }
+
+    #[test]
+    fn test_trait_try_from_string() {
+        #[nutype(validate(not_empty), derive(Debug, TryFrom))]
+        pub struct Name(String);
+
+        let name = Name::try_from("Anna".to_string()).unwrap();
+        assert_eq!(name.into_inner(), "Anna");
+
+        let error = Name::try_from("".to_string()).unwrap_err();
+        assert_eq!(error, NameError::NotEmptyViolated);
+    }
+
+    #[test]
+    fn test_string_input_conversions_share_error() {
+        #[nutype(
+            sanitize(trim),
+            validate(not_empty, len_char_max = 5),
+            derive(Debug, PartialEq, TryFrom, FromStr)
+        )]
+        pub struct Name(String);
+
+        let from_string: Result<Name, NameError> = Name::try_from(" Anna ".to_string());
+        let from_str: Result<Name, NameError> = Name::try_from(" Anna ");
+        let parsed: Result<Name, NameError> = " Anna ".parse::<Name>();
+        assert_eq!(from_string, Name::new("Anna"));
+        assert_eq!(from_str, Name::new("Anna"));
+        assert_eq!(parsed, Name::new("Anna"));
+
+        let errors = [
+            Name::try_from("   ".to_string()).unwrap_err(),
+            Name::try_from("   ").unwrap_err(),
+            "   ".parse::<Name>().unwrap_err(),
+        ];
+        for error in errors {
+            assert_eq!(error, NameError::NotEmptyViolated);
+            assert_eq!(error.to_string(), "Name is empty.");
+        }
+
+        let errors = [
+            Name::try_from("Annabel".to_string()).unwrap_err(),
+            Name::try_from("Annabel").unwrap_err(),
+            "Annabel".parse::<Name>().unwrap_err(),
+        ];
+        for error in errors {
+            assert_eq!(error, NameError::LenCharMaxViolated);
+            assert_eq!(
+                error.to_string(),
+                "Name is too long. The value length must be less than 5 character(s)."
+            );
+        }
+    }
+
+    #[test]
+    fn test_trait_display() {
+        #[nutype(derive(Display))]
+        pub struct Name(String);
+
+        let name = Name::new("Serhii");
+        assert_eq!(name.to_string(), "Serhii");
+    }
+
+    #[test]
+    fn test_trait_display_with_format() {
+        #[nutype(derive(Display), display = "Name({:?})")]
+        pub struct Name(String);
+
+        let name = Name::new("Serhii");
+        assert_eq!(name.to_string(), r#"Name("Serhii")"#);
+    }
+
+    #[test]
+    fn test_trait_display_respects_width_and_precision() {
+        #[nutype(derive(Display))]
+        pub struct Name(String);
+
+        let name = Name::new("Serhii");
+        assert_eq!(format!("[{name:>8}]"), "[  Serhii]");
+        assert_eq!(format!("[{name:-<8}]"), "[Serhii--]");
+        assert_eq!(format!("[{name:.3}]"), "[Ser]");
+    }
+
+    #[test]
+    fn test_trait_debug() {
+        #[nutype(derive(Debug))]
+        pub struct Username(String);
+
+        let username = Username::new("bob");
+        assert_eq!(format!("{username:?}"), r#"Username("bob")"#);
+    }
+
+    #[test]
+    fn test_trait_debug_with_transparent_debug() {
+        #[nutype(validate(not_empty), derive(Debug), transparent_debug)]
+        pub struct Username(String);
+
+        let username = Username::new("bob").unwrap();
+        assert_eq!(format!("{username:?}"), r#""bob""#);
+        assert_eq!(format!("{username:#?}"), r#""bob""#);
+        assert_eq!(format!("{:?}", Some(username)), r#"Some("bob")"#);
+    }
+
+    #[cfg(test)]
+    mod trait_default {
+        use super::*;
+
+        #[test]
+        fn test_default_without_validation() {
+            #[nutype(default = "Anonymous", derive(Default))]
+            pub struct Name(String);
+
+            assert_eq!(Name::default().into_inner(), "Anonymous");
+        }
+
+        #[test]
+        fn test_default_with_validation_when_valid() {
+            #[nutype(validate(len_char_min = 5), default = "Anonymous", derive(Default))]
+            pub struct Name(String);
+
+            assert_eq!(Name::default().into_inner(), "Anonymous");
+        }
+
+        #[test]
+        #[should_panic(expected = "Default value for type `Name` is invalid")]
+        fn test_default_with_validation_when_invalid() {
+            #[nutype(validate(len_char_min = 5), default = "Nope", derive(Default))]
+            pub struct Name(String);
+
+            let _ = Name::default();
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    mod serialization {
+        use super::*;
+
+        mod json_format {
+            use super::*;
+
+            #[test]
+            fn test_trait_serialize() {
+                #[nutype(derive(Serialize))]
+                pub struct Email(String);
+
+                let email = Email::new("my@example.com");
+                let email_json = serde_json::to_string(&email).unwrap();
+                assert_eq!(email_json, "\"my@example.com\"");
+            }
+
+            #[test]
+            fn test_trait_deserialize_without_validation() {
+                #[nutype(derive(Deserialize))]
+                pub struct NaiveEmail(String);
+
+                {
+                    let email: NaiveEmail = serde_json::from_str("\"foobar\"").unwrap();
+                    assert_eq!(email.into_inner(), "foobar");
+                }
+            }
+
+            #[test]
+            fn test_trait_deserialize_with_validation() {
+                #[nutype(
+                    validate(predicate = |address| address.contains('@') ),
+                    derive(Deserialize),
+                )]
+                pub struct NaiveEmail(String);
+
+                {
+                    let res: Result<NaiveEmail, _> = serde_json::from_str("\"foobar\"");
+                    assert!(res.is_err());
+                }
+
+                {
+                    let email: NaiveEmail = serde_json::from_str("\"foo@bar.com\"").unwrap();
+                    assert_eq!(email.into_inner(), "foo@bar.com");
+                }
+            }
+
+            #[cfg(feature = "serde_json")]
+            #[test]
+            fn test_into_json_value() {
+                #[nutype(validate(not_empty), derive(Serialize))]
+                pub struct Email(String);
+
+                let email = Email::new("my@example.com").unwrap();
+                let value: serde_json::Value = email.into();
+                assert_eq!(
+                    value,
+                    serde_json::Value::String("my@example.com".to_string())
+                );
+                assert_eq!(
+                    serde_json::json!({ "email": Email::new("a@b.c").unwrap() }),
+                    serde_json::json!({ "email": "a@b.c" })
+                );
+            }
+
+            #[cfg(feature = "serde_json")]
+            #[test]
+            fn test_into_json_value_with_object_repr() {
+                #[nutype(derive(Serialize), serde_repr = object)]
+                pub struct Email(String);
+
+                let value = serde_json::Value::from(Email::new("my@example.com"));
+                assert_eq!(value, serde_json::json!({ "value": "my@example.com" }));
+            }
+        }
+
+        mod ron_format {
+            use super::*;
+
+            #[test]
+            fn test_ron_roundtrip() {
+                #[nutype(derive(Serialize, Deserialize, PartialEq, Debug))]
+                pub struct Email(String);
+
+                let email = Email::new("me@example.com");
+
+                let serialized = ron::to_string(&email).unwrap();
+                let deserialized: Email = ron::from_str(&serialized).unwrap();
+
+                assert_eq!(deserialized, email);
+            }
+        }
+
+        mod message_pack_format {
+            use super::*;
+
+            #[test]
+            fn test_rmp_roundtrip() {
+                #[nutype(derive(Serialize, Deserialize, PartialEq, Debug))]
+                pub struct Email(String);
+
+                let email = Email::new("me@example.com");
+
+                let bytes = rmp_serde::to_vec(&email).unwrap();
+                let deserialized: Email = rmp_serde::from_slice(&bytes).unwrap();
+
+                assert_eq!(deserialized, email);
+            }
+        }
+
+        mod object_repr {
+            use super::*;
+
+            #[test]
+            fn test_json_object() {
+                #[nutype(
+                    validate(not_empty),
+                    serde_repr = object,
+                    derive(Serialize, Deserialize, PartialEq, Debug)
+                )]
+                pub struct Username(String);
+
+                let username = Username::new("ferris").unwrap();
+                let json = serde_json::to_string(&username).unwrap();
+                assert_eq!(json, r#"{"value":"ferris"}"#);
+
+                let deserialized: Username = serde_json::from_str(&json).unwrap();
+                assert_eq!(deserialized, username);
+
+                // Unknown fields are ignored
+                let deserialized: Username =
+                    serde_json::from_str(r#"{"kind":"user","value":"ferris"}"#).unwrap();
+                assert_eq!(deserialized, username);
+
+                let err = serde_json::from_str::<Username>(r#"{"value":""}"#).unwrap_err();
+                assert!(err.to_string().contains("Expected valid Username"));
+
+                let err = serde_json::from_str::<Username>(r#"{}"#).unwrap_err();
+                assert!(err.to_string().contains("missing field `value`"));
+
+                // The transparent representation is not accepted
+                assert!(serde_json::from_str::<Username>(r#""ferris""#).is_err());
+            }
+
+            #[test]
+            fn test_custom_field() {
+                #[nutype(
+                    serde_repr = object(field = "name"),
+                    derive(Serialize, Deserialize, PartialEq, Debug)
+                )]
+                pub struct Username(String);
+
+                let username = Username::new("ferris");
+                let json = serde_json::to_string(&username).unwrap();
+                assert_eq!(json, r#"{"name":"ferris"}"#);
+                assert_eq!(serde_json::from_str::<Username>(&json).unwrap(), username);
+            }
+
+            #[test]
+            fn test_explicit_transparent() {
+                #[nutype(serde_repr = transparent, derive(Serialize))]
+                pub struct Username(String);
+
+                let json = serde_json::to_string(&Username::new("ferris")).unwrap();
+                assert_eq!(json, r#""ferris""#);
+            }
+
+            #[test]
+            fn test_ron_and_rmp_roundtrip() {
+                #[nutype(
+                    serde_repr = object,
+                    derive(Serialize, Deserialize, PartialEq, Debug)
+                )]
+                pub struct Username(String);
+
+                let username = Username::new("ferris");
+
+                let serialized = ron::to_string(&username).unwrap();
+                assert_eq!(ron::from_str::<Username>(&serialized).unwrap(), username);
+
+                // MessagePack encodes structs as arrays by default
+                let bytes = rmp_serde::to_vec(&username).unwrap();
+                assert_eq!(rmp_serde::from_slice::<Username>(&bytes).unwrap(), username);
+
+                let bytes = rmp_serde::to_vec_named(&username).unwrap();
+                assert_eq!(rmp_serde::from_slice::<Username>(&bytes).unwrap(), username);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod sanitize_then_return_changed {
+    use super::*;
+
+    #[test]
+    fn test_new_reporting_with_validation() {
+        #[nutype(
+            sanitize_then_return_changed,
+            sanitize(trim, lowercase),
+            validate(not_empty),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Email(String);
+
+        let (email, changed) = Email::new_reporting("  Foo@Bar.com ").unwrap();
+        assert_eq!(email.into_inner(), "foo@bar.com");
+        assert!(changed);
+
+        let (email, changed) = Email::new_reporting("foo@bar.com").unwrap();
+        assert_eq!(email.into_inner(), "foo@bar.com");
+        assert!(!changed);
+
+        assert_eq!(
+            Email::new_reporting("   "),
+            Err(EmailError::NotEmptyViolated)
+        );
+    }
+
+    #[test]
+    fn test_new_reporting_without_validation() {
+        #[nutype(sanitize_then_return_changed, sanitize(trim))]
+        pub struct Comment(String);
+
+        let (comment, changed) = Comment::new_reporting(" hi ".to_string());
+        assert_eq!(comment.into_inner(), "hi");
+        assert!(changed);
+
+        let (_comment, changed) = Comment::new_reporting("hi");
+        assert!(!changed);
+    }
+
+    #[test]
+    fn test_count_records_that_needed_cleanup() {
+        #[nutype(sanitize_then_return_changed, sanitize(trim))]
+        pub struct Name(String);
+
+        let records = ["Alice", " Bob", "Carol ", "Dave"];
+        let cleaned_up = records
+            .into_iter()
+            .map(Name::new_reporting)
+            .filter(|(_, changed)| *changed)
+            .count();
+        assert_eq!(cleaned_up, 2);
+    }
+}
+
+#[cfg(test)]
+mod new_fn {
+    use super::*;
+
+    #[test]
+    fn test_new_fn() {
+        #[nutype(
+            new_fn = try_new,
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, PartialEq, TryFrom, FromStr)
+        )]
+        pub struct Name(String);
+
+        assert_eq!(Name::try_new("  "), Err(NameError::NotEmptyViolated));
+        assert_eq!(Name::try_new(" Anna ").unwrap().into_inner(), "Anna");
+        assert_eq!(Name::try_from("Anna"), Name::try_new("Anna"));
+        assert_eq!("Anna".parse::<Name>(), Name::try_new("Anna"));
+    }
+
+    #[test]
+    fn test_skip_new() {
+        #[nutype(
+            skip_new,
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, PartialEq, TryFrom, AsRef)
+        )]
+        pub struct Username(String);
+
+        assert_eq!(
+            Username::try_from("  "),
+            Err(UsernameError::NotEmptyViolated)
+        );
+        assert_eq!(Username::try_from(" alice ").unwrap().as_ref(), "alice");
+    }
+}
+
+#[cfg(test)]
+mod from_env {
+    use super::*;
+
+    #[test]
+    fn test_from_env() {
+        #[nutype(
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, PartialEq, FromStr),
+            from_env
+        )]
+        pub struct Host(String);
+
+        std::env::set_var("NUTYPE_TEST_STRING_HOST", " localhost ");
+        assert_eq!(
+            Host::from_env("NUTYPE_TEST_STRING_HOST").unwrap(),
+            Host::new("localhost").unwrap()
+        );
+
+        std::env::set_var("NUTYPE_TEST_STRING_HOST_BLANK", "  ");
+        let err = Host::from_env("NUTYPE_TEST_STRING_HOST_BLANK").unwrap_err();
+        assert!(matches!(
+            err,
+            HostFromEnvError::Validate {
+                error: HostError::NotEmptyViolated,
+                ..
+            }
+        ));
+        assert_eq!(
+            err.to_string(),
+            "Environment variable `NUTYPE_TEST_STRING_HOST_BLANK` is not a valid Host: Host is empty."
+        );
+
+        assert!(matches!(
+            Host::from_env("NUTYPE_TEST_STRING_HOST_MISSING"),
+            Err(HostFromEnvError::Missing { .. })
+        ));
+    }
+
+    #[test]
+    fn test_from_env_without_validation() {
+        #[nutype(derive(Debug, PartialEq, FromStr), from_env)]
+        pub struct Greeting(String);
+
+        std::env::set_var("NUTYPE_TEST_STRING_GREETING", "Hello");
+        assert_eq!(
+            Greeting::from_env("NUTYPE_TEST_STRING_GREETING").unwrap(),
+            Greeting::new("Hello")
+        );
+    }
+}
+
+#[cfg(test)]
+mod context {
+    use super::*;
+
+    pub struct Settings {
+        pub max_bio_len: usize,
+        pub banned_words: Vec<&'static str>,
+    }
+
+    #[nutype(
+        context = Settings,
+        sanitize(trim),
+        validate(
+            not_empty,
+            predicate = |bio, settings| bio.chars().count() <= settings.max_bio_len,
+            with_result = |bio: &str, settings: &Settings| {
+                match settings.banned_words.iter().find(|word| bio.contains(**word)) {
+                    Some(word) => Err(format!("contains banned word `{word}`")),
+                    None => Ok(()),
+                }
+            },
+        ),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Bio(String);
+
+    #[test]
+    fn test_new_with_context() {
+        let settings = Settings {
+            max_bio_len: 10,
+            banned_words: vec!["spam"],
+        };
+        let bio = Bio::new_with_context("  Rustacean ", &settings).unwrap();
+        assert_eq!(bio.as_ref(), "Rustacean");
+
+        assert_eq!(
+            Bio::new_with_context("   ", &settings),
+            Err(BioError::NotEmptyViolated)
+        );
+        assert_eq!(
+            Bio::new_with_context("Rustacean from Kyiv", &settings),
+            Err(BioError::PredicateViolated)
+        );
+        assert_eq!(
+            Bio::new_with_context("spam spam", &settings),
+            Err(BioError::WithResultViolated(
+                "contains banned word `spam`".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_the_same_value_with_different_contexts() {
+        let strict = Settings {
+            max_bio_len: 5,
+            banned_words: vec![],
+        };
+        let relaxed = Settings {
+            max_bio_len: 50,
+            banned_words: vec![],
+        };
+        assert!(Bio::new_with_context("Rustacean", &strict).is_err());
+        assert!(Bio::new_with_context("Rustacean", &relaxed).is_ok());
+
+        assert_eq!(
+            Bio::validate("Rustacean", &strict),
+            Err(BioError::PredicateViolated)
+        );
+        assert_eq!(Bio::validate("Rustacean", &relaxed), Ok(()));
+    }
+
+    fn is_allowed(name: &str, settings: &Settings) -> bool {
+        !settings.banned_words.contains(&name)
+    }
+
+    #[test]
+    fn test_with_renamed_constructor_and_path() {
+        #[nutype(
+            context = Settings,
+            new_fn = try_new,
+            validate(predicate = is_allowed),
+            derive(Debug)
+        )]
+        pub struct Name(String);
+
+        let settings = Settings {
+            max_bio_len: 0,
+            banned_words: vec!["root"],
+        };
+        assert!(Name::try_new_with_context("alice", &settings).is_ok());
+        assert!(Name::try_new_with_context("root", &settings).is_err());
+    }
+}
+
+#[cfg(test)]
+mod as_cow {
+    use super::*;
+    use std::borrow::Cow;
+
+    #[test]
+    fn test_as_cow() {
+        #[nutype(sanitize(lowercase))]
+        pub struct Email(String);
+
+        let email = Email::new("Foo@Example.com");
+        let cow: Cow<'_, str> = email.as_cow();
+        assert!(matches!(cow, Cow::Borrowed("foo@example.com")));
+    }
+}
+
+#[cfg(test)]
+mod into_inner {
+    use super::*;
+
+    #[test]
+    fn test_into_inner_and_inner() {
+        #[nutype(sanitize(trim), validate(not_empty))]
+        pub struct Username(String);
+
+        let username = Username::new("  ferris ").unwrap();
+        let inner: &String = username.inner();
+        assert_eq!(inner, "ferris");
+        let inner: String = username.into_inner();
+        assert_eq!(inner, "ferris");
+    }
+}
+
+#[cfg(test)]
+mod inner_views {
+    use super::*;
+
+    #[test]
+    fn test_as_str_and_as_bytes() {
+        #[nutype(sanitize(trim), validate(not_empty))]
+        pub struct Username(String);
+
+        let username = Username::new("  ferris ").unwrap();
+        assert_eq!(username.as_str(), "ferris");
+        assert_eq!(username.as_bytes(), b"ferris");
+    }
+}
+
+#[cfg(test)]
+mod validate_fn {
+    use super::*;
+
+    #[test]
+    fn test_validate_without_sanitizing() {
+        #[nutype(sanitize(trim), validate(len_char_min = 3), derive(Debug, PartialEq))]
+        pub struct Username(String);
+
+        assert_eq!(Username::validate("ferris"), Ok(()));
+        assert_eq!(
+            Username::validate("ab"),
+            Err(UsernameError::LenCharMinViolated)
+        );
+
+        // Sanitizers are not applied, so the whitespaces count
+        assert_eq!(Username::validate("ab "), Ok(()));
+        assert!(Username::new("ab ").is_err());
+
+        // The value is only borrowed
+        let raw = String::from("ferris");
+        assert_eq!(Username::validate(&raw), Ok(()));
+        assert_eq!(Username::new(raw).unwrap().into_inner(), "ferris");
+    }
+}
+
+#[cfg(test)]
+mod custom_functions_by_path {
+    use super::*;
+
+    mod name_rules {
+        pub fn sanitize(name: String) -> String {
+            name.trim().to_lowercase()
+        }
+
+        pub fn is_valid(name: &str) -> bool {
+            name.chars().all(char::is_alphabetic)
+        }
+
+        pub fn validate(name: &str) -> Result<(), String> {
+            if name.len() <= 8 {
+                Ok(())
+            } else {
+                Err(format!("`{name}` is longer than 8 bytes"))
+            }
+        }
+    }
+
+    fn is_not_reserved(name: &str) -> bool {
+        name != "admin"
+    }
+
+    #[test]
+    fn test_paths_to_functions_in_module() {
+        #[nutype(
+            sanitize(with = name_rules::sanitize),
+            validate(predicate = name_rules::is_valid, with_result = name_rules::validate),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Login(String);
+
+        assert_eq!(Login::new(" Ferris ").unwrap().into_inner(), "ferris");
+        assert_eq!(Login::new("r2d2"), Err(LoginError::PredicateViolated));
+        assert_eq!(
+            Login::new("rustacean"),
+            Err(LoginError::WithResultViolated(
+                "`rustacean` is longer than 8 bytes".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn test_paths_relative_to_crate_and_parent_module() {
+        #[nutype(
+            sanitize(with = crate::custom_functions_by_path::name_rules::sanitize),
+            validate(predicate = super::custom_functions_by_path::is_not_reserved),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Login(String);
+
+        assert_eq!(Login::new(" Ferris ").unwrap().into_inner(), "ferris");
+        assert_eq!(Login::new("Admin"), Err(LoginError::PredicateViolated));
+    }
+
+    #[test]
+    fn test_paths_and_closures_are_interchangeable() {
+        #[nutype(
+            sanitize(with = name_rules::sanitize),
+            validate(predicate = is_not_reserved),
+            derive(Debug, PartialEq)
+        )]
+        pub struct ByPath(String);
+
+        #[nutype(
+            sanitize(with = |name| name.trim().to_lowercase()),
+            validate(predicate = |name| name != "admin"),
+            derive(Debug, PartialEq)
+        )]
+        pub struct ByClosure(String);
+
+        for raw in [" Ferris ", "ADMIN", "bob"] {
+            assert_eq!(
+                ByPath::new(raw).map(ByPath::into_inner),
+                ByClosure::new(raw)
+                    .map(ByClosure::into_inner)
+                    .map_err(|_| ByPathError::PredicateViolated),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod closures_with_outer_items {
+    use super::*;
+    use std::collections::HashSet;
+    use std::sync::LazyLock;
+
+    static RESERVED_NAMES: LazyLock<HashSet<&'static str>> =
+        LazyLock::new(|| ["admin", "root", "system"].into_iter().collect());
+
+    fn is_reserved(name: &str) -> bool {
+        RESERVED_NAMES.contains(name)
+    }
+
+    #[test]
+    fn test_closure_with_static() {
+        #[nutype(
+            sanitize(trim, lowercase),
+            validate(predicate = |name| !RESERVED_NAMES.contains(name)),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Login(String);
+
+        assert_eq!(Login::new(" Ferris ").unwrap().into_inner(), "ferris");
+        assert_eq!(Login::new("ROOT"), Err(LoginError::PredicateViolated));
+    }
+
+    #[test]
+    fn test_closure_with_paths_to_outer_items() {
+        #[nutype(
+            validate(
+                predicate = |name| !self::RESERVED_NAMES.contains(name),
+                with_result = |name| match crate::closures_with_outer_items::is_reserved(name) {
+                    true => Err(format!("`{name}` is reserved")),
+                    false => Ok(()),
+                },
+            ),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Login(String);
+
+        assert!(Login::new("ferris").is_ok());
+        assert_eq!(Login::new("admin"), Err(LoginError::PredicateViolated));
+    }
+
+    mod nested {
+        use nutype::nutype;
+
+        #[nutype(
+            validate(predicate = |name| !super::RESERVED_NAMES.contains(name)),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Login(String);
+
+        #[nutype(
+            sanitize(with = |name| if super::is_reserved(&name) { String::new() } else { name }),
+            validate(not_empty),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Nickname(String);
+    }
+
+    #[test]
+    fn test_closure_with_path_to_parent_module() {
+        use nested::{Login, LoginError, Nickname, NicknameError};
+
+        assert!(Login::new("ferris").is_ok());
+        assert_eq!(Login::new("system"), Err(LoginError::PredicateViolated));
+
+        assert!(Nickname::new("ferris").is_ok());
+        assert_eq!(Nickname::new("root"), Err(NicknameError::NotEmptyViolated));
+    }
+}
+
+#[cfg(test)]
+mod modify {
+    use super::*;
+
+    #[test]
+    fn test_modify_with_validation() {
+        #[nutype(sanitize(trim), validate(not_empty), derive(Debug, PartialEq))]
+        pub struct Name(String);
+
+        let mut name = Name::new("Anna").unwrap();
+
+        name.modify(|s| s.push_str(" Maria  ")).unwrap();
+        assert_eq!(name.as_cow(), "Anna Maria");
+
+        // Invalid value is not committed
+        assert_eq!(name.modify(|s| s.clear()), Err(NameError::NotEmptyViolated));
+        assert_eq!(name.into_inner(), "Anna Maria");
+    }
+
+    #[test]
+    fn test_modify_without_validation() {
+        #[nutype(sanitize(lowercase))]
+        pub struct Tag(String);
+
+        let mut tag = Tag::new("rust");
+        tag.modify(|s| s.push_str("-LANG"));
+        assert_eq!(tag.into_inner(), "rust-lang");
+    }
+}
+
+#[cfg(test)]
+mod builder {
+    use super::*;
+
+    #[test]
+    fn test_builder() {
+        #[nutype(
+            sanitize(trim),
+            validate(len_char_max = 10),
+            builder,
+            derive(Debug, PartialEq)
+        )]
+        pub struct Name(String);
+
+        let name = Name::builder("  Anna")
+            .update(|s| s.push_str(" Maria "))
+            .build()
+            .unwrap();
+        assert_eq!(name.as_str(), "Anna Maria");
+
+        assert_eq!(
+            Name::builder("Anna")
+                .update(|s| s.push_str(" Maria Luisa"))
+                .build(),
+            Err(NameError::LenCharMaxViolated)
+        );
+    }
+}
+
+#[cfg(test)]
+mod map {
+    use super::*;
+
+    #[test]
+    fn test_try_map() {
+        #[nutype(sanitize(trim), validate(not_empty), derive(Debug, PartialEq))]
+        pub struct Name(String);
+
+        let name = Name::new("anna").unwrap();
+        let name = name
+            .try_map(|s| format!(" {} Maria ", s.to_uppercase()))
+            .unwrap();
+        assert_eq!(name.as_str(), "ANNA Maria");
+
+        assert_eq!(
+            name.try_map(|_| String::new()),
+            Err(NameError::NotEmptyViolated)
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        #[nutype(sanitize(lowercase), derive(Debug, PartialEq))]
+        pub struct Tag(String);
+
+        let tag = Tag::new("rust").map(|s| s + "-LANG");
+        assert_eq!(tag, Tag::new("rust-lang"));
+    }
+}
+
+#[cfg(test)]
+mod eq_case_insensitive {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[nutype(
+        eq = case_insensitive,
+        validate(not_empty),
+        derive(Debug, Clone, PartialEq, Eq, Hash, EqInner),
+    )]
+    pub struct Email(String);
+
+    #[test]
+    fn test_eq() {
+        let email = Email::new("Foo@Example.com").unwrap();
+        assert_eq!(email, Email::new("foo@example.com").unwrap());
+        assert_ne!(email, Email::new("bar@example.com").unwrap());
+
+        // The inner value is kept as it is
+        assert_eq!(email.into_inner(), "Foo@Example.com");
+    }
+
+    #[test]
+    fn test_hash_is_consistent_with_eq() {
+        let emails: HashSet<Email> = ["foo@example.com", "FOO@example.com", "bar@example.com"]
+            .into_iter()
+            .map(|email| Email::new(email).unwrap())
+            .collect();
+        assert_eq!(emails.len(), 2);
+        assert!(emails.contains(&Email::new("Foo@Example.COM").unwrap()));
+    }
+
+    #[test]
+    fn test_eq_inner() {
+        let email = Email::new("Foo@Example.com").unwrap();
+        assert_eq!(email, "foo@example.com");
+        assert_eq!(email, *"FOO@EXAMPLE.COM");
+        assert_eq!(email, "foo@EXAMPLE.com".to_string());
+        assert_ne!(email, "bar@example.com");
+    }
+}
+
+mod ord_case_insensitive {
+    use super::*;
+    use std::cmp::Ordering;
+    use std::collections::BTreeSet;
+
+    #[nutype(
+        eq = case_insensitive,
+        ord = case_insensitive,
+        derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)
+    )]
+    pub struct Username(String);
+
+    #[test]
+    fn test_sort() {
+        let mut usernames: Vec<Username> = ["bob", "Alice", "carol", "Bob"]
+            .into_iter()
+            .map(Username::new)
+            .collect();
+        usernames.sort();
+        let usernames: Vec<String> = usernames.into_iter().map(Username::into_inner).collect();
+        assert_eq!(usernames, vec!["Alice", "bob", "Bob", "carol"]);
+    }
+
+    #[test]
+    fn test_ord_agrees_with_eq() {
+        let lower = Username::new("alice");
+        let upper = Username::new("ALICE");
+        assert_eq!(lower, upper);
+        assert_eq!(lower.cmp(&upper), Ordering::Equal);
+        assert_eq!(lower.partial_cmp(&upper), Some(Ordering::Equal));
+
+        let usernames: BTreeSet<Username> = ["alice", "Alice", "bob"]
+            .into_iter()
+            .map(Username::new)
+            .collect();
+        assert_eq!(usernames.len(), 2);
+    }
+
+    #[test]
+    fn test_partial_ord_only() {
+        #[nutype(
+            eq = case_insensitive,
+            ord = case_insensitive,
+            derive(Debug, PartialEq, PartialOrd)
+        )]
+        pub struct Tag(String);
+
+        assert!(Tag::new("apple") < Tag::new("Banana"));
+        assert!(Tag::new("Cherry") > Tag::new("banana"));
+        assert_eq!(
+            Tag::new("Apple").partial_cmp(&Tag::new("aPPLE")),
+            Some(Ordering::Equal)
+        );
+    }
+
+    #[test]
+    fn test_ord_inner() {
+        #[nutype(
+            eq = case_insensitive,
+            ord = case_insensitive,
+            derive(Debug, PartialEq, PartialOrd, OrdInner)
+        )]
+        pub struct Tag(String);
+
+        let tag = Tag::new("Banana");
+        assert!(tag > "apple");
+        assert!(tag < "CHERRY");
+        assert_eq!(tag.partial_cmp("bANANA"), Some(Ordering::Equal));
+        assert_eq!(tag, "banana");
+    }
+}
+
+#[cfg(feature = "new_unchecked")]
+mod new_unchecked {
+    use super::*;
+
+    #[test]
+    fn test_new_unchecked() {
+        #[nutype(new_unchecked, sanitize(trim), validate(len_char_min = 8))]
+        pub struct Name(String);
+
+        let name = unsafe { Name::new_unchecked(" boo ".to_string()) };
+        assert_eq!(name.into_inner(), " boo ");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "schemars08")]
+mod derive_schemars_json_schema {
+    use super::*;
+    use schemars::{schema_for, JsonSchema};
+
+    #[test]
+    fn test_json_schema_derive() {
+        #[nutype(derive(JsonSchema))]
+        pub struct CustomerIdentifier(String);
+
+        assert_eq!(CustomerIdentifier::schema_name(), "CustomerIdentifier");
+        // Make sure it compiles
+        let _schema = schema_for!(CustomerIdentifier);
+    }
+
+    #[test]
+    fn test_json_schema_description_from_doc_comment() {
+        /// Customer identifier.
+        ///
+        /// Used everywhere.
+        #[nutype(derive(JsonSchema))]
+        pub struct CustomerIdentifier(String);
+
+        let schema = schema_for!(CustomerIdentifier);
+        let metadata = schema.schema.metadata.unwrap();
+        assert_eq!(
+            metadata.description.as_deref(),
+            Some("Customer identifier.\n\nUsed everywhere.")
+        );
+    }
+
+    #[test]
+    fn test_json_schema_example() {
+        #[nutype(
+            sanitize(trim, uppercase),
+            validate(not_empty),
+            derive(JsonSchema),
+            schema_example = " abc-123 "
+        )]
+        pub struct CustomerIdentifier(String);
+
+        let schema = schema_for!(CustomerIdentifier);
+        let examples = schema.schema.metadata.unwrap().examples;
+        let examples: Vec<String> = examples.iter().map(|e| e.to_string()).collect();
+        assert_eq!(examples, vec!["\"ABC-123\""]);
+    }
+
+    #[test]
+    #[should_panic(expected = "Schema example for type `CustomerIdentifier` is invalid")]
+    fn test_json_schema_invalid_example() {
+        #[nutype(validate(not_empty), derive(JsonSchema), schema_example = "")]
+        pub struct CustomerIdentifier(String);
+
+        let _schema = schema_for!(CustomerIdentifier);
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "email")]
+mod validation_with_email {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim, lowercase),
+        validate(email),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Email(String);
+
+    #[test]
+    fn test_valid_email() {
+        let email = Email::new("  John.Doe+news@Example.COM ").unwrap();
+        assert_eq!(email.as_ref(), "john.doe+news@example.com");
+    }
+
+    #[test]
+    fn test_invalid_email() {
+        assert_eq!(Email::new("john.doe"), Err(EmailError::EmailViolated));
+        assert_eq!(Email::new("john@localhost"), Err(EmailError::EmailViolated));
+        assert_eq!(
+            Email::new("john..doe@example.com"),
+            Err(EmailError::EmailViolated)
+        );
+        assert_eq!(
+            Email::new("john@").unwrap_err().to_string(),
+            "Email is not a valid email address."
+        );
+    }
+
+    #[test]
+    fn test_email_with_other_validators() {
+        #[nutype(validate(email, ends_with = "@example.com"), derive(Debug, PartialEq))]
+        pub struct CorporateEmail(String);
+
+        assert!(CorporateEmail::new("john@example.com").is_ok());
+        assert_eq!(
+            CorporateEmail::new("john@gmail.com"),
+            Err(CorporateEmailError::EndsWithViolated)
+        );
+    }
+}
+
+#[cfg(feature = "url")]
+mod validation_with_url {
+    use super::*;
+
+    #[nutype(sanitize(trim), validate(url), derive(Debug, PartialEq, AsRef))]
+    pub struct Link(String);
+
+    #[nutype(
+        sanitize(trim),
+        validate(url(schemes = ["https"])),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct SecureLink(String);
+
+    #[test]
+    fn test_valid_url() {
+        let link = Link::new("  https://example.com/docs?page=2#intro \n").unwrap();
+        assert_eq!(link.as_ref(), "https://example.com/docs?page=2#intro");
+        assert!(Link::new("ftp://ftp.example.com/file.txt").is_ok());
+        assert!(Link::new("mailto:john@example.com").is_ok());
+    }
+
+    #[test]
+    fn test_invalid_url() {
+        assert_eq!(Link::new("example.com"), Err(LinkError::UrlViolated));
+        assert_eq!(Link::new("/docs/intro"), Err(LinkError::UrlViolated));
+        assert_eq!(Link::new("http://"), Err(LinkError::UrlViolated));
+        assert_eq!(Link::new("   "), Err(LinkError::UrlViolated));
+        assert_eq!(
+            Link::new("https://exa mple.com").unwrap_err().to_string(),
+            "Link is not a valid URL."
+        );
+    }
+
+    #[test]
+    fn test_allowed_scheme() {
+        assert!(SecureLink::new(" https://example.com ").is_ok());
+        // Schemes are case-insensitive
+        assert!(SecureLink::new("HTTPS://example.com").is_ok());
+    }
+
+    #[test]
+    fn test_disallowed_scheme() {
+        assert_eq!(
+            SecureLink::new("http://example.com"),
+            Err(SecureLinkError::UrlSchemeViolated)
+        );
+        assert_eq!(
+            SecureLink::new("javascript:alert(1)"),
+            Err(SecureLinkError::UrlSchemeViolated)
+        );
+        assert_eq!(
+            SecureLink::new("ftp://example.com")
+                .unwrap_err()
+                .to_string(),
+            r#"SecureLink has a URL scheme that is not one of the allowed schemes ["https"]."#
+        );
+    }
+
+    #[test]
+    fn test_invalid_url_with_schemes() {
+        assert_eq!(
+            SecureLink::new("example.com"),
+            Err(SecureLinkError::UrlViolated)
+        );
+    }
+
+    #[test]
+    fn test_multiple_schemes() {
+        #[nutype(validate(url(schemes = ["http", "HTTPS", "git+ssh"])), derive(Debug, PartialEq))]
+        pub struct RepoUrl(String);
+
+        assert!(RepoUrl::new("http://example.com/repo.git").is_ok());
+        assert!(RepoUrl::new("https://example.com/repo.git").is_ok());
+        assert!(RepoUrl::new("git+ssh://git@example.com/repo.git").is_ok());
+        assert_eq!(
+            RepoUrl::new("ssh://git@example.com/repo.git"),
+            Err(RepoUrlError::UrlSchemeViolated)
+        );
+    }
+
+    #[test]
+    fn test_url_with_other_validators() {
+        #[nutype(
+            validate(url(schemes = ["https"]), len_char_max = 32),
+            derive(Debug, PartialEq)
+        )]
+        pub struct ShortLink(String);
+
+        assert!(ShortLink::new("https://example.com").is_ok());
+        assert_eq!(
+            ShortLink::new("https://example.com/a/very/long/path"),
+            Err(ShortLinkError::LenCharMaxViolated)
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "unicode-segmentation")]
+mod validation_with_len_grapheme_max {
+    use super::*;
+
+    #[nutype(
+        sanitize(trim),
+        validate(len_grapheme_max = 5),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Bio(String);
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(Bio::new("Hello").unwrap().as_ref(), "Hello");
+        assert_eq!(Bio::new("Hello!"), Err(BioError::LenGraphemeMaxViolated));
+    }
+
+    #[test]
+    fn test_zwj_emoji_sequences() {
+        // Every family emoji is 5 chars joined with ZWJ, but a single grapheme.
+        let families = "👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧";
+        assert_eq!(families.chars().count(), 25);
+        assert_eq!(Bio::new(families).unwrap().as_ref(), families);
+
+        let families = "👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧👨‍👩‍👧";
+        assert_eq!(Bio::new(families), Err(BioError::LenGraphemeMaxViolated));
+    }
+
+    #[test]
+    fn test_flags_and_combining_marks() {
+        // Every flag consists of 2 regional indicator symbols.
+        assert!(Bio::new("🇺🇦🇺🇦🇺🇦🇺🇦🇺🇦").is_ok());
+        // "e" followed by a combining acute accent is a single grapheme.
+        assert!(Bio::new("cafe\u{301}!").is_ok());
+        assert_eq!(
+            Bio::new("cafe\u{301}!!"),
+            Err(BioError::LenGraphemeMaxViolated)
+        );
+    }
+
+    #[test]
+    fn test_together_with_len_char_max() {
+        #[nutype(
+            validate(len_grapheme_max = 2, len_char_max = 4),
+            derive(Debug, PartialEq)
+        )]
+        pub struct Tag(String);
+
+        assert!(Tag::new("🇺🇦🇺🇦").is_ok());
+        assert_eq!(Tag::new("👨‍👩‍👧"), Err(TagError::LenCharMaxViolated));
+        assert_eq!(Tag::new("abc"), Err(TagError::LenGraphemeMaxViolated));
+    }
+
+    #[test]
+    fn test_error_display() {
+        assert_eq!(
+            Bio::new("Hello, world").unwrap_err().to_string(),
+            "Bio is too long. The value length must not exceed 5 grapheme(s)."
+        );
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "regex")]
+mod validation_with_regex {
+    use super::*;
+    use lazy_static::lazy_static;
+    use once_cell::sync::Lazy;
+    use regex::Regex;
+
+    lazy_static! {
+        static ref PHONE_REGEX_LAZY_STATIC: Regex = Regex::new("^[0-9]{3}-[0-9]{3}$").unwrap();
+    }
+
+    static PHONE_REGEX_ONCE_CELL: Lazy<Regex> =
+        Lazy::new(|| Regex::new("[0-9]{3}-[0-9]{3}$").unwrap());
+
+    #[test]
+    fn test_regex_as_string() {
+        #[nutype(validate(regex = "^[0-9]{3}-[0-9]{3}$"), derive(Debug, PartialEq))]
+        pub struct PhoneNumber(String);
+
+        // PredicateViolated
+        assert_eq!(
+            PhoneNumber::new("123456"),
+            Err(PhoneNumberError::RegexViolated)
+        );
+
+        // Valid
+        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
+        assert_eq!(inner, "123-456".to_string());
+    }
+
+    #[nutype(validate(regex = "^[a-z]+-[0-9]+$"), derive(Debug))]
+    pub struct Slug(String);
+
+    #[test]
+    fn test_regex_as_string_is_applied_by_all_validations() {
+        // `new()`, `validate()` and `modify()` agree on the same regex for many values.
+        for i in 0..5_000 {
+            let raw = format!("item-{i}");
+            assert!(Slug::validate(&raw).is_ok());
+            let mut slug = Slug::new(raw).unwrap();
+            assert_eq!(
+                slug.modify(|s| s.push('!')).unwrap_err(),
+                SlugError::RegexViolated
+            );
+        }
+        assert_eq!(Slug::validate("item"), Err(SlugError::RegexViolated));
+    }
+
+    #[test]
+    fn test_regex_with_lazy_static() {
+        #[nutype(validate(regex = PHONE_REGEX_LAZY_STATIC), derive(Debug, PartialEq))]
+        pub struct PhoneNumber(String);
+
+        // PredicateViolated
+        assert_eq!(
+            PhoneNumber::new("123456"),
+            Err(PhoneNumberError::RegexViolated)
+        );
+
+        // Valid
+        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
+        assert_eq!(inner, "123-456".to_string());
+    }
+
+    #[test]
+    fn test_regex_with_once_cell_lazy() {
+        #[nutype(validate(regex = PHONE_REGEX_ONCE_CELL), derive(Debug, PartialEq))]
+        pub struct PhoneNumber(String);
+
+        // PredicateViolated
+        assert_eq!(
+            PhoneNumber::new("123456"),
+            Err(PhoneNumberError::RegexViolated)
+        );
+
+        // Valid
+        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
+        assert_eq!(inner, "123-456".to_string());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "rkyv")]
+mod derive_rkyv_archive {
+    use super::*;
+
+    #[test]
+    fn test_archive() {
+        #[nutype(derive(Debug, PartialEq, Archive), sanitize(trim), validate(not_empty))]
+        pub struct Name(String);
+
+        let bytes = rkyv::to_bytes::<rkyv::rancor::Error>(&Name::new(" Anna ").unwrap()).unwrap();
+
+        let archived = rkyv::access::<rkyv::Archived<Name>, rkyv::rancor::Error>(&bytes).unwrap();
+        assert_eq!(archived.as_str(), "Anna");
+
+        let name = rkyv::deserialize::<Name, rkyv::rancor::Error>(archived).unwrap();
+        assert_eq!(name, Name::new("Anna").unwrap());
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "sqlx")]
+mod derive_sqlx_json {
+    use super::*;
+    use sqlx::{Connection, SqliteConnection};
+
+    #[nutype(
+        derive(Debug, PartialEq, SqlxJson),
+        sanitize(trim),
+        validate(not_empty, len_char_max = 20)
+    )]
+    pub struct Username(String);
+
+    async fn prepare_connection() -> SqliteConnection {
+        let mut conn = SqliteConnection::connect("sqlite::memory:").await.unwrap();
+        sqlx::query("CREATE TABLE users (id INTEGER PRIMARY KEY, username JSON NOT NULL)")
+            .execute(&mut conn)
+            .await
+            .unwrap();
+        conn
+    }
+
+    #[tokio::test]
+    async fn test_sqlx_json_roundtrip() {
+        let mut conn = prepare_connection().await;
+
+        let username = Username::new("  alice ").unwrap();
+        sqlx::query("INSERT INTO users (id, username) VALUES (1, ?)")
+            .bind(&username)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        // The inner value is stored as JSON
+        let raw: String = sqlx::query_scalar("SELECT username FROM users WHERE id = 1")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(raw, r#""alice""#);
+
+        let loaded: Username = sqlx::query_scalar("SELECT username FROM users WHERE id = 1")
+            .fetch_one(&mut conn)
+            .await
+            .unwrap();
+        assert_eq!(loaded, username);
+    }
+
+    #[tokio::test]
+    async fn test_sqlx_json_decode_revalidates() {
+        let mut conn = prepare_connection().await;
+
+        sqlx::query(r#"INSERT INTO users (id, username) VALUES (1, '"   "')"#)
+            .execute(&mut conn)
+            .await
+            .unwrap();
+
+        let result: Result<Username, _> =
+            sqlx::query_scalar("SELECT username FROM users WHERE id = 1")
+                .fetch_one(&mut conn)
+                .await;
+        let err = result.unwrap_err();
+        assert!(err.to_string().contains("Username is empty."), "{err}");
+    }
+}
+
+#[cfg(test)]
+#[cfg(feature = "proptest")]
+mod derive_proptest_arbitrary {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[nutype(derive(Debug, ProptestArbitrary))]
+    pub struct Comment(String);
+
+    #[nutype(
+        validate(not_empty, len_char_max = 8),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct Nickname(String);
+
+    #[nutype(
+        validate(
+            len_char_min = 6,
+            len_char_max = 12,
+            starts_with = "SKU-",
+            contains = '-',
+            ends_with = "!"
+        ),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct Sku(String);
+
+    #[nutype(
+        validate(len_char_max = 16, predicate = |s| !s.contains('x')),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct NoX(String);
+
+    #[nutype(
+        validate(len_char_min = 1, len_char_max = 10, ascii, alphanumeric),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct Slug(String);
+
+    #[nutype(validate(alphanumeric), derive(Debug, ProptestArbitrary))]
+    pub struct Word(String);
+
+    #[nutype(
+        validate(one_of = ["USD", "EUR", "GBP"], len_char_max = 3),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct Currency(String);
+
+    #[nutype(
+        validate(
+            contains_only = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz",
+            len_char_min = 8,
+            len_char_max = 16
+        ),
+        derive(Debug, ProptestArbitrary)
+    )]
+    pub struct Base58Id(String);
+
+    proptest! {
+        #[test]
+        fn test_without_validation(comment: Comment) {
+            let _: String = comment.into_inner();
+        }
+
+        #[test]
+        fn test_respects_length_boundaries(nickname: Nickname) {
+            let len = nickname.into_inner().chars().count();
+            prop_assert!((1..=8).contains(&len));
+        }
+
+        #[test]
+        fn test_respects_prefix_needle_and_suffix(sku: Sku) {
+            let value = sku.into_inner();
+            let len = value.chars().count();
+            prop_assert!((6..=12).contains(&len), "{value:?}");
+            prop_assert!(value.starts_with("SKU-"));
+            prop_assert!(value.ends_with('!'));
+        }
+
+        #[test]
+        fn test_respects_predicate(no_x: NoX) {
+            prop_assert!(!no_x.into_inner().contains('x'));
+        }
+
+        #[test]
+        fn test_respects_ascii_and_alphanumeric(slug: Slug) {
+            let value = slug.into_inner();
+            prop_assert!(value.chars().all(|c| c.is_ascii_alphanumeric()), "{value:?}");
+        }
+
+        #[test]
+        fn test_respects_alphanumeric(word: Word) {
+            prop_assert!(word.into_inner().chars().all(char::is_alphanumeric));
+        }
+
+        #[test]
+        fn test_respects_one_of(currency: Currency) {
+            prop_assert!(["USD", "EUR", "GBP"].contains(&currency.into_inner().as_str()));
+        }
+
+        #[test]
+        fn test_respects_contains_only(id: Base58Id) {
+            let value = id.into_inner();
+            prop_assert!((8..=16).contains(&value.chars().count()));
+            prop_assert!(value.chars().all(|c| c.is_ascii_alphanumeric() && !"0OIl".contains(c)), "{value:?}");
+        }
+    }
+
+    #[cfg(feature = "regex")]
+    mod with_regex {
+        use super::*;
+
+        #[nutype(
+            validate(regex = "^[0-9]{3}-[0-9]{4}$"),
+            derive(Debug, ProptestArbitrary)
+        )]
+        pub struct PhoneNumber(String);
+
+        proptest! {
+            #[test]
+            fn test_respects_regex(phone_number: PhoneNumber) {
+                let value = phone_number.into_inner();
+                prop_assert_eq!(value.len(), 8);
+                prop_assert_eq!(&value[3..4], "-");
             }
         }
+    }
 
-        mod message_pack_format {
-            use super::*;
+    #[cfg(feature = "email")]
+    mod with_email {
+        use super::*;
+
+        #[nutype(validate(email), derive(Debug, ProptestArbitrary))]
+        pub struct Email(String);
 
+        proptest! {
             #[test]
-            fn test_rmp_roundtrip() {
-                #[nutype(derive(Serialize, Deserialize, PartialEq, Debug))]
-                pub struct Email(String);
+            fn test_respects_email(email: Email) {
+                prop_assert!(nutype::email::is_valid_email(&email.into_inner()));
+            }
+        }
+    }
 
-                let email = Email::new("me@example.com");
+    #[cfg(feature = "url")]
+    mod with_url {
+        use super::*;
 
-                let bytes = rmp_serde::to_vec(&email).unwrap();
-                let deserialized: Email = rmp_serde::from_slice(&bytes).unwrap();
+        #[nutype(
+            validate(url(schemes = ["https", "git+ssh"])),
+            derive(Debug, ProptestArbitrary)
+        )]
+        pub struct RepoUrl(String);
 
-                assert_eq!(deserialized, email);
+        proptest! {
+            #[test]
+            fn test_respects_url(repo_url: RepoUrl) {
+                let scheme = nutype::url::parse_url_scheme(&repo_url.into_inner());
+                prop_assert!(scheme == Some("https".to_string()) || scheme == Some("git+ssh".to_string()));
             }
         }
     }
 }
 
-#[cfg(feature = "new_unchecked")]
-mod new_unchecked {
+#[cfg(test)]
+#[cfg(feature = "rand")]
+mod derive_rand_distribution {
     use super::*;
+    use rand::{rngs::StdRng, Rng, SeedableRng};
 
     #[test]
-    fn test_new_unchecked() {
-        #[nutype(new_unchecked, sanitize(trim), validate(len_char_min = 8))]
+    fn test_without_validation() {
+        #[nutype(derive(Debug, RandDistribution))]
+        pub struct Note(String);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        let _: Note = rng.gen();
+    }
+
+    #[test]
+    fn test_respects_len_boundaries() {
+        #[nutype(
+            validate(len_char_min = 3, len_char_max = 10),
+            derive(Debug, RandDistribution)
+        )]
         pub struct Name(String);
 
-        let name = unsafe { Name::new_unchecked(" boo ".to_string()) };
-        assert_eq!(name.into_inner(), " boo ");
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let len = rng.gen::<Name>().into_inner().chars().count();
+            assert!((3..=10).contains(&len));
+        }
     }
-}
 
-#[cfg(test)]
-#[cfg(feature = "schemars08")]
-mod derive_schemars_json_schema {
-    use super::*;
-    use schemars::{schema_for, JsonSchema};
+    #[test]
+    fn test_respects_ascii_alphanumeric_and_affixes() {
+        #[nutype(
+            validate(alphanumeric, len_char_max = 20, starts_with = "sk", ends_with = "42"),
+            derive(Debug, RandDistribution)
+        )]
+        pub struct ApiKey(String);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..1000 {
+            let key = rng.gen::<ApiKey>().into_inner();
+            assert!(key.chars().count() <= 20);
+            assert!(key.starts_with("sk") && key.ends_with("42"));
+            assert!(key.chars().all(|c| c.is_ascii_alphanumeric()));
+        }
+    }
 
     #[test]
-    fn test_json_schema_derive() {
-        #[nutype(derive(JsonSchema))]
-        pub struct CustomerIdentifier(String);
+    fn test_respects_predicate() {
+        #[nutype(
+            validate(ascii, not_empty, len_char_max = 4, predicate = |s| !s.contains(' ')),
+            derive(Debug, RandDistribution)
+        )]
+        pub struct Code(String);
 
-        assert_eq!(CustomerIdentifier::schema_name(), "CustomerIdentifier");
-        // Make sure it compiles
-        let _schema = schema_for!(CustomerIdentifier);
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let code = rng.gen::<Code>().into_inner();
+            assert!(!code.is_empty() && code.is_ascii() && !code.contains(' '));
+        }
     }
-}
 
-#[cfg(test)]
-#[cfg(feature = "regex")]
-mod validation_with_regex {
-    use super::*;
-    use lazy_static::lazy_static;
-    use once_cell::sync::Lazy;
-    use regex::Regex;
+    #[test]
+    fn test_respects_contains_only() {
+        #[nutype(
+            validate(contains_only = "ACGT", not_empty, len_char_max = 12),
+            derive(Debug, RandDistribution)
+        )]
+        pub struct Dna(String);
 
-    lazy_static! {
-        static ref PHONE_REGEX_LAZY_STATIC: Regex = Regex::new("^[0-9]{3}-[0-9]{3}$").unwrap();
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let dna = rng.gen::<Dna>().into_inner();
+            assert!((1..=12).contains(&dna.len()));
+            assert!(dna.chars().all(|c| "ACGT".contains(c)));
+        }
     }
 
-    static PHONE_REGEX_ONCE_CELL: Lazy<Regex> =
-        Lazy::new(|| Regex::new("[0-9]{3}-[0-9]{3}$").unwrap());
+    #[test]
+    fn test_picks_one_of() {
+        #[nutype(validate(one_of = ["USD", "EUR"]), derive(Debug, RandDistribution))]
+        pub struct Currency(String);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let currency = rng.gen::<Currency>().into_inner();
+            assert!(currency == "USD" || currency == "EUR");
+        }
+    }
 
+    #[cfg(feature = "email")]
     #[test]
-    fn test_regex_as_string() {
-        #[nutype(validate(regex = "^[0-9]{3}-[0-9]{3}$"), derive(Debug, PartialEq))]
-        pub struct PhoneNumber(String);
+    fn test_generates_emails() {
+        #[nutype(validate(email), derive(Debug, RandDistribution))]
+        pub struct Email(String);
 
-        // PredicateViolated
-        assert_eq!(
-            PhoneNumber::new("123456"),
-            Err(PhoneNumberError::RegexViolated)
-        );
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let email = rng.gen::<Email>().into_inner();
+            assert!(nutype::email::is_valid_email(&email));
+        }
+    }
 
-        // Valid
-        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
-        assert_eq!(inner, "123-456".to_string());
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_generates_urls() {
+        #[nutype(validate(url(schemes = ["https"])), derive(Debug, RandDistribution))]
+        pub struct SecureLink(String);
+
+        let mut rng = StdRng::seed_from_u64(42);
+        for _ in 0..100 {
+            let link = rng.gen::<SecureLink>().into_inner();
+            assert_eq!(
+                nutype::url::parse_url_scheme(&link),
+                Some("https".to_string())
+            );
+        }
     }
+}
+
+#[cfg(test)]
+#[cfg(feature = "redis")]
+mod derive_redis {
+    use super::*;
+    use redis::{FromRedisValue, ToRedisArgs, Value};
 
     #[test]
-    fn test_regex_with_lazy_static() {
-        #[nutype(validate(regex = PHONE_REGEX_LAZY_STATIC), derive(Debug, PartialEq))]
-        pub struct PhoneNumber(String);
+    fn test_round_trip() {
+        #[nutype(
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, PartialEq, ToRedisArgs, FromRedisValue)
+        )]
+        pub struct CacheKey(String);
 
-        // PredicateViolated
-        assert_eq!(
-            PhoneNumber::new("123456"),
-            Err(PhoneNumberError::RegexViolated)
-        );
+        let key = CacheKey::new("user:42").unwrap();
+        assert_eq!(key.to_redis_args(), vec![b"user:42".to_vec()]);
 
-        // Valid
-        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
-        assert_eq!(inner, "123-456".to_string());
+        let value = Value::BulkString(b" user:42 ".to_vec());
+        assert_eq!(CacheKey::from_redis_value(&value).unwrap(), key);
+
+        let value = Value::BulkString(b"  ".to_vec());
+        assert!(CacheKey::from_redis_value(&value).is_err());
     }
+}
+
+#[cfg(test)]
+#[cfg(feature = "bincode")]
+mod derive_bincode {
+    use super::*;
 
     #[test]
-    fn test_regex_with_once_cell_lazy() {
-        #[nutype(validate(regex = PHONE_REGEX_ONCE_CELL), derive(Debug, PartialEq))]
-        pub struct PhoneNumber(String);
+    fn test_round_trip() {
+        #[nutype(
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, PartialEq, Encode, Decode)
+        )]
+        pub struct Username(String);
 
-        // PredicateViolated
-        assert_eq!(
-            PhoneNumber::new("123456"),
-            Err(PhoneNumberError::RegexViolated)
-        );
+        let config = bincode::config::standard();
+        let username = Username::new("  alice ").unwrap();
+        let bytes = bincode::encode_to_vec(&username, config).unwrap();
+        assert_eq!(bytes, bincode::encode_to_vec("alice", config).unwrap());
 
-        // Valid
-        let inner = PhoneNumber::new("123-456").unwrap().into_inner();
-        assert_eq!(inner, "123-456".to_string());
+        let (decoded, _): (Username, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, username);
+
+        let (decoded, _): (Username, usize) =
+            bincode::borrow_decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded, username);
+    }
+
+    #[test]
+    fn test_decode_applies_sanitizers_and_validation() {
+        #[nutype(sanitize(trim), validate(not_empty), derive(Debug, PartialEq, Decode))]
+        pub struct Username(String);
+
+        let config = bincode::config::standard();
+
+        let bytes = bincode::encode_to_vec(" bob ", config).unwrap();
+        let (decoded, _): (Username, usize) = bincode::decode_from_slice(&bytes, config).unwrap();
+        assert_eq!(decoded.into_inner(), "bob");
+
+        let bytes = bincode::encode_to_vec("   ", config).unwrap();
+        let err = bincode::decode_from_slice::<Username, _>(&bytes, config).unwrap_err();
+        assert!(matches!(
+            err,
+            bincode::error::DecodeError::OtherString(msg) if msg == "Username is empty. Expected valid Username"
+        ));
     }
 }
 
@@ -694,4 +3364,282 @@ mod derive_diesel_derive_newtype {
 
         assert_eq!(inserted_obj.value.into_inner(), "some text");
     }
+
+    #[test]
+    fn test_diesel_derive_newtype_with_validation() {
+        table! {
+            diesel_derive_newtype_validation_test {
+                id -> Integer,
+                value -> Text,
+            }
+        }
+        let mut conn =
+            prepare_database_table_for_type!(diesel_derive_newtype_validation_test, "TEXT");
+
+        #[nutype(
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, PartialEq, TryFrom, DieselNewType)
+        )]
+        pub struct Username(String);
+
+        #[derive(Debug, Insertable, Queryable, Selectable)]
+        #[diesel(table_name = diesel_derive_newtype_validation_test)]
+        pub struct DieselDeriveNewtypeValidationTest {
+            pub id: i32,
+            pub value: Username,
+        }
+
+        diesel::sql_query(
+            "INSERT INTO diesel_derive_newtype_validation_test VALUES (1, ' alice '), (2, '  ')",
+        )
+        .execute(&mut conn)
+        .expect("Could not insert raw values into database table");
+
+        let objs: Vec<diesel::QueryResult<DieselDeriveNewtypeValidationTest>> =
+            diesel_derive_newtype_validation_test::table
+                .select(DieselDeriveNewtypeValidationTest::as_select())
+                .order(diesel_derive_newtype_validation_test::id)
+                .load_iter(&mut conn)
+                .expect("Could not query database table")
+                .collect();
+
+        assert_eq!(
+            objs[0].as_ref().unwrap().value,
+            Username::new("alice").unwrap()
+        );
+        assert!(objs[1].is_err());
+    }
+}
+
+#[cfg(test)]
+mod protobuf_message_conversion {
+    use super::*;
+
+    // The shape of a struct generated by `prost` for:
+    //
+    //     message User {
+    //       string username = 1;
+    //     }
+    #[derive(Debug, Clone, PartialEq, Default)]
+    pub struct UserMessage {
+        pub username: String,
+    }
+
+    #[nutype(
+        sanitize(trim),
+        validate(not_empty),
+        derive(Debug, Clone, PartialEq, Into, TryFrom)
+    )]
+    pub struct Username(String);
+
+    #[derive(Debug, PartialEq)]
+    pub struct User {
+        pub username: Username,
+    }
+
+    impl TryFrom<UserMessage> for User {
+        type Error = UsernameError;
+
+        fn try_from(message: UserMessage) -> Result<Self, Self::Error> {
+            Ok(Self {
+                username: message.username.try_into()?,
+            })
+        }
+    }
+
+    impl From<User> for UserMessage {
+        fn from(user: User) -> Self {
+            Self {
+                username: user.username.into(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_message_to_domain_and_back() {
+        let message = UserMessage {
+            username: " ferris ".to_string(),
+        };
+        let user = User::try_from(message).unwrap();
+        assert_eq!(user.username.clone().into_inner(), "ferris");
+
+        let message = UserMessage::from(user);
+        assert_eq!(
+            message,
+            UserMessage {
+                username: "ferris".to_string(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_invalid_message() {
+        // Unset string fields are decoded as empty strings
+        let message = UserMessage::default();
+        assert_eq!(
+            User::try_from(message),
+            Err(UsernameError::NotEmptyViolated)
+        );
+    }
+}
+
+#[cfg(test)]
+mod bound_consts {
+    use super::*;
+
+    #[test]
+    fn test_len_char_min_and_max() {
+        #[nutype(validate(len_char_min = 3, len_char_max = 20))]
+        pub struct Username(String);
+
+        assert_eq!(Username::MIN_LEN, Some(3));
+        assert_eq!(Username::MAX_LEN, Some(20));
+    }
+
+    #[test]
+    fn test_len_char_range() {
+        #[nutype(validate(len_char = 2..10))]
+        pub struct Code(String);
+
+        assert_eq!(Code::MIN_LEN, Some(2));
+        assert_eq!(Code::MAX_LEN, Some(9));
+    }
+
+    #[test]
+    fn test_not_empty() {
+        #[nutype(validate(not_empty, len_char_max = 255))]
+        pub struct Title(String);
+
+        assert_eq!(Title::MIN_LEN, Some(1));
+        assert_eq!(Title::MAX_LEN, Some(255));
+    }
+
+    #[test]
+    fn test_without_length_validators() {
+        #[nutype(sanitize(trim), validate(ascii))]
+        pub struct Slug(String);
+
+        assert_eq!(Slug::MIN_LEN, None);
+        assert_eq!(Slug::MAX_LEN, None);
+
+        #[nutype(sanitize(trim))]
+        pub struct Note(String);
+
+        assert_eq!(Note::MIN_LEN, None);
+        assert_eq!(Note::MAX_LEN, None);
+    }
+}
+
+#[cfg(test)]
+mod explicit_inner_type {
+    use super::*;
+
+    mod text {
+        pub use std::string::String as Text;
+    }
+
+    #[test]
+    fn test_re_exported_type() {
+        #[nutype(
+            inner = String,
+            sanitize(trim),
+            validate(not_empty),
+            derive(Debug, PartialEq, AsRef, Display)
+        )]
+        pub struct Title(text::Text);
+
+        // The string specific sanitizers, validators and traits are available.
+        let title = Title::new(" Dune ").unwrap();
+        assert_eq!(title.as_ref(), "Dune");
+        assert_eq!(title.to_string(), "Dune");
+        assert_eq!(Title::new("  "), Err(TitleError::NotEmptyViolated));
+    }
+
+    #[test]
+    fn test_full_path_in_any_position() {
+        #[nutype(validate(len_char_max = 5), inner = String, derive(Debug))]
+        pub struct Code(::std::string::String);
+
+        assert_eq!(Code::new("ABC").unwrap().into_inner(), "ABC");
+        assert_eq!(
+            Code::new("ABCDEF").unwrap_err(),
+            CodeError::LenCharMaxViolated
+        );
+    }
+}
+
+#[cfg(feature = "async")]
+mod new_async {
+    use super::*;
+
+    async fn is_not_taken(username: &str) -> Result<(), String> {
+        match username {
+            "admin" | "root" => Err(format!("`{username}` is already taken")),
+            _ => Ok(()),
+        }
+    }
+
+    #[nutype(
+        sanitize(trim, lowercase),
+        validate(not_empty, len_char_max = 20, async_with = is_not_taken),
+        derive(Debug, PartialEq, AsRef)
+    )]
+    pub struct Username(String);
+
+    #[tokio::test]
+    async fn test_new_async() {
+        let username = Username::new_async("  Alice ").await.unwrap();
+        assert_eq!(username.as_ref(), "alice");
+    }
+
+    #[tokio::test]
+    async fn test_new_async_regular_validation() {
+        let err = Username::new_async("   ").await.unwrap_err();
+        assert_eq!(
+            err,
+            UsernameAsyncError::Validate(UsernameError::NotEmptyViolated)
+        );
+        assert_eq!(err.to_string(), "Username is empty.");
+    }
+
+    #[tokio::test]
+    async fn test_new_async_async_validation() {
+        // The async validator receives the sanitized value.
+        let err = Username::new_async(" ROOT ").await.unwrap_err();
+        assert_eq!(
+            err,
+            UsernameAsyncError::AsyncWithViolated("`root` is already taken".to_string())
+        );
+        assert_eq!(
+            err.to_string(),
+            "Username is invalid: `root` is already taken"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validators_are_awaited_in_order() {
+        #[nutype(
+            validate(
+                async_with = |s| async move {
+                    if s.contains(' ') { Err("has spaces".to_string()) } else { Ok(()) }
+                },
+                async_with = |s| async move {
+                    if s.len() > 3 { Err("too long".to_string()) } else { Ok(()) }
+                },
+            ),
+            derive(Debug)
+        )]
+        pub struct Tag(String);
+
+        assert_eq!(Tag::new_async("abc").await.unwrap().into_inner(), "abc");
+        assert_eq!(
+            Tag::new_async("a b c d").await.unwrap_err(),
+            TagAsyncError::AsyncWithViolated("has spaces".to_string())
+        );
+        assert_eq!(
+            Tag::new_async("abcd").await.unwrap_err(),
+            TagAsyncError::AsyncWithViolated("too long".to_string())
+        );
+    }
 }
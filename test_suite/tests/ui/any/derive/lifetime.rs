@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug, TryFrom))]
+pub struct Name<'a>(&'a str);
+
+fn main () {}
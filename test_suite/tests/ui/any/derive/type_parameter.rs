@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug, Into))]
+pub struct Meters<T>(T);
+
+fn main () {}
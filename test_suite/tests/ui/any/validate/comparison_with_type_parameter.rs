@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater_or_equal = 0.0), derive(Debug))]
+pub struct Meters<T: PartialOrd<f64>>(T);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = (0, 0), greater_or_equal = (1, 0)))]
+pub struct Pair((i32, i32));
+
+fn main () {}
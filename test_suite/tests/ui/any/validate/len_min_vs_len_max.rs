@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_min = 10, len_max = 5))]
+pub struct Payload(Vec<u8>);
+
+fn main () {}
@@ -0,0 +1,14 @@
+use nutype::nutype;
+
+pub struct Config {
+    max_len: usize,
+}
+
+#[nutype(
+    context = Config,
+    validate(predicate = |name: &str, config: &Config| name.len() <= config.max_len),
+    builder,
+)]
+pub struct Name(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug))]
+pub struct Wrapper<const N: usize>([u8; N]);
+
+fn main () {}
@@ -0,0 +1,14 @@
+use nutype::nutype;
+
+pub struct Settings {
+    pub max_len: usize,
+}
+
+#[nutype(
+    context = Settings,
+    validate(predicate = |s, settings| s.len() <= settings.max_len),
+    derive(Debug, TryFrom),
+)]
+pub struct Name(String);
+
+fn main () {}
@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+pub struct Settings;
+
+#[nutype(context = Settings, sanitize(trim))]
+pub struct Name(String);
+
+fn main () {}
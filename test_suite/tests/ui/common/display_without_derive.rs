@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug), display = "User #{}")]
+pub struct UserId(u64);
+
+fn main () {}
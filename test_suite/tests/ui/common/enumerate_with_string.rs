@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(enumerate, validate(len_char_max = 5))]
+pub struct Code(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 0), derive(Debug), from_env)]
+pub struct Port(u16);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(inner = String, validate(not_empty), inner = String)]
+pub struct Name(String);
+
+fn main () {}
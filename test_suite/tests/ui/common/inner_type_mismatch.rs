@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(inner = u32, validate(less = 150))]
+pub struct Age(u64);
+
+fn main () {}
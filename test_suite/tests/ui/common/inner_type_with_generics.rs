@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(inner = Vec<T>, validate(not_empty))]
+pub struct NonEmpty<T>(Vec<T>);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty))]
+pub struct Name<'a>(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(with = |n: u8| n.min(99)), validate(raw(less = 100)))]
+pub struct Percentage(u8);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(repr_transparent, validate(not_empty))]
+pub struct Username(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize_then_return_changed, validate(not_empty))]
+pub struct Username(String);
+
+fn main () {}
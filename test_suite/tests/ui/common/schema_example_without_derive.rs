@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug), schema_example = 42)]
+pub struct UserId(u64);
+
+fn main () {}
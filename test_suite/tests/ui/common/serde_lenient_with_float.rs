@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(serde_lenient, derive(Debug, Clone))]
+pub struct Price(f64);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(serde_lenient, derive(Debug, Clone))]
+pub struct Age(u8);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(serde_repr = object, derive(Debug, Clone))]
+pub struct Login(String);
+
+fn main () {}
@@ -0,0 +1,8 @@
+use nutype::nutype;
+
+#[nutype(skip_new, validate(greater = 0), derive(TryFrom))]
+pub struct Amount(u32);
+
+fn main () {
+    let _amount = Amount::new(5);
+}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(skip_new, sanitize_then_return_changed, sanitize(trim), derive(From))]
+pub struct Name(String);
+
+fn main () {}
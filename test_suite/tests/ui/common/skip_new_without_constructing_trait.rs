@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(skip_new, validate(greater = 0), derive(Debug, PartialEq))]
+pub struct Amount(u32);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Clone), transparent_debug)]
+pub struct Username(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug))]
+pub struct Meters<T>(f64);
+
+fn main () {}
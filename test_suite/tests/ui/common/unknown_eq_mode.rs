@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(eq = ascii_case_insensitive, derive(Debug, PartialEq))]
+pub struct Login(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(serde_repr = tagged, derive(Debug))]
+pub struct Login(String);
+
+fn main () {}
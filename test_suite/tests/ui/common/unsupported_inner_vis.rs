@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(inner_vis = pub(super))]
+pub struct Username(String);
+
+fn main () {}
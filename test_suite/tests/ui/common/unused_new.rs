@@ -0,0 +1,10 @@
+#![deny(unused_must_use)]
+
+use nutype::nutype;
+
+#[nutype(validate(not_empty))]
+pub struct Username(String);
+
+fn main () {
+    Username::new("");
+}
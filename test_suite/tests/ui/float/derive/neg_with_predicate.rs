@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(predicate = |t| *t > -273.15), derive(Neg))]
+pub struct Celsius(f64);
+
+fn main () {}
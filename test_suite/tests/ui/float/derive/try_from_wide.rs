@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Debug, TryFromWide))]
+pub struct Distance(f64);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(eq = case_insensitive, derive(Debug, PartialEq))]
+pub struct Amount(u32);
+
+fn main () {}
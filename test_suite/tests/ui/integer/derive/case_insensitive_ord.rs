@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(ord = case_insensitive, derive(Debug, PartialEq, PartialOrd))]
+pub struct Amount(i32);
+
+fn main () {}
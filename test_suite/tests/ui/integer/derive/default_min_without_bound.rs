@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(less_or_equal = 20), default = min, derive(Default))]
+pub struct Amount(u32);
+
+fn main () {}
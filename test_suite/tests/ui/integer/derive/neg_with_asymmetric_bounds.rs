@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater_or_equal = -10, less_or_equal = 100), derive(Neg))]
+pub struct Offset(i32);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Neg))]
+pub struct Count(u32);
+
+fn main () {}
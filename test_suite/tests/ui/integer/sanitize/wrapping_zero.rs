@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(wrapping = 0))]
+pub struct Degree(i32);
+
+fn main () {}
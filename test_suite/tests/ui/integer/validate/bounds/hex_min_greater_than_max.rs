@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater_or_equal = 0xFF, less_or_equal = 0x0F))]
+pub struct Mask(u8);
+
+fn main () {}
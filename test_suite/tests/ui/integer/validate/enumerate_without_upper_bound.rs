@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(enumerate, validate(greater_or_equal = 1))]
+pub struct Count(u32);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(less_or_equal = 1.5))]
+pub struct Ratio(u8);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(greater = 300))]
+pub struct Level(u8);
+
+fn main () {}
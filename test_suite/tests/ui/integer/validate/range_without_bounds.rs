@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(range = ..))]
+pub struct Percent(u8);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(step = 0))]
+pub struct Price(u32);
+
+fn main () {}
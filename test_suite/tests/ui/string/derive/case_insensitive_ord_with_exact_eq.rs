@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(ord = case_insensitive, derive(Debug, PartialEq, Eq, PartialOrd, Ord))]
+pub struct Username(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(eq = case_insensitive, ord = case_insensitive, derive(Debug, PartialEq))]
+pub struct Username(String);
+
+fn main () {}
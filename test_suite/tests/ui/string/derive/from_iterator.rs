@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    validate(not_empty),
+    derive(FromIterator),
+)]
+struct Name(String);
+
+fn main() {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(eq = case_insensitive, derive(Debug, PartialEq, Eq, OrdInner))]
+pub struct Login(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(derive(Sum))]
+pub struct Name(String);
+
+fn main () {}
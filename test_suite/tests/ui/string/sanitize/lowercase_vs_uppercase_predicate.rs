@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    sanitize(lowercase),
+    validate(not_empty, predicate = |s| s.chars().all(|c| c.is_ascii_uppercase())),
+)]
+pub struct Tag(String);
+
+fn main () {}
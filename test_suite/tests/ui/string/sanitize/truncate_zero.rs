@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(truncate(0)))]
+pub struct Code(String);
+
+fn main () {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[nutype(
+    sanitize(trim, uppercase),
+    validate(predicate = |s| s.chars().all(char::is_lowercase)),
+)]
+pub struct Code(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(all_of()))]
+pub struct Name(String);
+
+fn main () {}
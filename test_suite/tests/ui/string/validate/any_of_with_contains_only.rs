@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(any_of(starts_with = "0x", contains_only = "0123456789")))]
+pub struct Number(String);
+
+fn main () {}
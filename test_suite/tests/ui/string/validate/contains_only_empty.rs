@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(contains_only = ""))]
+pub struct Code(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_char = 20))]
+pub struct Login(String);
+
+fn main () {}
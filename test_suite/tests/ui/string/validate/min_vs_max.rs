@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(min = "v2.0", max = "v1.9"))]
+pub struct Version(String);
+
+fn main () {}
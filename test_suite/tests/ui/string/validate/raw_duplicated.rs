@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(sanitize(trim), validate(raw(len_char_max = 100), len_char_max = 20))]
+pub struct Title(String);
+
+fn main () {}
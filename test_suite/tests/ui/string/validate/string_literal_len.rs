@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_char_max = "ten"))]
+pub struct Name(String);
+
+fn main () {}
@@ -0,0 +1,15 @@
+use nutype::nutype;
+use std::ops::Add;
+
+#[nutype(validate(greater = 0.0), derive(Zero))]
+pub struct Weight(f64);
+
+impl Add for Weight {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.into_inner() + other.into_inner()).unwrap()
+    }
+}
+
+fn main () {}
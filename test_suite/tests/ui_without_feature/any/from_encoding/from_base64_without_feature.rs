@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(from_base64, derive(Debug))]
+pub struct PublicKey(Vec<u8>);
+
+fn main () {}
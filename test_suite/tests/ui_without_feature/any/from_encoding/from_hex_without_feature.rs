@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(from_hex, validate(len_min = 32, len_max = 32))]
+pub struct Signature(Vec<u8>);
+
+fn main () {}
@@ -0,0 +1,9 @@
+use nutype::nutype;
+
+#[derive(Debug, PartialEq, PartialOrd)]
+pub struct NaiveDate(i64);
+
+#[nutype(validate(greater_or_equal = "2024-01-01"), derive(Debug))]
+pub struct Since2024(NaiveDate);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(not_empty, async_with = |s| async move { Ok(()) }))]
+pub struct Username(String);
+
+fn main () {}
@@ -0,0 +1,6 @@
+use nutype::nutype;
+
+#[nutype(validate(len_grapheme_max = 160))]
+pub struct Bio(String);
+
+fn main () {}